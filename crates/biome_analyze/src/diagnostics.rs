@@ -154,11 +154,7 @@ pub struct SuppressionDiagnostic {
 }
 
 impl SuppressionDiagnostic {
-    pub(crate) fn new(
-        category: &'static Category,
-        range: TextRange,
-        message: impl Display,
-    ) -> Self {
+    pub fn new(category: &'static Category, range: TextRange, message: impl Display) -> Self {
         Self {
             category,
             range,
@@ -182,9 +178,33 @@ pub enum RuleError {
     ReplacedRootWithNonRootError {
         rule_name: Option<(Cow<'static, str>, Cow<'static, str>)>,
     },
+    /// The suppression comment added by the rule with the specified name doesn't suppress its diagnostic.
+    IneffectiveSuppressionError {
+        rule_name: Option<(Cow<'static, str>, Cow<'static, str>)>,
+    },
+    /// The diagnostic of the rule with the specified name can't be suppressed, because the file doesn't allow comments.
+    UnsupportedSuppressionError {
+        rule_name: Option<(Cow<'static, str>, Cow<'static, str>)>,
+    },
 }
 
-impl Diagnostic for RuleError {}
+impl Diagnostic for RuleError {
+    fn severity(&self) -> Severity {
+        match self {
+            // The diagnostic is still reported, it's only left unsuppressed
+            RuleError::UnsupportedSuppressionError { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    fn description(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, fmt)
+    }
+
+    fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
+        biome_console::fmt::Display::fmt(self, fmt)
+    }
+}
 
 impl std::fmt::Display for RuleError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -203,6 +223,34 @@ impl std::fmt::Display for RuleError {
                     "a code action replaced the root of the file with a non-root node."
                 )
             }
+            RuleError::IneffectiveSuppressionError {
+                rule_name: Some((group, rule)),
+            } => {
+                std::write!(
+                    fmt,
+                    "the suppression comment added for the rule '{group}/{rule}' doesn't suppress its diagnostic."
+                )
+            }
+            RuleError::IneffectiveSuppressionError { rule_name: None } => {
+                std::write!(
+                    fmt,
+                    "a suppression comment doesn't suppress its diagnostic."
+                )
+            }
+            RuleError::UnsupportedSuppressionError {
+                rule_name: Some((group, rule)),
+            } => {
+                std::write!(
+                    fmt,
+                    "the diagnostic of the rule '{group}/{rule}' can't be suppressed, because the file doesn't allow comments."
+                )
+            }
+            RuleError::UnsupportedSuppressionError { rule_name: None } => {
+                std::write!(
+                    fmt,
+                    "the diagnostic can't be suppressed, because the file doesn't allow comments."
+                )
+            }
         }
     }
 }
@@ -224,6 +272,34 @@ impl biome_console::fmt::Display for RuleError {
                     "a code action replaced the root of the file with a non-root node."
                 )
             }
+            RuleError::IneffectiveSuppressionError {
+                rule_name: Some((group, rule)),
+            } => {
+                std::write!(
+                    fmt,
+                    "the suppression comment added for the rule '{group}/{rule}' doesn't suppress its diagnostic."
+                )
+            }
+            RuleError::IneffectiveSuppressionError { rule_name: None } => {
+                std::write!(
+                    fmt,
+                    "a suppression comment doesn't suppress its diagnostic."
+                )
+            }
+            RuleError::UnsupportedSuppressionError {
+                rule_name: Some((group, rule)),
+            } => {
+                std::write!(
+                    fmt,
+                    "the diagnostic of the rule '{group}/{rule}' can't be suppressed, because the file doesn't allow comments."
+                )
+            }
+            RuleError::UnsupportedSuppressionError { rule_name: None } => {
+                std::write!(
+                    fmt,
+                    "the diagnostic can't be suppressed, because the file doesn't allow comments."
+                )
+            }
         }
     }
 }
@@ -11,6 +11,7 @@ pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -22,6 +23,7 @@ mod visitor;
 
 // Re-exported for use in the `declare_group` macro
 pub use biome_diagnostics::category_concat;
+pub use biome_suppression::SuppressionScope;
 
 pub use crate::categories::{
     ActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder, RuleCategory,
@@ -30,6 +32,7 @@ pub use crate::categories::{
 pub use crate::diagnostics::{AnalyzerDiagnostic, RuleError, SuppressionDiagnostic};
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
 pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+pub use crate::profiling::{Profile, Profiler, RuleProfile};
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
@@ -45,7 +48,9 @@ pub use crate::signals::{
 };
 pub use crate::syntax::{Ast, SyntaxVisitor};
 pub use crate::visitor::{NodeVisitor, Visitor, VisitorContext, VisitorFinishContext};
-pub use suppression_action::{ApplySuppression, SuppressionAction};
+pub use suppression_action::{
+    merge_leading_suppression, merge_suppression_in_trivia, ApplySuppression, SuppressionAction,
+};
 
 use biome_console::markup;
 use biome_diagnostics::{
@@ -118,7 +123,15 @@ where
         self.phases.entry(phase).or_default().push(visitor);
     }
 
-    pub fn run(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
+    pub fn run(self, ctx: AnalyzerContext<L>) -> Option<Break> {
+        let options = ctx.options;
+        let start = profiling::start(options);
+        let result = self.run_phases(ctx);
+        profiling::record_file(options, start);
+        result
+    }
+
+    fn run_phases(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
         let Self {
             phases,
             metadata,
@@ -130,6 +143,7 @@ where
 
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let mut range_suppressions = Vec::new();
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -141,6 +155,7 @@ where
                 parse_suppression_comment,
                 line_index: &mut line_index,
                 line_suppressions: &mut line_suppressions,
+                range_suppressions: &mut range_suppressions,
                 emit_signal: &mut emit_signal,
                 root: &ctx.root,
                 services: &ctx.services,
@@ -173,15 +188,45 @@ where
             }
         }
 
-        for suppression in line_suppressions {
-            if suppression.did_suppress_signal {
+        // A range suppression without a `biome-ignore-end` comment extends to
+        // the end of the file, which is most likely a mistake
+        for suppression in &range_suppressions {
+            if !suppression.is_open() || !range_match(ctx.range, suppression.comment_span) {
+                continue;
+            }
+
+            let comment_span = suppression.comment_span;
+            let signal = DiagnosticSignal::new(move || {
+                SuppressionDiagnostic::new(
+                    category!("suppressions/incorrect"),
+                    comment_span,
+                    "This suppression opens a range that is never closed, add a matching biome-ignore-end comment after the code to suppress",
+                )
+            });
+
+            if let ControlFlow::Break(br) = (emit_signal)(&signal) {
+                return Some(br);
+            }
+        }
+
+        let line_suppressions = line_suppressions
+            .into_iter()
+            .map(|suppression| (suppression.comment_span, suppression.did_suppress_signal));
+        // The unclosed ranges were already reported as incorrect
+        let range_suppressions = range_suppressions
+            .into_iter()
+            .filter(|suppression| !suppression.is_open())
+            .map(|suppression| (suppression.comment_span, suppression.did_suppress_signal));
+
+        for (comment_span, did_suppress_signal) in line_suppressions.chain(range_suppressions) {
+            if did_suppress_signal {
                 continue;
             }
 
             let signal = DiagnosticSignal::new(|| {
                 SuppressionDiagnostic::new(
                     category!("suppressions/unused"),
-                    suppression.comment_span,
+                    comment_span,
                     "Suppression comment is not being used",
                 )
             });
@@ -215,6 +260,8 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
     line_suppressions: &'phase mut Vec<LineSuppression>,
+    /// Track file-level and range suppression comments, ordered by position
+    range_suppressions: &'phase mut Vec<RangeSuppression>,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: &'phase mut SignalHandler<'analyzer, L, Break>,
     /// Root node of the file being analyzed
@@ -249,6 +296,70 @@ struct LineSuppression {
     did_suppress_signal: bool,
 }
 
+/// Single entry for a `biome-ignore-all` or `biome-ignore-start` comment in
+/// the `range_suppressions` buffer
+#[derive(Debug)]
+struct RangeSuppression {
+    /// Either [SuppressionScope::File] or [SuppressionScope::RangeStart]
+    scope: SuppressionScope,
+    /// Range of source text covered by the suppression comment
+    comment_span: TextRange,
+    /// Start of the source text this comment is suppressing lint rules for
+    start: TextSize,
+    /// End of the source text this comment is suppressing lint rules for, or
+    /// `None` if the suppression extends to the end of the file
+    end: Option<TextSize>,
+    /// Set to true if this comment suppresses all the lint rules
+    suppress_all: bool,
+    /// List of all the rules this comment is suppressing
+    suppressed_rules: Vec<RuleFilter<'static>>,
+    /// List of all the rule instances this comment is suppressing
+    suppressed_instances: Vec<(RuleFilter<'static>, String)>,
+    /// Set to `true` when a signal matching this suppression was emitted and
+    /// suppressed
+    did_suppress_signal: bool,
+}
+
+impl RangeSuppression {
+    /// Returns true if this is a range suppression still waiting for its
+    /// `biome-ignore-end` comment
+    fn is_open(&self) -> bool {
+        self.scope == SuppressionScope::RangeStart && self.end.is_none()
+    }
+
+    /// Returns true if the source text at `offset` is covered by this suppression
+    fn covers(&self, offset: TextSize) -> bool {
+        self.start <= offset && self.end.map_or(true, |end| offset < end)
+    }
+}
+
+/// Returns true if a suppression comment disabling the given rules applies to
+/// the signal in `entry`
+fn is_suppressed<L: Language>(
+    suppress_all: bool,
+    suppressed_rules: &[RuleFilter<'static>],
+    suppressed_instances: &[(RuleFilter<'static>, String)],
+    entry: &SignalEntry<L>,
+) -> bool {
+    if suppress_all {
+        return true;
+    }
+
+    if suppressed_rules.iter().any(|filter| *filter == entry.rule) {
+        return true;
+    }
+
+    if entry.instances.is_empty() {
+        return false;
+    }
+
+    entry.instances.iter().all(|value| {
+        suppressed_instances
+            .iter()
+            .any(|(filter, v)| *filter == entry.rule && v == value)
+    })
+}
+
 impl<'a, 'phase, L, Matcher, Break, Diag> PhaseRunner<'a, 'phase, L, Matcher, Break, Diag>
 where
     L: Language,
@@ -404,34 +515,36 @@ where
             };
 
             let suppression = suppression.filter(|suppression| {
-                if suppression.suppress_all {
-                    return true;
-                }
-
-                if suppression
-                    .suppressed_rules
-                    .iter()
-                    .any(|filter| *filter == entry.rule)
-                {
-                    return true;
-                }
-
-                if entry.instances.is_empty() {
-                    return false;
-                }
+                is_suppressed(
+                    suppression.suppress_all,
+                    &suppression.suppressed_rules,
+                    &suppression.suppressed_instances,
+                    entry,
+                )
+            });
 
-                entry.instances.iter().all(|value| {
-                    suppression
-                        .suppressed_instances
-                        .iter()
-                        .any(|(filter, v)| *filter == entry.rule && v == value)
+            // If no line suppression applies, look for a file-level or range
+            // suppression covering the start of the signal
+            let range_suppression = if suppression.is_none() {
+                self.range_suppressions.iter_mut().find(|suppression| {
+                    suppression.covers(start)
+                        && is_suppressed(
+                            suppression.suppress_all,
+                            &suppression.suppressed_rules,
+                            &suppression.suppressed_instances,
+                            entry,
+                        )
                 })
-            });
+            } else {
+                None
+            };
 
-            // If the signal is being suppressed mark the line suppression as
-            // hit, otherwise emit the signal
+            // If the signal is being suppressed mark the suppression as hit,
+            // otherwise emit the signal
             if let Some(suppression) = suppression {
                 suppression.did_suppress_signal = true;
+            } else if let Some(suppression) = range_suppression {
+                suppression.did_suppress_signal = true;
             } else if range_match(self.range, entry.text_range) {
                 (self.emit_signal)(&*entry.signal)?;
             }
@@ -453,14 +566,18 @@ where
         text: &str,
         range: TextRange,
     ) -> ControlFlow<Break> {
+        let mut scope = None;
         let mut suppress_all = false;
         let mut suppressed_rules = Vec::new();
         let mut suppressed_instances = Vec::new();
         let mut has_legacy = false;
 
         for result in (self.parse_suppression_comment)(text) {
-            let kind = match result {
-                Ok(kind) => kind,
+            let AnalyzerSuppression {
+                kind,
+                scope: kind_scope,
+            } = match result {
+                Ok(suppression) => suppression,
                 Err(diag) => {
                     // Emit the suppression parser diagnostic
                     let signal = DiagnosticSignal::new(move || {
@@ -474,6 +591,27 @@ where
                 }
             };
 
+            // A block comment may contain several suppressions with different
+            // scopes: register the suppressions collected so far before
+            // starting a new group
+            if let Some(scope) = scope.filter(|scope| *scope != kind_scope) {
+                self.push_suppression(
+                    token,
+                    range,
+                    scope,
+                    std::mem::take(&mut suppress_all),
+                    std::mem::take(&mut suppressed_rules),
+                    std::mem::take(&mut suppressed_instances),
+                )?;
+            }
+            scope = Some(kind_scope);
+
+            // If this if a "suppress all lints" comment, no need to parse
+            // anything else for this group
+            if suppress_all {
+                continue;
+            }
+
             if matches!(kind, SuppressionKind::Deprecated) {
                 let signal = DiagnosticSignal::new(move || {
                     SuppressionDiagnostic::new(
@@ -539,10 +677,8 @@ where
                 }
             } else {
                 suppressed_rules.clear();
+                suppressed_instances.clear();
                 suppress_all = true;
-                // If this if a "suppress all lints" comment, no need to
-                // parse anything else
-                break;
             }
         }
 
@@ -563,10 +699,100 @@ where
             (self.emit_signal)(&signal)?;
         }
 
+        match scope {
+            Some(scope) => self.push_suppression(
+                token,
+                range,
+                scope,
+                suppress_all,
+                suppressed_rules,
+                suppressed_instances,
+            ),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    /// Register the rules disabled by a suppression comment according to the
+    /// scope of the comment
+    fn push_suppression(
+        &mut self,
+        token: &SyntaxToken<L>,
+        range: TextRange,
+        scope: SuppressionScope,
+        suppress_all: bool,
+        suppressed_rules: Vec<RuleFilter<'static>>,
+        suppressed_instances: Vec<(RuleFilter<'static>, String)>,
+    ) -> ControlFlow<Break> {
         if !suppress_all && suppressed_rules.is_empty() && suppressed_instances.is_empty() {
             return ControlFlow::Continue(());
         }
 
+        match scope {
+            SuppressionScope::Line => {}
+            SuppressionScope::File => {
+                // Top-level suppressions must be placed before the first
+                // token of the file
+                if token.prev_token().is_some() || token.text_trimmed_range().start() < range.end()
+                {
+                    return self.emit_incorrect_suppression(
+                        range,
+                        "Top-level suppressions must be placed at the top of the file",
+                    );
+                }
+
+                self.range_suppressions.push(RangeSuppression {
+                    scope,
+                    comment_span: range,
+                    start: TextSize::from(0),
+                    end: None,
+                    suppress_all,
+                    suppressed_rules,
+                    suppressed_instances,
+                    did_suppress_signal: false,
+                });
+
+                return ControlFlow::Continue(());
+            }
+            SuppressionScope::RangeStart => {
+                self.range_suppressions.push(RangeSuppression {
+                    scope,
+                    comment_span: range,
+                    start: range.start(),
+                    end: None,
+                    suppress_all,
+                    suppressed_rules,
+                    suppressed_instances,
+                    did_suppress_signal: false,
+                });
+
+                return ControlFlow::Continue(());
+            }
+            SuppressionScope::RangeEnd => {
+                // Close the innermost range suppressing the same set of rules
+                let start = self
+                    .range_suppressions
+                    .iter_mut()
+                    .rev()
+                    .find(|suppression| {
+                        suppression.is_open()
+                            && suppression.suppress_all == suppress_all
+                            && suppression.suppressed_rules == suppressed_rules
+                            && suppression.suppressed_instances == suppressed_instances
+                    });
+
+                return match start {
+                    Some(start) => {
+                        start.end = Some(range.end());
+                        ControlFlow::Continue(())
+                    }
+                    None => self.emit_incorrect_suppression(
+                        range,
+                        "This suppression closes a range that was never opened, add a matching biome-ignore-start comment before it",
+                    ),
+                };
+            }
+        }
+
         // Suppression comments apply to the next line
         let line_index = *self.line_index + 1;
 
@@ -607,6 +833,24 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Emit a diagnostic for a range or top-level suppression comment that
+    /// is misplaced and has no effect
+    fn emit_incorrect_suppression(
+        &mut self,
+        range: TextRange,
+        message: &'static str,
+    ) -> ControlFlow<Break> {
+        if !range_match(self.range, range) {
+            return ControlFlow::Continue(());
+        }
+
+        let signal = DiagnosticSignal::new(move || {
+            SuppressionDiagnostic::new(category!("suppressions/incorrect"), range, message)
+        });
+
+        (self.emit_signal)(&signal)
+    }
+
     /// Check a piece of source text (token or trivia) for line breaks and
     /// increment the line index accordingly, extending the range of the
     /// current suppression as required
@@ -694,7 +938,8 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
 ///
 /// This function receives the text content of a comment and returns a list of
 /// lint suppressions as an optional lint rule (if the lint rule is `None` the
-/// comment is interpreted as suppressing all lints)
+/// comment is interpreted as suppressing all lints), each one along with the
+/// [SuppressionScope] it applies to
 ///
 /// # Examples
 ///
@@ -705,7 +950,23 @@ fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
 /// - `// biome-ignore lint/style/useWhile lint/nursery/noUnreachable` -> `vec![Rule("style/useWhile"), Rule("nursery/noUnreachable")]`
 /// - `// biome-ignore lint(style/useWhile)` -> `vec![MaybeLegacy("style/useWhile")]`
 /// - `// biome-ignore lint(style/useWhile) lint(nursery/noUnreachable)` -> `vec![MaybeLegacy("style/useWhile"), MaybeLegacy("nursery/noUnreachable")]`
-type SuppressionParser<D> = fn(&str) -> Vec<Result<SuppressionKind, D>>;
+/// - `// biome-ignore-all lint/style/useWhile` -> `vec![Rule("style/useWhile")]` with the [SuppressionScope::File] scope
+type SuppressionParser<D> = fn(&str) -> Vec<Result<AnalyzerSuppression, D>>;
+
+/// A single suppression parsed from a comment, along with the portion of the
+/// file it applies to
+pub struct AnalyzerSuppression<'a> {
+    /// What is disabled by the suppression
+    pub kind: SuppressionKind<'a>,
+    /// Where the suppression applies
+    pub scope: SuppressionScope,
+}
+
+impl<'a> AnalyzerSuppression<'a> {
+    pub fn new(kind: SuppressionKind<'a>, scope: SuppressionScope) -> Self {
+        Self { kind, scope }
+    }
+}
 
 /// This enum is used to categorize what is disabled by a suppression comment and with what syntax
 pub enum SuppressionKind<'a> {
@@ -784,6 +1045,8 @@ pub struct SuppressionCommentEmitterPayload<'a, L: Language> {
     pub mutation: &'a mut BatchMutation<L>,
     /// A string equals to "rome-ignore: lint(<RULE_GROUP>/<RULE_NAME>)"
     pub suppression_text: &'a str,
+    /// The reason written after the categories of the suppression comment
+    pub suppression_reason: &'a str,
     /// The original range of the diagnostic where the rule was triggered
     pub diagnostic_text_range: &'a TextRange,
 }
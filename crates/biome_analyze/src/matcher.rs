@@ -204,7 +204,7 @@ mod tests {
         ControlFlow, MetadataRegistry, Never, Phases, QueryMatcher, RuleKey, ServiceBag,
         SignalEntry, SuppressionAction, SyntaxVisitor,
     };
-    use crate::{AnalyzerOptions, SuppressionKind};
+    use crate::{AnalyzerOptions, AnalyzerSuppression, SuppressionKind, SuppressionScope};
     use biome_diagnostics::{category, DiagnosticExt};
     use biome_diagnostics::{Diagnostic, Severity};
     use biome_rowan::{
@@ -351,11 +351,13 @@ mod tests {
 
         fn parse_suppression_comment(
             comment: &'_ str,
-        ) -> Vec<Result<SuppressionKind<'_>, Infallible>> {
+        ) -> Vec<Result<AnalyzerSuppression<'_>, Infallible>> {
             comment
                 .trim_start_matches("//")
                 .split(' ')
-                .map(SuppressionKind::Rule)
+                .map(|rule| {
+                    AnalyzerSuppression::new(SuppressionKind::Rule(rule), SuppressionScope::Line)
+                })
                 .map(Ok)
                 .collect()
         }
@@ -380,6 +382,7 @@ mod tests {
                 _: &mut BatchMutation<Self::Language>,
                 _: ApplySuppression<Self::Language>,
                 _: &str,
+                _: &str,
             ) {
                 unreachable!("")
             }
@@ -1,6 +1,6 @@
 use rustc_hash::FxHashMap;
 
-use crate::{FixKind, Rule, RuleKey};
+use crate::{FixKind, Profiler, Rule, RuleKey};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 use std::path::PathBuf;
@@ -69,6 +69,9 @@ pub struct AnalyzerConfiguration {
     pub jsx_runtime: Option<JsxRuntime>,
 }
 
+/// The reason of the suppression comments when none is provided
+pub const DEFAULT_SUPPRESSION_REASON: &str = "<explanation>";
+
 /// A set of information useful to the analyzer infrastructure
 #[derive(Debug, Default)]
 pub struct AnalyzerOptions {
@@ -77,6 +80,12 @@ pub struct AnalyzerOptions {
 
     /// The file that is being analyzed
     pub file_path: PathBuf,
+
+    /// The reason written in the suppression comments of the suppression actions
+    pub suppression_reason: Option<String>,
+
+    /// Measures the time spent by the analyzer, when it's set
+    pub profiler: Option<Profiler>,
 }
 
 impl AnalyzerOptions {
@@ -114,6 +123,12 @@ impl AnalyzerOptions {
     pub fn preferred_quote(&self) -> &PreferredQuote {
         &self.configuration.preferred_quote
     }
+
+    pub fn suppression_reason(&self) -> &str {
+        self.suppression_reason
+            .as_deref()
+            .unwrap_or(DEFAULT_SUPPRESSION_REASON)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -0,0 +1,196 @@
+//! Measures the time spent by the analyzer on each rule and on each file.
+//!
+//! The profiling is disabled by default, and the analyzer doesn't read the clock
+//! unless a [Profiler] is set in [AnalyzerOptions::profiler]. The timings of the
+//! rules are accumulated by each thread without locking, and they are merged in the
+//! profiler at the end of the analysis of each file. The measurements are collected
+//! with [Profiler::take].
+
+use crate::{AnalyzerOptions, RuleKey};
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Collects the time spent by the analyzer.
+///
+/// The clones of a profiler share their measurements, so the same profiler can
+/// be passed to several runs of the analyzer, e.g. to the runs that apply the
+/// fixes of a file.
+#[derive(Debug, Default, Clone)]
+pub struct Profiler(Arc<Mutex<ProfileData>>);
+
+thread_local! {
+    /// The timings of the rules measured by the current thread, since the analysis
+    /// of the last file ended
+    static THREAD_RULES: RefCell<FxHashMap<RuleKey, RuleProfile>> = RefCell::default();
+}
+
+#[derive(Debug, Default)]
+struct ProfileData {
+    rules: FxHashMap<RuleKey, RuleProfile>,
+    files: FxHashMap<PathBuf, Duration>,
+}
+
+impl Profiler {
+    /// Returns the measurements collected since the last call, and resets them
+    pub fn take(&self) -> Profile {
+        let data = std::mem::take(&mut *self.flush_thread_rules());
+
+        Profile {
+            rules: data
+                .rules
+                .into_iter()
+                .map(|(key, profile)| (format!("{}/{}", key.group(), key.rule_name()), profile))
+                .collect(),
+            files: data.files.into_iter().collect(),
+        }
+    }
+
+    /// Moves the timings of the rules measured by the current thread into the profiler
+    fn flush_thread_rules(&self) -> MutexGuard<'_, ProfileData> {
+        let rules = THREAD_RULES.take();
+        let mut data = self.0.lock().unwrap();
+        for (rule, profile) in rules {
+            data.rules.entry(rule).or_default().merge(profile);
+        }
+        data
+    }
+}
+
+/// The time spent by a rule, accumulated across all the analyzed files
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+pub struct RuleProfile {
+    /// The number of query matches the rule was executed on
+    pub matches: u64,
+    /// The time spent extracting the query match and building the context of the rule.
+    /// The matching of the queries is shared by all the rules, so it isn't measured.
+    pub context: Duration,
+    /// The time spent in [Rule::run](crate::Rule::run), and in the handling of its signals
+    pub run: Duration,
+    /// The time spent computing the code actions of the signals of the rule
+    pub action: Duration,
+}
+
+impl RuleProfile {
+    /// The time spent by the rule, in all the phases
+    pub fn total(&self) -> Duration {
+        self.context + self.run + self.action
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.matches += other.matches;
+        self.context += other.context;
+        self.run += other.run;
+        self.action += other.action;
+    }
+}
+
+/// The measurements collected by [Profiler::take]
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+pub struct Profile {
+    /// The rules that were executed, by name, e.g. `suspicious/noDebugger`
+    pub rules: BTreeMap<String, RuleProfile>,
+    /// The time spent by the analyzer on each file
+    pub files: BTreeMap<PathBuf, Duration>,
+}
+
+impl Profile {
+    /// Adds the measurements of `other`, e.g. the profile of another file
+    pub fn merge(&mut self, other: Self) {
+        for (rule, profile) in other.rules {
+            self.rules.entry(rule).or_default().merge(profile);
+        }
+        for (path, duration) in other.files {
+            *self.files.entry(path).or_default() += duration;
+        }
+    }
+
+    /// The rules that were executed, from the slowest to the fastest
+    pub fn slowest_rules(&self) -> Vec<(&str, &RuleProfile)> {
+        let mut rules: Vec<_> = self
+            .rules
+            .iter()
+            .map(|(rule, profile)| (rule.as_str(), profile))
+            .collect();
+        // The sort is stable, so the rules that took the same time stay sorted by name
+        rules.sort_by(|(_, left), (_, right)| right.total().cmp(&left.total()));
+        rules
+    }
+
+    /// The analyzed files, from the slowest to the fastest
+    pub fn slowest_files(&self) -> Vec<(&Path, Duration)> {
+        let mut files: Vec<_> = self
+            .files
+            .iter()
+            .map(|(path, duration)| (path.as_path(), *duration))
+            .collect();
+        files.sort_by(|(_, left), (_, right)| right.cmp(left));
+        files
+    }
+}
+
+/// The phase of a rule that is measured
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RulePhase {
+    Context,
+    Run,
+    Action,
+}
+
+/// Starts a measurement. It returns [None] when the analyzer isn't profiled.
+pub(crate) fn start(options: &AnalyzerOptions) -> Option<Instant> {
+    options.profiler.as_ref().map(|_| Instant::now())
+}
+
+/// Records the time elapsed since `start` in the given phase of the rule
+pub(crate) fn record_rule(
+    options: &AnalyzerOptions,
+    rule: RuleKey,
+    phase: RulePhase,
+    start: Option<Instant>,
+) {
+    let (Some(_), Some(start)) = (&options.profiler, start) else {
+        return;
+    };
+    let elapsed = start.elapsed();
+
+    THREAD_RULES.with_borrow_mut(|rules| {
+        let profile = rules.entry(rule).or_default();
+        match phase {
+            RulePhase::Context => {
+                profile.matches += 1;
+                profile.context += elapsed;
+            }
+            RulePhase::Run => profile.run += elapsed,
+            RulePhase::Action => profile.action += elapsed,
+        }
+    });
+}
+
+/// Records the time elapsed since `start` in the analysis of the file, and merges
+/// the timings of the rules measured by the current thread in the profiler
+pub(crate) fn record_file(options: &AnalyzerOptions, start: Option<Instant>) {
+    let (Some(profiler), Some(start)) = (&options.profiler, start) else {
+        return;
+    };
+    let elapsed = start.elapsed();
+
+    let mut data = profiler.flush_thread_rules();
+    match data.files.get_mut(&options.file_path) {
+        Some(total) => *total += elapsed,
+        None => {
+            data.files.insert(options.file_path.clone(), elapsed);
+        }
+    }
+}
@@ -10,7 +10,7 @@ pub trait Queryable: Sized {
     type Output;
 
     type Language: Language;
-    type Services: FromServices + Phase;
+    type Services: FromServices + Phase + 'static;
 
     /// Registers one or more [Visitor] that will emit `Self::Input` query
     /// matches during the analyzer run
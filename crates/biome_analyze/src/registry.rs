@@ -1,6 +1,7 @@
 use crate::{
     context::RuleContext,
     matcher::{GroupKey, MatchQueryParams},
+    profiling::{self, RulePhase},
     query::{QueryKey, Queryable},
     signals::RuleSignal,
     AddVisitor, AnalysisFilter, GroupCategory, QueryMatcher, Rule, RuleGroup, RuleKey,
@@ -393,6 +394,7 @@ impl<L: Language + Default> RegistryRule<L> {
                 }
             }
 
+            let start = profiling::start(params.options);
             // SAFETY: The rule should never get executed in the first place
             // if the query doesn't match
             let query_result = params.query.downcast_ref().unwrap();
@@ -401,7 +403,7 @@ impl<L: Language + Default> RegistryRule<L> {
             let preferred_quote = params.options.preferred_quote();
             let jsx_runtime = params.options.jsx_runtime();
             let options = params.options.rule_options::<R>().unwrap_or_default();
-            let ctx = match RuleContext::new(
+            let ctx = RuleContext::new(
                 &query_result,
                 params.root,
                 params.services,
@@ -410,11 +412,19 @@ impl<L: Language + Default> RegistryRule<L> {
                 &options,
                 preferred_quote,
                 jsx_runtime,
-            ) {
+            );
+            profiling::record_rule(
+                params.options,
+                RuleKey::rule::<R>(),
+                RulePhase::Context,
+                start,
+            );
+            let ctx = match ctx {
                 Ok(ctx) => ctx,
                 Err(error) => return Err(error),
             };
 
+            let start = profiling::start(params.options);
             for result in R::run(&ctx) {
                 let text_range =
                     R::text_range(&ctx, &result).unwrap_or_else(|| params.query.text_range());
@@ -438,6 +448,7 @@ impl<L: Language + Default> RegistryRule<L> {
                     text_range,
                 });
             }
+            profiling::record_rule(params.options, RuleKey::rule::<R>(), RulePhase::Run, start);
 
             Ok(())
         }
@@ -875,6 +875,7 @@ pub trait Rule: RuleMeta + Sized {
         ctx: &RuleContext<Self>,
         text_range: &TextRange,
         suppression_action: &dyn SuppressionAction<Language = RuleLanguage<Self>>,
+        suppression_reason: &str,
     ) -> Option<SuppressAction<RuleLanguage<Self>>>
     where
         Self: 'static,
@@ -892,10 +893,15 @@ pub trait Rule: RuleMeta + Sized {
             let mut mutation = root.begin();
             suppression_action.apply_suppression_comment(SuppressionCommentEmitterPayload {
                 suppression_text: suppression_text.as_str(),
+                suppression_reason,
                 mutation: &mut mutation,
                 token_offset: token,
                 diagnostic_text_range: text_range,
             });
+            // The language doesn't know where to place the suppression comment
+            if mutation.is_empty() {
+                return None;
+            }
 
             Some(SuppressAction {
                 mutation,
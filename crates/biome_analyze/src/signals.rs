@@ -2,9 +2,11 @@ use crate::categories::SUPPRESSION_ACTION_CATEGORY;
 use crate::{
     categories::ActionCategory,
     context::RuleContext,
+    profiling::{self, RulePhase},
     registry::{RuleLanguage, RuleRoot},
     rule::Rule,
-    AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, ServiceBag, SuppressionAction,
+    AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, RuleKey, ServiceBag,
+    SuppressionAction,
 };
 use biome_console::MarkupBuf;
 use biome_diagnostics::{advice::CodeSuggestionAdvice, Applicability, CodeSuggestion, Error};
@@ -299,6 +301,7 @@ impl<L: Language> ExactSizeIterator for AnalyzerTransformationIter<L> {
 
 #[derive(Debug, Clone)]
 pub struct AnalyzerTransformation<L: Language> {
+    pub rule_name: Option<(&'static str, &'static str)>,
     pub mutation: BatchMutation<L>,
 }
 
@@ -378,6 +381,7 @@ where
         } else {
             None
         };
+        let start = profiling::start(self.options);
         let options = self.options.rule_options::<R>().unwrap_or_default();
         let ctx = RuleContext::new(
             &self.query_result,
@@ -390,7 +394,7 @@ where
             self.options.jsx_runtime(),
         )
         .ok();
-        if let Some(ctx) = ctx {
+        let actions = if let Some(ctx) = ctx {
             let mut actions = Vec::new();
             if let Some(action) = R::action(&ctx, &self.state) {
                 actions.push(AnalyzerAction {
@@ -402,9 +406,12 @@ where
                 });
             };
             if let Some(text_range) = R::text_range(&ctx, &self.state) {
-                if let Some(suppression_action) =
-                    R::suppress(&ctx, &text_range, self.suppression_action)
-                {
+                if let Some(suppression_action) = R::suppress(
+                    &ctx,
+                    &text_range,
+                    self.suppression_action,
+                    self.options.suppression_reason(),
+                ) {
                     let action = AnalyzerAction {
                         rule_name: Some((<R::Group as RuleGroup>::NAME, R::METADATA.name)),
                         category: ActionCategory::Other(Cow::Borrowed(SUPPRESSION_ACTION_CATEGORY)),
@@ -416,10 +423,13 @@ where
                 }
             }
 
-            AnalyzerActionIter::new(actions)
+            actions
         } else {
-            AnalyzerActionIter::new(vec![])
-        }
+            Vec::new()
+        };
+        profiling::record_rule(self.options, RuleKey::rule::<R>(), RulePhase::Action, start);
+
+        AnalyzerActionIter::new(actions)
     }
 
     fn transformations(&self) -> AnalyzerTransformationIter<RuleLanguage<R>> {
@@ -440,7 +450,10 @@ where
             let mut transformations = Vec::new();
             let mutation = R::transform(&ctx, &self.state);
             if let Some(mutation) = mutation {
-                let transformation = AnalyzerTransformation { mutation };
+                let transformation = AnalyzerTransformation {
+                    rule_name: Some((<R::Group as RuleGroup>::NAME, R::METADATA.name)),
+                    mutation,
+                };
                 transformations.push(transformation)
             }
             AnalyzerTransformationIter::new(transformations)
@@ -1,5 +1,8 @@
 use crate::SuppressionCommentEmitterPayload;
-use biome_rowan::{BatchMutation, Language, SyntaxToken, TextRange, TokenAtOffset};
+use biome_rowan::syntax::SyntaxTrivia;
+use biome_rowan::{
+    BatchMutation, Language, SyntaxToken, TextRange, TokenAtOffset, TriviaPieceKind,
+};
 
 pub trait SuppressionAction {
     type Language: Language;
@@ -9,6 +12,7 @@ pub trait SuppressionAction {
             token_offset,
             mutation,
             suppression_text,
+            suppression_reason,
             diagnostic_text_range,
         } = payload;
 
@@ -22,10 +26,32 @@ pub trait SuppressionAction {
         });
 
         if let Some(apply_suppression) = apply_suppression {
-            self.apply_suppression(mutation, apply_suppression, suppression_text);
+            if !self.merge_suppression(mutation, &apply_suppression, suppression_text) {
+                self.apply_suppression(
+                    mutation,
+                    apply_suppression,
+                    suppression_text,
+                    suppression_reason,
+                );
+            }
         }
     }
 
+    /// Adds the category of `suppression_text` to the suppression comment placed right above the
+    /// line of the diagnostic, if there's one. Returns `false` when there isn't such comment.
+    fn merge_suppression(
+        &self,
+        mutation: &mut BatchMutation<Self::Language>,
+        apply_suppression: &ApplySuppression<Self::Language>,
+        suppression_text: &str,
+    ) -> bool {
+        merge_leading_suppression(
+            mutation,
+            &apply_suppression.token_to_apply_suppression,
+            suppression_text,
+        )
+    }
+
     /// Finds the first token, starting with the current token and traversing backwards,
     /// until it find one that has a leading newline trivia.
     ///
@@ -72,9 +98,80 @@ pub trait SuppressionAction {
         mutation: &mut BatchMutation<Self::Language>,
         apply_suppression: ApplySuppression<Self::Language>,
         suppression_text: &str,
+        suppression_reason: &str,
     );
 }
 
+/// Adds the category of `suppression_text` to the last suppression comment of the leading trivia
+/// of `token`, when the comment is on the line that precedes the token.
+///
+/// For example, `// biome-ignore lint/a/b: reason` becomes `// biome-ignore lint/a/b lint/c/d: reason`.
+pub fn merge_leading_suppression<L: Language>(
+    mutation: &mut BatchMutation<L>,
+    token: &SyntaxToken<L>,
+    suppression_text: &str,
+) -> bool {
+    let Some(trivia) = merge_suppression_in_trivia(&token.leading_trivia(), suppression_text, 1)
+    else {
+        return false;
+    };
+    let new_token =
+        token.with_leading_trivia(trivia.iter().map(|(kind, text)| (*kind, text.as_str())));
+    mutation.replace_token_discard_trivia(token.clone(), new_token);
+    true
+}
+
+/// Returns the pieces of `trivia` where its last comment contains the category of
+/// `suppression_text`, if the comment is a suppression comment followed by exactly `newlines` newlines.
+pub fn merge_suppression_in_trivia<L: Language>(
+    trivia: &SyntaxTrivia<L>,
+    suppression_text: &str,
+    newlines: usize,
+) -> Option<Vec<(TriviaPieceKind, String)>> {
+    let mut pieces: Vec<_> = trivia
+        .pieces()
+        .map(|piece| (piece.kind(), piece.text().to_string()))
+        .collect();
+    let comment_index = pieces.iter().rposition(|(kind, _)| kind.is_comment())?;
+    let newlines_after_comment = pieces[comment_index + 1..]
+        .iter()
+        .filter(|(kind, _)| kind.is_newline())
+        .count();
+    if newlines_after_comment != newlines {
+        return None;
+    }
+    let merged = merge_suppression_text(&pieces[comment_index].1, suppression_text)?;
+    pieces[comment_index].1 = merged;
+    Some(pieces)
+}
+
+/// Inserts the category of `suppression_text` before the colon of the suppression `comment`
+fn merge_suppression_text(comment: &str, suppression_text: &str) -> Option<String> {
+    const SUPPRESSION_PREFIX: &str = "biome-ignore";
+
+    let start = comment.find(SUPPRESSION_PREFIX)?;
+    // Only the comment delimiters can come before the suppression
+    if !comment[..start]
+        .chars()
+        .all(|char| matches!(char, '/' | '*' | '#' | '{') || char.is_whitespace())
+    {
+        return None;
+    }
+    // `biome-ignore-start`, `biome-ignore-end` and `biome-ignore-all` don't
+    // suppress the line that follows them
+    if !comment[start + SUPPRESSION_PREFIX.len()..].starts_with(char::is_whitespace) {
+        return None;
+    }
+    let category = suppression_text.strip_prefix(SUPPRESSION_PREFIX)?;
+    let colon = start + comment[start..].find(':')?;
+
+    Some(format!(
+        "{}{category}{}",
+        &comment[..colon],
+        &comment[colon..]
+    ))
+}
+
 /// Convenient type to store useful information
 pub struct ApplySuppression<L: Language> {
     /// If the token is following by trailing comments
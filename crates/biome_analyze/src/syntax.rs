@@ -166,6 +166,7 @@ mod tests {
                 _: &mut BatchMutation<Self::Language>,
                 _: ApplySuppression<Self::Language>,
                 _: &str,
+                _: &str,
             ) {
                 unreachable!("")
             }
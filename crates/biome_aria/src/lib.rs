@@ -2,10 +2,12 @@ use std::str::FromStr;
 
 pub mod iso;
 mod macros;
+pub mod options;
 pub mod properties;
 pub mod roles;
 
 pub use biome_aria_metadata::{AriaPropertiesEnum, AriaPropertyTypeEnum};
+pub use options::ValidAriaRoleOptions;
 pub use properties::AriaProperties;
 pub(crate) use roles::AriaRoleDefinition;
 pub use roles::AriaRoles;
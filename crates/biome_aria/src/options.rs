@@ -0,0 +1,11 @@
+use biome_deserialize_macros::Deserializable;
+use serde::{Deserialize, Serialize};
+
+/// Options of the `useValidAriaRole` rule, shared by the JSX and HTML versions of the rule
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ValidAriaRoleOptions {
+    pub allow_invalid_roles: Vec<String>,
+    pub ignore_non_dom: bool,
+}
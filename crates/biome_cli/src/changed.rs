@@ -1,25 +1,38 @@
 use crate::CliDiagnostic;
 use biome_configuration::PartialConfiguration;
+use biome_diagnostics::Error;
 use biome_fs::FileSystem;
+use biome_rowan::TextRange;
 use biome_service::DynRef;
+use rustc_hash::FxHashMap;
 use std::ffi::OsString;
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+
+/// Returns the reference to compare against, when `--changed` is used
+fn resolve_base<'a>(
+    configuration: &'a PartialConfiguration,
+    since: Option<&'a str>,
+) -> Result<&'a str, CliDiagnostic> {
+    let default_branch = configuration
+        .vcs
+        .as_ref()
+        .and_then(|v| v.default_branch.as_deref());
+
+    match (since, default_branch) {
+        (Some(since), Some(_)) => Ok(since),
+        (Some(since), None) => Ok(since),
+        (None, Some(branch)) => Ok(branch),
+        (None, None) => Err(CliDiagnostic::incompatible_end_configuration("The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.")),
+    }
+}
 
 pub(crate) fn get_changed_files(
     fs: &DynRef<'_, dyn FileSystem>,
     configuration: &PartialConfiguration,
     since: Option<String>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
-    let default_branch = configuration
-        .vcs
-        .as_ref()
-        .and_then(|v| v.default_branch.as_ref());
-
-    let base = match (since.as_ref(), default_branch) {
-        (Some(since), Some(_)) => since,
-        (Some(since), None) => since,
-        (None, Some(branch)) => branch,
-        (None, None) => return Err(CliDiagnostic::incompatible_end_configuration("The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.")),
-    };
+    let base = resolve_base(configuration, since.as_deref())?;
 
     let changed_files = fs.get_changed_files(base)?;
 
@@ -28,6 +41,18 @@ pub(crate) fn get_changed_files(
     Ok(filtered_changed_files)
 }
 
+pub(crate) fn get_changed_lines(
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration: &PartialConfiguration,
+    since: Option<String>,
+) -> Result<ChangedLines, CliDiagnostic> {
+    let base = resolve_base(configuration, since.as_deref())?;
+
+    let hunks = fs.get_changed_hunks(base)?;
+
+    Ok(ChangedLines::from_diff(&hunks, fs.working_directory()))
+}
+
 pub(crate) fn get_staged_files(
     fs: &DynRef<'_, dyn FileSystem>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
@@ -37,3 +62,185 @@ pub(crate) fn get_staged_files(
 
     Ok(filtered_staged_files)
 }
+
+/// The lines added or modified in each file of the working tree, compared to the base
+/// of `--changed`.
+///
+/// The paths are relative to the working directory, and the line numbers start at 1.
+/// The ranges are exclusive.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ChangedLines {
+    files: FxHashMap<String, Vec<Range<usize>>>,
+    working_directory: Option<PathBuf>,
+}
+
+impl ChangedLines {
+    /// Reads the hunks of a unified diff without context lines, as emitted by `git diff --unified=0`.
+    /// The paths of the diff are relative to `working_directory`.
+    pub(crate) fn from_diff(lines: &[String], working_directory: Option<PathBuf>) -> Self {
+        let mut files: FxHashMap<String, Vec<Range<usize>>> = FxHashMap::default();
+        let mut current_file = None;
+        // The number of removed and added lines of the current hunk that weren't read yet
+        let mut remaining_lines = 0;
+        for line in lines {
+            if remaining_lines > 0 {
+                // Lines like `\ No newline at end of file` aren't counted by the hunk header
+                if !line.starts_with('\\') {
+                    remaining_lines -= 1;
+                }
+            } else if let Some(path) = line.strip_prefix("+++ ") {
+                // Deleted files are reported as `/dev/null`
+                current_file = path
+                    .strip_prefix("b/")
+                    .map(|path| normalize(Path::new(path)));
+            } else if let Some(header) = line.strip_prefix("@@ ") {
+                let Some((removed, added)) = parse_hunk_header(header) else {
+                    continue;
+                };
+                remaining_lines = removed.len() + added.len();
+                // The hunks that only remove lines don't change any line of the new file
+                if let Some(file) = current_file.as_ref().filter(|_| !added.is_empty()) {
+                    files.entry(file.clone()).or_default().push(added);
+                }
+            }
+        }
+
+        Self {
+            files,
+            working_directory,
+        }
+    }
+
+    /// The files that have changed lines
+    pub(crate) fn files(&self) -> Vec<OsString> {
+        self.files.keys().map(OsString::from).collect()
+    }
+
+    /// Whether the diagnostic highlights a line that changed. The diagnostics that don't
+    /// highlight any code are always kept.
+    pub(crate) fn contains(&self, name: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(span) = diagnostic.location().span else {
+            return true;
+        };
+        self.contains_range(name, content, span)
+    }
+
+    /// Whether the text range of the file `name` overlaps a line that changed
+    fn contains_range(&self, name: &str, content: &str, span: TextRange) -> bool {
+        let path = Path::new(name);
+        let path = self
+            .working_directory
+            .as_deref()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path);
+        let Some(ranges) = self.files.get(&normalize(path)) else {
+            return false;
+        };
+        let start = usize::from(span.start()).min(content.len());
+        let end = usize::from(span.end()).min(content.len());
+        let first_line = line_number(content, start);
+        // The end is exclusive, so the last highlighted character is the one before it
+        let last_line = line_number(content, end.saturating_sub(1).max(start));
+
+        ranges
+            .iter()
+            .any(|range| range.start <= last_line && first_line < range.end)
+    }
+}
+
+/// Parses the ranges of the removed and added lines from the header of a hunk, e.g. `-1,2 +3,4 @@`
+fn parse_hunk_header(header: &str) -> Option<(Range<usize>, Range<usize>)> {
+    fn parse_range(range: &str) -> Option<Range<usize>> {
+        let (start, count) = match range.split_once(',') {
+            Some((start, count)) => (start.parse().ok()?, count.parse().ok()?),
+            None => (range.parse().ok()?, 1),
+        };
+        Some(start..start + count)
+    }
+
+    let mut parts = header.split(' ');
+    let removed = parse_range(parts.next()?.strip_prefix('-')?)?;
+    let added = parse_range(parts.next()?.strip_prefix('+')?)?;
+
+    Some((removed, added))
+}
+
+/// Returns the path without the `.` components, using `/` as separator, like the paths of a diff
+fn normalize(path: &Path) -> String {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns the number of the line of the given offset, starting at 1
+fn line_number(content: &str, offset: usize) -> usize {
+    content.as_bytes()[..offset]
+        .split(|byte| *byte == b'\n')
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChangedLines;
+    use biome_rowan::TextRange;
+    use std::path::PathBuf;
+
+    #[test]
+    fn reads_the_changed_lines_of_a_diff() {
+        let diff = [
+            "diff --git a/src/a.js b/src/a.js",
+            "index 1111111..2222222 100644",
+            "--- a/src/a.js",
+            "+++ b/src/a.js",
+            "@@ -3 +3 @@ let a;",
+            "-let b;",
+            "+let c;",
+            "@@ -10,2 +10,0 @@",
+            "-let d;",
+            "-let e;",
+            "@@ -20,0 +19,3 @@",
+            "+++ let f;",
+            "+let g;",
+            "+let h;",
+            "diff --git a/src/b.js b/src/b.js",
+            "new file mode 100644",
+            "--- /dev/null",
+            "+++ b/src/b.js",
+            "@@ -0,0 +1,2 @@",
+            "+let i;",
+            "+let j;",
+            "\\ No newline at end of file",
+        ]
+        .map(String::from);
+
+        let changed_lines = ChangedLines::from_diff(&diff, None);
+
+        assert_eq!(changed_lines.files["src/a.js"], vec![3..4, 19..22]);
+        assert_eq!(changed_lines.files["src/b.js"], vec![1..3]);
+    }
+
+    #[test]
+    fn ends_the_range_before_a_newline() {
+        let diff = ["+++ b/a.js", "@@ -2 +2 @@", "-let c;", "+let b;"].map(String::from);
+        let changed_lines = ChangedLines::from_diff(&diff, None);
+        let content = "let a;\nlet b;\n";
+
+        // The range ends right after the newline of the first line
+        assert!(!changed_lines.contains_range("a.js", content, TextRange::new(0.into(), 7.into())));
+        assert!(changed_lines.contains_range("a.js", content, TextRange::new(7.into(), 13.into())));
+    }
+
+    #[test]
+    fn normalizes_the_paths() {
+        let diff = ["+++ b/src/a.js", "@@ -1 +1 @@", "-let a;", "+let b;"].map(String::from);
+        let changed_lines = ChangedLines::from_diff(&diff, Some(PathBuf::from("/project")));
+        let range = TextRange::new(0.into(), 6.into());
+
+        assert!(changed_lines.contains_range("src/a.js", "let b;", range));
+        assert!(changed_lines.contains_range("./src/./a.js", "let b;", range));
+        assert!(changed_lines.contains_range("/project/src/a.js", "let b;", range));
+        assert!(!changed_lines.contains_range("src/b.js", "let b;", range));
+    }
+}
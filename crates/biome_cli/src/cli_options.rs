@@ -48,10 +48,24 @@ pub struct CliOptions {
     #[bpaf(long("error-on-warnings"), switch)]
     pub error_on_warnings: bool,
 
+    /// Store the results of the processed files in a cache, and reuse them for
+    /// the files that didn't change in the next runs. Use `biome clean` to clear the cache.
+    #[bpaf(long("cache"), switch)]
+    pub cache: bool,
+
+    /// Don't report the diagnostics recorded in the given baseline file, so only the new
+    /// diagnostics are reported. The entries of the baseline that don't match any diagnostic are reported too.
+    #[bpaf(long("baseline"), argument("PATH"), optional)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record the diagnostics emitted by the command in the given baseline file. The recorded diagnostics aren't reported.
+    #[bpaf(long("write-baseline"), argument("PATH"), optional)]
+    pub write_baseline: Option<PathBuf>,
+
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument("json|json-pretty|github|junit|summary|gitlab|sarif|diff"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -133,6 +147,10 @@ pub enum CliReporter {
     Summary,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Reports diagnostics using the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Prints the changes of the formatter and of the fixes as unified diffs, without writing the files.
+    Diff,
 }
 
 impl CliReporter {
@@ -152,6 +170,8 @@ impl FromStr for CliReporter {
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "sarif" => Ok(Self::Sarif),
+            "diff" => Ok(Self::Diff),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -169,6 +189,8 @@ impl Display for CliReporter {
             CliReporter::GitHub => f.write_str("github"),
             CliReporter::Junit => f.write_str("junit"),
             CliReporter::GitLab => f.write_str("gitlab"),
+            CliReporter::Sarif => f.write_str("sarif"),
+            CliReporter::Diff => f.write_str("diff"),
         }
     }
 }
@@ -1,8 +1,11 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
-    get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    get_changed_lines_to_report, get_files_to_process, get_stdin, resolve_manifest,
+    validate_configuration_diagnostics,
 };
+use crate::execute::cache::{is_cache_enabled, settings_fingerprint};
 use crate::execute::VcsTargeted;
+use crate::watch::watch;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -15,6 +18,7 @@ use biome_console::{markup, ConsoleExt};
 use biome_deserialize::Merge;
 use biome_diagnostics::PrintDiagnostic;
 use biome_service::configuration::{load_editorconfig, PartialConfigurationExt};
+use biome_service::workspace::FeaturesBuilder;
 use biome_service::workspace::RegisterProjectFolderParams;
 use biome_service::{
     configuration::{load_configuration, LoadedConfiguration},
@@ -22,8 +26,11 @@ use biome_service::{
 };
 use std::ffi::OsString;
 
-use super::{determine_fix_file_mode, FixFileModeOptions};
+use super::{
+    check_watch_incompatible_arguments, determine_fix_file_mode, FixFileModeOptions, WatchOptions,
+};
 
+#[derive(Clone)]
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
@@ -41,6 +48,9 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) watch: bool,
+    pub(crate) profile_rules: bool,
 }
 
 /// Handler for the "check" command of the Biome CLI
@@ -48,6 +58,46 @@ pub(crate) fn check(
     session: CliSession,
     payload: CheckCommandPayload,
 ) -> Result<(), CliDiagnostic> {
+    let cli_options = &payload.cli_options;
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    if payload.watch {
+        check_watch_incompatible_arguments(WatchOptions {
+            stdin_file_path: payload.stdin_file_path.as_deref(),
+            staged: payload.staged,
+            changed: payload.changed,
+            changed_lines: payload.changed_lines,
+            cli_options,
+        })?;
+        let features = FeaturesBuilder::new()
+            .with_organize_imports()
+            .with_formatter()
+            .with_linter()
+            .with_assists()
+            .build();
+        return watch(
+            session,
+            cli_options,
+            features,
+            payload.paths.clone(),
+            |session, paths| {
+                run_check(
+                    session,
+                    CheckCommandPayload {
+                        paths,
+                        watch: false,
+                        ..payload.clone()
+                    },
+                )
+            },
+        );
+    }
+
+    run_check(session, payload)
+}
+
+/// Runs the "check" command once
+fn run_check(session: CliSession, payload: CheckCommandPayload) -> Result<(), CliDiagnostic> {
     let CheckCommandPayload {
         apply,
         apply_unsafe,
@@ -65,8 +115,10 @@ pub(crate) fn check(
         assists_enabled,
         staged,
         changed,
+        changed_lines,
+        watch: _,
+        profile_rules,
     } = payload;
-    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
     let fix_file_mode = determine_fix_file_mode(
         FixFileModeOptions {
@@ -168,8 +220,23 @@ pub(crate) fn check(
 
     let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "check")?;
 
-    let vcs_targeted_paths =
-        get_files_to_process(since, changed, staged, &session.app.fs, &fs_configuration)?;
+    let changed_lines = get_changed_lines_to_report(
+        changed_lines,
+        since.clone(),
+        staged,
+        &session.app.fs,
+        &fs_configuration,
+    )?;
+    // Only the changed files can have changed lines
+    let changed = changed || changed_lines.is_some();
+    let vcs_targeted_paths = get_files_to_process(
+        since,
+        changed,
+        staged,
+        changed_lines.as_ref(),
+        &session.app.fs,
+        &fs_configuration,
+    )?;
 
     session
         .app
@@ -179,6 +246,8 @@ pub(crate) fn check(
             set_as_current_workspace: true,
         })?;
     let manifest_data = resolve_manifest(&session.app.fs)?;
+    let settings_fingerprint = is_cache_enabled(&cli_options, &fs_configuration)
+        .then(|| settings_fingerprint(&fs_configuration, manifest_data.as_ref()));
 
     if let Some(manifest_data) = manifest_data {
         session
@@ -203,7 +272,10 @@ pub(crate) fn check(
             stdin,
             vcs_targeted: VcsTargeted { staged, changed },
         })
-        .set_report(&cli_options),
+        .set_report(&cli_options)
+        .set_cache(settings_fingerprint)
+        .set_profile_rules(profile_rules)
+        .set_changed_lines(changed_lines),
         session,
         &cli_options,
         vcs_targeted_paths.unwrap_or(paths),
@@ -1,6 +1,7 @@
-use crate::changed::get_changed_files;
+use crate::changed::{get_changed_files, get_changed_lines};
 use crate::cli_options::CliOptions;
 use crate::commands::{resolve_manifest, validate_configuration_diagnostics};
+use crate::execute::cache::{is_cache_enabled, settings_fingerprint};
 use crate::execute::VcsTargeted;
 use crate::{execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution};
 use biome_configuration::analyzer::assists::PartialAssistsConfiguration;
@@ -25,6 +26,7 @@ pub(crate) struct CiCommandPayload {
     pub(crate) cli_options: CliOptions,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
 }
 
 /// Handler for the "ci" command of the Biome CLI
@@ -39,6 +41,7 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         mut paths,
         since,
         changed,
+        changed_lines,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -137,12 +140,28 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
+    // Only the changed files can have changed lines
+    let changed = changed || changed_lines;
     if since.is_some() && !changed {
         return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
     }
 
+    let changed_lines = if changed_lines {
+        Some(get_changed_lines(
+            &session.app.fs,
+            &fs_configuration,
+            since.clone(),
+        )?)
+    } else {
+        None
+    };
+
     if changed {
-        paths = get_changed_files(&session.app.fs, &fs_configuration, since)?;
+        paths = match &changed_lines {
+            // The changed lines include the uncommitted changes of the working tree
+            Some(changed_lines) => changed_lines.files(),
+            None => get_changed_files(&session.app.fs, &fs_configuration, since)?,
+        };
     }
 
     session
@@ -154,6 +173,8 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         })?;
 
     let manifest_data = resolve_manifest(&session.app.fs)?;
+    let settings_fingerprint = is_cache_enabled(&cli_options, &fs_configuration)
+        .then(|| settings_fingerprint(&fs_configuration, manifest_data.as_ref()));
 
     if let Some(manifest_data) = manifest_data {
         session
@@ -176,7 +197,9 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
             staged: false,
             changed,
         })
-        .set_report(&cli_options),
+        .set_report(&cli_options)
+        .set_cache(settings_fingerprint)
+        .set_changed_lines(changed_lines),
         session,
         &cli_options,
         paths,
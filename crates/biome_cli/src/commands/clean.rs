@@ -1,4 +1,5 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::execute::cache::biome_cache_path;
 use crate::{CliDiagnostic, CliSession};
 use biome_flags::biome_env;
 use std::fs::{create_dir, remove_dir_all};
@@ -11,5 +12,10 @@ pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
         .value()
         .map_or(default_biome_log_path(), PathBuf::from);
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
+
+    let cache_path = biome_cache_path();
+    if cache_path.exists() {
+        remove_dir_all(cache_path)?;
+    }
     Ok(())
 }
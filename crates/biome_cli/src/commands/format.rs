@@ -3,7 +3,9 @@ use crate::commands::{
     get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
 };
 use crate::diagnostics::DeprecatedArgument;
+use crate::execute::cache::{is_cache_enabled, settings_fingerprint};
 use crate::execute::VcsTargeted;
+use crate::watch::watch;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -18,11 +20,13 @@ use biome_diagnostics::PrintDiagnostic;
 use biome_service::configuration::{
     load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
 };
+use biome_service::workspace::FeaturesBuilder;
 use biome_service::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
 use std::ffi::OsString;
 
-use super::check_fix_incompatible_arguments;
+use super::{check_fix_incompatible_arguments, check_watch_incompatible_arguments, WatchOptions};
 
+#[derive(Clone)]
 pub(crate) struct FormatCommandPayload {
     pub(crate) javascript_formatter: Option<PartialJavascriptFormatter>,
     pub(crate) json_formatter: Option<PartialJsonFormatter>,
@@ -39,6 +43,7 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
 }
 
 /// Handler for the "format" command of the Biome CLI
@@ -46,6 +51,41 @@ pub(crate) fn format(
     session: CliSession,
     payload: FormatCommandPayload,
 ) -> Result<(), CliDiagnostic> {
+    let cli_options = &payload.cli_options;
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    if payload.watch {
+        check_watch_incompatible_arguments(WatchOptions {
+            stdin_file_path: payload.stdin_file_path.as_deref(),
+            staged: payload.staged,
+            changed: payload.changed,
+            changed_lines: false,
+            cli_options,
+        })?;
+        let features = FeaturesBuilder::new().with_formatter().build();
+        return watch(
+            session,
+            cli_options,
+            features,
+            payload.paths.clone(),
+            |session, paths| {
+                run_format(
+                    session,
+                    FormatCommandPayload {
+                        paths,
+                        watch: false,
+                        ..payload.clone()
+                    },
+                )
+            },
+        );
+    }
+
+    run_format(session, payload)
+}
+
+/// Runs the "format" command once
+fn run_format(session: CliSession, payload: FormatCommandPayload) -> Result<(), CliDiagnostic> {
     let FormatCommandPayload {
         mut javascript_formatter,
         mut formatter_configuration,
@@ -62,8 +102,8 @@ pub(crate) fn format(
         since,
         staged,
         changed,
+        watch: _,
     } = payload;
-    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
     check_fix_incompatible_arguments(super::FixFileModeOptions {
         apply: false,
@@ -214,9 +254,14 @@ pub(crate) fn format(
     let (vcs_base_path, gitignore_matches) =
         configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
-    if let Some(_paths) =
-        get_files_to_process(since, changed, staged, &session.app.fs, &configuration)?
-    {
+    if let Some(_paths) = get_files_to_process(
+        since,
+        changed,
+        staged,
+        None,
+        &session.app.fs,
+        &configuration,
+    )? {
         paths = _paths;
     }
 
@@ -229,6 +274,8 @@ pub(crate) fn format(
         })?;
 
     let manifest_data = resolve_manifest(&session.app.fs)?;
+    let settings_fingerprint = is_cache_enabled(&cli_options, &configuration)
+        .then(|| settings_fingerprint(&configuration, manifest_data.as_ref()));
 
     if let Some(manifest_data) = manifest_data {
         session
@@ -254,7 +301,8 @@ pub(crate) fn format(
         stdin,
         vcs_targeted: VcsTargeted { staged, changed },
     })
-    .set_report(&cli_options);
+    .set_report(&cli_options)
+    .set_cache(settings_fingerprint);
 
     execute_mode(execution, session, &cli_options, paths)
 }
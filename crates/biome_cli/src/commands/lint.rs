@@ -1,8 +1,11 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
-    get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    get_changed_lines_to_report, get_files_to_process, get_stdin, resolve_manifest,
+    validate_configuration_diagnostics,
 };
+use crate::execute::cache::{is_cache_enabled, settings_fingerprint};
 use crate::execute::VcsTargeted;
+use crate::watch::watch;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -19,11 +22,15 @@ use biome_deserialize::Merge;
 use biome_service::configuration::{
     load_configuration, LoadedConfiguration, PartialConfigurationExt,
 };
+use biome_service::workspace::{FeaturesBuilder, FixFileMode};
 use biome_service::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
 use std::ffi::OsString;
 
-use super::{determine_fix_file_mode, FixFileModeOptions};
+use super::{
+    check_watch_incompatible_arguments, determine_fix_file_mode, FixFileModeOptions, WatchOptions,
+};
 
+#[derive(Clone)]
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
@@ -41,14 +48,54 @@ pub(crate) struct LintCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) watch: bool,
+    pub(crate) profile_rules: bool,
     pub(crate) javascript_linter: Option<PartialJavascriptLinter>,
     pub(crate) json_linter: Option<PartialJsonLinter>,
     pub(crate) css_linter: Option<PartialCssLinter>,
     pub(crate) graphql_linter: Option<PartialGraphqlLinter>,
+    /// When set, the suppression comments are applied instead of the fixes
+    pub(crate) suppression_reason: Option<String>,
 }
 
 /// Handler for the "lint" command of the Biome CLI
 pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(), CliDiagnostic> {
+    let cli_options = &payload.cli_options;
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    if payload.watch {
+        check_watch_incompatible_arguments(WatchOptions {
+            stdin_file_path: payload.stdin_file_path.as_deref(),
+            staged: payload.staged,
+            changed: payload.changed,
+            changed_lines: payload.changed_lines,
+            cli_options,
+        })?;
+        let features = FeaturesBuilder::new().with_linter().build();
+        return watch(
+            session,
+            cli_options,
+            features,
+            payload.paths.clone(),
+            |session, paths| {
+                run_lint(
+                    session,
+                    LintCommandPayload {
+                        paths,
+                        watch: false,
+                        ..payload.clone()
+                    },
+                )
+            },
+        );
+    }
+
+    run_lint(session, payload)
+}
+
+/// Runs the "lint" command once
+fn run_lint(session: CliSession, payload: LintCommandPayload) -> Result<(), CliDiagnostic> {
     let LintCommandPayload {
         apply,
         apply_unsafe,
@@ -70,19 +117,26 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         css_linter,
         json_linter,
         graphql_linter,
+        changed_lines,
+        watch: _,
+        profile_rules,
+        suppression_reason,
     } = payload;
-    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
-    let fix_file_mode = determine_fix_file_mode(
-        FixFileModeOptions {
-            apply,
-            apply_unsafe,
-            write,
-            fix,
-            unsafe_,
-        },
-        session.app.console,
-    )?;
+    let fix_file_mode = if suppression_reason.is_some() {
+        Some(FixFileMode::ApplySuppressions)
+    } else {
+        determine_fix_file_mode(
+            FixFileModeOptions {
+                apply,
+                apply_unsafe,
+                write,
+                fix,
+                unsafe_,
+            },
+            session.app.console,
+        )?
+    };
 
     let loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
@@ -138,8 +192,23 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         json.linter.merge_with(json_linter);
     }
 
-    let vcs_targeted_paths =
-        get_files_to_process(since, changed, staged, &session.app.fs, &fs_configuration)?;
+    let changed_lines = get_changed_lines_to_report(
+        changed_lines,
+        since.clone(),
+        staged,
+        &session.app.fs,
+        &fs_configuration,
+    )?;
+    // Only the changed files can have changed lines
+    let changed = changed || changed_lines.is_some();
+    let vcs_targeted_paths = get_files_to_process(
+        since,
+        changed,
+        staged,
+        changed_lines.as_ref(),
+        &session.app.fs,
+        &fs_configuration,
+    )?;
 
     // check if support of git ignore files is enabled
     let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
@@ -156,6 +225,8 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             set_as_current_workspace: true,
         })?;
     let manifest_data = resolve_manifest(&session.app.fs)?;
+    let settings_fingerprint = is_cache_enabled(&cli_options, &fs_configuration)
+        .then(|| settings_fingerprint(&fs_configuration, manifest_data.as_ref()));
 
     if let Some(manifest_data) = manifest_data {
         session
@@ -181,8 +252,12 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             only,
             skip,
             vcs_targeted: VcsTargeted { staged, changed },
+            suppression_reason,
         })
-        .set_report(&cli_options),
+        .set_report(&cli_options)
+        .set_cache(settings_fingerprint)
+        .set_profile_rules(profile_rules)
+        .set_changed_lines(changed_lines),
         session,
         &cli_options,
         vcs_targeted_paths.unwrap_or(paths),
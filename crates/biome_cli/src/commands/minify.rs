@@ -0,0 +1,125 @@
+use crate::cli_options::CliOptions;
+use crate::commands::{get_stdin, resolve_manifest, validate_configuration_diagnostics};
+use crate::{setup_cli_subscriber, CliDiagnostic, CliSession};
+use biome_configuration::PartialFilesConfiguration;
+use biome_console::{markup, ConsoleExt};
+use biome_deserialize::Merge;
+use biome_fs::BiomePath;
+use biome_service::configuration::{
+    load_configuration, LoadedConfiguration, PartialConfigurationExt,
+};
+use biome_service::workspace::{
+    MinifyFileParams, OpenFileParams, RegisterProjectFolderParams, UpdateSettingsParams,
+};
+use biome_service::WorkspaceError;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+pub(crate) struct MinifyCommandPayload {
+    pub(crate) cli_options: CliOptions,
+    pub(crate) files_configuration: Option<PartialFilesConfiguration>,
+    pub(crate) path: Option<OsString>,
+    pub(crate) stdin_file_path: Option<String>,
+}
+
+/// Handler for the "minify" command of the Biome CLI
+pub(crate) fn minify(
+    session: CliSession,
+    payload: MinifyCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let MinifyCommandPayload {
+        cli_options,
+        files_configuration,
+        path,
+        stdin_file_path,
+    } = payload;
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+
+    if path.is_some() && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "stdin-file-path",
+            "PATH",
+        ));
+    }
+
+    let loaded_configuration =
+        load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
+    validate_configuration_diagnostics(
+        &loaded_configuration,
+        session.app.console,
+        cli_options.verbose,
+    )?;
+
+    let LoadedConfiguration {
+        mut configuration,
+        directory_path: configuration_path,
+        ..
+    } = loaded_configuration;
+
+    configuration.files.merge_with(files_configuration);
+
+    let vcs_base_path = configuration_path.or(session.app.fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
+
+    session
+        .app
+        .workspace
+        .register_project_folder(RegisterProjectFolderParams {
+            path: session.app.fs.working_directory(),
+            set_as_current_workspace: true,
+        })?;
+    let manifest_data = resolve_manifest(&session.app.fs)?;
+
+    if let Some(manifest_data) = manifest_data {
+        session
+            .app
+            .workspace
+            .set_manifest_for_project(manifest_data.into())?;
+    }
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            workspace_directory: session.app.fs.working_directory(),
+            configuration,
+            vcs_base_path,
+            gitignore_matches,
+        })?;
+
+    let console = &mut *session.app.console;
+    let (path, content) = match get_stdin(stdin_file_path, console, "minify")? {
+        Some(stdin) => (
+            stdin.as_path().to_path_buf(),
+            stdin.as_content().to_string(),
+        ),
+        None => {
+            let Some(path) = path else {
+                return Err(CliDiagnostic::missing_argument("PATH", "minify"));
+            };
+            let path = PathBuf::from(path);
+            let content = session
+                .app
+                .fs
+                .read_file_from_path(&path)
+                .map_err(WorkspaceError::from)?;
+            (path, content)
+        }
+    };
+
+    let biome_path = BiomePath::new(path);
+    session.app.workspace.open_file(OpenFileParams {
+        path: biome_path.clone(),
+        version: 0,
+        content,
+        document_file_source: None,
+    })?;
+    let result = session
+        .app
+        .workspace
+        .minify_file(MinifyFileParams { path: biome_path })?;
+    console.append(markup! {{result.code}});
+
+    Ok(())
+}
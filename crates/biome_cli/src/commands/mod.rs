@@ -1,4 +1,4 @@
-use crate::changed::{get_changed_files, get_staged_files};
+use crate::changed::{get_changed_files, get_changed_lines, get_staged_files, ChangedLines};
 use crate::cli_options::{cli_options, CliOptions, CliReporter, ColorsArg};
 use crate::diagnostics::{DeprecatedArgument, DeprecatedConfigurationFile};
 use crate::execute::Stdin;
@@ -39,8 +39,11 @@ pub(crate) mod format;
 pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
+pub(crate) mod minify;
 pub(crate) mod rage;
 pub(crate) mod search;
+pub(crate) mod suppress;
+pub(crate) mod transform;
 pub(crate) mod version;
 
 #[derive(Debug, Clone, Bpaf)]
@@ -172,6 +175,24 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Report only the diagnostics of the lines that have been changed compared to your
+        /// `defaultBranch`, or to the base branch of --since. It implies --changed.
+        /// The untracked files aren't checked.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Watch the files, and run the command again on the files that change.
+        /// A change to the configuration runs the command again on all the files.
+        /// The files that import a changed file aren't processed again.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Measure the time spent by each lint rule, and print the slowest rules and files
+        /// at the end of the run. The files whose results are read from the cache aren't
+        /// analyzed, so they're missing from the measurements.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -257,6 +278,80 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// Report only the diagnostics of the lines that have been changed compared to your
+        /// `defaultBranch`, or to the base branch of --since. It implies --changed.
+        /// The untracked files aren't checked.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+        /// Watch the files, and run the command again on the files that change.
+        /// A change to the configuration runs the command again on all the files.
+        /// The files that import a changed file aren't processed again.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+        /// Measure the time spent by each lint rule, and print the slowest rules and files
+        /// at the end of the run. The files whose results are read from the cache aren't
+        /// analyzed, so they're missing from the measurements.
+        #[bpaf(long("profile-rules"), switch)]
+        profile_rules: bool,
+        /// Single file, single path or list of paths
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+    /// Add suppression comments above the lint diagnostics of a set of files.
+    ///
+    /// The suppression comments of a line are merged into a single comment.
+    #[bpaf(command)]
+    Suppress {
+        /// The reason written in the suppression comments.
+        #[bpaf(
+            long("reason"),
+            argument("STRING"),
+            fallback(String::from(suppress::DEFAULT_REASON)),
+            display_fallback
+        )]
+        reason: String,
+
+        #[bpaf(external(partial_vcs_configuration), optional, hide_usage)]
+        vcs_configuration: Option<PartialVcsConfiguration>,
+
+        #[bpaf(external(partial_files_configuration), optional, hide_usage)]
+        files_configuration: Option<PartialFilesConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// Suppress only the diagnostics of the given rule or group of rules.
+        ///
+        /// Example: `biome suppress --only=correctness/noUnusedVariables --only=suspicious`
+        #[bpaf(long("only"), argument("GROUP|RULE"))]
+        only: Vec<RuleSelector>,
+
+        /// Skip the given rule or group of rules by setting the severity level of the rules to `off`.
+        /// This option takes precedence over `--only`.
+        ///
+        /// Example: `biome suppress --skip=correctness/noUnusedVariables --skip=suspicious`
+        #[bpaf(long("skip"), argument("GROUP|RULE"))]
+        skip: Vec<RuleSelector>,
+
+        /// Use this option when you want to suppress the diagnostics of code piped from `stdin`, and print the output to `stdout`.
+        ///
+        /// The file doesn't need to exist on disk, what matters is the extension of the file. Based on the extension, Biome knows how to lint the code.
+        ///
+        /// Example: `echo 'debugger;' | biome suppress --stdin-file-path=file.js`
+        #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
+        stdin_file_path: Option<String>,
+        /// When set to true, only the files that have been staged (the ones prepared to be committed)
+        /// will be suppressed.
+        #[bpaf(long("staged"), switch)]
+        staged: bool,
+        /// When set to true, only the files that have been changed compared to your `defaultBranch`
+        /// configuration will be suppressed.
+        #[bpaf(long("changed"), switch)]
+        changed: bool,
+        /// Use this to specify the base branch to compare against when you're using the --changed
+        /// flag and the `defaultBranch` is not set in your biome.json
+        #[bpaf(long("since"), argument("REF"))]
+        since: Option<String>,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -318,6 +413,12 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Watch the files, and run the command again on the files that change.
+        /// A change to the configuration runs the command again on all the files.
+        /// The files that import a changed file aren't processed again.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -356,6 +457,12 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Report only the diagnostics of the lines that have been changed compared to your
+        /// `defaultBranch`, or to the base branch of --since. It implies --changed.
+        /// The untracked files aren't checked.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -449,9 +556,14 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// Writes the rewrites of the pattern to the files. Without this option,
+        /// the rewrites are printed as a diff.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
         /// The GritQL pattern to search for.
         ///
-        /// Note that the search command (currently) does not support rewrites.
+        /// A pattern can rewrite the code it matches, e.g. `` `console.log($x)` => `logger.debug($x)` ``.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -460,6 +572,84 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
 
+    /// EXPERIMENTAL: Applies transformations to the JavaScript and TypeScript files of a project.
+    ///
+    /// Without `--write`, the changes are printed as a diff.
+    ///
+    /// ## Example
+    ///
+    /// ```shell
+    /// biome transform --only=transformEnum --write ./src
+    /// ```
+    #[bpaf(command)]
+    Transform {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        #[bpaf(external(partial_files_configuration), optional, hide_usage)]
+        files_configuration: Option<PartialFilesConfiguration>,
+
+        #[bpaf(external(partial_vcs_configuration), optional, hide_usage)]
+        vcs_configuration: Option<PartialVcsConfiguration>,
+
+        /// Apply only the given transformation. All the transformations are applied
+        /// when this option isn't provided.
+        ///
+        /// Example: `biome transform --only=transformEnum`
+        #[bpaf(long("only"), argument("TRANSFORMATION"))]
+        only: Vec<String>,
+
+        /// Use this option when you want to transform code piped from `stdin`,
+        /// and print the output to `stdout`.
+        ///
+        /// The file doesn't need to exist on disk, what matters is the
+        /// extension of the file. Based on the extension, Biome knows how to
+        /// parse the code.
+        ///
+        /// Example: `echo 'enum A { B }' | biome transform --stdin-file-path=file.ts`
+        #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
+        stdin_file_path: Option<String>,
+
+        /// Writes the transformed code to the files. Without this option,
+        /// the changes are printed as a diff.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
+        /// Single file, single path or list of paths.
+        #[bpaf(positional("PATH"), many)]
+        paths: Vec<OsString>,
+    },
+
+    /// EXPERIMENTAL: Minifies a JavaScript file, and prints the minified code to the standard output.
+    ///
+    /// ## Example
+    ///
+    /// ```shell
+    /// biome minify ./src/index.js > ./dist/index.min.js
+    /// ```
+    #[bpaf(command)]
+    Minify {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        #[bpaf(external(partial_files_configuration), optional, hide_usage)]
+        files_configuration: Option<PartialFilesConfiguration>,
+
+        /// Use this option when you want to minify code piped from `stdin`.
+        ///
+        /// The file doesn't need to exist on disk, what matters is the
+        /// extension of the file. Based on the extension, Biome knows how to
+        /// parse the code.
+        ///
+        /// Example: `cat index.js | biome minify --stdin-file-path=index.js`
+        #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
+        stdin_file_path: Option<String>,
+
+        /// The file to minify.
+        #[bpaf(positional("PATH"), optional)]
+        path: Option<OsString>,
+    },
+
     /// Shows documentation of various aspects of the CLI.
     ///
     /// ## Examples
@@ -479,7 +669,7 @@ pub enum BiomeCommand {
     },
 
     #[bpaf(command)]
-    /// Cleans the logs emitted by the daemon.
+    /// Cleans the logs emitted by the daemon, and the cache created by the `--cache` option.
     Clean,
 
     #[bpaf(command("__run_server"), hide)]
@@ -545,10 +735,13 @@ impl BiomeCommand {
             | BiomeCommand::Rage(cli_options, ..)
             | BiomeCommand::Check { cli_options, .. }
             | BiomeCommand::Lint { cli_options, .. }
+            | BiomeCommand::Suppress { cli_options, .. }
             | BiomeCommand::Ci { cli_options, .. }
             | BiomeCommand::Format { cli_options, .. }
             | BiomeCommand::Migrate { cli_options, .. }
-            | BiomeCommand::Search { cli_options, .. } => Some(cli_options),
+            | BiomeCommand::Search { cli_options, .. }
+            | BiomeCommand::Transform { cli_options, .. }
+            | BiomeCommand::Minify { cli_options, .. } => Some(cli_options),
             BiomeCommand::LspProxy { .. }
             | BiomeCommand::Start { .. }
             | BiomeCommand::Stop
@@ -695,6 +888,7 @@ fn get_files_to_process(
     since: Option<String>,
     changed: bool,
     staged: bool,
+    changed_lines: Option<&ChangedLines>,
     fs: &DynRef<'_, dyn FileSystem>,
     configuration: &PartialConfiguration,
 ) -> Result<Option<Vec<OsString>>, CliDiagnostic> {
@@ -711,7 +905,11 @@ fn get_files_to_process(
         if staged {
             return Err(CliDiagnostic::incompatible_arguments("changed", "staged"));
         }
-        Ok(Some(get_changed_files(fs, configuration, since)?))
+        match changed_lines {
+            // The changed lines include the uncommitted changes of the working tree
+            Some(changed_lines) => Ok(Some(changed_lines.files())),
+            None => Ok(Some(get_changed_files(fs, configuration, since)?)),
+        }
     } else if staged {
         Ok(Some(get_staged_files(fs)?))
     } else {
@@ -719,6 +917,27 @@ fn get_files_to_process(
     }
 }
 
+/// Computes the lines changed compared to the base of `--changed`, when `--changed-lines` is used
+fn get_changed_lines_to_report(
+    changed_lines: bool,
+    since: Option<String>,
+    staged: bool,
+    fs: &DynRef<'_, dyn FileSystem>,
+    configuration: &PartialConfiguration,
+) -> Result<Option<ChangedLines>, CliDiagnostic> {
+    if !changed_lines {
+        return Ok(None);
+    }
+    if staged {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "changed-lines",
+            "staged",
+        ));
+    }
+
+    Ok(Some(get_changed_lines(fs, configuration, since)?))
+}
+
 /// Holds the options to determine the fix file mode.
 pub(crate) struct FixFileModeOptions {
     apply: bool,
@@ -804,6 +1023,50 @@ fn check_fix_incompatible_arguments(options: FixFileModeOptions) -> Result<(), C
     Ok(())
 }
 
+/// Options that are checked against `--watch`
+pub(crate) struct WatchOptions<'a> {
+    stdin_file_path: Option<&'a str>,
+    staged: bool,
+    changed: bool,
+    changed_lines: bool,
+    cli_options: &'a CliOptions,
+}
+
+/// Checks if the arguments are incompatible with `--watch`.
+fn check_watch_incompatible_arguments(options: WatchOptions) -> Result<(), CliDiagnostic> {
+    let WatchOptions {
+        stdin_file_path,
+        staged,
+        changed,
+        changed_lines,
+        cli_options,
+    } = options;
+    if stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--stdin-file-path",
+        ));
+    } else if staged {
+        return Err(CliDiagnostic::incompatible_arguments("--watch", "--staged"));
+    } else if changed {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--changed",
+        ));
+    } else if changed_lines {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--changed-lines",
+        ));
+    } else if cli_options.write_baseline.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--write-baseline",
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use biome_console::BufferConsole;
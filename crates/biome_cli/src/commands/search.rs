@@ -20,6 +20,7 @@ pub(crate) struct SearchCommandPayload {
     pub(crate) pattern: String,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
+    pub(crate) write: bool,
 }
 
 /// Handler for the "search" command of the Biome CLI
@@ -34,6 +35,7 @@ pub(crate) fn search(
         pattern,
         stdin_file_path,
         vcs_configuration,
+        write,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -94,8 +96,12 @@ pub(crate) fn search(
         .parse_pattern(ParsePatternParams { pattern })?
         .pattern_id;
 
-    let execution =
-        Execution::new(TraversalMode::Search { pattern, stdin }).set_report(&cli_options);
+    let execution = Execution::new(TraversalMode::Search {
+        pattern,
+        stdin,
+        write,
+    })
+    .set_report(&cli_options);
 
     execute_mode(execution, session, &cli_options, paths)
 }
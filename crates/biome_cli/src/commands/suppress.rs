@@ -0,0 +1,76 @@
+use crate::cli_options::CliOptions;
+use crate::commands::lint::{lint, LintCommandPayload};
+use crate::{CliDiagnostic, CliSession};
+use biome_configuration::analyzer::RuleSelector;
+use biome_configuration::vcs::PartialVcsConfiguration;
+use biome_configuration::PartialFilesConfiguration;
+use std::ffi::OsString;
+
+/// The reason of the suppression comments when `--reason` isn't provided
+pub(crate) const DEFAULT_REASON: &str = "ignored using `biome suppress`";
+
+pub(crate) struct SuppressCommandPayload {
+    pub(crate) reason: String,
+    pub(crate) cli_options: CliOptions,
+    pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
+    pub(crate) files_configuration: Option<PartialFilesConfiguration>,
+    pub(crate) paths: Vec<OsString>,
+    pub(crate) only: Vec<RuleSelector>,
+    pub(crate) skip: Vec<RuleSelector>,
+    pub(crate) stdin_file_path: Option<String>,
+    pub(crate) staged: bool,
+    pub(crate) changed: bool,
+    pub(crate) since: Option<String>,
+}
+
+/// Handler for the "suppress" command of the Biome CLI
+///
+/// It runs the linter, and applies the suppression actions of the diagnostics instead of their fixes.
+pub(crate) fn suppress(
+    session: CliSession,
+    payload: SuppressCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    let SuppressCommandPayload {
+        reason,
+        cli_options,
+        vcs_configuration,
+        files_configuration,
+        paths,
+        only,
+        skip,
+        stdin_file_path,
+        staged,
+        changed,
+        since,
+    } = payload;
+
+    lint(
+        session,
+        LintCommandPayload {
+            apply: false,
+            apply_unsafe: false,
+            write: false,
+            fix: false,
+            unsafe_: false,
+            cli_options,
+            linter_configuration: None,
+            vcs_configuration,
+            files_configuration,
+            paths,
+            only,
+            skip,
+            stdin_file_path,
+            staged,
+            changed,
+            since,
+            changed_lines: false,
+            watch: false,
+            profile_rules: false,
+            javascript_linter: None,
+            json_linter: None,
+            css_linter: None,
+            graphql_linter: None,
+            suppression_reason: Some(reason),
+        },
+    )
+}
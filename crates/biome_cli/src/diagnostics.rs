@@ -30,6 +30,8 @@ pub enum CliDiagnostic {
     UnexpectedArgument(UnexpectedArgument),
     /// Returned when a required argument is not present in the command line
     MissingArgument(MissingArgument),
+    /// Returned when the `transform` command is called with a transformation it doesn't know
+    UnknownTransformation(UnknownTransformation),
     /// Returned when a subcommand is called without any arguments
     EmptyArguments(EmptyArguments),
     /// Returned when a subcommand is called with an unsupported combination of arguments
@@ -58,6 +60,8 @@ pub enum CliDiagnostic {
     Report(ReportDiagnostic),
     /// Emitted when there's an error emitted when using stdin mode
     Stdin(StdinDiagnostic),
+    /// Emitted when the baseline file can't be read or written
+    Baseline(BaselineDiagnostic),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -86,6 +90,21 @@ pub struct UnknownCommandHelp {
     command_name: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "Unknown transformation {name}",
+        message("Unknown transformation "<Emphasis>{self.name}</Emphasis>".")
+    ),
+)]
+pub struct UnknownTransformation {
+    name: String,
+    #[advice]
+    help: CliAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "flags/invalid",
@@ -247,6 +266,20 @@ pub struct MigrationDiagnostic {
     pub reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Error,
+    message(
+        message("The baseline "<Emphasis>{self.path}</Emphasis>" can't be used: "{{&self.reason}}),
+        description = "The baseline {path} can't be used: {reason}"
+    )
+)]
+pub struct BaselineDiagnostic {
+    pub path: String,
+    pub reason: String,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
@@ -313,6 +346,14 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the baseline file can't be read or written
+    pub fn baseline(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::Baseline(BaselineDiagnostic {
+            path: path.into(),
+            reason: reason.into(),
+        })
+    }
+
     /// To throw when there's been an error while parsing an argument
     pub fn parse_error_bpaf(source: bpaf::ParseFailure) -> Self {
         Self::ParseError(ParseDiagnostic {
@@ -341,6 +382,14 @@ impl CliDiagnostic {
         })
     }
 
+    /// Returned when the `transform` command is called with a transformation it doesn't know
+    pub fn unknown_transformation(name: impl Into<String>) -> Self {
+        Self::UnknownTransformation(UnknownTransformation {
+            name: name.into(),
+            help: CliAdvice::new_with_help("transform"),
+        })
+    }
+
     /// When no files were processed while traversing the file system
     pub fn no_files_processed() -> Self {
         Self::NoFilesWereProcessed(NoFilesWereProcessed)
@@ -0,0 +1,319 @@
+use crate::cli_options::CliOptions;
+use crate::execute::diagnostics::{StaleBaselineAdvice, StaleBaselineDiagnostic};
+use crate::CliDiagnostic;
+use biome_diagnostics::Error;
+use biome_fs::{BiomePath, FileSystem, OpenOptions};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// The version of the format of the baseline file
+const BASELINE_VERSION: u32 = 1;
+
+/// How the baseline is used during the traversal
+#[derive(Debug, Clone)]
+pub(crate) enum BaselineMode {
+    /// The diagnostics recorded in the baseline aren't reported
+    Read(PathBuf),
+    /// The diagnostics emitted during the traversal are recorded in the baseline
+    Write(PathBuf),
+}
+
+impl BaselineMode {
+    /// Reads the mode from the options `--baseline` and `--write-baseline`
+    pub(crate) fn from_cli_options(
+        cli_options: &CliOptions,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        match (&cli_options.baseline, &cli_options.write_baseline) {
+            (Some(_), Some(_)) => Err(CliDiagnostic::incompatible_arguments(
+                "baseline",
+                "write-baseline",
+            )),
+            (Some(path), None) => Ok(Some(Self::Read(path.clone()))),
+            (None, Some(path)) => Ok(Some(Self::Write(path.clone()))),
+            (None, None) => Ok(None),
+        }
+    }
+
+    fn path(&self) -> &Path {
+        match self {
+            Self::Read(path) | Self::Write(path) => path,
+        }
+    }
+}
+
+/// The content of a baseline file. The entries are grouped by file, and the
+/// paths are relative to the working directory.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct BaselineFile {
+    version: u32,
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct BaselineEntry {
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`
+    category: String,
+    /// The fingerprint of the code highlighted by the diagnostic
+    fingerprint: String,
+    /// The number of diagnostics with the same category and fingerprint in the file
+    count: u32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct BaselineKey {
+    path: String,
+    category: String,
+    fingerprint: String,
+}
+
+/// The baseline of a traversal.
+///
+/// A diagnostic is identified by its file, its category and a fingerprint of the code it
+/// highlights, so the baseline isn't affected by the changes that move the code around.
+pub(crate) struct Baseline {
+    mode: BaselineMode,
+    /// The path of the baseline, resolved from the working directory
+    path: PathBuf,
+    working_directory: Option<PathBuf>,
+    /// In [BaselineMode::Read], the number of diagnostics that the baseline can still suppress.
+    /// In [BaselineMode::Write], the number of diagnostics recorded so far.
+    entries: Mutex<BTreeMap<BaselineKey, u32>>,
+    /// The number of diagnostics suppressed or recorded by the baseline
+    matched: AtomicUsize,
+}
+
+impl Baseline {
+    /// Loads the baseline. In [BaselineMode::Read], the file must exist and be valid.
+    pub(crate) fn load(fs: &dyn FileSystem, mode: &BaselineMode) -> Result<Self, CliDiagnostic> {
+        let working_directory = fs.working_directory();
+        let path = match &working_directory {
+            Some(working_directory) => working_directory.join(mode.path()),
+            None => mode.path().to_path_buf(),
+        };
+
+        let entries = match mode {
+            BaselineMode::Read(_) => read_entries(fs, &path, mode.path())?,
+            BaselineMode::Write(_) => BTreeMap::new(),
+        };
+
+        Ok(Self {
+            mode: mode.clone(),
+            path,
+            working_directory,
+            entries: Mutex::new(entries),
+            matched: AtomicUsize::new(0),
+        })
+    }
+
+    /// The number of diagnostics suppressed or recorded by the baseline
+    pub(crate) fn matched(&self) -> usize {
+        self.matched.load(Ordering::Relaxed)
+    }
+
+    /// Checks a diagnostic of the file `name`, and returns `true` if it shouldn't be reported.
+    ///
+    /// In [BaselineMode::Write], the diagnostic is recorded, and it's never reported.
+    pub(crate) fn suppress(&self, name: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(category) = diagnostic.category() else {
+            return false;
+        };
+        let code = diagnostic
+            .location()
+            .span
+            .and_then(|span| content.get(span.start().into()..span.end().into()))
+            .unwrap_or_default();
+        let key = BaselineKey {
+            path: self.relativize(name),
+            category: category.name().to_string(),
+            fingerprint: fingerprint(code),
+        };
+
+        let mut entries = self.entries.lock().unwrap();
+        let suppressed = match self.mode {
+            BaselineMode::Write(_) => {
+                *entries.entry(key).or_default() += 1;
+                true
+            }
+            BaselineMode::Read(_) => match entries.get_mut(&key) {
+                Some(remaining) if *remaining > 0 => {
+                    *remaining -= 1;
+                    true
+                }
+                _ => false,
+            },
+        };
+        if suppressed {
+            self.matched.fetch_add(1, Ordering::Relaxed);
+        }
+        suppressed
+    }
+
+    /// Ends the traversal.
+    ///
+    /// In [BaselineMode::Write], the baseline is persisted. The entries of the files that
+    /// weren't evaluated are kept from the existing baseline, if it can be read, unless the
+    /// files don't exist anymore. In [BaselineMode::Read], it returns the diagnostics of the
+    /// entries that didn't match any diagnostic. Only the files that were evaluated, or that
+    /// don't exist anymore, are checked.
+    pub(crate) fn finish(
+        &self,
+        fs: &dyn FileSystem,
+        evaluated_paths: &BTreeSet<BiomePath>,
+    ) -> Result<Vec<Error>, CliDiagnostic> {
+        let mut entries = std::mem::take(&mut *self.entries.lock().unwrap());
+        let evaluated_paths: BTreeSet<_> = evaluated_paths
+            .iter()
+            .map(|path| self.relativize(&path.display().to_string()))
+            .collect();
+        let is_checked =
+            |path: &str| evaluated_paths.contains(path) || !fs.path_exists(&self.resolve(path));
+
+        match &self.mode {
+            BaselineMode::Write(path) => {
+                if fs.path_exists(&self.path) {
+                    let previous = read_entries(fs, &self.path, path).unwrap_or_default();
+                    for (key, count) in previous {
+                        if !is_checked(&key.path) {
+                            entries.insert(key, count);
+                        }
+                    }
+                }
+
+                let mut baseline_file = BaselineFile {
+                    version: BASELINE_VERSION,
+                    files: BTreeMap::new(),
+                };
+                for (key, count) in entries {
+                    baseline_file
+                        .files
+                        .entry(key.path)
+                        .or_default()
+                        .push(BaselineEntry {
+                            category: key.category,
+                            fingerprint: key.fingerprint,
+                            count,
+                        });
+                }
+
+                let display_path = path.display().to_string();
+                let mut content = serde_json::to_string_pretty(&baseline_file)
+                    .map_err(|error| CliDiagnostic::baseline(&display_path, error.to_string()))?;
+                content.push('\n');
+                fs.open_with_options(
+                    &self.path,
+                    OpenOptions::default()
+                        .write(true)
+                        .create(true)
+                        .truncate(true),
+                )
+                .and_then(|mut file| file.set_content(content.as_bytes()))
+                .map_err(|error| CliDiagnostic::baseline(&display_path, error.to_string()))?;
+
+                Ok(Vec::new())
+            }
+            BaselineMode::Read(_) => {
+                let diagnostics = entries
+                    .into_iter()
+                    .filter(|(key, remaining)| *remaining > 0 && is_checked(&key.path))
+                    .fold(
+                        BTreeMap::<(String, String), u32>::new(),
+                        |mut stale, (key, remaining)| {
+                            *stale.entry((key.path, key.category)).or_default() += remaining;
+                            stale
+                        },
+                    )
+                    .into_iter()
+                    .map(|((file_name, rule), count)| {
+                        Error::from(StaleBaselineDiagnostic {
+                            file_name,
+                            rule,
+                            advice: StaleBaselineAdvice { count },
+                        })
+                    })
+                    .collect();
+
+                Ok(diagnostics)
+            }
+        }
+    }
+
+    /// Returns the path relative to the working directory, using `/` as separator
+    fn relativize(&self, path: &str) -> String {
+        let path = Path::new(path);
+        let path = self
+            .working_directory
+            .as_deref()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path);
+        path.to_string_lossy().replace('\\', "/")
+    }
+
+    fn resolve(&self, path: &str) -> PathBuf {
+        match &self.working_directory {
+            Some(working_directory) => working_directory.join(path),
+            None => PathBuf::from(path),
+        }
+    }
+}
+
+/// Reads the entries of the baseline file at `path`. `display_path` is the path
+/// printed in the diagnostics.
+fn read_entries(
+    fs: &dyn FileSystem,
+    path: &Path,
+    display_path: &Path,
+) -> Result<BTreeMap<BaselineKey, u32>, CliDiagnostic> {
+    let display_path = display_path.display().to_string();
+    let content = fs.read_file_from_path(&path.to_path_buf()).map_err(|_| {
+        CliDiagnostic::baseline(&display_path, "the file doesn't exist or can't be read.")
+    })?;
+    let baseline_file: BaselineFile = serde_json::from_str(&content)
+        .map_err(|error| CliDiagnostic::baseline(&display_path, error.to_string()))?;
+    if baseline_file.version != BASELINE_VERSION {
+        return Err(CliDiagnostic::baseline(
+            &display_path,
+            format!(
+                "the version {} isn't supported, use --write-baseline to update it.",
+                baseline_file.version
+            ),
+        ));
+    }
+
+    let mut entries = BTreeMap::new();
+    for (path, file_entries) in baseline_file.files {
+        for entry in file_entries {
+            let key = BaselineKey {
+                path: path.clone(),
+                category: entry.category,
+                fingerprint: entry.fingerprint,
+            };
+            *entries.entry(key).or_default() += entry.count;
+        }
+    }
+    Ok(entries)
+}
+
+/// Computes the fingerprint of the code highlighted by a diagnostic.
+///
+/// The whitespace is normalized, so the fingerprint isn't affected by the formatting.
+/// It uses the FNV-1a hash, because the fingerprint must be stable across the versions of Biome.
+fn fingerprint(code: &str) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (index, word) in code.split_whitespace().enumerate() {
+        if index > 0 {
+            hash = (hash ^ u64::from(b' ')).wrapping_mul(PRIME);
+        }
+        for byte in word.bytes() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    }
+    format!("{hash:016x}")
+}
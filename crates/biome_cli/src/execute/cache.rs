@@ -0,0 +1,331 @@
+use crate::cli_options::CliOptions;
+use crate::execute::process_file::{DiffKind, Message};
+use crate::execute::Execution;
+use crate::VERSION;
+use biome_configuration::PartialConfiguration;
+use biome_diagnostics::serde::Diagnostic;
+use biome_diagnostics::Error;
+use biome_flags::biome_env;
+use biome_fs::{BiomePath, FileSystem, OpenOptions};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Returns the default directory where Biome stores the results of the traversal, when
+/// the cache is enabled.
+pub fn default_biome_cache_path() -> PathBuf {
+    biome_fs::cache_dir().join("biome-cache")
+}
+
+/// Returns the directory where Biome stores the results of the traversal. It can
+/// be changed using the `BIOME_CACHE_PATH` environment variable.
+pub fn biome_cache_path() -> PathBuf {
+    biome_env()
+        .biome_cache_path
+        .value()
+        .map_or_else(default_biome_cache_path, PathBuf::from)
+}
+
+/// Whether the cache is enabled, via the `--cache` option or the `files.cache` setting.
+pub(crate) fn is_cache_enabled(
+    cli_options: &CliOptions,
+    configuration: &PartialConfiguration,
+) -> bool {
+    cli_options.cache
+        || configuration
+            .files
+            .as_ref()
+            .and_then(|files| files.cache)
+            .unwrap_or_default()
+}
+
+/// Computes a fingerprint of the settings that are used to process the files.
+///
+/// It accepts the resolved configuration, and the content of the manifest, if present.
+pub(crate) fn settings_fingerprint(
+    configuration: &PartialConfiguration,
+    manifest: Option<&(BiomePath, String)>,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    // The serialization of the configuration is stable, as long as the version of Biome stays the same
+    serde_json::to_string(configuration)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    if let Some((_, content)) = manifest {
+        content.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// The persistent cache of a traversal.
+///
+/// Each processed file is stored with a fingerprint computed from its content, the
+/// settings, the traversal mode and the version of Biome. If a file has the
+/// same fingerprint during the next traversal, its messages are replayed without
+/// processing the file again.
+///
+/// When the module graph is built, its fingerprint is part of the fingerprint of every
+/// file: rules such as `noImportCycles` report diagnostics that depend on other files,
+/// so a change to any module invalidates all the entries.
+///
+/// The entries are read once at the beginning of the traversal, and they are persisted
+/// at the end of it.
+pub(crate) struct FileCache {
+    /// The path of the file where the entries are persisted
+    path: PathBuf,
+    /// The fingerprint of the traversal: settings, execution and version of Biome
+    fingerprint: u64,
+    /// The fingerprint of the module graph, when it's built for the traversal
+    module_graph: AtomicU64,
+    /// The entries persisted by the previous traversal
+    previous: BTreeMap<String, CacheEntry>,
+    /// The entries computed during the current traversal
+    current: Mutex<BTreeMap<String, CacheEntry>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheFile {
+    version: String,
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct CacheEntry {
+    fingerprint: u64,
+    messages: Vec<CachedMessage>,
+}
+
+/// A serializable version of [Message], stripped of the information that can
+/// be computed from the file itself.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub(crate) enum CachedMessage {
+    SkippedFixes {
+        skipped_suggested_fixes: u32,
+    },
+    Failure,
+    Error(Diagnostic),
+    Diagnostics {
+        diagnostics: Vec<Diagnostic>,
+        skipped_diagnostics: u32,
+    },
+    Diff {
+        new: String,
+        diff_kind: DiffKind,
+    },
+}
+
+impl CachedMessage {
+    /// Converts a [Message] into its cached version. Since diagnostics can't be
+    /// cloned, it also returns the message restored from its cached version.
+    pub(crate) fn record(message: Message) -> (Self, Message) {
+        match message {
+            Message::SkippedFixes {
+                skipped_suggested_fixes,
+            } => (
+                Self::SkippedFixes {
+                    skipped_suggested_fixes,
+                },
+                message,
+            ),
+            Message::Failure => (Self::Failure, message),
+            Message::Error(error) => {
+                let diagnostic = Diagnostic::new(error);
+                (
+                    Self::Error(diagnostic.clone()),
+                    Message::Error(Error::from(diagnostic)),
+                )
+            }
+            Message::Diagnostics {
+                name,
+                content,
+                diagnostics,
+                skipped_diagnostics,
+            } => {
+                let diagnostics: Vec<_> = diagnostics.into_iter().map(Diagnostic::new).collect();
+                (
+                    Self::Diagnostics {
+                        diagnostics: diagnostics.clone(),
+                        skipped_diagnostics,
+                    },
+                    Message::Diagnostics {
+                        name,
+                        content,
+                        diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                        skipped_diagnostics,
+                    },
+                )
+            }
+            Message::Diff {
+                file_name,
+                old,
+                new,
+                diff_kind,
+            } => (
+                Self::Diff {
+                    new: new.clone(),
+                    diff_kind,
+                },
+                Message::Diff {
+                    file_name,
+                    old,
+                    new,
+                    diff_kind,
+                },
+            ),
+        }
+    }
+
+    /// Restores the original [Message], using the name and the content of the file
+    fn into_message(self, name: &str, content: &str) -> Message {
+        match self {
+            Self::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Message::SkippedFixes {
+                skipped_suggested_fixes,
+            },
+            Self::Failure => Message::Failure,
+            Self::Error(diagnostic) => Message::Error(Error::from(diagnostic)),
+            Self::Diagnostics {
+                diagnostics,
+                skipped_diagnostics,
+            } => Message::Diagnostics {
+                name: name.to_string(),
+                content: content.to_string(),
+                diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                skipped_diagnostics,
+            },
+            Self::Diff { new, diff_kind } => Message::Diff {
+                file_name: name.to_string(),
+                old: content.to_string(),
+                new,
+                diff_kind,
+            },
+        }
+    }
+}
+
+impl FileCache {
+    /// Loads the cache of the given execution. Entries that can't be read are discarded.
+    pub(crate) fn load(fs: &dyn FileSystem, execution: &Execution, settings: u64) -> Self {
+        let mut hasher = DefaultHasher::new();
+        fs.working_directory().hash(&mut hasher);
+        execution.traversal_mode().to_string().hash(&mut hasher);
+        let path = biome_cache_path().join(format!("{:016x}.json", hasher.finish()));
+
+        let mut hasher = DefaultHasher::new();
+        VERSION.hash(&mut hasher);
+        settings.hash(&mut hasher);
+        format!("{:?}", execution.traversal_mode()).hash(&mut hasher);
+        execution.get_max_diagnostics().hash(&mut hasher);
+        // The baseline lifts the limit of the diagnostics pulled from each file
+        execution.baseline().is_some().hash(&mut hasher);
+        // The diff reporter applies the changes to the workspace instead of emitting diffs
+        execution.is_diff_report().hash(&mut hasher);
+        let fingerprint = hasher.finish();
+
+        let previous = fs
+            .read_file_from_path(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| cache_file.version == VERSION)
+            .map(|cache_file| cache_file.entries)
+            .unwrap_or_default();
+        debug!(
+            "Loaded {} entries from the cache {}",
+            previous.len(),
+            path.display()
+        );
+
+        Self {
+            path,
+            fingerprint,
+            module_graph: AtomicU64::default(),
+            previous,
+            current: Mutex::default(),
+        }
+    }
+
+    /// Stores the fingerprint of the module graph. It must be called before processing the files.
+    pub(crate) fn set_module_graph_fingerprint(&self, fingerprint: u64) {
+        self.module_graph.store(fingerprint, Ordering::Relaxed);
+    }
+
+    /// Computes the fingerprint of a file, based on its content
+    pub(crate) fn file_fingerprint(&self, content: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.fingerprint.hash(&mut hasher);
+        self.module_graph.load(Ordering::Relaxed).hash(&mut hasher);
+        content.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the messages of the file, if it was processed with the same fingerprint
+    pub(crate) fn get(&self, path: &Path, content: &str, fingerprint: u64) -> Option<Vec<Message>> {
+        let name = path.display().to_string();
+        let entry = self
+            .previous
+            .get(&name)
+            .filter(|entry| entry.fingerprint == fingerprint)?;
+        Some(
+            entry
+                .messages
+                .iter()
+                .cloned()
+                .map(|message| message.into_message(&name, content))
+                .collect(),
+        )
+    }
+
+    /// Stores the messages of a processed file
+    pub(crate) fn insert(&self, path: &Path, fingerprint: u64, messages: Vec<CachedMessage>) {
+        self.current.lock().unwrap().insert(
+            path.display().to_string(),
+            CacheEntry {
+                fingerprint,
+                messages,
+            },
+        );
+    }
+
+    /// Persists the entries of the cache. The entries of the previous traversal
+    /// that weren't evaluated are kept, unless their file doesn't exist anymore.
+    pub(crate) fn persist(self, fs: &dyn FileSystem) {
+        let Self {
+            path,
+            mut previous,
+            current,
+            ..
+        } = self;
+        let current = current.into_inner().unwrap();
+        previous.retain(|name, _| current.contains_key(name) || fs.path_exists(Path::new(name)));
+        previous.extend(current);
+        let cache_file = CacheFile {
+            version: VERSION.to_string(),
+            entries: previous,
+        };
+
+        let result = serde_json::to_string(&cache_file)
+            .map_err(std::io::Error::from)
+            .and_then(|content| {
+                if let Some(parent) = path.parent() {
+                    fs.create_dir_all(parent)?;
+                }
+                let mut file = fs.open_with_options(
+                    &path,
+                    OpenOptions::default()
+                        .write(true)
+                        .create(true)
+                        .truncate(true),
+                )?;
+                file.set_content(content.as_bytes())
+            });
+
+        if let Err(error) = result {
+            warn!("Failed to persist the cache {}: {error}", path.display());
+        }
+    }
+}
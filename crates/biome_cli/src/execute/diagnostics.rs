@@ -1,6 +1,7 @@
+use biome_console::markup;
 use biome_diagnostics::adapters::{IoError, StdError};
 use biome_diagnostics::{
-    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Visit,
+    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, LogCategory, Visit,
 };
 use biome_text_edit::TextEdit;
 use std::io;
@@ -80,6 +81,32 @@ pub(crate) struct AssistsDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern would have rewritten the following content:"
+)]
+pub(crate) struct RewriteDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "transform",
+    severity = Information,
+    message = "The transformations would have changed the following content:"
+)]
+pub(crate) struct TransformDiffDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "migrate",
@@ -93,6 +120,45 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Information,
+    message(
+        description = "The baseline contains entries of {rule} that don't match any diagnostic.",
+        message("The baseline contains entries of "<Emphasis>{self.rule}</Emphasis>" that don't match any diagnostic.")
+    )
+)]
+pub(crate) struct StaleBaselineDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    pub(crate) rule: String,
+    #[advice]
+    pub(crate) advice: StaleBaselineAdvice,
+}
+
+#[derive(Debug)]
+pub(crate) struct StaleBaselineAdvice {
+    /// The number of diagnostics that were fixed
+    pub(crate) count: u32,
+}
+
+impl Advices for StaleBaselineAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        let fixed = if self.count == 1 {
+            markup! { "1 diagnostic was fixed." }.to_owned()
+        } else {
+            markup! { {self.count}" diagnostics were fixed." }.to_owned()
+        };
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                {fixed}" Use "<Emphasis>"--write-baseline"</Emphasis>" to remove the entries from the baseline."
+            },
+        )
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
@@ -1,18 +1,24 @@
+pub(crate) mod baseline;
+pub(crate) mod cache;
 mod diagnostics;
 mod migrate;
 mod process_file;
 mod std_in;
 pub(crate) mod traverse;
 
+use crate::changed::ChangedLines;
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
+use crate::execute::baseline::BaselineMode;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{traverse, TraverseResult};
+use crate::reporter::diff::{DiffReporter, DiffReporterVisitor};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
@@ -41,6 +47,18 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// The fingerprint of the settings. It's [Some] only when the persistent cache is enabled
+    settings_fingerprint: Option<u64>,
+
+    /// How the baseline is used, if any
+    baseline: Option<BaselineMode>,
+
+    /// Whether the time spent by each lint rule is measured and reported
+    profile_rules: bool,
+
+    /// The lines changed compared to the base of `--changed`, when only their diagnostics are reported
+    changed_lines: Option<ChangedLines>,
 }
 
 impl Execution {
@@ -54,6 +72,10 @@ impl Execution {
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            settings_fingerprint: None,
+            baseline: None,
+            profile_rules: false,
+            changed_lines: None,
         }
     }
 
@@ -75,6 +97,7 @@ impl Execution {
                 .build(),
             TraversalMode::Migrate { .. } => FeatureName::empty(),
             TraversalMode::Search { .. } => FeaturesBuilder::new().with_search().build(),
+            TraversalMode::Transform { .. } => FeaturesBuilder::new().with_transform().build(),
         }
     }
 }
@@ -94,11 +117,11 @@ pub struct Stdin(
 );
 
 impl Stdin {
-    fn as_path(&self) -> &Path {
+    pub(crate) fn as_path(&self) -> &Path {
         self.0.as_path()
     }
 
-    fn as_content(&self) -> &str {
+    pub(crate) fn as_content(&self) -> &str {
         self.1.as_str()
     }
 }
@@ -151,6 +174,8 @@ pub enum TraversalMode {
         skip: Vec<RuleSelector>,
         /// A flag to know vcs integrated options such as `--staged` or `--changed` are enabled
         vcs_targeted: VcsTargeted,
+        /// The reason written in the suppression comments, when running the command `biome suppress`
+        suppression_reason: Option<String>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -185,14 +210,29 @@ pub enum TraversalMode {
     /// This mode is enabled when running the command `biome search`
     Search {
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command does not support rewrites.
         pattern: PatternId,
 
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<Stdin>,
+
+        /// It writes the rewrites of the pattern to disk
+        write: bool,
+    },
+    /// This mode is enabled when running the command `biome transform`
+    Transform {
+        /// The names of the transformations to apply. All the transformations
+        /// are applied when it's empty.
+        transformations: Vec<String>,
+
+        /// An optional tuple.
+        /// 1. The virtual path to the file
+        /// 2. The content of the file
+        stdin: Option<Stdin>,
+
+        /// It writes the transformed code to disk
+        write: bool,
     },
 }
 
@@ -205,6 +245,7 @@ impl Display for TraversalMode {
             TraversalMode::Migrate { .. } => write!(f, "migrate"),
             TraversalMode::Lint { .. } => write!(f, "lint"),
             TraversalMode::Search { .. } => write!(f, "search"),
+            TraversalMode::Transform { .. } => write!(f, "transform"),
         }
     }
 }
@@ -223,6 +264,10 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Reports the changes to the files as unified diffs, that can be applied with `git apply`
+    Diff,
 }
 
 impl Default for ReportMode {
@@ -245,6 +290,8 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Diff => Self::Diff,
         }
     }
 }
@@ -255,6 +302,10 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            settings_fingerprint: None,
+            baseline: None,
+            profile_rules: false,
+            changed_lines: None,
         }
     }
 
@@ -275,6 +326,10 @@ impl Execution {
                 vcs_targeted,
             },
             max_diagnostics: 20,
+            settings_fingerprint: None,
+            baseline: None,
+            profile_rules: false,
+            changed_lines: None,
         }
     }
 
@@ -284,6 +339,40 @@ impl Execution {
         self
     }
 
+    /// It enables the persistent cache, if the fingerprint of the settings is provided
+    pub(crate) fn set_cache(mut self, settings_fingerprint: Option<u64>) -> Self {
+        self.settings_fingerprint = settings_fingerprint;
+        self
+    }
+
+    /// It enables the profiling of the lint rules
+    pub(crate) fn set_profile_rules(mut self, profile_rules: bool) -> Self {
+        self.profile_rules = profile_rules;
+        self
+    }
+
+    pub(crate) fn profile_rules(&self) -> bool {
+        self.profile_rules
+    }
+
+    /// It reports only the diagnostics of the given lines
+    pub(crate) fn set_changed_lines(mut self, changed_lines: Option<ChangedLines>) -> Self {
+        self.changed_lines = changed_lines;
+        self
+    }
+
+    pub(crate) fn changed_lines(&self) -> Option<&ChangedLines> {
+        self.changed_lines.as_ref()
+    }
+
+    pub(crate) fn settings_fingerprint(&self) -> Option<u64> {
+        self.settings_fingerprint
+    }
+
+    pub(crate) fn baseline(&self) -> Option<&BaselineMode> {
+        self.baseline.as_ref()
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -300,7 +389,23 @@ impl Execution {
             TraversalMode::Format { .. }
             | TraversalMode::CI { .. }
             | TraversalMode::Migrate { .. }
-            | TraversalMode::Search { .. } => None,
+            | TraversalMode::Search { .. }
+            | TraversalMode::Transform { .. } => None,
+        }
+    }
+
+    /// The reason of the suppression comments, when the diagnostics are suppressed
+    pub(crate) fn suppression_reason(&self) -> Option<&str> {
+        match &self.traversal_mode {
+            TraversalMode::Lint {
+                suppression_reason, ..
+            } => suppression_reason.as_deref(),
+            TraversalMode::Check { .. }
+            | TraversalMode::CI { .. }
+            | TraversalMode::Format { .. }
+            | TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Transform { .. } => None,
         }
     }
 
@@ -312,9 +417,16 @@ impl Execution {
             TraversalMode::Format { .. } => category!("format"),
             TraversalMode::Migrate { .. } => category!("migrate"),
             TraversalMode::Search { .. } => category!("search"),
+            TraversalMode::Transform { .. } => category!("transform"),
         }
     }
 
+    /// Whether the changes are reported as unified diffs. In this mode, the changes are
+    /// applied to the workspace only, and the files are never written.
+    pub(crate) const fn is_diff_report(&self) -> bool {
+        matches!(self.report_mode, ReportMode::Diff)
+    }
+
     pub(crate) const fn is_ci(&self) -> bool {
         matches!(self.traversal_mode, TraversalMode::CI { .. })
     }
@@ -365,11 +477,17 @@ impl Execution {
 
     /// Whether the traversal mode requires write access to files
     pub(crate) const fn requires_write_access(&self) -> bool {
+        if self.is_diff_report() {
+            return false;
+        }
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. }
+            | TraversalMode::Transform { write, .. } => write,
         }
     }
 
@@ -378,7 +496,8 @@ impl Execution {
             TraversalMode::Format { stdin, .. }
             | TraversalMode::Lint { stdin, .. }
             | TraversalMode::Check { stdin, .. }
-            | TraversalMode::Search { stdin, .. } => stdin.as_ref(),
+            | TraversalMode::Search { stdin, .. }
+            | TraversalMode::Transform { stdin, .. } => stdin.as_ref(),
             TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => None,
         }
     }
@@ -389,7 +508,9 @@ impl Execution {
             | TraversalMode::Lint { vcs_targeted, .. }
             | TraversalMode::Format { vcs_targeted, .. }
             | TraversalMode::CI { vcs_targeted, .. } => vcs_targeted.staged || vcs_targeted.changed,
-            TraversalMode::Migrate { .. } | TraversalMode::Search { .. } => false,
+            TraversalMode::Migrate { .. }
+            | TraversalMode::Search { .. }
+            | TraversalMode::Transform { .. } => false,
         }
     }
 
@@ -401,7 +522,8 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
+            TraversalMode::Transform { write, .. } => write,
         }
     }
 }
@@ -421,6 +543,7 @@ pub fn execute_mode(
         info!("Removing the limit of --max-diagnostics, because of a reporter different from the default one: {}", cli_options.reporter);
         u32::MAX
     };
+    execution.baseline = BaselineMode::from_cli_options(cli_options)?;
 
     // don't do any traversal if there's some content coming from stdin
     if let Some(stdin) = execution.as_stdin_file() {
@@ -453,6 +576,8 @@ pub fn execute_mode(
             summary,
             evaluated_paths,
             diagnostics,
+            diffs,
+            profile: rule_profile,
         } = traverse(&execution, &mut session, cli_options, paths)?;
         let console = session.app.console;
         let errors = summary.errors;
@@ -471,6 +596,7 @@ pub fn execute_mode(
                             diagnostics,
                         },
                         execution: execution.clone(),
+                        rule_profile,
                     };
                     reporter.write(&mut SummaryReporterVisitor(console))?;
                 } else {
@@ -483,6 +609,7 @@ pub fn execute_mode(
                         },
                         execution: execution.clone(),
                         evaluated_paths,
+                        rule_profile,
                     };
                     reporter.write(&mut ConsoleReporterVisitor(console))?;
                 }
@@ -499,6 +626,7 @@ pub fn execute_mode(
                         diagnostics,
                     },
                     execution: execution.clone(),
+                    rule_profile,
                 };
                 let mut buffer = JsonReporterVisitor::new(summary);
                 reporter.write(&mut buffer)?;
@@ -552,6 +680,35 @@ pub fn execute_mode(
                     session.app.fs.borrow().working_directory(),
                 ))?;
             }
+            ReportMode::Sarif => {
+                let reporter = SarifReporter {
+                    diagnostics: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut SarifReporterVisitor::new(
+                    console,
+                    session.app.fs.borrow().working_directory(),
+                ))?;
+            }
+            ReportMode::Diff => {
+                let reporter = DiffReporter {
+                    diagnostics: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    diffs,
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut DiffReporterVisitor::new(
+                    console,
+                    session.app.fs.borrow().working_directory(),
+                ))?;
+            }
             ReportMode::Junit => {
                 let reporter = JunitReporter {
                     summary,
@@ -4,8 +4,10 @@ mod format;
 mod lint;
 mod organize_imports;
 mod search;
+mod transform;
 pub(crate) mod workspace_file;
 
+use crate::execute::cache::CachedMessage;
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
@@ -16,8 +18,11 @@ use check::check_file;
 use format::format;
 use lint::lint;
 use search::search;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::ops::Deref;
+use transform::transform;
 
 #[derive(Debug)]
 pub(crate) enum FileStatus {
@@ -70,11 +75,17 @@ impl Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
     Assists,
+    /// The rewrites of a GritQL pattern
+    Rewrite,
+    /// The changes of the transformations
+    Transform,
+    /// All the changes applied to a file, when they are reported as diffs
+    Patch,
 }
 
 impl<D> From<D> for Message
@@ -102,6 +113,8 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// The messages sent while processing the file, recorded only when the cache is enabled
+    recorded_messages: Option<RefCell<Vec<CachedMessage>>>,
     _p: PhantomData<&'app ()>,
 }
 
@@ -110,8 +123,32 @@ impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
         Self {
             _p: PhantomData,
             inner: t,
+            recorded_messages: None,
         }
     }
+
+    /// It records the messages sent to the display thread, so they can be stored in the cache
+    fn with_recorded_messages(mut self) -> Self {
+        self.recorded_messages = Some(RefCell::default());
+        self
+    }
+
+    fn into_recorded_messages(self) -> Vec<CachedMessage> {
+        self.recorded_messages
+            .map(RefCell::into_inner)
+            .unwrap_or_default()
+    }
+
+    /// Send a message to the display thread
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        let mut msg = msg.into();
+        if let Some(recorded_messages) = &self.recorded_messages {
+            let (cached_message, message) = CachedMessage::record(msg);
+            recorded_messages.borrow_mut().push(cached_message);
+            msg = message;
+        }
+        self.inner.push_message(msg);
+    }
 }
 
 impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
@@ -126,8 +163,55 @@ impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
 /// and parse it; analyze and / or format it; then it either fails if error
 /// diagnostics were emitted, or compare the formatted code with the original
 /// content of the file and emit a diff or write the new content to the disk if
-/// write mode is enabled
+/// write mode is enabled.
+///
+/// When the cache is enabled, the messages of a file that didn't change since the
+/// last traversal are replayed, and the file isn't processed.
 pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> FileResult {
+    let Some(cache) = ctx.cache else {
+        return process_file_with_options(&SharedTraversalOptions::new(ctx), biome_path);
+    };
+    let Ok(content) = ctx.fs.read_file_from_path(&biome_path.to_path_buf()) else {
+        return process_file_with_options(&SharedTraversalOptions::new(ctx), biome_path);
+    };
+
+    let fingerprint = cache.file_fingerprint(&content);
+    if let Some(messages) = cache.get(biome_path, &content, fingerprint) {
+        for message in messages {
+            ctx.push_message(message);
+        }
+        ctx.increment_cached();
+        return Ok(FileStatus::Unchanged);
+    }
+
+    let shared_context = SharedTraversalOptions::new(ctx).with_recorded_messages();
+    let result = process_file_with_options(&shared_context, biome_path);
+    match result {
+        // Only the files that weren't changed are stored, because their content is the one
+        // used to compute the fingerprint
+        Ok(FileStatus::Unchanged) => {
+            cache.insert(
+                biome_path,
+                fingerprint,
+                shared_context.into_recorded_messages(),
+            );
+            Ok(FileStatus::Unchanged)
+        }
+        Ok(FileStatus::Message(message)) => {
+            let (cached_message, message) = CachedMessage::record(message);
+            let mut messages = shared_context.into_recorded_messages();
+            messages.push(cached_message);
+            cache.insert(biome_path, fingerprint, messages);
+            Ok(FileStatus::Message(message))
+        }
+        result => result,
+    }
+}
+
+fn process_file_with_options<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    biome_path: &BiomePath,
+) -> FileResult {
     tracing::trace_span!("process_file", path = ?biome_path).in_scope(move || {
         let file_features = ctx
             .workspace
@@ -187,6 +271,9 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             TraversalMode::Lint { .. } => file_features.support_kind_for(&FeatureKind::Lint),
             TraversalMode::Migrate { .. } => None,
             TraversalMode::Search { .. } => file_features.support_kind_for(&FeatureKind::Search),
+            TraversalMode::Transform { .. } => {
+                file_features.support_kind_for(&FeatureKind::Transform)
+            }
         };
 
         if let Some(reason) = unsupported_reason {
@@ -206,26 +293,31 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             };
         }
 
-        let shared_context = &SharedTraversalOptions::new(ctx);
-
         match ctx.execution.traversal_mode {
             TraversalMode::Lint { .. } => {
                 // the unsupported case should be handled already at this point
-                lint(shared_context, biome_path)
+                lint(ctx, biome_path)
             }
             TraversalMode::Format { .. } => {
                 // the unsupported case should be handled already at this point
-                format(shared_context, biome_path)
+                format(ctx, biome_path)
             }
             TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
-                check_file(shared_context, biome_path, &file_features)
+                check_file(ctx, biome_path, &file_features)
             }
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
             TraversalMode::Search { ref pattern, .. } => {
                 // the unsupported case should be handled already at this point
-                search(shared_context, biome_path, pattern)
+                search(ctx, biome_path, pattern)
+            }
+            TraversalMode::Transform {
+                ref transformations,
+                ..
+            } => {
+                // the unsupported case should be handled already at this point
+                transform(ctx, biome_path, transformations)
             }
         }
     })
@@ -1,5 +1,3 @@
-use std::ffi::OsStr;
-
 use crate::execute::diagnostics::ResultExt;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
@@ -7,8 +5,7 @@ use crate::execute::process_file::{
 };
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::category;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
-use biome_service::workspace::FixFileMode;
+use biome_service::workspace::{FixFileGuardParams, FixFileMode};
 
 /// Lints a single file and returns a [FileResult]
 pub(crate) fn assists_with_guard<'ctx>(
@@ -22,13 +19,15 @@ pub(crate) fn assists_with_guard<'ctx>(
             let skip = Vec::new();
             let fix_result = workspace_file
                 .guard()
-                .fix_file(
-                    FixFileMode::SafeFixes,
-                    false,
-                    RuleCategoriesBuilder::default().with_action().build(),
-                    only.clone(),
-                    skip.clone(),
-                )
+                .fix_file(FixFileGuardParams {
+                    fix_file_mode: FixFileMode::SafeFixes,
+                    should_format: false,
+                    rule_categories: RuleCategoriesBuilder::default().with_action().build(),
+                    only: only.clone(),
+                    skip: skip.clone(),
+                    suppression_reason: None,
+                    profile: false,
+                })
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("assists"),
@@ -38,22 +37,9 @@ pub(crate) fn assists_with_guard<'ctx>(
                 skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
             });
 
-            let mut output = fix_result.code;
-
-            match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
-                Some(b"astro") => {
-                    output = AstroFileHandler::output(input.as_str(), output.as_str());
-                }
-                Some(b"vue") => {
-                    output = VueFileHandler::output(input.as_str(), output.as_str());
-                }
-                Some(b"svelte") => {
-                    output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                }
-                _ => {}
-            }
+            let output = fix_result.code;
             if input != output {
-                if ctx.execution.as_fix_file_mode().is_none() {
+                if ctx.execution.as_fix_file_mode().is_none() && !ctx.execution.is_diff_report() {
                     return Ok(FileStatus::Message(Message::Diff {
                         file_name: workspace_file.path.display().to_string(),
                         old: input,
@@ -61,9 +47,7 @@ pub(crate) fn assists_with_guard<'ctx>(
                         diff_kind: DiffKind::Assists,
                     }));
                 } else {
-                    if output != input && ctx.execution.as_fix_file_mode().is_some() {
-                        workspace_file.update_file(output)?;
-                    }
+                    workspace_file.update_file(output)?;
                     Ok(FileStatus::Changed)
                 }
             } else {
@@ -101,6 +101,10 @@ pub(crate) fn check_file<'ctx>(
                 }
             }
 
+            if let Some(patch) = workspace_file.to_patch()? {
+                ctx.push_message(patch);
+            }
+
             if has_failures {
                 Ok(FileStatus::Message(Message::Failure))
             } else if changed {
@@ -6,15 +6,17 @@ use crate::execute::process_file::{
 use crate::execute::TraversalMode;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Error, Severity};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
-use std::ffi::OsStr;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use tracing::debug;
 
 pub(crate) fn format<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    format_with_guard(ctx, &mut workspace_file)
+    let result = format_with_guard(ctx, &mut workspace_file);
+    if let Some(patch) = workspace_file.to_patch()? {
+        ctx.push_message(patch);
+    }
+    result
 }
 
 pub(crate) fn format_with_guard<'ctx>(
@@ -32,6 +34,7 @@ pub(crate) fn format_with_guard<'ctx>(
                     max_diagnostics,
                     Vec::new(),
                     Vec::new(),
+                    false,
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
@@ -51,6 +54,8 @@ pub(crate) fn format_with_guard<'ctx>(
                     false,
                 ),
             };
+            // The diff reporter applies the changes to the workspace only, and prints them at the end
+            let should_write = should_write || ctx.execution.is_diff_report();
             debug!("Should write the file to disk? {}", should_write);
             debug!("Should ignore errors? {}", ignore_errors);
 
@@ -85,35 +90,12 @@ pub(crate) fn format_with_guard<'ctx>(
                     category!("format"),
                 )?;
 
-            let mut output = printed.into_code();
+            let output = printed.into_code();
 
             if ignore_errors {
                 return Ok(FileStatus::Ignored);
             }
 
-            match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
-                Some(b"astro") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = AstroFileHandler::output(input.as_str(), output.as_str());
-                }
-                Some(b"vue") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = VueFileHandler::output(input.as_str(), output.as_str());
-                }
-
-                Some(b"svelte") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                }
-                _ => {}
-            }
-
             if output != input {
                 if should_write {
                     workspace_file.update_file(output)?;
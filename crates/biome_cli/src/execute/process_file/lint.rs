@@ -4,16 +4,18 @@ use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraver
 use crate::TraversalMode;
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{category, Error};
-use biome_rowan::TextSize;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
-use std::ffi::OsStr;
+use biome_service::workspace::FixFileGuardParams;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
 /// Lints a single file and returns a [FileResult]
 pub(crate) fn lint<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    lint_with_guard(ctx, &mut workspace_file)
+    let result = lint_with_guard(ctx, &mut workspace_file);
+    if let Some(patch) = workspace_file.to_patch()? {
+        ctx.push_message(patch);
+    }
+    result
 }
 
 pub(crate) fn lint_with_guard<'ctx>(
@@ -33,39 +35,32 @@ pub(crate) fn lint_with_guard<'ctx>(
             if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
                 let fix_result = workspace_file
                     .guard()
-                    .fix_file(
-                        *fix_mode,
-                        false,
-                        RuleCategoriesBuilder::default()
+                    .fix_file(FixFileGuardParams {
+                        fix_file_mode: *fix_mode,
+                        should_format: false,
+                        rule_categories: RuleCategoriesBuilder::default()
                             .with_syntax()
                             .with_lint()
                             .build(),
-                        only.clone(),
-                        skip.clone(),
-                    )
+                        only: only.clone(),
+                        skip: skip.clone(),
+                        suppression_reason: ctx.execution.suppression_reason().map(str::to_string),
+                        profile: ctx.execution.profile_rules(),
+                    })
                     .with_file_path_and_code(
                         workspace_file.path.display().to_string(),
                         category!("lint"),
                     )?;
 
+                ctx.merge_profile(fix_result.profile);
+                for diagnostic in fix_result.diagnostics {
+                    ctx.push_message(Error::from(diagnostic));
+                }
                 ctx.push_message(Message::SkippedFixes {
                     skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
                 });
 
-                let mut output = fix_result.code;
-
-                match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
-                    Some(b"astro") => {
-                        output = AstroFileHandler::output(input.as_str(), output.as_str());
-                    }
-                    Some(b"vue") => {
-                        output = VueFileHandler::output(input.as_str(), output.as_str());
-                    }
-                    Some(b"svelte") => {
-                        output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                    }
-                    _ => {}
-                }
+                let output = fix_result.code;
                 if output != input {
                     changed = true;
                     workspace_file.update_file(output)?;
@@ -84,36 +79,25 @@ pub(crate) fn lint_with_guard<'ctx>(
                     max_diagnostics,
                     only,
                     skip,
+                    ctx.execution.profile_rules(),
                 )
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("lint"),
                 )?;
 
+            ctx.merge_profile(pull_diagnostics_result.profile);
+
             let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
                 && pull_diagnostics_result.skipped_diagnostics == 0;
 
             if !no_diagnostics {
-                let offset = match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
-                    Some(b"vue") => VueFileHandler::start(input.as_str()),
-                    Some(b"astro") => AstroFileHandler::start(input.as_str()),
-                    Some(b"svelte") => SvelteFileHandler::start(input.as_str()),
-                    _ => None,
-                };
-
                 ctx.push_message(Message::Diagnostics {
                     name: workspace_file.path.display().to_string(),
                     content: input,
                     diagnostics: pull_diagnostics_result
                         .diagnostics
                         .into_iter()
-                        .map(|d| {
-                            if let Some(offset) = offset {
-                                d.with_offset(TextSize::from(offset))
-                            } else {
-                                d
-                            }
-                        })
                         .map(Error::from)
                         .collect(),
                     skipped_diagnostics: pull_diagnostics_result.skipped_diagnostics as u32,
@@ -1,12 +1,9 @@
-use std::ffi::OsStr;
-
 use crate::execute::diagnostics::ResultExt;
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{
     DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
 };
 use biome_diagnostics::category;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 
 /// Lints a single file and returns a [FileResult]
 pub(crate) fn organize_imports_with_guard<'ctx>(
@@ -24,33 +21,13 @@ pub(crate) fn organize_imports_with_guard<'ctx>(
                 )?;
 
             let input = workspace_file.input()?;
-            let mut output = sorted.code;
-
-            match workspace_file.as_extension().map(OsStr::as_encoded_bytes) {
-                Some(b"astro") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = AstroFileHandler::output(input.as_str(), output.as_str());
-                }
-                Some(b"vue") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = VueFileHandler::output(input.as_str(), output.as_str());
-                }
-
-                Some(b"svelte") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                }
-                _ => {}
-            }
+            let output = sorted.code;
 
             if output != input {
-                if ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe() {
+                if ctx.execution.is_check_apply()
+                    || ctx.execution.is_check_apply_unsafe()
+                    || ctx.execution.is_diff_report()
+                {
                     workspace_file.update_file(output)?;
                 } else {
                     return Ok(FileStatus::Message(Message::Diff {
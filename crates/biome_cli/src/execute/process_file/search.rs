@@ -1,6 +1,8 @@
 use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
 use biome_diagnostics::{category, DiagnosticExt};
 use biome_service::workspace::PatternId;
 use std::path::Path;
@@ -11,11 +13,15 @@ pub(crate) fn search<'ctx>(
     pattern: &PatternId,
 ) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    search_with_guard(ctx, &mut workspace_file, pattern)
+    let result = search_with_guard(ctx, &mut workspace_file, pattern);
+    if let Some(patch) = workspace_file.to_patch()? {
+        ctx.push_message(patch);
+    }
+    result
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
 ) -> FileResult {
@@ -32,6 +38,18 @@ pub(crate) fn search_with_guard<'ctx>(
             let input = workspace_file.input()?;
             let file_name = workspace_file.path.display().to_string();
             let matches_len = result.matches.len();
+            let rewritten = result.rewritten.filter(|rewritten| *rewritten != input);
+
+            if let Some(rewritten) = &rewritten {
+                if !ctx.execution.is_write() {
+                    ctx.push_message(Message::Diff {
+                        file_name: file_name.clone(),
+                        old: input.clone(),
+                        new: rewritten.clone(),
+                        diff_kind: DiffKind::Rewrite,
+                    });
+                }
+            }
 
             let search_results = Message::Diagnostics {
                 name: file_name,
@@ -44,7 +62,15 @@ pub(crate) fn search_with_guard<'ctx>(
                 skipped_diagnostics: 0,
             };
 
-            Ok(FileStatus::SearchResult(matches_len, search_results))
+            match rewritten {
+                Some(rewritten) if ctx.execution.is_write() => {
+                    workspace_file.update_file(rewritten)?;
+                    ctx.increment_matches(matches_len);
+                    ctx.push_message(search_results);
+                    Ok(FileStatus::Changed)
+                }
+                _ => Ok(FileStatus::SearchResult(matches_len, search_results)),
+            }
         },
     )
 }
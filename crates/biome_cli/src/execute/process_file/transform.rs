@@ -0,0 +1,100 @@
+use crate::execute::diagnostics::ResultExt;
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
+use biome_analyze::RuleCategoriesBuilder;
+use biome_diagnostics::{category, Error};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+
+/// Applies the transformations to a single file and returns a [FileResult]
+pub(crate) fn transform<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    path: &Path,
+    transformations: &[String],
+) -> FileResult {
+    let mut workspace_file = WorkspaceFile::new(ctx, path)?;
+    let result = transform_with_guard(ctx, &mut workspace_file, transformations);
+    if let Some(patch) = workspace_file.to_patch()? {
+        ctx.push_message(patch);
+    }
+    result
+}
+
+pub(crate) fn transform_with_guard<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &mut WorkspaceFile,
+    transformations: &[String],
+) -> FileResult {
+    tracing::info_span!("Processes transforming", path =? workspace_file.path.display()).in_scope(
+        move || {
+            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            let diagnostics_result = workspace_file
+                .guard()
+                .pull_diagnostics(
+                    RuleCategoriesBuilder::default().with_syntax().build(),
+                    max_diagnostics,
+                    Vec::new(),
+                    Vec::new(),
+                    false,
+                )
+                .with_file_path_and_code(
+                    workspace_file.path.display().to_string(),
+                    category!("transform"),
+                )?;
+
+            let input = workspace_file.input()?;
+            let file_name = workspace_file.path.display().to_string();
+            let has_errors = diagnostics_result.errors > 0;
+            ctx.push_message(Message::Diagnostics {
+                name: file_name.clone(),
+                content: input.clone(),
+                diagnostics: diagnostics_result
+                    .diagnostics
+                    .into_iter()
+                    .map(Error::from)
+                    .collect(),
+                skipped_diagnostics: diagnostics_result.skipped_diagnostics as u32,
+            });
+
+            // The transformations could emit broken code out of a broken syntax tree
+            if has_errors {
+                return Ok(FileStatus::Unchanged);
+            }
+
+            let result = workspace_file
+                .guard()
+                .transform_file(transformations.to_vec())
+                .with_file_path_and_code(file_name.clone(), category!("transform"))?;
+            let output = result.code;
+
+            // The diagnostics of the transformations refer to the transformed code
+            if !result.diagnostics.is_empty() {
+                ctx.push_message(Message::Diagnostics {
+                    name: file_name.clone(),
+                    content: output.clone(),
+                    diagnostics: result.diagnostics.into_iter().map(Error::from).collect(),
+                    skipped_diagnostics: 0,
+                });
+            }
+
+            if output == input {
+                return Ok(FileStatus::Unchanged);
+            }
+
+            // The diff reporter applies the changes to the workspace only, and prints them at the end
+            if ctx.execution.is_write() || ctx.execution.is_diff_report() {
+                workspace_file.update_file(output)?;
+                Ok(FileStatus::Changed)
+            } else {
+                Ok(FileStatus::Message(Message::Diff {
+                    file_name,
+                    old: input,
+                    new: output,
+                    diff_kind: DiffKind::Transform,
+                }))
+            }
+        },
+    )
+}
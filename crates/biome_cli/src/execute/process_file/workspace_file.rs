@@ -1,10 +1,9 @@
 use crate::execute::diagnostics::{ResultExt, ResultIoExt};
-use crate::execute::process_file::SharedTraversalOptions;
+use crate::execute::process_file::{DiffKind, Message, SharedTraversalOptions};
 use biome_diagnostics::{category, Error};
 use biome_fs::{BiomePath, File, OpenOptions};
 use biome_service::workspace::{FileGuard, OpenFileParams};
 use biome_service::{Workspace, WorkspaceError};
-use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 
 /// Small wrapper that holds information and operations around the current processed file
@@ -12,6 +11,11 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
     guard: FileGuard<'app, dyn Workspace + 'ctx>,
     file: Box<dyn File>,
     pub(crate) path: PathBuf,
+    /// The content read from disk, kept when the changes are reported as diffs
+    /// instead of being written to disk
+    original: Option<String>,
+    /// The version of the workspace document, when the file isn't written to disk
+    version: i32,
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
@@ -46,9 +50,11 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         .with_file_path_and_code(path.display().to_string(), category!("internalError/fs"))?;
 
         Ok(Self {
+            version: file.file_version(),
             file,
             guard,
             path: PathBuf::from(path),
+            original: ctx.execution.is_diff_report().then_some(input),
         })
     }
 
@@ -60,19 +66,39 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         self.guard().get_file_content()
     }
 
-    pub(crate) fn as_extension(&self) -> Option<&OsStr> {
-        self.path.extension()
-    }
-
-    /// It updates the workspace file with `new_content`
+    /// It updates the workspace file with `new_content`. The file on disk is left untouched
+    /// when the changes are reported as diffs.
     pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
         let new_content = new_content.into();
 
-        self.file
-            .set_content(new_content.as_bytes())
-            .with_file_path(self.path.display().to_string())?;
-        self.guard
-            .change_file(self.file.file_version(), new_content)?;
+        if self.original.is_some() {
+            self.version += 1;
+        } else {
+            self.file
+                .set_content(new_content.as_bytes())
+                .with_file_path(self.path.display().to_string())?;
+            self.version = self.file.file_version();
+        }
+        self.guard.change_file(self.version, new_content)?;
         Ok(())
     }
+
+    /// It returns the diff between the content read from disk and the content of the
+    /// workspace, if the changes are reported as diffs and the file was updated.
+    pub(crate) fn to_patch(&self) -> Result<Option<Message>, WorkspaceError> {
+        let Some(original) = &self.original else {
+            return Ok(None);
+        };
+        let content = self.input()?;
+        if &content == original {
+            return Ok(None);
+        }
+
+        Ok(Some(Message::Diff {
+            file_name: self.path.display().to_string(),
+            old: original.clone(),
+            new: content,
+            diff_kind: DiffKind::Patch,
+        }))
+    }
 }
@@ -4,13 +4,13 @@ use crate::execute::Execution;
 use crate::{CliDiagnostic, CliSession, TraversalMode};
 use biome_analyze::RuleCategoriesBuilder;
 use biome_console::{markup, ConsoleExt};
-use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
+use biome_diagnostics::{Diagnostic, DiagnosticExt, Error};
 use biome_fs::BiomePath;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
     ChangeFileParams, DropPatternParams, FeaturesBuilder, FixFileParams, FormatFileParams,
-    OpenFileParams, OrganizeImportsParams, SupportsFeatureParams,
+    OpenFileParams, OrganizeImportsParams, SearchPatternParams, SupportsFeatureParams,
+    TransformFileParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -55,13 +55,7 @@ pub(crate) fn run<'a>(
                 path: biome_path.clone(),
             })?;
 
-            let code = printed.into_code();
-            let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                Some(b"astro") => AstroFileHandler::output(content, code.as_str()),
-                Some(b"vue") => VueFileHandler::output(content, code.as_str()),
-                Some(b"svelte") => SvelteFileHandler::output(content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             console.append(markup! {
                 {output}
             });
@@ -125,14 +119,13 @@ pub(crate) fn run<'a>(
                         .with_syntax()
                         .with_lint()
                         .build(),
+                    suppression_reason: mode.suppression_reason().map(str::to_string),
+                    profile: false,
                 })?;
-                let code = fix_file_result.code;
-                let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                    Some(b"astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                    Some(b"vue") => VueFileHandler::output(&new_content, code.as_str()),
-                    Some(b"svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                    _ => code,
-                };
+                for diagnostic in &fix_file_result.diagnostics {
+                    console.error(markup! {{PrintDiagnostic::simple(diagnostic)}});
+                }
+                let output = fix_file_result.code;
                 if output != new_content {
                     version += 1;
                     workspace.change_file(ChangeFileParams {
@@ -148,13 +141,7 @@ pub(crate) fn run<'a>(
                 let result = workspace.organize_imports(OrganizeImportsParams {
                     path: biome_path.clone(),
                 })?;
-                let code = result.code;
-                let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                    Some(b"astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                    Some(b"vue") => VueFileHandler::output(&new_content, code.as_str()),
-                    Some(b"svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                    _ => code,
-                };
+                let output = result.code;
                 if output != new_content {
                     version += 1;
                     workspace.change_file(ChangeFileParams {
@@ -171,13 +158,7 @@ pub(crate) fn run<'a>(
             let printed = workspace.format_file(FormatFileParams {
                 path: biome_path.clone(),
             })?;
-            let code = printed.into_code();
-            let output = match biome_path.extension().map(|ext| ext.as_encoded_bytes()) {
-                Some(b"astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                Some(b"vue") => VueFileHandler::output(&new_content, code.as_str()),
-                Some(b"svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             if (mode.is_check_apply() || mode.is_check_apply_unsafe()) && output != new_content {
                 new_content = Cow::Owned(output);
             }
@@ -199,13 +180,68 @@ pub(crate) fn run<'a>(
                 });
             }
         }
-    } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+    } else if let TraversalMode::Search { pattern, write, .. } = mode.traversal_mode() {
+        let result = if *write {
+            workspace
+                .open_file(OpenFileParams {
+                    path: biome_path.clone(),
+                    version: 0,
+                    content: content.into(),
+                    document_file_source: None,
+                })
+                .and_then(|_| {
+                    workspace.search_pattern(SearchPatternParams {
+                        path: biome_path.clone(),
+                        pattern: pattern.clone(),
+                    })
+                })
+                .map(|results| results.rewritten)
+        } else {
+            Ok(None)
+        };
+
         // Make sure patterns are always cleaned up at the end of execution.
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
             pattern: pattern.clone(),
         });
 
-        console.append(markup! {{content}});
+        match result? {
+            Some(rewritten) => console.append(markup! {{rewritten}}),
+            None => console.append(markup! {{content}}),
+        }
+    } else if let TraversalMode::Transform {
+        transformations, ..
+    } = mode.traversal_mode()
+    {
+        let file_features = workspace.file_features(SupportsFeatureParams {
+            path: biome_path.clone(),
+            features: FeaturesBuilder::new().with_transform().build(),
+        })?;
+        if file_features.supports_transform() {
+            workspace.open_file(OpenFileParams {
+                path: biome_path.clone(),
+                version: 0,
+                content: content.into(),
+                document_file_source: None,
+            })?;
+            let result = workspace.transform_file(TransformFileParams {
+                path: biome_path.clone(),
+                transformations: transformations.clone(),
+            })?;
+            for diagnostic in result.diagnostics {
+                let diagnostic = Error::from(diagnostic)
+                    .with_file_path(biome_path.display().to_string())
+                    .with_file_source_code(content);
+                console.error(markup! {{PrintDiagnostic::simple(&diagnostic)}});
+            }
+            console.append(markup! {{result.code}});
+        } else {
+            console.append(markup! {{content}});
+            console.error(markup! {
+                <Warn>"The content was not transformed because the file isn't supported by the transformations."</Warn>
+            });
+            return Err(CliDiagnostic::stdin());
+        }
     } else {
         console.append(markup! {{content}});
     }
@@ -1,30 +1,39 @@
+use super::baseline::Baseline;
+use super::cache::FileCache;
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     AssistsDiffDiagnostic, CIAssistsDiffDiagnostic, CIFormatDiffDiagnostic,
     CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic,
-    OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    OrganizeImportsDiffDiagnostic, PanicDiagnostic, RewriteDiffDiagnostic, TransformDiffDiagnostic,
 };
+use crate::reporter::diff::FileDiff;
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
+use biome_analyze::Profile;
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
 use biome_fs::{BiomePath, FileSystem, PathInterner};
 use biome_fs::{TraversalContext, TraversalScope};
 use biome_service::dome::Dome;
-use biome_service::workspace::{DropPatternParams, IsPathIgnoredParams};
+use biome_service::workspace::{
+    DropPatternParams, IsModuleGraphRequiredParams, IsPathIgnoredParams,
+    SetModuleGraphCompleteParams, UpdateKind, UpdateModuleGraphParams,
+};
 use biome_service::{extension_error, workspace::SupportsFeatureParams, Workspace, WorkspaceError};
 use crossbeam::channel::{unbounded, Receiver, Sender};
+use rayon::prelude::*;
 use rustc_hash::FxHashSet;
 use std::collections::BTreeSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::atomic::AtomicU32;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 use std::{
     env::current_dir,
     ffi::OsString,
     panic::catch_unwind,
-    path::PathBuf,
+    path::{Component, Path, PathBuf},
     sync::{
         atomic::{AtomicUsize, Ordering},
         Once,
@@ -37,6 +46,10 @@ pub(crate) struct TraverseResult {
     pub(crate) summary: TraversalSummary,
     pub(crate) evaluated_paths: BTreeSet<BiomePath>,
     pub(crate) diagnostics: Vec<Error>,
+    /// The changes to the files, collected only when they are reported as diffs
+    pub(crate) diffs: Vec<FileDiff>,
+    /// The time spent by the analyzer, collected only when the rules are profiled
+    pub(crate) profile: Option<Profile>,
 }
 
 pub(crate) fn traverse(
@@ -53,7 +66,8 @@ pub(crate) fn traverse(
             | TraversalMode::Lint { .. }
             | TraversalMode::Format { .. }
             | TraversalMode::CI { .. }
-            | TraversalMode::Search { .. } => {
+            | TraversalMode::Search { .. }
+            | TraversalMode::Transform { .. } => {
                 // If `--staged` or `--changed` is specified, it's acceptable for them to be empty, so ignore it.
                 if !execution.is_vcs_targeted() {
                     match current_dir() {
@@ -80,19 +94,36 @@ pub(crate) fn traverse(
     let unchanged = AtomicUsize::new(0);
     let matches = AtomicUsize::new(0);
     let skipped = AtomicUsize::new(0);
+    let cached = AtomicUsize::new(0);
+    let profile = Mutex::default();
 
     let fs = &*session.app.fs;
     let workspace = &*session.app.workspace;
 
+    let baseline = execution
+        .baseline()
+        .map(|mode| Baseline::load(fs, mode))
+        .transpose()?;
+
     let max_diagnostics = execution.get_max_diagnostics();
-    let remaining_diagnostics = AtomicU32::new(max_diagnostics);
+    // The baseline needs all the diagnostics of a file, even the ones that aren't printed
+    let remaining_diagnostics = AtomicU32::new(if baseline.is_some() {
+        u32::MAX
+    } else {
+        max_diagnostics
+    });
 
     let printer = DiagnosticsPrinter::new(execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
-        .with_max_diagnostics(max_diagnostics);
+        .with_max_diagnostics(max_diagnostics)
+        .with_baseline(baseline.as_ref());
+
+    let cache = execution
+        .settings_fingerprint()
+        .map(|settings_fingerprint| FileCache::load(fs, execution, settings_fingerprint));
 
-    let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
+    let (duration, evaluated_paths, mut diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("biome::console"))
             .spawn_scoped(s, || printer.run(receiver, recv_files))
@@ -112,8 +143,11 @@ pub(crate) fn traverse(
                 changed: &changed,
                 unchanged: &unchanged,
                 skipped: &skipped,
+                cached: &cached,
+                cache: cache.as_ref(),
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
+                profile: &profile,
                 evaluated_paths: RwLock::default(),
             },
         );
@@ -123,6 +157,15 @@ pub(crate) fn traverse(
         (elapsed, evaluated_paths, diagnostics)
     });
 
+    if let Some(cache) = cache {
+        cache.persist(fs);
+    }
+
+    let baselined = baseline.as_ref().map_or(0, Baseline::matched);
+    if let Some(baseline) = &baseline {
+        diagnostics.extend(baseline.finish(fs, &evaluated_paths)?);
+    }
+
     // Make sure patterns are always cleaned up at the end of traversal.
     if let TraversalMode::Search { pattern, .. } = execution.traversal_mode() {
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
@@ -136,8 +179,13 @@ pub(crate) fn traverse(
     let unchanged = unchanged.load(Ordering::Relaxed);
     let matches = matches.load(Ordering::Relaxed);
     let skipped = skipped.load(Ordering::Relaxed);
+    let cached = cached.load(Ordering::Relaxed);
     let suggested_fixes_skipped = printer.skipped_fixes();
     let diagnostics_not_printed = printer.not_printed_diagnostics();
+    let diffs = printer.take_diffs();
+    let profile = execution
+        .profile_rules()
+        .then(|| profile.into_inner().unwrap());
     Ok(TraverseResult {
         summary: TraversalSummary {
             changed,
@@ -147,11 +195,15 @@ pub(crate) fn traverse(
             matches,
             warnings,
             skipped,
+            cached,
+            baselined,
             suggested_fixes_skipped,
             diagnostics_not_printed,
         },
         evaluated_paths,
         diagnostics,
+        diffs,
+        profile,
     })
 }
 
@@ -176,6 +228,7 @@ fn traverse_inputs(
     ctx: &TraversalOptions,
 ) -> (Duration, BTreeSet<BiomePath>) {
     let start = Instant::now();
+    let is_project_scanned = is_project_scanned(fs, ctx.execution, &inputs);
     fs.traversal(Box::new(move |scope: &dyn TraversalScope| {
         for input in inputs {
             scope.evaluate(ctx, PathBuf::from(input));
@@ -183,6 +236,21 @@ fn traverse_inputs(
     }));
 
     let paths = ctx.evaluated_paths();
+    if is_module_graph_required(ctx) {
+        let fingerprint = update_module_graph(ctx, &paths);
+        if let Some(cache) = ctx.cache {
+            cache.set_module_graph_fingerprint(fingerprint);
+        }
+        if is_project_scanned {
+            let result = ctx
+                .workspace
+                .set_module_graph_complete(SetModuleGraphCompleteParams {});
+            if let Err(error) = result {
+                ctx.push_diagnostic(error.into());
+            }
+        }
+    }
+
     let dome = Dome::new(paths);
     let mut iter = dome.iter();
     fs.traversal(Box::new(|scope: &dyn TraversalScope| {
@@ -202,6 +270,78 @@ fn traverse_inputs(
     (start.elapsed(), ctx.evaluated_paths())
 }
 
+/// Whether the traversal evaluates all the files of the project, in which case
+/// the module graph built from the evaluated paths contains every module of the
+/// project.
+///
+/// It's the case only when one of the inputs is the root of the project, i.e.
+/// the working directory, or one of its ancestors, and the files aren't
+/// filtered by `--changed`, `--staged` or `--changed-lines`.
+fn is_project_scanned(fs: &dyn FileSystem, execution: &Execution, inputs: &[OsString]) -> bool {
+    if execution.is_vcs_targeted() || execution.changed_lines().is_some() {
+        return false;
+    }
+
+    let working_directory = fs.working_directory().unwrap_or_default();
+    let project_root = normalize_input(&working_directory);
+    inputs.iter().any(|input| {
+        let input = normalize_input(&working_directory.join(input));
+        project_root.starts_with(input)
+    })
+}
+
+/// Removes the `.` components of `path`, so `./` and the working directory match
+fn normalize_input(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
+
+/// Whether one of the lint rules that run queries the module graph, in which
+/// case the files must be added to the graph before they are processed
+fn is_module_graph_required(ctx: &TraversalOptions) -> bool {
+    let (only, skip) = match ctx.execution.traversal_mode() {
+        TraversalMode::Check { .. } | TraversalMode::CI { .. } => (Vec::new(), Vec::new()),
+        TraversalMode::Lint { only, skip, .. } => (only.clone(), skip.clone()),
+        _ => return false,
+    };
+    ctx.workspace
+        .is_module_graph_required(IsModuleGraphRequiredParams { only, skip })
+        .unwrap_or_default()
+}
+
+/// Adds the evaluated files to the module graph of the workspace before they
+/// are processed, so the lint rules can resolve the imports between them.
+///
+/// It returns a fingerprint of the paths and the contents of the modules.
+fn update_module_graph(ctx: &TraversalOptions, paths: &BTreeSet<BiomePath>) -> u64 {
+    let mut fingerprints: Vec<u64> = paths
+        .par_iter()
+        .filter_map(|path| {
+            // The files that can't be read are reported when they are processed
+            let content = ctx.fs.read_file_from_path(&path.to_path_buf()).ok()?;
+            let mut hasher = DefaultHasher::new();
+            path.hash(&mut hasher);
+            content.hash(&mut hasher);
+            let fingerprint = hasher.finish();
+
+            let result = ctx.workspace.update_module_graph(UpdateModuleGraphParams {
+                path: path.clone(),
+                update_kind: UpdateKind::AddOrUpdate { content },
+            });
+            if let Err(error) = result {
+                ctx.push_diagnostic(error.into());
+            }
+            Some(fingerprint)
+        })
+        .collect();
+
+    fingerprints.sort_unstable();
+    let mut hasher = DefaultHasher::new();
+    fingerprints.hash(&mut hasher);
+    hasher.finish()
+}
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
@@ -226,6 +366,10 @@ struct DiagnosticsPrinter<'ctx> {
     not_printed_diagnostics: AtomicU32,
     printed_diagnostics: AtomicU32,
     total_skipped_suggested_fixes: AtomicU32,
+    /// The baseline used to suppress or record the diagnostics, if any
+    baseline: Option<&'ctx Baseline>,
+    /// The changes to the files, when they are reported as diffs
+    diffs: Mutex<Vec<FileDiff>>,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -241,6 +385,8 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             not_printed_diagnostics: AtomicU32::new(0),
             printed_diagnostics: AtomicU32::new(0),
             total_skipped_suggested_fixes: AtomicU32::new(0),
+            baseline: None,
+            diffs: Mutex::default(),
         }
     }
 
@@ -259,6 +405,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self
     }
 
+    fn with_baseline(mut self, baseline: Option<&'ctx Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
     fn errors(&self) -> u32 {
         self.errors.load(Ordering::Relaxed)
     }
@@ -275,6 +426,13 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self.total_skipped_suggested_fixes.load(Ordering::Relaxed)
     }
 
+    /// Returns the diffs collected during the traversal, sorted by file name
+    fn take_diffs(&self) -> Vec<FileDiff> {
+        let mut diffs = std::mem::take(&mut *self.diffs.lock().unwrap());
+        diffs.sort_unstable_by(|left, right| left.file_name.cmp(&right.file_name));
+        diffs
+    }
+
     /// Checks if the diagnostic we received from the thread should be considered or not. Logic:
     /// - it should not be considered if its severity level is lower than the one provided via CLI;
     /// - it should not be considered if it's a verbose diagnostic and the CLI **didn't** request a `--verbose` option.
@@ -290,6 +448,22 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         false
     }
 
+    /// Checks if the diagnostic is outside the changed lines, when only their diagnostics are reported
+    fn is_outside_changed_lines(&self, name: &str, content: &str, diagnostic: &Error) -> bool {
+        self.execution
+            .changed_lines()
+            .map_or(false, |changed_lines| {
+                !changed_lines.contains(name, content, diagnostic)
+            })
+    }
+
+    /// Checks if the diagnostic is suppressed, or recorded, by the baseline
+    fn is_baselined(&self, name: &str, content: &str, diagnostic: &Error) -> bool {
+        self.baseline.map_or(false, |baseline| {
+            baseline.suppress(name, content, diagnostic)
+        })
+    }
+
     /// Count the diagnostic, and then returns a boolean that tells if it should be printed
     fn should_print(&self) -> bool {
         let printed_diagnostics = self.printed_diagnostics.load(Ordering::Relaxed);
@@ -384,6 +558,12 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             if self.should_skip_diagnostic(severity, diag.tags()) {
                                 continue;
                             }
+                            if self.is_outside_changed_lines(&name, &content, &diag) {
+                                continue;
+                            }
+                            if self.is_baselined(&name, &content, &diag) {
+                                continue;
+                            }
 
                             if severity == Severity::Error {
                                 self.errors.fetch_add(1, Ordering::Relaxed);
@@ -401,6 +581,12 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                             if self.should_skip_diagnostic(severity, diag.tags()) {
                                 continue;
                             }
+                            if self.is_outside_changed_lines(&name, &content, &diag) {
+                                continue;
+                            }
+                            if self.is_baselined(&name, &content, &diag) {
+                                continue;
+                            }
                             if severity == Severity::Error {
                                 self.errors.fetch_add(1, Ordering::Relaxed);
                             }
@@ -424,14 +610,36 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     new,
                     diff_kind,
                 } => {
-                    // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    // The diffs aren't printed as diagnostics by the diff reporter. They are
+                    // an error when the command wasn't asked to write the changes.
+                    if self.execution.is_diff_report() {
+                        if !matches!(diff_kind, DiffKind::Rewrite | DiffKind::Transform)
+                            && !self.execution.is_write()
+                        {
+                            self.errors.fetch_add(1, Ordering::Relaxed);
+                        }
+                        self.diffs.lock().unwrap().push(FileDiff {
+                            file_name,
+                            old,
+                            new,
+                        });
+                        continue;
+                    }
+
+                    // A diff is an error in CI mode and in format check mode. The rewrites
+                    // of a pattern are results of the search, like its matches, and the
+                    // changes of the transformations are the output of the command.
+                    let is_rewrite = matches!(diff_kind, DiffKind::Rewrite | DiffKind::Transform);
+                    let is_error = !is_rewrite
+                        && (self.execution.is_ci() || !self.execution.is_format_write());
                     if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
                     let severity: Severity = if is_error {
                         Severity::Error
+                    } else if is_rewrite {
+                        Severity::Information
                     } else {
                         // we set lowest
                         Severity::Hint
@@ -446,7 +654,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     if should_print {
                         if self.execution.is_ci() {
                             match diff_kind {
-                                DiffKind::Format => {
+                                DiffKind::Format | DiffKind::Patch => {
                                     let diag = CIFormatDiffDiagnostic {
                                         file_name: file_name.clone(),
                                         diff: ContentDiffAdvice {
@@ -485,10 +693,36 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
+                                DiffKind::Transform => {
+                                    let diag = TransformDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         } else {
                             match diff_kind {
-                                DiffKind::Format => {
+                                DiffKind::Format | DiffKind::Patch => {
                                     let diag = FormatDiffDiagnostic {
                                         file_name: file_name.clone(),
                                         diff: ContentDiffAdvice {
@@ -527,6 +761,32 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                             .with_file_source_code(old.clone()),
                                     )
                                 }
+                                DiffKind::Rewrite => {
+                                    let diag = RewriteDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
+                                DiffKind::Transform => {
+                                    let diag = TransformDiffDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(
+                                        diag.with_severity(severity)
+                                            .with_file_source_code(old.clone()),
+                                    )
+                                }
                             };
                         }
                     }
@@ -555,11 +815,17 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     matches: &'ctx AtomicUsize,
     /// Shared atomic counter storing the number of skipped files
     skipped: &'ctx AtomicUsize,
+    /// Shared atomic counter storing the number of files whose result was read from the cache
+    cached: &'ctx AtomicUsize,
+    /// The persistent cache, if enabled
+    pub(crate) cache: Option<&'ctx FileCache>,
     /// Channel sending messages to the display thread
     pub(crate) messages: Sender<Message>,
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU32,
+    /// The time spent by the analyzer on the processed files, when the rules are profiled
+    profile: &'ctx Mutex<Profile>,
 
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<BTreeSet<BiomePath>>,
@@ -577,10 +843,21 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
         self.unchanged.fetch_add(1, Ordering::Relaxed);
     }
 
+    pub(crate) fn increment_cached(&self) {
+        self.cached.fetch_add(1, Ordering::Relaxed);
+    }
+
     pub(crate) fn increment_matches(&self, num_matches: usize) {
         self.matches.fetch_add(num_matches, Ordering::Relaxed);
     }
 
+    /// Adds the time spent by the analyzer on a file to the profile of the traversal
+    pub(crate) fn merge_profile(&self, profile: Option<Profile>) {
+        if let Some(profile) = profile {
+            self.profile.lock().unwrap().merge(profile);
+        }
+    }
+
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
         self.messages.send(msg.into()).ok();
@@ -677,6 +954,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
             // Imagine if Biome can't handle its own configuration file...
             TraversalMode::Migrate { .. } => true,
             TraversalMode::Search { .. } => file_features.supports_search(),
+            TraversalMode::Transform { .. } => file_features.supports_transform(),
         }
     }
 
@@ -9,7 +9,9 @@
 use biome_console::{markup, ColorMode, Console, ConsoleExt};
 use biome_fs::OsFileSystem;
 use biome_service::{App, DynRef, Workspace, WorkspaceRef};
+use commands::minify::MinifyCommandPayload;
 use commands::search::SearchCommandPayload;
+use commands::transform::TransformCommandPayload;
 use std::env;
 
 mod changed;
@@ -22,12 +24,14 @@ mod metrics;
 mod panic;
 mod reporter;
 mod service;
+mod watch;
 
 use crate::cli_options::ColorsArg;
 use crate::commands::check::CheckCommandPayload;
 use crate::commands::ci::CiCommandPayload;
 use crate::commands::format::FormatCommandPayload;
 use crate::commands::lint::LintCommandPayload;
+use crate::commands::suppress::SuppressCommandPayload;
 pub use crate::commands::{biome_command, BiomeCommand};
 pub use crate::logging::{setup_cli_subscriber, LoggingLevel};
 pub use diagnostics::CliDiagnostic;
@@ -103,6 +107,9 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                watch,
+                profile_rules,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
@@ -122,6 +129,9 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    watch,
+                    profile_rules,
                 },
             ),
             BiomeCommand::Lint {
@@ -141,6 +151,9 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                watch,
+                profile_rules,
                 css_linter,
                 javascript_linter,
                 json_linter,
@@ -164,10 +177,42 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    watch,
+                    profile_rules,
                     css_linter,
                     javascript_linter,
                     json_linter,
                     graphql_linter,
+                    suppression_reason: None,
+                },
+            ),
+            BiomeCommand::Suppress {
+                reason,
+                vcs_configuration,
+                files_configuration,
+                cli_options,
+                only,
+                skip,
+                stdin_file_path,
+                staged,
+                changed,
+                since,
+                paths,
+            } => commands::suppress::suppress(
+                self,
+                SuppressCommandPayload {
+                    reason,
+                    cli_options,
+                    vcs_configuration,
+                    files_configuration,
+                    paths,
+                    only,
+                    skip,
+                    stdin_file_path,
+                    staged,
+                    changed,
+                    since,
                 },
             ),
             BiomeCommand::Ci {
@@ -180,6 +225,7 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                changed_lines,
             } => commands::ci::ci(
                 self,
                 CiCommandPayload {
@@ -192,6 +238,7 @@ impl<'app> CliSession<'app> {
                     cli_options,
                     changed,
                     since,
+                    changed_lines,
                 },
             ),
             BiomeCommand::Format {
@@ -210,6 +257,7 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                watch,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
@@ -228,6 +276,7 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    watch,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
@@ -251,6 +300,7 @@ impl<'app> CliSession<'app> {
                 pattern,
                 stdin_file_path,
                 vcs_configuration,
+                write,
             } => commands::search::search(
                 self,
                 SearchCommandPayload {
@@ -260,6 +310,41 @@ impl<'app> CliSession<'app> {
                     pattern,
                     stdin_file_path,
                     vcs_configuration,
+                    write,
+                },
+            ),
+            BiomeCommand::Transform {
+                cli_options,
+                files_configuration,
+                vcs_configuration,
+                only,
+                stdin_file_path,
+                write,
+                paths,
+            } => commands::transform::transform(
+                self,
+                TransformCommandPayload {
+                    cli_options,
+                    files_configuration,
+                    paths,
+                    only,
+                    stdin_file_path,
+                    vcs_configuration,
+                    write,
+                },
+            ),
+            BiomeCommand::Minify {
+                cli_options,
+                files_configuration,
+                stdin_file_path,
+                path,
+            } => commands::minify::minify(
+                self,
+                MinifyCommandPayload {
+                    cli_options,
+                    files_configuration,
+                    path,
+                    stdin_file_path,
                 },
             ),
             BiomeCommand::RunServer {
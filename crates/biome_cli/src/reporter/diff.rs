@@ -0,0 +1,97 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::PrintDiagnostic;
+use similar::TextDiff;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The number of unchanged lines printed around each change
+const CONTEXT_RADIUS: usize = 3;
+
+/// The changes that Biome would apply to a file
+#[derive(Debug)]
+pub struct FileDiff {
+    pub file_name: String,
+    pub old: String,
+    pub new: String,
+}
+
+pub(crate) struct DiffReporter {
+    pub(crate) diagnostics: DiagnosticsPayload,
+    pub(crate) diffs: Vec<FileDiff>,
+    pub(crate) execution: Execution,
+}
+
+impl Reporter for DiffReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+        visitor.report_file_diffs(self.diffs)?;
+        Ok(())
+    }
+}
+
+/// Prints the diffs as a patch on the standard output, so it can be piped to `git apply`.
+/// The diagnostics are printed on the standard error.
+pub(crate) struct DiffReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    working_directory: Option<PathBuf>,
+}
+
+impl<'a> DiffReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, working_directory: Option<PathBuf>) -> Self {
+        Self {
+            console,
+            working_directory,
+        }
+    }
+
+    /// The paths of the patch are relative to the working directory, when possible
+    fn relativize<'p>(&self, file_name: &'p str) -> &'p Path {
+        let path = Path::new(file_name);
+        self.working_directory
+            .as_deref()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path)
+    }
+}
+
+impl<'a> ReporterVisitor for DiffReporterVisitor<'a> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &payload.diagnostics {
+            if diagnostic.severity() >= payload.diagnostic_level {
+                if diagnostic.tags().is_verbose() && payload.verbose {
+                    self.console
+                        .error(markup! {{PrintDiagnostic::verbose(diagnostic)}});
+                } else {
+                    self.console
+                        .error(markup! {{PrintDiagnostic::simple(diagnostic)}});
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report_file_diffs(&mut self, diffs: Vec<FileDiff>) -> io::Result<()> {
+        for diff in diffs {
+            let path = self.relativize(&diff.file_name).display().to_string();
+            let patch = TextDiff::from_lines(&diff.old, &diff.new)
+                .unified_diff()
+                .context_radius(CONTEXT_RADIUS)
+                .header(&format!("a/{path}"), &format!("b/{path}"))
+                .to_string();
+            // The patch already ends with a newline
+            self.console.log(markup! {{patch.trim_end_matches('\n')}});
+        }
+
+        Ok(())
+    }
+}
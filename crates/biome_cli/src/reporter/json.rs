@@ -1,6 +1,8 @@
 use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_analyze::Profile;
 use biome_console::fmt::Formatter;
 use serde::Serialize;
+use std::time::Duration;
 
 #[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -8,6 +10,8 @@ pub(crate) struct JsonReporterVisitor {
     summary: TraversalSummary,
     diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
     command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<JsonRuleProfile>,
 }
 
 impl JsonReporterVisitor {
@@ -16,6 +20,64 @@ impl JsonReporterVisitor {
             summary,
             diagnostics: vec![],
             command: String::new(),
+            profile: None,
+        }
+    }
+}
+
+/// The time spent by the lint rules. The durations are in milliseconds.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonRuleProfile {
+    rules: Vec<JsonRuleTimings>,
+    files: Vec<JsonFileTimings>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonRuleTimings {
+    rule: String,
+    matches: u64,
+    total: f64,
+    context: f64,
+    run: f64,
+    action: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFileTimings {
+    path: String,
+    total: f64,
+}
+
+impl From<Profile> for JsonRuleProfile {
+    fn from(profile: Profile) -> Self {
+        fn millis(duration: Duration) -> f64 {
+            duration.as_secs_f64() * 1000.0
+        }
+
+        Self {
+            rules: profile
+                .slowest_rules()
+                .into_iter()
+                .map(|(rule, timings)| JsonRuleTimings {
+                    rule: rule.to_string(),
+                    matches: timings.matches,
+                    total: millis(timings.total()),
+                    context: millis(timings.context),
+                    run: millis(timings.run),
+                    action: millis(timings.action),
+                })
+                .collect(),
+            files: profile
+                .slowest_files()
+                .into_iter()
+                .map(|(path, total)| JsonFileTimings {
+                    path: path.display().to_string(),
+                    total: millis(total),
+                })
+                .collect(),
         }
     }
 }
@@ -31,12 +93,16 @@ pub struct JsonReporter {
     pub execution: Execution,
     pub diagnostics: DiagnosticsPayload,
     pub summary: TraversalSummary,
+    pub rule_profile: Option<Profile>,
 }
 
 impl Reporter for JsonReporter {
     fn write(self, visitor: &mut dyn ReporterVisitor) -> std::io::Result<()> {
         visitor.report_summary(&self.execution, self.summary)?;
         visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+        if let Some(rule_profile) = self.rule_profile {
+            visitor.report_rule_profile(rule_profile)?;
+        }
 
         Ok(())
     }
@@ -54,6 +120,12 @@ impl ReporterVisitor for JsonReporterVisitor {
         Ok(())
     }
 
+    fn report_rule_profile(&mut self, profile: Profile) -> std::io::Result<()> {
+        self.profile = Some(profile.into());
+
+        Ok(())
+    }
+
     fn report_diagnostics(
         &mut self,
         _execution: &Execution,
@@ -1,11 +1,15 @@
+pub(crate) mod diff;
 pub(crate) mod github;
 pub(crate) mod gitlab;
 pub(crate) mod json;
 pub(crate) mod junit;
+pub(crate) mod sarif;
 pub(crate) mod summary;
 pub(crate) mod terminal;
 
 use crate::execute::Execution;
+use crate::reporter::diff::FileDiff;
+use biome_analyze::Profile;
 use biome_diagnostics::{Error, Severity};
 use biome_fs::BiomePath;
 use serde::Serialize;
@@ -32,6 +36,8 @@ pub struct TraversalSummary {
     pub errors: u32,
     pub warnings: u32,
     pub skipped: usize,
+    pub cached: usize,
+    pub baselined: usize,
     pub suggested_fixes_skipped: u32,
     pub diagnostics_not_printed: u32,
 }
@@ -57,6 +63,18 @@ pub trait ReporterVisitor {
         Ok(())
     }
 
+    /// Writes the time spent by the lint rules, when they are profiled
+    fn report_rule_profile(&mut self, profile: Profile) -> io::Result<()> {
+        let _ = profile;
+        Ok(())
+    }
+
+    /// Writes the changes that would be applied to the files, when they are reported as diffs
+    fn report_file_diffs(&mut self, diffs: Vec<FileDiff>) -> io::Result<()> {
+        let _ = diffs;
+        Ok(())
+    }
+
     /// Writes a diagnostics
     fn report_diagnostics(
         &mut self,
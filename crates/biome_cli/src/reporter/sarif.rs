@@ -0,0 +1,516 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use biome_analyze::RuleMetadata;
+use biome_console::fmt::{Display, Formatter, Termcolor};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::Category;
+use biome_diagnostics::{
+    Error, LineIndex, LogCategory, PrintDescription, Resource, Severity, SourceCode, Visit,
+};
+use biome_service::documentation::Doc;
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use path_absolutize::Absolutize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub struct SarifReporter {
+    pub execution: Execution,
+    pub diagnostics: DiagnosticsPayload,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> std::io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    repository_root: Option<PathBuf>,
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    pub fn new(console: &'a mut dyn Console, repository_root: Option<PathBuf>) -> Self {
+        Self {
+            console,
+            repository_root,
+        }
+    }
+}
+
+impl<'a> ReporterVisitor for SarifReporterVisitor<'a> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> std::io::Result<()> {
+        let diagnostics = SarifDiagnostics(payload, self.repository_root.as_deref());
+        self.console.log(markup!({ diagnostics }));
+        Ok(())
+    }
+}
+
+struct SarifDiagnostics<'a>(DiagnosticsPayload, Option<&'a Path>);
+
+impl<'a> SarifDiagnostics<'a> {
+    fn attempt_to_relativize(&self, subject: &str) -> Option<PathBuf> {
+        let Ok(resolved) = Path::new(subject).absolutize() else {
+            return None;
+        };
+
+        let Ok(relativized) = resolved.strip_prefix(self.1?) else {
+            return None;
+        };
+
+        Some(relativized.to_path_buf())
+    }
+}
+
+impl<'a> Display for SarifDiagnostics<'a> {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        let mut rules: Vec<SarifRule> = Vec::new();
+        let mut rule_indices: HashMap<&str, usize> = HashMap::new();
+
+        let results: Vec<_> = self
+            .0
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity() >= self.0.diagnostic_level)
+            .filter(|d| {
+                if d.tags().is_verbose() {
+                    self.0.verbose
+                } else {
+                    true
+                }
+            })
+            .map(|diagnostic| {
+                let rule_index = diagnostic.category().map(|category| {
+                    *rule_indices.entry(category.name()).or_insert_with(|| {
+                        rules.push(SarifRule::from_category(category));
+                        rules.len() - 1
+                    })
+                });
+
+                let path = match diagnostic.location().resource {
+                    Some(Resource::File(file)) => Some(
+                        self.attempt_to_relativize(file)
+                            .and_then(|path| path.to_str().map(|path| path.replace('\\', "/")))
+                            .unwrap_or_else(|| file.to_string()),
+                    ),
+                    _ => None,
+                };
+
+                SarifResult::from_diagnostic(diagnostic, rule_index, path)
+            })
+            .collect();
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules,
+                    },
+                },
+                results,
+            }],
+        };
+
+        let serialized = serde_json::to_string_pretty(&log)?;
+        fmt.write_str(serialized.as_str())?;
+        Ok(())
+    }
+}
+
+/// The root object of a SARIF log.
+/// See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317478
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun<'a>>,
+}
+
+#[derive(Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+/// A `reportingDescriptor` object, created for each category emitted during the traversal.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    /// The name of the category, e.g. `lint/suspicious/noDebugger`
+    id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRuleProperties {
+    group: Option<&'static str>,
+    recommended: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<SarifRuleSource>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRuleSource {
+    /// The tool that the rule is inspired by, e.g. `eslint-plugin-react`
+    name: String,
+    /// The namespaced name of the rule, e.g. `react/jsx-key`
+    rule_name: String,
+    url: String,
+}
+
+impl SarifRule {
+    fn from_category(category: &'static Category) -> Self {
+        let metadata = category
+            .name()
+            .strip_prefix("lint/")
+            .and_then(|name| name.rsplit_once('/'))
+            .and_then(|(group, rule_name)| {
+                let Ok(Doc::Rule(metadata)) = Doc::from_str(rule_name) else {
+                    return None;
+                };
+                Some((group, metadata))
+            });
+
+        match metadata {
+            Some((group, metadata)) => Self::from_metadata(category, group, metadata),
+            None => Self {
+                id: category.name(),
+                name: None,
+                short_description: None,
+                help_uri: category.link(),
+                properties: None,
+            },
+        }
+    }
+
+    fn from_metadata(
+        category: &'static Category,
+        group: &'static str,
+        metadata: RuleMetadata,
+    ) -> Self {
+        let short_description = metadata
+            .docs
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(|text| SarifMessage {
+                text: text.to_string(),
+            });
+
+        Self {
+            id: category.name(),
+            name: Some(metadata.name),
+            short_description,
+            help_uri: category.link(),
+            properties: Some(SarifRuleProperties {
+                group: Some(group),
+                recommended: metadata.recommended,
+                sources: metadata
+                    .sources
+                    .iter()
+                    .map(|source| SarifRuleSource {
+                        name: source.to_string(),
+                        rule_name: source.to_namespaced_rule_name(),
+                        url: source.to_rule_url(),
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    /// One of `error`, `warning` or `note`
+    level: &'static str,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+impl<'a> SarifResult<'a> {
+    fn from_diagnostic(
+        diagnostic: &'a Error,
+        rule_index: Option<usize>,
+        path: Option<String>,
+    ) -> Self {
+        let location = diagnostic.location();
+        let source_code = location.source_code;
+
+        let region = location
+            .span
+            .zip(source_code)
+            .and_then(|(span, source_code)| {
+                SarifRegion::from_offsets(source_code, span.start().into(), span.end().into())
+            });
+
+        let mut fixes = Vec::new();
+        if let (Some(path), Some(source_code)) = (&path, source_code) {
+            let mut visitor = FixesVisitor {
+                source_code,
+                path,
+                description: None,
+                fixes: &mut fixes,
+            };
+            // Fixes are optional, a failure shouldn't prevent the result from being reported
+            let _ = diagnostic.advices(&mut visitor);
+        }
+
+        let locations = path
+            .map(|uri| SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation { uri },
+                    region,
+                },
+            })
+            .into_iter()
+            .collect();
+
+        Self {
+            rule_id: diagnostic.category().map(|category| category.name()),
+            rule_index,
+            level: match diagnostic.severity() {
+                Severity::Fatal | Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Information | Severity::Hint => "note",
+            },
+            message: SarifMessage {
+                text: PrintDescription(diagnostic).to_string(),
+            },
+            locations,
+            fixes,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+/// A region of a file. Lines and columns are one-based.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifRegion {
+    fn from_offsets(
+        source_code: SourceCode<&str, &LineIndex>,
+        start: u32,
+        end: u32,
+    ) -> Option<Self> {
+        let source = SourceFile::new(source_code);
+        let start = source.location(start.into()).ok()?;
+        let end = source.location(end.into()).ok()?;
+        Some(Self {
+            start_line: start.line_number.get(),
+            start_column: start.column_number.get(),
+            end_line: end.line_number.get(),
+            end_column: end.column_number.get(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inserted_content: Option<SarifArtifactContent>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactContent {
+    text: String,
+}
+
+/// Collects the code suggestions of a diagnostic.
+///
+/// A code suggestion is emitted as a log advice that describes the fix, followed by
+/// the diff of the whole document.
+struct FixesVisitor<'a, 'b> {
+    source_code: SourceCode<&'a str, &'a LineIndex>,
+    path: &'b str,
+    description: Option<String>,
+    fixes: &'b mut Vec<SarifFix>,
+}
+
+impl<'a, 'b> Visit for FixesVisitor<'a, 'b> {
+    fn record_log(&mut self, category: LogCategory, text: &dyn Display) -> std::io::Result<()> {
+        self.description = match category {
+            LogCategory::Info => Some(markup_to_string(text)?),
+            _ => None,
+        };
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> std::io::Result<()> {
+        let Some(description) = self.description.take() else {
+            return Ok(());
+        };
+
+        let replacements = diff_to_replacements(self.source_code, diff);
+        if !replacements.is_empty() {
+            self.fixes.push(SarifFix {
+                description: SarifMessage { text: description },
+                artifact_changes: vec![SarifArtifactChange {
+                    artifact_location: SarifArtifactLocation {
+                        uri: self.path.to_string(),
+                    },
+                    replacements,
+                }],
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Converts a [TextEdit] of the whole document into a list of replacements.
+/// Consecutive deletions and insertions are merged into a single replacement.
+fn diff_to_replacements(
+    source_code: SourceCode<&str, &LineIndex>,
+    diff: &TextEdit,
+) -> Vec<SarifReplacement> {
+    let mut replacements = Vec::new();
+    // The offset in the old text, and the pending replacement: start offset and inserted text
+    let mut position = 0u32;
+    let mut pending: Option<(u32, String)> = None;
+
+    let mut flush = |pending: &mut Option<(u32, String)>, end: u32| {
+        if let Some((start, text)) = pending.take() {
+            if let Some(deleted_region) = SarifRegion::from_offsets(source_code, start, end) {
+                replacements.push(SarifReplacement {
+                    deleted_region,
+                    inserted_content: (!text.is_empty()).then_some(SarifArtifactContent { text }),
+                });
+            }
+        }
+    };
+
+    for op in diff.iter() {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                flush(&mut pending, position);
+                position += u32::from(range.len());
+            }
+            CompressedOp::DiffOp(op @ DiffOp::Insert { .. }) => {
+                pending
+                    .get_or_insert_with(|| (position, String::new()))
+                    .1
+                    .push_str(op.text(diff));
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                pending.get_or_insert_with(|| (position, String::new()));
+                position += u32::from(range.len());
+            }
+            CompressedOp::EqualLines { line_count } => {
+                flush(&mut pending, position);
+                let input = &source_code.text[position as usize..];
+                let line_break_count = line_count.get() as usize + 1;
+                for line in input.split_inclusive('\n').take(line_break_count) {
+                    position += line.len() as u32;
+                }
+            }
+        }
+    }
+    flush(&mut pending, position);
+
+    replacements
+}
+
+fn markup_to_string(text: &dyn Display) -> std::io::Result<String> {
+    let mut buffer = Vec::new();
+    let mut write = Termcolor(NoColor::new(&mut buffer));
+    let mut fmt = Formatter::new(&mut write);
+    fmt.write_markup(markup! { {text} })?;
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
@@ -1,5 +1,6 @@
-use crate::reporter::terminal::ConsoleTraversalSummary;
+use crate::reporter::terminal::{ConsoleRuleProfile, ConsoleTraversalSummary};
 use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_analyze::Profile;
 use biome_console::fmt::{Display, Formatter};
 use biome_console::{markup, Console, ConsoleExt, HorizontalLine, Padding, SOFT_LINE};
 use biome_diagnostics::{Resource, Severity};
@@ -12,11 +13,15 @@ pub(crate) struct SummaryReporter {
     pub(crate) summary: TraversalSummary,
     pub(crate) diagnostics_payload: DiagnosticsPayload,
     pub(crate) execution: Execution,
+    pub(crate) rule_profile: Option<Profile>,
 }
 
 impl Reporter for SummaryReporter {
     fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
         visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        if let Some(rule_profile) = self.rule_profile {
+            visitor.report_rule_profile(rule_profile)?;
+        }
         visitor.report_summary(&self.execution, self.summary)?;
         Ok(())
     }
@@ -51,6 +56,14 @@ impl<'a> ReporterVisitor for SummaryReporterVisitor<'a> {
         Ok(())
     }
 
+    fn report_rule_profile(&mut self, profile: Profile) -> io::Result<()> {
+        self.0.log(markup! {
+            {ConsoleRuleProfile(&profile)}
+        });
+
+        Ok(())
+    }
+
     fn report_diagnostics(
         &mut self,
         execution: &Execution,
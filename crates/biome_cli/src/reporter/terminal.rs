@@ -1,8 +1,9 @@
 use crate::execute::{Execution, TraversalMode};
 use crate::reporter::{DiagnosticsPayload, ReporterVisitor, TraversalSummary};
 use crate::Reporter;
+use biome_analyze::Profile;
 use biome_console::fmt::Formatter;
-use biome_console::{fmt, markup, Console, ConsoleExt};
+use biome_console::{fmt, markup, Console, ConsoleExt, HorizontalLine};
 use biome_diagnostics::advice::ListAdvice;
 use biome_diagnostics::{Diagnostic, PrintDiagnostic};
 use biome_fs::BiomePath;
@@ -15,12 +16,16 @@ pub(crate) struct ConsoleReporter {
     pub(crate) diagnostics_payload: DiagnosticsPayload,
     pub(crate) execution: Execution,
     pub(crate) evaluated_paths: BTreeSet<BiomePath>,
+    pub(crate) rule_profile: Option<Profile>,
 }
 
 impl Reporter for ConsoleReporter {
     fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
         let verbose = self.diagnostics_payload.verbose;
         visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        if let Some(rule_profile) = self.rule_profile {
+            visitor.report_rule_profile(rule_profile)?;
+        }
         visitor.report_summary(&self.execution, self.summary)?;
         if verbose {
             visitor.report_handled_paths(self.evaluated_paths)?;
@@ -80,6 +85,14 @@ impl<'a> ReporterVisitor for ConsoleReporterVisitor<'a> {
         Ok(())
     }
 
+    fn report_rule_profile(&mut self, profile: Profile) -> io::Result<()> {
+        self.0.log(markup! {
+            {ConsoleRuleProfile(&profile)}
+        });
+
+        Ok(())
+    }
+
     fn report_handled_paths(&mut self, evaluated_paths: BTreeSet<BiomePath>) -> io::Result<()> {
         let evaluated_paths_diagnostic = EvaluatedPathsDiagnostic {
             advice: ListAdvice {
@@ -116,7 +129,8 @@ impl<'a> ReporterVisitor for ConsoleReporterVisitor<'a> {
         diagnostics_payload: DiagnosticsPayload,
     ) -> io::Result<()> {
         for diagnostic in &diagnostics_payload.diagnostics {
-            if execution.is_search() {
+            // The matches of a pattern are printed as code frames, while its rewrites are printed as diffs
+            if execution.is_search() && diagnostic.location().span.is_some() {
                 self.0.log(markup! {{PrintDiagnostic::search(diagnostic)}});
                 continue;
             }
@@ -153,7 +167,7 @@ struct SummaryDetail<'a>(pub(crate) &'a TraversalMode, usize);
 
 impl<'a> fmt::Display for SummaryDetail<'a> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
-        if let TraversalMode::Search { .. } = self.0 {
+        if let TraversalMode::Search { write: false, .. } = self.0 {
             return Ok(());
         }
 
@@ -206,6 +220,18 @@ impl<'a> fmt::Display for SummaryTotal<'a> {
             TraversalMode::Search { .. } => fmt.write_markup(markup! {
                 "Searched "{files}" in "{self.2}"."
             }),
+
+            TraversalMode::Transform { write, .. } => {
+                if *write {
+                    fmt.write_markup(markup! {
+                        "Transformed "{files}" in "{self.2}"."
+                    })
+                } else {
+                    fmt.write_markup(markup! {
+                        "Checked "{files}" in "{self.2}"."
+                    })
+                }
+            }
         }
     }
 }
@@ -220,6 +246,24 @@ impl<'a> fmt::Display for ConsoleTraversalSummary<'a> {
         let detail = SummaryDetail(self.0, self.1.changed);
         fmt.write_markup(markup!(<Info>{summary}{detail}</Info>))?;
 
+        if self.1.cached > 0 {
+            fmt.write_markup(
+                markup!(" "<Info>"Restored "{Files(self.1.cached)}" from the cache."</Info>),
+            )?;
+        }
+
+        if self.1.baselined > 0 {
+            if self.1.baselined == 1 {
+                fmt.write_markup(
+                    markup!("\n"<Info>"Skipped 1 diagnostic recorded in the baseline."</Info>),
+                )?;
+            } else {
+                fmt.write_markup(
+                    markup!("\n"<Info>"Skipped "{self.1.baselined}" diagnostics recorded in the baseline."</Info>),
+                )?;
+            }
+        }
+
         if self.1.errors > 0 {
             if self.1.errors == 1 {
                 fmt.write_markup(markup!("\n"<Error>"Found "{self.1.errors}" error."</Error>))?;
@@ -245,3 +289,87 @@ impl<'a> fmt::Display for ConsoleTraversalSummary<'a> {
         Ok(())
     }
 }
+
+/// The number of rules printed in the profile of the rules, from the slowest
+const PROFILED_RULES_LIMIT: usize = 20;
+
+/// The number of files printed in the profile of the rules, from the slowest
+const PROFILED_FILES_LIMIT: usize = 10;
+
+/// Prints the time spent by each rule, and by the analyzer on the slowest files
+pub(crate) struct ConsoleRuleProfile<'a>(pub(crate) &'a Profile);
+
+impl<'a> fmt::Display for ConsoleRuleProfile<'a> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let rules = self.0.slowest_rules();
+        let files = self.0.slowest_files();
+
+        let header = "Rules ";
+        fmt.write_markup(markup! {
+            <Emphasis>{header}</Emphasis>{HorizontalLine::new(100 - header.len())}"\n"
+        })?;
+        if rules.is_empty() {
+            fmt.write_str("No rules were executed.\n")?;
+        } else {
+            let omitted_rules = rules.len().saturating_sub(PROFILED_RULES_LIMIT);
+            let rules = &rules[..rules.len().min(PROFILED_RULES_LIMIT)];
+            let rule_names: Vec<_> = rules.iter().map(|(name, _)| (*name).to_string()).collect();
+            let width = rule_names
+                .iter()
+                .map(String::len)
+                .chain(["Rule".len()])
+                .max()
+                .unwrap_or_default();
+            let columns = format!(
+                "{:<width$}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+                "Rule", "Total", "Context", "Run", "Action", "Matches"
+            );
+            fmt.write_markup(markup! {
+                <Info><Underline>{columns}</Underline></Info>"\n"
+            })?;
+            for (name, (_, profile)) in rule_names.iter().zip(rules) {
+                fmt.write_str(&format!(
+                    "{:<width$}  {:>10.1?}  {:>10.1?}  {:>10.1?}  {:>10.1?}  {:>8}\n",
+                    name,
+                    profile.total(),
+                    profile.context,
+                    profile.run,
+                    profile.action,
+                    profile.matches
+                ))?;
+            }
+            if omitted_rules > 0 {
+                fmt.write_markup(markup! {
+                    <Dim>"... and "{omitted_rules}" faster rules. Use --reporter=json to print all of them."</Dim>"\n"
+                })?;
+            }
+        }
+
+        if !files.is_empty() {
+            let header = "Slowest files ";
+            fmt.write_markup(markup! {
+                "\n"<Emphasis>{header}</Emphasis>{HorizontalLine::new(100 - header.len())}"\n"
+            })?;
+            let files = &files[..files.len().min(PROFILED_FILES_LIMIT)];
+            let file_names: Vec<_> = files
+                .iter()
+                .map(|(path, _)| path.display().to_string())
+                .collect();
+            let width = file_names
+                .iter()
+                .map(String::len)
+                .chain(["File".len()])
+                .max()
+                .unwrap_or_default();
+            let columns = format!("{:<width$}  {:>10}", "File", "Total");
+            fmt.write_markup(markup! {
+                <Info><Underline>{columns}</Underline></Info>"\n"
+            })?;
+            for (name, (_, duration)) in file_names.iter().zip(files) {
+                fmt.write_str(&format!("{name:<width$}  {duration:>10.1?}\n"))?;
+            }
+        }
+
+        Ok(())
+    }
+}
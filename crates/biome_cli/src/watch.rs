@@ -0,0 +1,445 @@
+use crate::cli_options::CliOptions;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::{Diagnostic, PrintDiagnostic};
+use biome_fs::{BiomePath, ConfigName, FileSystem, DEFAULT_IGNORE};
+use biome_service::workspace::{
+    FeatureName, IsPathIgnoredParams, SupportsFeatureParams, UpdateKind, UpdateModuleGraphParams,
+};
+use biome_service::{App, DynRef, WorkspaceRef};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use rustc_hash::FxHashMap;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::Duration;
+
+/// The time to wait for more events after a change, so that a batch of changes
+/// (e.g. a `git checkout`) triggers a single run
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Files that affect the settings, besides the configuration files of Biome.
+/// A change to one of them runs the command again on all the files.
+const SETTINGS_FILES: &[&str] = &[".editorconfig", ".gitignore", ".ignore", "package.json"];
+
+/// Runs the command on `paths`, then watches them and runs the command again on the
+/// files that change, until the process is terminated.
+///
+/// The errors of each run are printed, and they don't stop the watch mode. A change
+/// to the configuration runs the command again on all the `paths`, and `run` is in
+/// charge of loading the configuration and updating the settings of the workspace.
+///
+/// The changes to the files ignored by the settings, and to the directories that are
+/// never traversed, e.g. `node_modules`, are discarded. Only the files that change
+/// are processed again: the files that import them aren't, so the diagnostics of the
+/// rules that query the module graph, e.g. `noImportCycles`, can be outdated until
+/// these files change too.
+pub(crate) fn watch<F>(
+    session: CliSession,
+    cli_options: &CliOptions,
+    features: FeatureName,
+    paths: Vec<OsString>,
+    run: F,
+) -> Result<(), CliDiagnostic>
+where
+    F: FnMut(CliSession, Vec<OsString>) -> Result<(), CliDiagnostic>,
+{
+    let App {
+        fs,
+        workspace,
+        console,
+    } = session.app;
+    let working_directory = fs.working_directory().unwrap_or_else(|| PathBuf::from("."));
+    let mut watch_session = WatchSession {
+        fs,
+        console,
+        workspace,
+        cli_options,
+        features,
+        working_directory,
+        paths,
+        run,
+        fingerprints: FxHashMap::default(),
+    };
+
+    let paths = watch_session.paths.clone();
+    watch_session.run_once(&paths);
+
+    let (sender, receiver) = channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+    let working_directory = &watch_session.working_directory;
+    // The configuration file can be in the working directory, even when it isn't traversed
+    watcher
+        .watch(working_directory, RecursiveMode::NonRecursive)
+        .map_err(watch_error)?;
+    if paths.is_empty() {
+        watcher
+            .watch(working_directory, RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+    }
+    for path in &paths {
+        watcher
+            .watch(&working_directory.join(path), RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+    }
+
+    watch_session.process_events(&receiver)
+}
+
+/// The state of the watch mode, shared by all the runs of the command
+struct WatchSession<'app, 'options, F> {
+    fs: DynRef<'app, dyn FileSystem>,
+    console: &'app mut dyn Console,
+    workspace: WorkspaceRef<'app>,
+    cli_options: &'options CliOptions,
+    features: FeatureName,
+    working_directory: PathBuf,
+    /// The paths passed to the command
+    paths: Vec<OsString>,
+    run: F,
+    /// The fingerprints of the content of the files at the end of the last run that
+    /// processed them. A file with the same content isn't processed again, so the
+    /// changes written by the command itself, e.g. with `--write`, don't trigger a run.
+    fingerprints: FxHashMap<PathBuf, u64>,
+}
+
+impl<'app, 'options, F> WatchSession<'app, 'options, F>
+where
+    F: FnMut(CliSession, Vec<OsString>) -> Result<(), CliDiagnostic>,
+{
+    /// Runs the command on the files of the events, until the watcher is dropped
+    fn process_events(
+        &mut self,
+        receiver: &Receiver<notify::Result<Event>>,
+    ) -> Result<(), CliDiagnostic> {
+        self.print_waiting();
+        loop {
+            let mut changed_paths = BTreeSet::new();
+            loop {
+                let event = if changed_paths.is_empty() {
+                    match receiver.recv() {
+                        Ok(event) => event,
+                        Err(_) => return Ok(()),
+                    }
+                } else {
+                    match receiver.recv_timeout(DEBOUNCE_TIMEOUT) {
+                        Ok(event) => event,
+                        Err(_) => break,
+                    }
+                };
+                let Event {
+                    kind,
+                    paths: event_paths,
+                    ..
+                } = event.map_err(watch_error)?;
+                if !matches!(kind, EventKind::Access(_)) {
+                    changed_paths.extend(event_paths);
+                }
+            }
+
+            let changed_paths: Vec<_> = changed_paths
+                .into_iter()
+                .map(|path| match path.strip_prefix(&self.working_directory) {
+                    Ok(relative_path) => relative_path.to_path_buf(),
+                    Err(_) => path,
+                })
+                .filter(|path| !self.is_ignored(path))
+                .collect();
+
+            if changed_paths
+                .iter()
+                .any(|path| is_settings_file(path, self.cli_options))
+            {
+                let paths = self.paths.clone();
+                self.run_once(&paths);
+                self.print_waiting();
+                continue;
+            }
+
+            let changed_files: Vec<_> = changed_paths
+                .into_iter()
+                .filter(|path| self.is_changed_file(path))
+                .map(PathBuf::into_os_string)
+                .collect();
+            if !changed_files.is_empty() {
+                self.run_once(&changed_files);
+                self.print_waiting();
+            }
+        }
+    }
+
+    /// Whether the changes to the file at `path`, relative to the working directory, are
+    /// discarded, because it's in a directory that is never traversed, e.g. `.git` or
+    /// `node_modules`, or because it's ignored by the settings, e.g. by the ignore file of
+    /// the VCS.
+    ///
+    /// The files that affect the settings are only discarded in the directories that are
+    /// never traversed.
+    fn is_ignored(&self, path: &Path) -> bool {
+        let in_ignored_directory = path
+            .components()
+            .any(|component| DEFAULT_IGNORE.contains(&component.as_os_str().as_encoded_bytes()));
+        in_ignored_directory
+            || (!is_settings_file(path, self.cli_options)
+                && self
+                    .workspace
+                    .is_path_ignored(IsPathIgnoredParams {
+                        biome_path: BiomePath::new(path),
+                        features: self.features,
+                    })
+                    .unwrap_or_default())
+    }
+
+    /// Whether the file at `path`, relative to the working directory, must be processed again
+    fn is_changed_file(&mut self, path: &Path) -> bool {
+        let absolute_path = self.working_directory.join(path);
+        if !self.fs.path_exists(&absolute_path) {
+            self.fingerprints.remove(path);
+            // The module of a deleted file can't be imported anymore
+            let _ = self.workspace.update_module_graph(UpdateModuleGraphParams {
+                path: BiomePath::new(path),
+                update_kind: UpdateKind::Remove,
+            });
+            return false;
+        }
+        // The content is read last, because most of the files that change in a project
+        // aren't supported, e.g. the files written by a build
+        self.fs.path_is_file(&absolute_path)
+            && self
+                .workspace
+                .file_features(SupportsFeatureParams {
+                    path: BiomePath::new(path),
+                    features: self.features,
+                })
+                .is_ok_and(|file_features| file_features.is_supported())
+            && self.fingerprints.get(path) != self.fingerprint(path).as_ref()
+    }
+
+    /// Computes the fingerprint of the content of the file at `path`, relative to the
+    /// working directory
+    fn fingerprint(&self, path: &Path) -> Option<u64> {
+        let content = self
+            .fs
+            .read_file_from_path(&self.working_directory.join(path))
+            .ok()?;
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    /// Runs the command with a new session that borrows the file system, the console and
+    /// the workspace of the watch mode, and prints its error, if any.
+    fn run_once(&mut self, paths: &[OsString]) {
+        let session = CliSession {
+            app: App::new(
+                DynRef::Borrowed(&mut *self.fs),
+                &mut *self.console,
+                WorkspaceRef::Borrowed(&*self.workspace),
+            ),
+        };
+        if let Err(error) = (self.run)(session, paths.to_vec()) {
+            if error.tags().is_verbose() && self.cli_options.verbose {
+                self.console
+                    .error(markup! {{PrintDiagnostic::verbose(&error)}});
+            } else {
+                self.console
+                    .error(markup! {{PrintDiagnostic::simple(&error)}});
+            }
+        }
+
+        // The events of the changes written by the run are received after it ends. The
+        // files traversed from a directory aren't known, so they are fingerprinted the
+        // first time they change.
+        for path in paths {
+            let path = PathBuf::from(path);
+            if let Some(fingerprint) = self.fingerprint(&path) {
+                self.fingerprints.insert(path, fingerprint);
+            }
+        }
+    }
+
+    fn print_waiting(&mut self) {
+        if self.cli_options.reporter.is_default() {
+            self.console.log(markup! {
+                <Dim>"Waiting for changes..."</Dim>
+            });
+        }
+    }
+}
+
+/// Whether a change to the file requires to load the configuration again
+fn is_settings_file(path: &Path, cli_options: &CliOptions) -> bool {
+    if let Some(config_path) = &cli_options.config_path {
+        if path.ends_with(config_path) {
+            return true;
+        }
+    }
+    path.file_name()
+        .and_then(|file_name| file_name.to_str())
+        .is_some_and(|file_name| {
+            ConfigName::file_names().contains(&file_name) || SETTINGS_FILES.contains(&file_name)
+        })
+}
+
+fn watch_error(error: notify::Error) -> CliDiagnostic {
+    CliDiagnostic::io_error(io::Error::new(io::ErrorKind::Other, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WatchSession;
+    use crate::cli_options::cli_options;
+    use biome_configuration::{PartialConfiguration, PartialFilesConfiguration};
+    use biome_console::BufferConsole;
+    use biome_deserialize::StringSet;
+    use biome_fs::{MemoryFileSystem, OpenOptions};
+    use biome_service::workspace::{
+        server, FeaturesBuilder, RegisterProjectFolderParams, UpdateSettingsParams,
+    };
+    use biome_service::{DynRef, WorkspaceRef};
+    use bpaf::Parser;
+    use notify::event::{DataChange, ModifyKind};
+    use notify::{Event, EventKind};
+    use rustc_hash::FxHashMap;
+    use std::ffi::OsString;
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::channel;
+
+    fn modify_event(path: &str) -> notify::Result<Event> {
+        Ok(
+            Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Content)))
+                .add_path(path.into()),
+        )
+    }
+
+    #[test]
+    fn runs_the_command_on_the_files_that_change() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("./file.js"), "debugger;\n".as_bytes());
+        fs.insert(PathBuf::from("./other.js"), "debugger;\n".as_bytes());
+        let mut console = BufferConsole::default();
+        let workspace = server();
+        workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: None,
+                set_as_current_workspace: true,
+            })
+            .unwrap();
+        let cli_options = cli_options().to_options().run_inner(&[]).unwrap();
+
+        let (sender, receiver) = channel();
+        let mut self_sender = Some(sender.clone());
+        let mut runs = Vec::new();
+        let mut watch_session = WatchSession {
+            fs: DynRef::Borrowed(&mut fs),
+            console: &mut console,
+            workspace: WorkspaceRef::Borrowed(workspace.as_ref()),
+            cli_options: &cli_options,
+            features: FeaturesBuilder::new().with_linter().build(),
+            working_directory: PathBuf::from("."),
+            paths: Vec::new(),
+            run: |session: crate::CliSession, paths: Vec<OsString>| {
+                // Like `--write`, the run changes the file and receives the event of its own
+                // change, while another file is changed by the user
+                if let Some(sender) = self_sender.take() {
+                    for path in ["./file.js", "./other.js"] {
+                        session
+                            .app
+                            .fs
+                            .open_with_options(Path::new(path), OpenOptions::default().write(true))
+                            .and_then(|mut file| file.set_content(b"\n"))
+                            .unwrap();
+                        sender.send(modify_event(path)).unwrap();
+                    }
+                }
+                runs.push(paths);
+                Ok(())
+            },
+            fingerprints: FxHashMap::default(),
+        };
+
+        sender.send(modify_event("./file.js")).unwrap();
+        drop(sender);
+        watch_session.process_events(&receiver).unwrap();
+
+        assert_eq!(
+            runs,
+            vec![
+                vec![OsString::from("file.js")],
+                vec![OsString::from("other.js")]
+            ]
+        );
+    }
+
+    #[test]
+    fn discards_the_changes_to_the_ignored_files() {
+        let mut fs = MemoryFileSystem::default();
+        for path in [
+            "./src/file.js",
+            "./dist/file.js",
+            "./node_modules/pkg/index.js",
+            "./node_modules/pkg/package.json",
+            "./.git/index",
+        ] {
+            fs.insert(PathBuf::from(path), "debugger;\n".as_bytes());
+        }
+        let mut console = BufferConsole::default();
+        let workspace = server();
+        workspace
+            .register_project_folder(RegisterProjectFolderParams {
+                path: None,
+                set_as_current_workspace: true,
+            })
+            .unwrap();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration: PartialConfiguration {
+                    files: Some(PartialFilesConfiguration {
+                        ignore: Some(StringSet::from_iter(["dist/**".to_string()])),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vcs_base_path: None,
+                gitignore_matches: Vec::new(),
+                workspace_directory: None,
+            })
+            .unwrap();
+        let cli_options = cli_options().to_options().run_inner(&[]).unwrap();
+
+        let (sender, receiver) = channel();
+        let mut runs = Vec::new();
+        let mut watch_session = WatchSession {
+            fs: DynRef::Borrowed(&mut fs),
+            console: &mut console,
+            workspace: WorkspaceRef::Borrowed(workspace.as_ref()),
+            cli_options: &cli_options,
+            features: FeaturesBuilder::new().with_linter().build(),
+            working_directory: PathBuf::from("."),
+            paths: Vec::new(),
+            run: |_: crate::CliSession, paths: Vec<OsString>| {
+                runs.push(paths);
+                Ok(())
+            },
+            fingerprints: FxHashMap::default(),
+        };
+
+        // The `package.json` of a dependency would run the command on all the files
+        for path in [
+            "./node_modules/pkg/package.json",
+            "./node_modules/pkg/index.js",
+            "./.git/index",
+            "./dist/file.js",
+            "./src/file.js",
+        ] {
+            sender.send(modify_event(path)).unwrap();
+        }
+        drop(sender);
+        watch_session.process_events(&receiver).unwrap();
+
+        assert_eq!(runs, vec![vec![OsString::from("src/file.js")]]);
+    }
+}
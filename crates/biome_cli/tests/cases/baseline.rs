@@ -0,0 +1,254 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const BASELINE_PATH: &str = "biome-baseline.json";
+
+fn write_baseline(fs: &mut MemoryFileSystem, file_path: &Path) {
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline",
+                BASELINE_PATH,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn write_baseline_records_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\nif (a == b) {\n\tdebugger;\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline",
+                BASELINE_PATH,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_records_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_baseline_keeps_the_entries_of_other_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("b.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("c.js").into(), "debugger;\n".as_bytes());
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline",
+                BASELINE_PATH,
+                "a.js",
+                "b.js",
+                "c.js",
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // Only `a.js` is evaluated: its entries are replaced, the ones of `b.js` are kept,
+    // and the ones of the deleted `c.js` are removed
+    fs.insert(Path::new("a.js").into(), "if (a == b) {}\n".as_bytes());
+    fs.remove(Path::new("c.js"));
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--write-baseline", BASELINE_PATH, "a.js"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_keeps_the_entries_of_other_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_only_new_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+    write_baseline(&mut fs, file_path);
+
+    // The code moved, and a new diagnostic was added
+    fs.insert(
+        file_path.into(),
+        "console.log(1);\n\n  debugger;\nif (a == b) {}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "ci",
+                "--baseline",
+                BASELINE_PATH,
+                "--formatter-enabled=false",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_only_new_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_stale_entries() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\ndebugger;\n".as_bytes());
+    write_baseline(&mut fs, file_path);
+
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline",
+                BASELINE_PATH,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_stale_entries",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_doesnt_exist() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline",
+                BASELINE_PATH,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_doesnt_exist",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_and_write_baseline_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline",
+                BASELINE_PATH,
+                "--write-baseline",
+                BASELINE_PATH,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_and_write_baseline_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,244 @@
+use crate::run_cli;
+use crate::snap_test::markup_to_string;
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+fn console_contains(console: &BufferConsole, text: &str) -> bool {
+    console.out_buffer.iter().any(|message| {
+        let content = markup_to_string(markup! {
+            {message.content}
+        });
+        content.contains(text)
+    })
+}
+
+#[test]
+fn lint_restores_diagnostics_from_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    for run in 0..2 {
+        let mut console = BufferConsole::default();
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(["lint", "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+        assert!(console_contains(&console, "lint/suspicious/noDebugger"));
+        assert_eq!(
+            console_contains(&console, "Restored 1 file from the cache."),
+            run == 1
+        );
+    }
+}
+
+#[test]
+fn lint_doesnt_restore_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), "console.log(1);\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(!console_contains(&console, "lint/suspicious/noDebugger"));
+    assert!(!console_contains(&console, "from the cache"));
+}
+
+#[test]
+fn format_restores_diff_from_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "statement(  )".as_bytes());
+
+    for run in 0..2 {
+        let mut console = BufferConsole::default();
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(["format", "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+        assert!(console_contains(
+            &console,
+            "Formatter would have printed the following content"
+        ));
+        assert_eq!(
+            console_contains(&console, "Restored 1 file from the cache."),
+            run == 1
+        );
+    }
+}
+
+#[test]
+fn cache_isnt_shared_between_settings() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(!console_contains(&console, "from the cache"));
+}
+
+#[test]
+fn lint_doesnt_restore_files_when_another_module_changed() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Path::new("a.js").into(),
+        "import { b } from \"./b.js\";\nexport const a = () => b();\n".as_bytes(),
+    );
+    fs.insert(
+        Path::new("b.js").into(),
+        "import { a } from \"./a.js\";\nexport const b = () => a();\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--cache",
+                "--only=nursery/noImportCycles",
+                "a.js",
+                "b.js",
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(console_contains(&console, "lint/nursery/noImportCycles"));
+
+    // Only `b.js` changes, but it also fixes the cycle reported in `a.js`
+    fs.insert(
+        Path::new("b.js").into(),
+        "export const b = () => {};\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--cache",
+                "--only=nursery/noImportCycles",
+                "a.js",
+                "b.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(!console_contains(&console, "lint/nursery/noImportCycles"));
+    assert!(!console_contains(&console, "from the cache"));
+}
+
+#[test]
+fn files_cache_enables_the_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "files": { "cache": true } }"#.as_bytes(),
+    );
+
+    for run in 0..2 {
+        let mut console = BufferConsole::default();
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+        assert!(console_contains(&console, "lint/suspicious/noDebugger"));
+        assert_eq!(
+            console_contains(&console, "Restored 1 file from the cache."),
+            run == 1
+        );
+    }
+}
+
+#[test]
+fn cache_evicts_deleted_files() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Path::new("a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("b.js").into(), "debugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", "a.js", "b.js"].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Path::new("b.js"));
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "--cache", "a.js"].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let (_, cache_file) = fs
+        .files()
+        .find(|(path, _)| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .expect("the cache should have been persisted");
+    let cache: serde_json::Value = serde_json::from_slice(&cache_file.lock()).unwrap();
+    let entries = cache["entries"].as_object().unwrap();
+    assert!(entries.contains_key("a.js"));
+    assert!(!entries.contains_key("b.js"));
+}
@@ -0,0 +1,150 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const FILE_CONTENT: &str = r#"debugger;
+let a = 1;
+debugger;
+let b = 2;
+"#;
+
+fn changed_hunks() -> Vec<String> {
+    [
+        "diff --git a/file.js b/file.js",
+        "index 1111111..2222222 100644",
+        "--- a/file.js",
+        "+++ b/file.js",
+        "@@ -3,0 +3,2 @@ let a = 1;",
+        "+debugger;",
+        "+let b = 2;",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+#[test]
+fn lint_reports_only_the_diagnostics_of_the_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_hunks(Box::new(changed_hunks));
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), FILE_CONTENT.as_bytes());
+
+    let file_path2 = Path::new("file2.js");
+    fs.insert(file_path2.into(), FILE_CONTENT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--changed-lines",
+                "--since=main",
+                file_path.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_only_the_diagnostics_of_the_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_reports_only_the_diagnostics_of_the_changed_lines() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    fs.set_on_get_changed_files(Box::new(|| vec![String::from("file.js")]));
+    fs.set_on_get_changed_hunks(Box::new(changed_hunks));
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+    "vcs": {
+        "defaultBranch": "main"
+    },
+    "formatter": {
+        "enabled": false
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), FILE_CONTENT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--changed-lines",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_reports_only_the_diagnostics_of_the_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn changed_lines_and_staged_are_incompatible() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), FILE_CONTENT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--changed-lines",
+                "--staged",
+                "--since=main",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "changed_lines_and_staged_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,131 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const STYLED_COMPONENT_UNFORMATTED: &str = r#"import styled from "styled-components";
+
+export const Button = styled.button`
+  color:${(props) => props.color};
+      background:white;
+  ${mixin};
+  &:hover{color:red}
+`;
+"#;
+
+const STYLED_COMPONENT_FORMATTED: &str = r#"import styled from "styled-components";
+
+export const Button = styled.button`
+	color: ${(props) => props.color};
+	background: white;
+	${mixin};
+	&:hover {
+		color: red;
+	}
+`;
+"#;
+
+const STYLED_COMPONENT_LINT: &str = r#"import { css } from "@emotion/react";
+
+export const title = css`
+  color: ${(props) => props.color};
+  colr: red;
+  padding-left: 4px;
+  padding: 8px;
+`;
+"#;
+
+#[test]
+fn format_css_template_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.jsx");
+    fs.insert(file_path.into(), STYLED_COMPONENT_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, STYLED_COMPONENT_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_css_template_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_css_template_disabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.jsx");
+    fs.insert(file_path.into(), STYLED_COMPONENT_UNFORMATTED.as_bytes());
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "javascript": {
+    "formatter": {
+      "formatEmbeddedCss": false
+    }
+  }
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, STYLED_COMPONENT_UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_css_template_disabled",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_css_template() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), STYLED_COMPONENT_LINT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_css_template",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,162 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const QUERY_UNFORMATTED: &str = r#"import gql from "graphql-tag";
+
+export const USER = gql`
+  query User($id: ID!) {   user(id:$id){ ...UserDetails }
+  }
+  ${USER_DETAILS_FRAGMENT}
+`;
+"#;
+
+const QUERY_FORMATTED: &str = r#"import gql from "graphql-tag";
+
+export const USER = gql`
+	query User($id: ID!) {
+		user(id: $id) {
+			...UserDetails
+		}
+	}
+	${USER_DETAILS_FRAGMENT}
+`;
+"#;
+
+const CUSTOM_TAG_UNFORMATTED: &str = r#"export const USER = relayQuery`
+  query User {   user { name }
+  }
+`;
+"#;
+
+const CUSTOM_TAG_FORMATTED: &str = r#"export const USER = relayQuery`
+	query User {
+		user {
+			name
+		}
+	}
+`;
+"#;
+
+const QUERY_LINT: &str = r#"import gql from "graphql-tag";
+
+export const USER = gql`
+  query User {
+    user {
+      name
+      ...${USER_DETAILS_FRAGMENT}
+      name
+    }
+  }
+`;
+"#;
+
+#[test]
+fn format_graphql_template_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), QUERY_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, QUERY_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_graphql_template_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_graphql_template_with_custom_tags() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), CUSTOM_TAG_UNFORMATTED.as_bytes());
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "javascript": {
+        "graphqlTags": ["relayQuery"]
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, CUSTOM_TAG_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_graphql_template_with_custom_tags",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_graphql_template() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), QUERY_LINT.as_bytes());
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+    "graphql": {
+        "linter": { "enabled": true }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=nursery/noDuplicatedFields",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_graphql_template",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -23,6 +23,28 @@ statement();
 ---
 <div></div>"#;
 
+const ASTRO_FILE_WITH_SCRIPTS_UNFORMATTED: &str = r#"---
+const   title = "Astro"
+---
+<h1>{title}</h1>
+<script>
+const heading   = document.querySelector( "h1" )
+</script>
+<script type="application/json">
+{ "title":   "Astro" }
+</script>"#;
+
+const ASTRO_FILE_WITH_SCRIPTS_FORMATTED: &str = r#"---
+const title = "Astro";
+---
+<h1>{title}</h1>
+<script>
+const heading = document.querySelector("h1");
+</script>
+<script type="application/json">
+{ "title":   "Astro" }
+</script>"#;
+
 const ASTRO_FILE_DEBUGGER_BEFORE: &str = r#"---
 debugger;
 ---
@@ -158,6 +180,43 @@ fn format_astro_files_write() {
     ));
 }
 
+#[test]
+fn format_astro_files_with_scripts_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let astro_file_path = Path::new("file.astro");
+    fs.insert(
+        astro_file_path.into(),
+        ASTRO_FILE_WITH_SCRIPTS_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                astro_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, astro_file_path, ASTRO_FILE_WITH_SCRIPTS_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_astro_files_with_scripts_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn format_empty_astro_files_write() {
     let mut fs = MemoryFileSystem::default();
@@ -76,6 +76,68 @@ const foo = "";
 </script>
 <div></div>"#;
 
+const SVELTE_MULTIPLE_SCRIPT_BLOCKS_UNFORMATTED: &str = r#"<script context="module" lang="ts">
+export const prerender    =   true
+</script>
+
+<script lang="ts">
+let   count :   number = 0
+</script>
+
+<button on:click={() => count++}>{count}</button>
+"#;
+
+const SVELTE_MULTIPLE_SCRIPT_BLOCKS_FORMATTED: &str = r#"<script context="module" lang="ts">
+export const prerender = true;
+</script>
+
+<script lang="ts">
+let count: number = 0;
+</script>
+
+<button on:click={() => count++}>{count}</button>
+"#;
+
+const SVELTE_MULTIPLE_SCRIPT_BLOCKS_LINT_BEFORE: &str = r#"<script context="module" lang="ts">
+export const prerender = true;
+</script>
+
+<script lang="ts">
+let count = 0;
+debugger;
+</script>
+
+<button on:click={() => count++}>{count}</button>
+"#;
+
+const SVELTE_STYLE_BLOCK_UNFORMATTED: &str = r#"<script>
+let name  =  "world"
+</script>
+
+<h1>Hello {name}!</h1>
+
+<style>
+  h1{color:red}
+  :global(body)   h1{margin:0}
+</style>
+"#;
+
+const SVELTE_STYLE_BLOCK_FORMATTED: &str = r#"<script>
+let name = "world";
+</script>
+
+<h1>Hello {name}!</h1>
+
+<style>
+  h1 {
+    color: red;
+  }
+  :global(body) h1 {
+    margin: 0;
+  }
+</style>
+"#;
+
 #[test]
 fn sorts_imports_check() {
     let mut fs = MemoryFileSystem::default();
@@ -575,3 +637,117 @@ fn check_stdin_write_unsafe_successfully() {
         result,
     ));
 }
+
+#[test]
+fn format_svelte_multiple_script_blocks_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_MULTIPLE_SCRIPT_BLOCKS_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                svelte_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        svelte_file_path,
+        SVELTE_MULTIPLE_SCRIPT_BLOCKS_FORMATTED,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_svelte_multiple_script_blocks_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_svelte_multiple_script_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_MULTIPLE_SCRIPT_BLOCKS_LINT_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=suspicious/noDebugger",
+                svelte_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_multiple_script_blocks",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_svelte_style_block_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_STYLE_BLOCK_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--indent-style=space",
+                svelte_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, svelte_file_path, SVELTE_STYLE_BLOCK_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_svelte_style_block_write",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -141,6 +141,110 @@ const slots = defineSlots<{
 </script>
 <template></template>"#;
 
+const VUE_MULTIPLE_SCRIPT_BLOCKS_UNFORMATTED: &str = r#"<script lang="ts">
+export default    {   name :  "App" }
+</script>
+
+<template>
+  <p>{{ message }}</p>
+</template>
+
+<script setup lang="ts">
+const message  :   string = "Hello"
+</script>
+"#;
+
+const VUE_MULTIPLE_SCRIPT_BLOCKS_FORMATTED: &str = r#"<script lang="ts">
+export default { name: "App" };
+</script>
+
+<template>
+  <p>{{ message }}</p>
+</template>
+
+<script setup lang="ts">
+const message: string = "Hello";
+</script>
+"#;
+
+const VUE_MULTIPLE_SCRIPT_BLOCKS_LINT_BEFORE: &str = r#"<script lang="ts">
+import { helper } from "./helper";
+debugger;
+</script>
+
+<template>
+  <p>{{ message }}</p>
+</template>
+
+<script setup lang="ts">
+helper();
+debugger;
+</script>
+"#;
+
+const VUE_MULTIPLE_SCRIPT_BLOCKS_LINT_AFTER: &str = r#"<script lang="ts">
+import { helper } from "./helper";
+</script>
+
+<template>
+  <p>{{ message }}</p>
+</template>
+
+<script setup lang="ts">
+helper();
+</script>
+"#;
+
+const VUE_STYLE_BLOCKS_UNFORMATTED: &str = r#"<script setup lang="ts">
+const color  =  "red"
+</script>
+
+<template>
+  <p class="message">Hello</p>
+</template>
+
+<style scoped>
+.message   :deep(.icon){color:v-bind(color)}
+</style>
+
+<style module>
+:global(.page)  .title{margin:0}
+</style>
+"#;
+
+const VUE_STYLE_BLOCKS_FORMATTED: &str = r#"<script setup lang="ts">
+const color = "red";
+</script>
+
+<template>
+  <p class="message">Hello</p>
+</template>
+
+<style scoped>
+.message :deep(.icon) {
+	color: v-bind(color);
+}
+</style>
+
+<style module>
+:global(.page) .title {
+	margin: 0;
+}
+</style>
+"#;
+
+const VUE_STYLE_BLOCK_LINT: &str = r#"<template>
+  <p class="message">Hello</p>
+</template>
+
+<style scoped>
+.message :deep(.icon) {
+  color: v-bind(color);
+  width: 10pixels;
+}
+</style>
+"#;
+
 #[test]
 fn format_vue_implicit_js_files() {
     let mut fs = MemoryFileSystem::default();
@@ -913,3 +1017,177 @@ fn vue_compiler_macros_as_globals() {
         result,
     ));
 }
+
+#[test]
+fn format_vue_multiple_script_blocks_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_MULTIPLE_SCRIPT_BLOCKS_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_MULTIPLE_SCRIPT_BLOCKS_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_multiple_script_blocks_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_multiple_script_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_MULTIPLE_SCRIPT_BLOCKS_LINT_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=correctness/noUnusedImports",
+                "--only=suspicious/noDebugger",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_multiple_script_blocks",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_multiple_script_blocks_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_MULTIPLE_SCRIPT_BLOCKS_LINT_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write",
+                "--unsafe",
+                "--only=suspicious/noDebugger",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_MULTIPLE_SCRIPT_BLOCKS_LINT_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_multiple_script_blocks_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_vue_style_blocks_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_STYLE_BLOCKS_UNFORMATTED.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                vue_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_STYLE_BLOCKS_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_style_blocks_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_style_block() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(vue_file_path.into(), VUE_STYLE_BLOCK_LINT.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", vue_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_style_block",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2,24 +2,34 @@
 //! case that affects many commands
 
 mod assists;
+mod baseline;
 mod biome_json_support;
+mod cache;
+mod changed_lines;
 mod config_extends;
 mod config_path;
+mod css_in_js;
 mod cts_files;
 mod diagnostics;
 mod editorconfig;
 mod graphql;
+mod graphql_in_js;
 mod handle_astro_files;
 mod handle_css_files;
 mod handle_svelte_files;
 mod handle_vue_files;
 mod included_files;
+mod module_graph;
 mod overrides_formatter;
 mod overrides_linter;
 mod overrides_organize_imports;
+mod profile_rules;
 mod protected_files;
+mod reporter_diff;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
+mod reporter_sarif;
 mod reporter_summary;
 mod unknown_files;
+mod watch;
@@ -0,0 +1,248 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn lint_reports_import_cycles() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("src/a.js").into(),
+        r#"import { b } from "./utils/b";
+export const a = () => b();
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/utils/b.js").into(),
+        r#"import { c } from "../c.js";
+export const b = () => c();
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/c.js").into(),
+        r#"import { a } from "./a.js";
+export const c = () => a();
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=nursery/noImportCycles",
+                "src/a.js",
+                "src/utils/b.js",
+                "src/c.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_import_cycles",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_resolves_tsconfig_paths_and_packages() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("tsconfig.json").into(),
+        r#"{
+    // The aliases of the project
+    "compilerOptions": {
+        "paths": { "@app/*": ["./app/*"] },
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/ui/package.json").into(),
+        r#"{
+    "name": "@acme/ui",
+    "exports": { ".": { "import": "./src/index.ts" } }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("packages/ui/src/index.ts").into(),
+        r#"import { theme } from "@app/theme";
+export const button = theme;
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("app/theme.ts").into(),
+        r#"import { button } from "@acme/ui";
+export const theme = { button };
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=nursery/noImportCycles",
+                "tsconfig.json",
+                "packages/ui/package.json",
+                "packages/ui/src/index.ts",
+                "app/theme.ts",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_resolves_tsconfig_paths_and_packages",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reports_unused_exports() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUnusedExports": {
+                    "level": "warn",
+                    "options": { "entryPoints": ["scripts/build.js"] }
+                }
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{
+    "main": "./src/index.js",
+    "bin": { "cli": "./bin/cli.js" }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/index.js").into(),
+        r#"import { used } from "./utils.js";
+export const api = used();
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/utils.js").into(),
+        r#"export function used() {}
+export function unused() {}
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("bin/cli.js").into(),
+        r#"export const run = () => {};
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("scripts/build.js").into(),
+        r#"export const build = () => {};
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "."].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_unused_exports",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_doesnt_report_unused_exports_of_a_single_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noUnusedExports": "warn"
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/main.js").into(),
+        r#"import { used } from "./utils.js";
+used();
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Path::new("src/utils.js").into(),
+        r#"export function used() {}
+"#
+        .as_bytes(),
+    );
+
+    // The importers of `src/utils.js` aren't part of the module graph
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["lint", "src/utils.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_doesnt_report_unused_exports_of_a_single_file",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,118 @@
+use crate::run_cli;
+use crate::snap_test::markup_to_string;
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+#[test]
+fn profile_rules_reports_the_rules_and_the_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--profile-rules",
+                "--reporter=json",
+                "--only=suspicious/noDebugger",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The timings change at each run, so the report can't be snapshotted
+    let content = console
+        .out_buffer
+        .iter()
+        .map(|message| {
+            markup_to_string(markup! {
+                {message.content}
+            })
+        })
+        .find(|content| content.starts_with('{'))
+        .expect("Console should have written the report");
+    let report: serde_json::Value = serde_json::from_str(&content).unwrap();
+    let profile = &report["profile"];
+
+    let rule = profile["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["rule"] == "suspicious/noDebugger")
+        .expect("the rule should be profiled");
+    assert!(rule["matches"].as_u64().unwrap() >= 1);
+    assert!(rule["total"].as_f64().unwrap() > 0.0);
+
+    assert!(profile["files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|file| file["path"] == "file.js"));
+}
+
+#[test]
+fn profile_rules_merges_the_profiles_of_the_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Path::new("b.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write",
+                "--unsafe",
+                "--profile-rules",
+                "--reporter=json",
+                "--only=suspicious/noDebugger",
+                "a.js",
+                "b.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let content = console
+        .out_buffer
+        .iter()
+        .map(|message| {
+            markup_to_string(markup! {
+                {message.content}
+            })
+        })
+        .find(|content| content.starts_with('{'))
+        .expect("Console should have written the report");
+    let report: serde_json::Value = serde_json::from_str(&content).unwrap();
+    let profile = &report["profile"];
+
+    let rule = profile["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["rule"] == "suspicious/noDebugger")
+        .expect("the rule should be profiled");
+    // The rule matches once in each file while fixing it, and the code actions are computed
+    assert!(rule["matches"].as_u64().unwrap() >= 2);
+    assert!(rule["action"].as_f64().unwrap() > 0.0);
+
+    let files = profile["files"].as_array().unwrap();
+    assert!(files.iter().any(|file| file["path"] == "a.js"));
+    assert!(files.iter().any(|file| file["path"] == "b.js"));
+}
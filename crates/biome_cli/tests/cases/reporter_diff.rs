@@ -0,0 +1,161 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const UNFORMATTED: &str = r#"import { b, a } from "lodash";
+const   foo = { a:1,b: 2 };
+
+
+debugger;
+let x = a == b;
+"#;
+
+const FIXABLE: &str = r#"let value = 1;
+if (value == null) {
+}
+"#;
+
+#[test]
+fn reports_diff_format_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--reporter=diff",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diff_format_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_diff_format_write_command_without_writing() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                "--reporter=diff",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diff_format_write_command_without_writing",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_diff_lint_write_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("main.js");
+    fs.insert(file_path.into(), FIXABLE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--write",
+                "--unsafe",
+                "--reporter=diff",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert_file_contents(&fs, file_path, FIXABLE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diff_lint_write_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_diff_check_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("main.js");
+    fs.insert(file_path1.into(), UNFORMATTED.as_bytes());
+
+    let file_path2 = Path::new("fixable.js");
+    fs.insert(file_path2.into(), FIXABLE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--write",
+                "--reporter=diff",
+                file_path1.as_os_str().to_str().unwrap(),
+                file_path2.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert_file_contents(&fs, file_path1, UNFORMATTED);
+    assert_file_contents(&fs, file_path2, FIXABLE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_diff_check_command",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -8,5 +8,9 @@ mod lsp_proxy;
 mod migrate;
 mod migrate_eslint;
 mod migrate_prettier;
+mod minify;
 mod rage;
+mod search;
+mod suppress;
+mod transform;
 mod version;
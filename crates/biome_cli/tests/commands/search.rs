@@ -0,0 +1,152 @@
+use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const REWRITE_PATTERN: &str = "`console.log($message)` => `logger.debug($message)`";
+
+const REWRITE_BEFORE: &str = r#"console.log("hello");
+console.info("world");
+"#;
+
+const REWRITE_AFTER: &str = r#"logger.debug("hello");
+console.info("world");
+"#;
+
+#[test]
+fn search_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("search"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_rewrite_prints_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), REWRITE_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                REWRITE_PATTERN,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, REWRITE_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_prints_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_rewrite_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), REWRITE_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                ("--write"),
+                REWRITE_PATTERN,
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, REWRITE_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_rewrite_write_stdin() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(REWRITE_BEFORE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("search"),
+                ("--write"),
+                ("--stdin-file-path"),
+                ("file.js"),
+                REWRITE_PATTERN,
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, REWRITE_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_write_stdin",
+        fs,
+        console,
+        result,
+    ));
+}
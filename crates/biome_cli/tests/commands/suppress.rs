@@ -0,0 +1,324 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const DIAGNOSTICS: &str = r#"function f(a, b) {
+    debugger; if (a == b) {}
+    // biome-ignore lint/suspicious/noDebugger: legacy code
+    debugger; return a == b;
+}
+"#;
+
+const JSX_DIAGNOSTICS: &str = r#"export const Image = () => (
+    <div>
+        <img src="image.png" onClick={() => {}} />
+    </div>
+);
+"#;
+
+#[test]
+fn suppress_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("suppress"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_merges_the_categories_of_a_line() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), DIAGNOSTICS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("suppress"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"function f(a, b) {
+    // biome-ignore lint/suspicious/noDebugger lint/suspicious/noDoubleEquals: ignored using `biome suppress`
+    debugger; if (a == b) {}
+    // biome-ignore lint/suspicious/noDebugger lint/suspicious/noDoubleEquals: legacy code
+    debugger; return a == b;
+}
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_merges_the_categories_of_a_line",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_doesnt_merge_the_range_and_file_suppressions() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        r#"// biome-ignore-all lint/suspicious/noDoubleEquals: legacy code
+debugger;
+// biome-ignore-start lint/suspicious/noEmptyBlockStatements: legacy code
+debugger;
+// biome-ignore-end lint/suspicious/noEmptyBlockStatements: legacy code
+debugger;
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("suppress"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"// biome-ignore-all lint/suspicious/noDoubleEquals: legacy code
+// biome-ignore lint/suspicious/noDebugger: ignored using `biome suppress`
+debugger;
+// biome-ignore-start lint/suspicious/noEmptyBlockStatements: legacy code
+// biome-ignore lint/suspicious/noDebugger: ignored using `biome suppress`
+debugger;
+// biome-ignore-end lint/suspicious/noEmptyBlockStatements: legacy code
+// biome-ignore lint/suspicious/noDebugger: ignored using `biome suppress`
+debugger;
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_doesnt_merge_the_range_and_file_suppressions",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_with_reason() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("suppress"),
+                "--reason=enabled in a later release",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "// biome-ignore lint/suspicious/noDebugger: enabled in a later release\ndebugger;\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_with_reason",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_only_the_given_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), DIAGNOSTICS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("suppress"),
+                "--only=suspicious/noDoubleEquals",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_only_the_given_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_jsx_children() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.jsx");
+    fs.insert(file_path.into(), JSX_DIAGNOSTICS.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("suppress"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_jsx_children",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_doesnt_change_json_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.json");
+    let content = r#"{ "a": 1, "a": 2 }
+"#;
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("suppress"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, content);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_doesnt_change_json_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_jsonc_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.jsonc");
+    fs.insert(
+        file_path.into(),
+        r#"{
+    "a": 1,
+    "a": 2
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("suppress"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_jsonc_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_json_files_allowing_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "json": { "parser": { "allowComments": true } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("file.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+    "a": 1,
+    "a": 2
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("suppress"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_json_files_allowing_comments",
+        fs,
+        console,
+        result,
+    ));
+}
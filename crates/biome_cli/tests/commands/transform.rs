@@ -0,0 +1,273 @@
+use crate::snap_test::{assert_file_contents, markup_to_string, SnapshotPayload};
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::{markup, BufferConsole};
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const ENUM_BEFORE: &str = r#"import { log } from "./log";
+
+enum Status {
+	Enabled,
+	Disabled,
+}
+
+log(Status.Enabled);
+"#;
+
+const ENUM_AFTER: &str = r#"import { log } from "./log";
+
+var Status;
+(function (Status) {
+	Status[Status["Enabled"] = 0] = "Enabled";
+	Status[Status["Disabled"] = 1] = "Disabled";
+})(Status || (Status = {}));
+
+log(Status.Enabled);
+"#;
+
+const TYPES_BEFORE: &str = r#"import type { Options } from "./options";
+
+interface Point {
+	x: number;
+}
+
+export function distance(a: Point, b?: Point): number {
+	return Math.hypot(a.x - (b?.x ?? 0)) as number;
+}
+"#;
+
+const TYPES_AFTER: &str = r#";                                        
+
+;                
+           
+ 
+
+export function distance(a       , b        )         {
+	return Math.hypot(a.x - (b?.x ?? 0))          ;
+}
+"#;
+
+#[test]
+fn transform_help() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("transform"), "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_prints_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), ENUM_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("transform"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, ENUM_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_prints_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), ENUM_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--only=transformEnum"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, ENUM_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_stdin() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(ENUM_BEFORE.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("transform"), ("--stdin-file-path"), ("file.ts")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, ENUM_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_stdin",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_unknown_transformation() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), ENUM_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--only=transformUnknown"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, ENUM_BEFORE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_unknown_transformation",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_strip_types() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(file_path.into(), TYPES_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--only=stripTypes"),
+                ("--write"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, TYPES_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_strip_types",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn transform_strip_types_reports_parameter_properties() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.ts");
+    fs.insert(
+        file_path.into(),
+        "class Point {\n\tconstructor(private x: number) {}\n}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("transform"),
+                ("--only=stripTypes"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "transform_strip_types_reports_parameter_properties",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -101,6 +101,10 @@ pub struct JavascriptFormatter {
     /// Whether to insert spaces around brackets in object literals. Defaults to true.
     #[partial(bpaf(long("bracket-spacing"), argument("true|false"), optional))]
     pub bracket_spacing: Option<BracketSpacing>,
+
+    /// Whether to format the CSS of the CSS-in-JS templates, e.g. `` styled.div`color: red;` ``. Defaults to true.
+    #[partial(bpaf(long("format-embedded-css"), argument("true|false"), optional))]
+    pub format_embedded_css: bool,
 }
 
 impl PartialJavascriptFormatter {
@@ -122,6 +126,7 @@ impl PartialJavascriptFormatter {
             line_width: self.line_width,
             quote_style: self.quote_style.unwrap_or_default(),
             attribute_position: self.attribute_position,
+            format_embedded_css: self.format_embedded_css.unwrap_or(true),
         }
     }
 }
@@ -145,6 +150,7 @@ impl Default for JavascriptFormatter {
             line_width: Default::default(),
             quote_style: Default::default(),
             attribute_position: Default::default(),
+            format_embedded_css: true,
         }
     }
 }
@@ -11,7 +11,7 @@ pub use formatter::{
 use serde::{Deserialize, Serialize};
 
 /// A set of options applied to the JavaScript files
-#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
 #[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
 #[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
 #[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
@@ -44,6 +44,27 @@ pub struct JavascriptConfiguration {
 
     #[partial(type, bpaf(external(partial_javascript_organize_imports), optional))]
     pub organize_imports: JavascriptOrganizeImports,
+
+    /// A list of tags of the template literals that contain GraphQL, e.g. `` gql`query { user }` ``.
+    ///
+    /// These templates are formatted and linted as GraphQL. Defaults to `["gql", "graphql"]`.
+    #[partial(bpaf(hide))]
+    pub graphql_tags: StringSet,
+}
+
+impl Default for JavascriptConfiguration {
+    fn default() -> Self {
+        Self {
+            formatter: JavascriptFormatter::default(),
+            linter: JavascriptLinter::default(),
+            assists: JavascriptAssists::default(),
+            parser: JavascriptParser::default(),
+            globals: StringSet::default(),
+            jsx_runtime: JsxRuntime::default(),
+            organize_imports: JavascriptOrganizeImports::default(),
+            graphql_tags: StringSet::new(["gql", "graphql"].map(str::to_string).into()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -342,6 +342,12 @@ pub struct FilesConfiguration {
     /// match these patterns.
     #[partial(bpaf(hide))]
     pub include: StringSet,
+
+    /// Stores the results of the processed files, and reuses them for the files
+    /// that didn't change in the next runs of `check`, `ci`, `format` and `lint`.
+    /// The same as passing `--cache`. Defaults to `false`
+    #[partial(bpaf(long("files-cache"), argument("true|false"), optional, hide))]
+    pub cache: bool,
 }
 
 impl Default for FilesConfiguration {
@@ -351,6 +357,7 @@ impl Default for FilesConfiguration {
             ignore: Default::default(),
             include: Default::default(),
             ignore_unknown: false,
+            cache: false,
         }
     }
 }
@@ -894,6 +894,12 @@ pub const A_NPLUS_BOF_SNOTATION_PSEUDO_CLASSES: [&str; 2] = ["nth-child", "nth-l
 
 pub const LINGUISTIC_PSEUDO_CLASSES: [&str; 2] = ["dir", "lang"];
 
+/// See https://vuejs.org/api/sfc-css-features
+pub const VUE_SCOPED_STYLES_PSEUDO_CLASSES: [&str; 3] = ["deep", "global", "slotted"];
+
+/// See https://svelte.dev/docs/svelte/global-styles
+pub const SVELTE_SCOPED_STYLES_PSEUDO_CLASSES: [&str; 1] = ["global"];
+
 pub const LOGICAL_COMBINATIONS_PSEUDO_CLASSES: [&str; 5] = ["has", "is", "matches", "not", "where"];
 
 /// See https://drafts.csswg.org/selectors/#resource-pseudos
@@ -9,10 +9,10 @@ mod utils;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::CssSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionKind,
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
+    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleRegistry, SuppressionKind,
 };
-use biome_css_syntax::CssLanguage;
+use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::{category, Error};
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
@@ -31,13 +31,14 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    file_source: CssFileSource,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, file_source, emit_signal)
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -51,6 +52,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    file_source: CssFileSource,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -60,16 +62,20 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
+            let (categories, scope) = match comment {
                 Ok(comment) => {
+                    let scope = comment.scope;
                     if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Deprecated,
+                            scope,
+                        )));
                     }
-                    comment.categories
+                    (comment.categories, scope)
                 }
                 Err(err) => {
                     result.push(Err(err));
@@ -80,14 +86,23 @@ where
             for (key, value) in categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::MaybeLegacy(value),
+                            scope,
+                        )));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Everything,
+                            scope,
+                        )));
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
-                        result.push(Ok(SuppressionKind::Rule(rule)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Rule(rule),
+                            scope,
+                        )));
                     }
                 }
             }
@@ -99,7 +114,7 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -118,6 +133,8 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    services.insert_service(file_source);
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -135,7 +152,7 @@ mod tests {
     use biome_console::fmt::{Formatter, Termcolor};
     use biome_console::{markup, Markup};
     use biome_css_parser::{parse_css, CssParserOptions};
-    use biome_css_syntax::TextRange;
+    use biome_css_syntax::{CssFileSource, TextRange};
     use biome_diagnostics::termcolor::NoColor;
     use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
     use std::slice;
@@ -186,6 +203,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            CssFileSource::css(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
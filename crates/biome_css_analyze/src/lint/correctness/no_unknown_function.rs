@@ -2,16 +2,18 @@ use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
-use biome_css_syntax::CssFunction;
+use biome_css_syntax::{CssFileSource, CssFunction};
 use biome_rowan::{AstNode, TextRange};
 
-use crate::utils::{is_custom_function, is_function_keyword};
+use crate::utils::{is_custom_function, is_embedded_function, is_function_keyword};
 
 declare_lint_rule! {
     /// Disallow unknown CSS value functions.
     ///
     /// This rule ignores double-dashed custom functions, e.g. `--custom-function()`.
     ///
+    /// The `v-bind()` function is allowed in the `<style>` blocks of Vue files.
+    ///
     /// Data sources of known CSS value functions are:
     /// - MDN reference on [CSS value functions](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Functions)
     /// - MDN reference on [CSS reference](https://developer.mozilla.org/en-US/docs/Web/CSS/Reference)
@@ -65,6 +67,11 @@ impl Rule for NoUnknownFunction {
             return None;
         }
 
+        let embedding_kind = ctx.source_type::<CssFileSource>().as_embedding_kind();
+        if is_embedded_function(&function_name, embedding_kind) {
+            return None;
+        }
+
         Some(NoUnknownFunctionState {
             function_name,
             span: node.name().ok()?.range(),
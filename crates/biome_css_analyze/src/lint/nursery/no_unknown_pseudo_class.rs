@@ -1,17 +1,21 @@
 use crate::{
     keywords::{WEBKIT_SCROLLBAR_PSEUDO_CLASSES, WEBKIT_SCROLLBAR_PSEUDO_ELEMENTS},
-    utils::{is_custom_selector, is_known_pseudo_class, is_page_pseudo_class, vendor_prefixed},
+    utils::{
+        is_custom_selector, is_embedded_pseudo_class, is_known_pseudo_class, is_page_pseudo_class,
+        vendor_prefixed,
+    },
 };
 use biome_analyze::{
     context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
 };
 use biome_console::markup;
 use biome_css_syntax::{
-    CssBogusPseudoClass, CssPageSelectorPseudo, CssPseudoClassFunctionCompoundSelector,
-    CssPseudoClassFunctionCompoundSelectorList, CssPseudoClassFunctionIdentifier,
-    CssPseudoClassFunctionNth, CssPseudoClassFunctionRelativeSelectorList,
-    CssPseudoClassFunctionSelector, CssPseudoClassFunctionSelectorList,
-    CssPseudoClassFunctionValueList, CssPseudoClassIdentifier, CssPseudoElementSelector,
+    CssBogusPseudoClass, CssFileSource, CssPageSelectorPseudo,
+    CssPseudoClassFunctionCompoundSelector, CssPseudoClassFunctionCompoundSelectorList,
+    CssPseudoClassFunctionIdentifier, CssPseudoClassFunctionNth,
+    CssPseudoClassFunctionRelativeSelectorList, CssPseudoClassFunctionSelector,
+    CssPseudoClassFunctionSelectorList, CssPseudoClassFunctionValueList, CssPseudoClassIdentifier,
+    CssPseudoElementSelector,
 };
 use biome_rowan::{declare_node_union, AstNode, TextRange};
 use biome_string_case::StrOnlyExtension;
@@ -23,6 +27,9 @@ declare_lint_rule! {
     ///
     /// This rule ignores vendor-prefixed pseudo-class selectors.
     ///
+    /// The `:deep()`, `:slotted()` and `:global()` pseudo-classes are allowed in the `<style>` blocks of Vue files,
+    /// and the `:global()` pseudo-class is allowed in the `<style>` blocks of Svelte and Astro files.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -181,6 +188,10 @@ impl Rule for NoUnknownPseudoClass {
                 is_custom_selector(lower_name)
                     || vendor_prefixed(lower_name)
                     || is_known_pseudo_class(lower_name)
+                    || is_embedded_pseudo_class(
+                        lower_name,
+                        ctx.source_type::<CssFileSource>().as_embedding_kind(),
+                    )
             }
         };
 
@@ -41,6 +41,7 @@ impl SuppressionAction for CssSuppressionAction {
         mutation: &mut BatchMutation<Self::Language>,
         apply_suppression: ApplySuppression<Self::Language>,
         suppression_text: &str,
+        suppression_reason: &str,
     ) {
         let ApplySuppression {
             token_to_apply_suppression,
@@ -58,12 +59,12 @@ impl SuppressionAction for CssSuppressionAction {
             new_token = new_token.with_trailing_trivia([
                 (
                     TriviaPieceKind::SingleLineComment,
-                    format!("/* {}: <explanation> */", suppression_text).as_str(),
+                    format!("/* {suppression_text}: {suppression_reason} */").as_str(),
                 ),
                 (TriviaPieceKind::Newline, "\n"),
             ]);
         } else if has_leading_whitespace {
-            let suppression_comment = format!("/* {}: <explanation> */", suppression_text);
+            let suppression_comment = format!("/* {suppression_text}: {suppression_reason} */");
             let mut trivia = vec![
                 (
                     TriviaPieceKind::SingleLineComment,
@@ -85,7 +86,7 @@ impl SuppressionAction for CssSuppressionAction {
             new_token = new_token.with_leading_trivia([
                 (
                     TriviaPieceKind::SingleLineComment,
-                    format!("/* {}: <explanation> */", suppression_text).as_str(),
+                    format!("/* {suppression_text}: {suppression_reason} */").as_str(),
                 ),
                 (TriviaPieceKind::Newline, "\n"),
             ]);
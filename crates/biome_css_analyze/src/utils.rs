@@ -11,9 +11,12 @@ use crate::keywords::{
     MEDIA_FEATURE_NAMES, OTHER_PSEUDO_CLASSES, OTHER_PSEUDO_ELEMENTS,
     RESET_TO_INITIAL_PROPERTIES_BY_BORDER, RESET_TO_INITIAL_PROPERTIES_BY_FONT,
     RESOURCE_STATE_PSEUDO_CLASSES, SHADOW_TREE_PSEUDO_ELEMENTS, SHORTHAND_PROPERTIES,
-    SYSTEM_FAMILY_NAME_KEYWORDS, VENDOR_PREFIXES, VENDOR_SPECIFIC_PSEUDO_ELEMENTS,
+    SVELTE_SCOPED_STYLES_PSEUDO_CLASSES, SYSTEM_FAMILY_NAME_KEYWORDS, VENDOR_PREFIXES,
+    VENDOR_SPECIFIC_PSEUDO_ELEMENTS, VUE_SCOPED_STYLES_PSEUDO_CLASSES,
+};
+use biome_css_syntax::{
+    AnyCssGenericComponentValue, AnyCssValue, CssGenericComponentValueList, EmbeddingKind,
 };
-use biome_css_syntax::{AnyCssGenericComponentValue, AnyCssValue, CssGenericComponentValueList};
 use biome_rowan::{AstNode, SyntaxNodeCast};
 use biome_string_case::StrOnlyExtension;
 
@@ -116,6 +119,12 @@ pub fn is_function_keyword(value: &str) -> bool {
         .is_ok()
 }
 
+/// Check if the value is a function added by the framework of the file that
+/// embeds the styles, e.g. `v-bind()` in the `<style>` blocks of Vue files.
+pub fn is_embedded_function(value: &str, embedding_kind: &EmbeddingKind) -> bool {
+    embedding_kind.is_vue() && value.eq_ignore_ascii_case("v-bind")
+}
+
 /// Check if the value is a double-dashed custom function.
 pub fn is_custom_function(value: &str) -> bool {
     value.starts_with("--")
@@ -158,6 +167,18 @@ pub fn is_known_pseudo_class(prop: &str) -> bool {
         || OTHER_PSEUDO_CLASSES.contains(&prop)
 }
 
+/// Check if the input string is a pseudo-class added by the framework of the
+/// file that embeds the styles, e.g. `:deep()` in the `<style scoped>` blocks of Vue files.
+pub fn is_embedded_pseudo_class(prop: &str, embedding_kind: &EmbeddingKind) -> bool {
+    match embedding_kind {
+        EmbeddingKind::Vue => VUE_SCOPED_STYLES_PSEUDO_CLASSES.contains(&prop),
+        EmbeddingKind::Svelte | EmbeddingKind::Astro => {
+            SVELTE_SCOPED_STYLES_PSEUDO_CLASSES.contains(&prop)
+        }
+        EmbeddingKind::Html | EmbeddingKind::None => false,
+    }
+}
+
 pub fn is_known_properties(prop: &str) -> bool {
     KNOWN_PROPERTIES.binary_search(&prop).is_ok()
         || KNOWN_CHROME_PROPERTIES.binary_search(&prop).is_ok()
@@ -2987,7 +2987,10 @@ impl SyntaxFactory for CssSyntaxFactory {
                 let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T![global] | T![local]) {
+                    if matches!(
+                        element.kind(),
+                        T![global] | T![local] | T![deep] | T![slotted]
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
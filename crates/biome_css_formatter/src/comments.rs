@@ -8,7 +8,7 @@ use biome_formatter::comments::{
 use biome_formatter::formatter::Formatter;
 use biome_formatter::{write, FormatResult, FormatRule};
 use biome_rowan::SyntaxTriviaPieceComments;
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 pub type CssComments = Comments<CssLanguage>;
 
@@ -68,6 +68,7 @@ impl CommentStyle for CssCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Line)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
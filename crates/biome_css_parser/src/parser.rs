@@ -33,6 +33,11 @@ pub struct CssParserOptions {
     /// Enables parsing of Grit metavariables.
     /// Defaults to `false`.
     pub grit_metavariables: bool,
+
+    /// Enables parsing of the `:deep()` and `:slotted()` pseudo-classes of the
+    /// scoped styles of Vue components.
+    /// Defaults to `false`.
+    pub vue_scoped_styles: bool,
 }
 
 impl CssParserOptions {
@@ -54,6 +59,12 @@ impl CssParserOptions {
         self
     }
 
+    /// Enables parsing of the pseudo-classes of the scoped styles of Vue components.
+    pub fn allow_vue_scoped_styles(mut self) -> Self {
+        self.vue_scoped_styles = true;
+        self
+    }
+
     /// Checks if parsing of CSS Modules features is disabled.
     pub fn is_css_modules_disabled(&self) -> bool {
         !self.css_modules
@@ -63,6 +74,11 @@ impl CssParserOptions {
     pub fn is_metavariable_enabled(&self) -> bool {
         self.grit_metavariables
     }
+
+    /// Checks if parsing of the pseudo-classes of the scoped styles of Vue components is enabled.
+    pub fn is_vue_scoped_styles_enabled(&self) -> bool {
+        self.vue_scoped_styles
+    }
 }
 
 impl<'source> CssParser<'source> {
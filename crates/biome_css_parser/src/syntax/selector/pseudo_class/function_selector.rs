@@ -7,18 +7,24 @@ use crate::syntax::selector::{
 };
 use biome_css_syntax::CssSyntaxKind::CSS_PSEUDO_CLASS_FUNCTION_SELECTOR;
 use biome_css_syntax::CssSyntaxKind::*;
-use biome_css_syntax::T;
+use biome_css_syntax::{CssSyntaxKind, T};
 use biome_parser::parsed_syntax::ParsedSyntax;
 use biome_parser::parsed_syntax::ParsedSyntax::{Absent, Present};
-use biome_parser::Parser;
+use biome_parser::{token_set, Parser, TokenSet};
+
+/// The pseudo-classes of the scoped styles of Vue components, `:deep` and `:slotted`.
+const VUE_SCOPED_STYLES_SET: TokenSet<CssSyntaxKind> = token_set![T![deep], T![slotted]];
 
 /// Checks if the current parser position is at a pseudo-class function selector for CSS Modules.
 ///
 /// This function determines if the parser is currently positioned at the start of a `:local` or `:global`
-/// pseudo-class function selector, which is part of the CSS Modules syntax.
+/// pseudo-class function selector, which is part of the CSS Modules syntax, or of a `:deep` or `:slotted`
+/// pseudo-class function selector of the scoped styles of Vue components, when they are enabled.
 #[inline]
 pub(crate) fn is_at_pseudo_class_function_selector(p: &mut CssParser) -> bool {
-    p.at_ts(CSS_MODULES_SCOPE_SET) && p.nth_at(1, T!['('])
+    (p.at_ts(CSS_MODULES_SCOPE_SET)
+        || (p.options().is_vue_scoped_styles_enabled() && p.at_ts(VUE_SCOPED_STYLES_SET)))
+        && p.nth_at(1, T!['('])
 }
 
 /// Parses a pseudo-class function selector for CSS Modules.
@@ -36,13 +42,21 @@ pub(crate) fn is_at_pseudo_class_function_selector(p: &mut CssParser) -> bool {
 ///     margin: 0;
 /// }
 /// ```
+///
+/// The `:deep` and `:slotted` pseudo-class function selectors of the scoped styles of Vue components
+/// are parsed the same way.
+/// ```css
+/// .a :deep(.b) {
+///     color: red;
+/// }
+/// ```
 #[inline]
 pub(crate) fn parse_pseudo_class_function_selector(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_pseudo_class_function_selector(p) {
         return Absent;
     }
 
-    if p.options().is_css_modules_disabled() {
+    if p.at_ts(CSS_MODULES_SCOPE_SET) && p.options().is_css_modules_disabled() {
         // :local and :global are not standard CSS features
         // provide a hint on how to enable parsing of these pseudo-classes
         p.error(local_or_global_not_allowed(p, p.cur_range()));
@@ -58,7 +72,7 @@ pub(crate) fn parse_pseudo_class_function_selector(p: &mut CssParser) -> ParsedS
 
     let m = p.start();
 
-    p.bump_ts(CSS_MODULES_SCOPE_SET);
+    p.bump_ts(CSS_MODULES_SCOPE_SET.union(VUE_SCOPED_STYLES_SET));
     p.bump(T!['(']);
 
     let kind = match parse_selector(p) {
@@ -45,6 +45,12 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
         // TODO: find a way to make it configurable
         .allow_metavariables();
 
+    // the scoped styles of Vue components are only enabled for the `<style>` blocks of Vue files,
+    // so they cannot be configured via options.json either
+    if test_directory.ends_with("vue_scoped_styles") {
+        options = options.allow_vue_scoped_styles();
+    }
+
     let options_path = Path::new(test_directory).join("options.json");
 
     if options_path.exists() {
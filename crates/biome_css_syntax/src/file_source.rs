@@ -1,3 +1,4 @@
+pub use biome_rowan::EmbeddingKind;
 use biome_rowan::FileSourceError;
 use biome_string_case::StrLikeExtension;
 use std::{ffi::OsStr, path::Path};
@@ -10,6 +11,8 @@ pub struct CssFileSource {
     // Unused until we potentially support postcss/less/sass
     #[allow(unused)]
     variant: CssVariant,
+    /// Used to mark if the source is the `<style>` block of an Astro, HTML, Svelte or Vue file
+    embedding_kind: EmbeddingKind,
 }
 
 /// The style of CSS contained in the file.
@@ -29,9 +32,19 @@ impl CssFileSource {
     pub fn css() -> Self {
         Self {
             variant: CssVariant::Standard,
+            embedding_kind: EmbeddingKind::None,
         }
     }
 
+    pub const fn with_embedding_kind(mut self, kind: EmbeddingKind) -> Self {
+        self.embedding_kind = kind;
+        self
+    }
+
+    pub const fn as_embedding_kind(&self) -> &EmbeddingKind {
+        &self.embedding_kind
+    }
+
     /// Try to return the CSS file source corresponding to this file name from well-known files
     pub fn try_from_well_known(_: &Path) -> Result<Self, FileSourceError> {
         // TODO: to be implemented
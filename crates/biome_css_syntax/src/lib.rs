@@ -8,7 +8,7 @@ pub use self::generated::*;
 pub use biome_rowan::{
     SyntaxNodeText, TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent,
 };
-pub use file_source::CssFileSource;
+pub use file_source::{CssFileSource, EmbeddingKind};
 pub use syntax_node::*;
 
 use crate::CssSyntaxKind::*;
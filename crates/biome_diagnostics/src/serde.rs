@@ -82,6 +82,14 @@ impl Diagnostic {
             .map(|span| TextRange::new(span.start() + offset, span.end() + offset));
         self
     }
+
+    /// Replaces the diffs of the advices, e.g. the diffs of the code suggestions,
+    /// with the ones returned by `map_diff`
+    pub fn with_mapped_diffs(mut self, mut map_diff: impl FnMut(&TextEdit) -> TextEdit) -> Self {
+        self.advices.map_diffs(&mut map_diff);
+        self.verbose_advices.map_diffs(&mut map_diff);
+        self
+    }
 }
 
 impl super::Diagnostic for Diagnostic {
@@ -175,6 +183,16 @@ impl Advices {
             advices: Vec::new(),
         }
     }
+
+    fn map_diffs(&mut self, map_diff: &mut dyn FnMut(&TextEdit) -> TextEdit) {
+        for advice in &mut self.advices {
+            match advice {
+                Advice::Diff(diff) => *diff = map_diff(diff),
+                Advice::Group(_, advices) => advices.map_diffs(map_diff),
+                _ => {}
+            }
+        }
+    }
 }
 
 impl Visit for Advices {
@@ -18,6 +18,7 @@ pub struct BiomeEnv {
     pub biome_log_path: BiomeEnvVariable,
     pub biome_log_prefix: BiomeEnvVariable,
     pub biome_config_path: BiomeEnvVariable,
+    pub biome_cache_path: BiomeEnvVariable,
 }
 
 pub static BIOME_ENV: OnceLock<BiomeEnv> = OnceLock::new();
@@ -37,6 +38,10 @@ impl BiomeEnv {
                 "BIOME_CONFIG_PATH",
                 "A path to the configuration file",
             ),
+            biome_cache_path: BiomeEnvVariable::new(
+                "BIOME_CACHE_PATH",
+                "The directory where the results of `--cache` will be saved.",
+            ),
         }
     }
 }
@@ -106,6 +111,17 @@ impl Display for BiomeEnv {
             }
         };
 
+        match self.biome_cache_path.value() {
+            None => {
+                KeyValuePair(self.biome_cache_path.name, markup! { <Dim>"unset"</Dim> })
+                    .fmt(fmt)?;
+            }
+            Some(value) => {
+                KeyValuePair(self.biome_cache_path.name, markup! {{DebugDisplay(value)}})
+                    .fmt(fmt)?;
+            }
+        };
+
         Ok(())
     }
 }
@@ -2,7 +2,7 @@ use crate::{BiomePath, PathInterner};
 use biome_diagnostics::{console, Advices, Diagnostic, LogCategory, Visit};
 use biome_diagnostics::{Error, Severity};
 pub use memory::{ErrorEntry, MemoryFileSystem};
-pub use os::OsFileSystem;
+pub use os::{OsFileSystem, DEFAULT_IGNORE};
 use oxc_resolver::{Resolution, ResolveError};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
@@ -69,6 +69,9 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path is a symlink
     fn path_is_symlink(&self, path: &Path) -> bool;
 
+    /// Creates the given directory, and all its missing parents
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
     /// This method accepts a directory path (`search_dir`) and a list of filenames (`file_names`),
     /// It looks for the files in the specified directory in the order they appear in the list.
     /// If a file is not found in the initial directory, the search may continue into the parent
@@ -182,6 +185,12 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
 
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
 
+    /// Returns the lines of the unified diff between the merge base of `base` and `HEAD`,
+    /// and the working tree, without context lines. The untracked files aren't part of the diff.
+    ///
+    /// An error is returned if `git diff` fails, e.g. when there's no merge base.
+    fn get_changed_hunks(&self, base: &str) -> io::Result<Vec<String>>;
+
     fn get_staged_files(&self) -> io::Result<Vec<String>>;
 
     fn resolve_configuration(
@@ -373,10 +382,18 @@ where
         T::path_is_symlink(self, path)
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        T::create_dir_all(self, path)
+    }
+
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
         T::get_changed_files(self, base)
     }
 
+    fn get_changed_hunks(&self, base: &str) -> io::Result<Vec<String>> {
+        T::get_changed_hunks(self, base)
+    }
+
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
         T::get_staged_files(self)
     }
@@ -30,6 +30,7 @@ pub struct MemoryFileSystem {
     allow_write: bool,
     on_get_staged_files: OnGetChangedFiles,
     on_get_changed_files: OnGetChangedFiles,
+    on_get_changed_hunks: OnGetChangedFiles,
 }
 
 impl Default for MemoryFileSystem {
@@ -44,6 +45,9 @@ impl Default for MemoryFileSystem {
             on_get_changed_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
                 Vec::new,
             )))))),
+            on_get_changed_hunks: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                Vec::new,
+            )))))),
         }
     }
 }
@@ -115,6 +119,13 @@ impl MemoryFileSystem {
         self.on_get_changed_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
 
+    pub fn set_on_get_changed_hunks(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> Vec<String> + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_changed_hunks = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
+
     pub fn set_on_get_staged_files(
         &mut self,
         cfn: Box<dyn FnOnce() -> Vec<String> + Send + RefUnwindSafe + 'static>,
@@ -209,6 +220,18 @@ impl FileSystem for MemoryFileSystem {
         false
     }
 
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        // Directories are implicit in the memory filesystem
+        if self.allow_write {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "cannot create a directory in read-only filesystem",
+            ))
+        }
+    }
+
     fn get_changed_files(&self, _base: &str) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_changed_files.as_ref().unwrap().clone();
 
@@ -219,6 +242,16 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
+    fn get_changed_hunks(&self, _base: &str) -> io::Result<Vec<String>> {
+        let cb_arc = self.on_get_changed_hunks.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
         let cb_arc = self.on_get_staged_files.as_ref().unwrap().clone();
 
@@ -90,6 +90,10 @@ impl FileSystem for OsFileSystem {
         path.is_symlink()
     }
 
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -118,6 +122,40 @@ impl FileSystem for OsFileSystem {
             .collect())
     }
 
+    fn get_changed_hunks(&self, base: &str) -> io::Result<Vec<String>> {
+        let output = Command::new("git")
+            .arg("diff")
+            .arg("--relative")
+            .arg("--no-color")
+            .arg("--no-ext-diff")
+            .arg("--unified=0")
+            .arg("--src-prefix=a/")
+            .arg("--dst-prefix=b/")
+            .arg("--diff-filter=ACMR")
+            // Compares the working tree, which includes the uncommitted changes, to the
+            // commit where the current branch diverged from `base`
+            .arg("--merge-base")
+            .arg(base)
+            .output()?;
+
+        // Without hunks, every diagnostic would be filtered out, e.g. when the base
+        // doesn't exist or a shallow clone doesn't contain the merge base
+        if !output.status.success() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "git diff --merge-base {base} failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|l| l.to_string())
+            .collect())
+    }
+
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
         let output = Command::new("git")
             .arg("diff")
@@ -219,7 +257,7 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
 // TODO: remove in Biome 2.0, and directly use `.gitignore`
 /// Default list of ignored directories, in the future will be supplanted by
 /// detecting and parsing .ignore files
-const DEFAULT_IGNORE: &[&[u8]] = &[b".git", b".svn", b".hg", b".yarn", b"node_modules"];
+pub const DEFAULT_IGNORE: &[&[u8]] = &[b".git", b".svn", b".hg", b".yarn", b"node_modules"];
 
 /// Traverse a single directory
 fn handle_dir<'scope>(
@@ -6,8 +6,8 @@ mod suppression_action;
 pub use crate::registry::visit_registry;
 use crate::suppression_action::GraphqlSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionKind,
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
+    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleRegistry, SuppressionKind,
 };
 use biome_diagnostics::{category, Error};
 use biome_graphql_syntax::GraphqlLanguage;
@@ -57,16 +57,20 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
+            let (categories, scope) = match comment {
                 Ok(comment) => {
+                    let scope = comment.scope;
                     if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Deprecated,
+                            scope,
+                        )));
                     }
-                    comment.categories
+                    (comment.categories, scope)
                 }
                 Err(err) => {
                     result.push(Err(err));
@@ -77,14 +81,23 @@ where
             for (key, value) in categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::MaybeLegacy(value),
+                            scope,
+                        )));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Everything,
+                            scope,
+                        )));
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
-                        result.push(Ok(SuppressionKind::Rule(rule)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Rule(rule),
+                            scope,
+                        )));
                     }
                 }
             }
@@ -189,7 +189,7 @@ fn check_code_action(
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
 
-pub(crate) fn _run_suppression_test(input: &'static str, _: &str, _: &str, _: &str) {
+pub(crate) fn run_suppression_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
 
     let input_file = Path::new(input);
@@ -7,7 +7,7 @@ use biome_formatter::formatter::Formatter;
 use biome_formatter::{write, FormatResult, FormatRule};
 use biome_graphql_syntax::{GraphqlLanguage, TextLen};
 use biome_rowan::SyntaxTriviaPieceComments;
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 pub type GraphqlComments = Comments<GraphqlLanguage>;
 
@@ -67,6 +67,7 @@ impl CommentStyle for GraphqlCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Line)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
@@ -1,8 +1,8 @@
 use crate::{
     grit_context::GritQueryContext, grit_target_language::GritTargetLanguage,
-    grit_target_node::GritTargetNode, source_location_ext::SourceFileExt, util::TextRangeGritExt,
+    grit_target_node::GritTargetNode, linearization::linearize_binding,
+    source_location_ext::SourceFileExt, util::TextRangeGritExt,
 };
-use anyhow::bail;
 use biome_diagnostics::{display::SourceFile, SourceCode};
 use biome_rowan::TextRange;
 use grit_pattern_matcher::{
@@ -155,14 +155,24 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
         _distributed_indent: Option<usize>,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> anyhow::Result<Cow<'a, str>> {
-        bail!("Not implemented") // TODO: Implement rewriting
+        match self {
+            Self::Node(..) | Self::Range(..) => {
+                let (Some(source), Some(range)) = (self.source(), self.code_range(language)) else {
+                    return Ok(self.text(language)?.into_owned().into());
+                };
+                linearize_binding(language, effects, files, memo, source, range, logs)
+            }
+            Self::File(..) | Self::Empty(..) | Self::Constant(..) => {
+                Ok(self.text(language)?.into_owned().into())
+            }
+        }
     }
 
     fn text(&self, _language: &GritTargetLanguage) -> anyhow::Result<Cow<'a, str>> {
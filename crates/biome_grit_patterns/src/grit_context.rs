@@ -7,6 +7,7 @@ use crate::grit_resolved_pattern::GritResolvedPattern;
 use crate::grit_target_language::GritTargetLanguage;
 use crate::grit_target_node::GritTargetNode;
 use crate::grit_tree::GritTargetTree;
+use crate::linearization::linearize_binding;
 use anyhow::{anyhow, bail, Result};
 use biome_parser::AnyParse;
 use grit_pattern_matcher::constants::{GLOBAL_VARS_SCOPE_INDEX, NEW_FILES_INDEX};
@@ -16,9 +17,10 @@ use grit_pattern_matcher::pattern::{
     CallBuiltIn, File, FilePtr, GritFunctionDefinition, Matcher, Pattern, PatternDefinition,
     PredicateDefinition, ResolvedPattern, State,
 };
-use grit_util::{AnalysisLogs, FileOrigin, InputRanges, MatchRanges};
+use grit_util::{AnalysisLogs, CodeRange, FileOrigin, InputRanges, MatchRanges};
 use im::vector;
 use path_absolutize::Absolutize;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -128,15 +130,52 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
             variables,
             suppressed,
         };
+        let effects: Vec<_> = state.effects.iter().cloned().collect();
         for file_ptr in files {
             let file = state.files.get_file_owner(file_ptr);
-            let mut match_log = file.matches.borrow_mut();
+            {
+                let mut match_log = file.matches.borrow_mut();
+                if match_log.input_matches.is_none() {
+                    match_log.input_matches = Some(input_ranges.clone());
+                }
+            }
+
+            if effects.is_empty() {
+                continue;
+            }
 
-            if match_log.input_matches.is_none() {
-                match_log.input_matches = Some(input_ranges.clone());
+            let source = file.tree.text();
+            let new_source = linearize_binding(
+                &self.lang,
+                &effects,
+                &state.files,
+                &mut HashMap::new(),
+                source,
+                CodeRange::new(0, source.len() as u32, source),
+                logs,
+            )?;
+            if new_source == source {
+                continue;
             }
 
-            // TODO: Implement effect application
+            let Some(new_file) = new_file_owner(
+                file.name.clone(),
+                &new_source,
+                &self.lang,
+                FileOrigin::Mutated,
+                logs,
+            )?
+            else {
+                bail!(
+                    "failed to construct the rewritten file {}",
+                    file.name.to_string_lossy()
+                )
+            };
+            self.files().push(new_file);
+            // SAFETY: We just pushed to the list of files, so there must be one.
+            state
+                .files
+                .push_revision(&file_ptr, self.files().last().unwrap());
         }
 
         let new_files_binding =
@@ -165,12 +204,13 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
                 .into();
             let body = file.body(&state.files).text(&state.files, &self.lang)?;
             let owned_file =
-                new_file_owner(name.clone(), &body, &self.lang, logs)?.ok_or_else(|| {
-                    anyhow!(
-                        "failed to construct new file for file {}",
-                        name.to_string_lossy()
-                    )
-                })?;
+                new_file_owner(name.clone(), &body, &self.lang, FileOrigin::New, logs)?
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "failed to construct new file for file {}",
+                            name.to_string_lossy()
+                        )
+                    })?;
             self.files().push(owned_file);
             // SAFETY: We just pushed to the list of files, so there must be one.
             let _ = state.files.push_new_file(self.files().last().unwrap());
@@ -251,17 +291,21 @@ fn file_owner_from_matches(
     }))
 }
 
+/// Creates the owner of a file constructed by Grit: either a new file, or
+/// a revision of an existing file with the effects applied.
 fn new_file_owner(
     name: impl Into<PathBuf>,
     source: &str,
     language: &GritTargetLanguage,
+    origin: FileOrigin<'_, GritTargetTree>,
     logs: &mut AnalysisLogs,
 ) -> Result<Option<FileOwner<GritTargetTree>>> {
     let name = name.into();
+    let new = matches!(origin, FileOrigin::New);
 
     let Some(tree) = language
         .get_parser()
-        .parse_file(source, Some(&name), logs, FileOrigin::New)
+        .parse_file(source, Some(&name), logs, origin)
     else {
         return Ok(None);
     };
@@ -272,7 +316,7 @@ fn new_file_owner(
         absolute_path,
         tree,
         matches: Default::default(),
-        new: true,
+        new,
     }))
 }
 
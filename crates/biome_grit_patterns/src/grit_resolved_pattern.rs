@@ -503,14 +503,59 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<CodeRange, Option<String>>,
         _should_pad_snippet: bool,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> Result<Cow<'a, str>> {
-        bail!("Not implemented") // TODO: Implement rewriting
+        // The snippets are linearized without padding, since the bindings keep the
+        // indentation of the code they refer to
+        match self {
+            Self::Binding(bindings) => bindings
+                .last()
+                .ok_or_else(|| anyhow!("cannot linearize an empty binding"))?
+                .linearized_text(language, effects, files, memo, None, logs),
+            Self::Snippets(snippets) => {
+                let mut text = String::new();
+                for snippet in snippets {
+                    text.push_str(
+                        &snippet.linearized_text(language, effects, files, memo, None, logs)?,
+                    );
+                }
+                Ok(text.into())
+            }
+            Self::List(elements) => {
+                let mut texts = Vec::with_capacity(elements.len());
+                for element in elements {
+                    texts.push(
+                        element.linearized_text(language, effects, files, memo, false, logs)?,
+                    );
+                }
+                Ok(texts.join(",").into())
+            }
+            Self::Map(map) => {
+                let mut entries = Vec::with_capacity(map.len());
+                for (key, value) in map {
+                    let value =
+                        value.linearized_text(language, effects, files, memo, false, logs)?;
+                    entries.push(format!("\"{key}\": {value}"));
+                }
+                Ok(format!("{{{}}}", entries.join(", ")).into())
+            }
+            Self::File(file) => {
+                let name = file.name(files).text(files, language)?;
+                let body = file
+                    .body(files)
+                    .linearized_text(language, effects, files, memo, false, logs)?;
+                Ok(format!("{name}:\n{body}").into())
+            }
+            Self::Files(files_pattern) => {
+                files_pattern.linearized_text(language, effects, files, memo, false, logs)
+            }
+            Self::Constant(constant) => Ok(constant.to_string().into()),
+        }
     }
 
     fn matches_undefined(&self) -> bool {
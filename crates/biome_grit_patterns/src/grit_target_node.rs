@@ -289,7 +289,7 @@ impl<'a> GritAstNode for GritTargetNode<'a> {
     }
 
     fn code_range(&self) -> CodeRange {
-        self.text_trimmed_range().to_code_range(self.text())
+        self.text_trimmed_range().to_code_range(self.source())
     }
 
     #[allow(refining_impl_trait)]
@@ -0,0 +1,104 @@
+use crate::grit_context::GritQueryContext;
+use crate::grit_target_language::GritTargetLanguage;
+use anyhow::Result;
+use grit_pattern_matcher::binding::Binding;
+use grit_pattern_matcher::effects::Effect;
+use grit_pattern_matcher::pattern::{FileRegistry, ResolvedPattern};
+use grit_util::{AnalysisLogs, CodeRange, EffectKind};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// A replacement computed from an [Effect], in the coordinates of the source
+/// that is linearized.
+struct Replacement {
+    start: u32,
+    end: u32,
+    text: String,
+}
+
+/// Returns the text of `range` in `source`, with the effects that apply to it.
+///
+/// The replacement of an effect is linearized too, so the effects on the
+/// bindings used by the replacement are applied as well. `memo` stores the
+/// replacement of each rewritten range, and it's used to stop the recursion
+/// when a replacement refers to the binding it rewrites.
+pub(crate) fn linearize_binding<'a>(
+    language: &GritTargetLanguage,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    memo: &mut HashMap<CodeRange, Option<String>>,
+    source: &'a str,
+    range: CodeRange,
+    logs: &mut AnalysisLogs,
+) -> Result<Cow<'a, str>> {
+    let mut replacements = Vec::new();
+    for effect in effects {
+        let binding = &effect.binding;
+        let (Some(binding_source), Some(binding_range)) =
+            (binding.source(), binding.code_range(language))
+        else {
+            continue;
+        };
+        if !range.applies_to(binding_source)
+            || binding_range.start < range.start
+            || binding_range.end > range.end
+        {
+            continue;
+        }
+
+        let text = match effect.kind {
+            EffectKind::Rewrite => match memo.get(&binding_range) {
+                Some(Some(text)) => text.clone(),
+                // The binding is being rewritten, the replacement refers to the original code
+                Some(None) => continue,
+                None => {
+                    memo.insert(binding_range.clone(), None);
+                    let text = effect
+                        .pattern
+                        .linearized_text(language, effects, files, memo, false, logs)?
+                        .into_owned();
+                    memo.insert(binding_range.clone(), Some(text.clone()));
+                    text
+                }
+            },
+            EffectKind::Insert => effect
+                .pattern
+                .linearized_text(language, effects, files, memo, false, logs)?
+                .into_owned(),
+        };
+
+        let (start, end) = match effect.kind {
+            EffectKind::Rewrite => (binding_range.start, binding_range.end),
+            EffectKind::Insert => (binding_range.end, binding_range.end),
+        };
+        replacements.push(Replacement { start, end, text });
+    }
+
+    if replacements.is_empty() {
+        return Ok(Cow::Borrowed(
+            &source[range.start as usize..range.end as usize],
+        ));
+    }
+
+    // The outermost replacements win: the nested ones are already applied
+    // by the linearization of their replacements.
+    replacements.sort_by(|left, right| {
+        left.start
+            .cmp(&right.start)
+            .then_with(|| right.end.cmp(&left.end))
+    });
+
+    let mut text = String::new();
+    let mut cursor = range.start;
+    for replacement in replacements {
+        if replacement.start < cursor {
+            continue;
+        }
+        text.push_str(&source[cursor as usize..replacement.start as usize]);
+        text.push_str(&replacement.text);
+        cursor = replacement.end;
+    }
+    text.push_str(&source[cursor as usize..range.end as usize]);
+
+    Ok(Cow::Owned(text))
+}
@@ -0,0 +1,6 @@
+console.log("apple");
+console.info("pear");
+
+function eat(fruit) {
+    console.log(fruit, "eaten");
+}
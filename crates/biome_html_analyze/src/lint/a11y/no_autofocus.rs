@@ -0,0 +1,82 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_html_syntax::{AnyHtmlTag, HtmlAttribute};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Enforce that the `autofocus` attribute is not used on elements.
+    ///
+    /// Autofocusing elements can cause usability issues for sighted and non-sighted users, alike.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <input autofocus>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input autofocus="true">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <input>
+    /// ```
+    ///
+    /// ```html
+    /// <button>Send</button>
+    /// ```
+    ///
+    /// ## Resources
+    ///
+    /// - [WHATWG HTML Standard, The autofocus attribute](https://html.spec.whatwg.org/multipage/interaction.html#attr-fe-autofocus)
+    /// - [The accessibility of HTML 5 autofocus](https://brucelawson.co.uk/2009/the-accessibility-of-html-5-autofocus/)
+    ///
+    pub NoAutofocus {
+        version: "next",
+        name: "noAutofocus",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("no-autofocus")],
+        recommended: true,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoAutofocus {
+    type Query = Ast<AnyHtmlTag>;
+    type State = HtmlAttribute;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        ctx.query().find_attribute_by_name("autofocus")
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            attribute.syntax().text_trimmed_range(),
+            markup! {
+                "Avoid the "<Emphasis>"autofocus"</Emphasis>" attribute."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<HtmlRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.remove_node(attribute.clone());
+        Some(HtmlRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the "<Emphasis>"autofocus"</Emphasis>" attribute." }.to_owned(),
+            mutation,
+        ))
+    }
+}
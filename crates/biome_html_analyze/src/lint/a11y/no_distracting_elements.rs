@@ -0,0 +1,94 @@
+use crate::HtmlRuleAction;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{
+    declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_html_syntax::{AnyHtmlElement, AnyHtmlTag, HtmlElement, HtmlSyntaxToken};
+use biome_rowan::{AstNode, BatchMutationExt};
+use biome_string_case::StrOnlyExtension;
+
+declare_lint_rule! {
+    /// Enforces that no distracting elements are used.
+    ///
+    /// Elements that can be visually distracting can cause accessibility issues with visually impaired users.
+    /// Such elements are most likely deprecated, and should be avoided.
+    /// By default, the following elements are visually distracting: `<marquee>` and `<blink>`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <marquee>Breaking news</marquee>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <blink>Sale</blink>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <div>Breaking news</div>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 2.2.2](https://www.w3.org/WAI/WCAG21/Understanding/pause-stop-hide)
+    ///
+    pub NoDistractingElements {
+        version: "next",
+        name: "noDistractingElements",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("no-distracting-elements")],
+        recommended: true,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoDistractingElements {
+    type Query = Ast<AnyHtmlTag>;
+    type State = HtmlSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        let name = element.name_value_token()?;
+        match name.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+            "marquee" | "blink" => Some(name),
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let element = ctx.query();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            element.range(),
+            markup! {"Don't use the '"{name.text_trimmed()}"' element."}.to_owned(),
+        )
+        .note(markup! {
+            "Visually distracting elements can cause accessibility issues and should be avoided."
+        });
+
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, name: &Self::State) -> Option<HtmlRuleAction> {
+        let element: AnyHtmlElement = match ctx.query() {
+            AnyHtmlTag::HtmlOpeningElement(opening) => opening.parent::<HtmlElement>()?.into(),
+            AnyHtmlTag::HtmlSelfClosingElement(element) => element.clone().into(),
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.remove_node(element);
+
+        Some(HtmlRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the '"{name.text_trimmed()}"' element." }.to_owned(),
+            mutation,
+        ))
+    }
+}
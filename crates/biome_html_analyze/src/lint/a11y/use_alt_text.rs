@@ -0,0 +1,160 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::{fmt::Display, fmt::Formatter, markup};
+use biome_html_syntax::{AnyHtmlTag, HtmlElement, TextRange};
+use biome_rowan::{AstNode, AstNodeList};
+use biome_string_case::StrOnlyExtension;
+
+declare_lint_rule! {
+    /// Enforce that all elements that require alternative text have meaningful information to relay back to the end user.
+    ///
+    /// This is a critical component of accessibility for screen reader users in order for them to understand the content's purpose on the page.
+    /// By default, this rule checks for alternative text on the following elements: `<img>`, `<area>`, `<input type="image">`, and `<object>`.
+    ///
+    /// An empty `alt` attribute marks an image as decorative, so it's valid.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <img src="image.png">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input type="image" src="image.png">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <img src="image.png" alt="image alt">
+    /// ```
+    ///
+    /// ```html
+    /// <img src="decoration.png" alt="">
+    /// ```
+    ///
+    /// ```html
+    /// <input type="image" src="image.png" aria-label="alt text">
+    /// ```
+    ///
+    /// ```html
+    /// <object data="movie.mp4">A movie about rabbits</object>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.1.1](https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html)
+    ///
+    pub UseAltText {
+        version: "next",
+        name: "useAltText",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("alt-text")],
+        recommended: true,
+    }
+}
+
+pub enum ValidatedElement {
+    Object,
+    Img,
+    Area,
+    Input,
+}
+
+impl Display for ValidatedElement {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        match self {
+            ValidatedElement::Object => fmt.write_markup(markup!(<Emphasis>"title"</Emphasis>)),
+            _ => fmt.write_markup(markup!(<Emphasis>"alt"</Emphasis>)),
+        }
+    }
+}
+
+impl Rule for UseAltText {
+    type Query = Ast<AnyHtmlTag>;
+    type State = (ValidatedElement, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        let name = element.name_value_token()?;
+        let validated_element = match name.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+            "object" => ValidatedElement::Object,
+            "img" => ValidatedElement::Img,
+            "area" => ValidatedElement::Area,
+            "input" if has_type_image_attribute(element) => ValidatedElement::Input,
+            _ => return None,
+        };
+
+        if has_valid_label(element, "aria-label")
+            || has_valid_label(element, "aria-labelledby")
+            || is_aria_hidden(element)
+        {
+            return None;
+        }
+
+        let has_alternative_text = match validated_element {
+            ValidatedElement::Object => {
+                has_valid_label(element, "title") || has_accessible_child(element)
+            }
+            // An empty `alt` is the alternative text of decorative images
+            _ => element.has_attribute("alt"),
+        };
+        if has_alternative_text {
+            return None;
+        }
+
+        let range = match element {
+            AnyHtmlTag::HtmlOpeningElement(opening) => opening
+                .parent::<HtmlElement>()
+                .map_or(opening.range(), |element| element.range()),
+            AnyHtmlTag::HtmlSelfClosingElement(element) => element.range(),
+        };
+        Some((validated_element, range))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (validate_element, range) = state;
+        let message = markup!(
+            "Provide a text alternative through the "{{validate_element}}", "<Emphasis>"aria-label"</Emphasis>" or "<Emphasis>"aria-labelledby"</Emphasis>" attribute"
+        ).to_owned();
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message).note(markup! {
+                "Meaningful alternative text on elements helps users relying on screen readers to understand content's purpose within a page."
+            }).note(markup! { "If the content is decorative, redundant, or obscured, consider hiding it from assistive technologies with the "<Emphasis>"aria-hidden"</Emphasis>" attribute."}),
+        )
+    }
+}
+
+fn has_type_image_attribute(element: &AnyHtmlTag) -> bool {
+    element
+        .find_attribute_by_name("type")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.eq_ignore_ascii_case("image"))
+}
+
+fn has_valid_label(element: &AnyHtmlTag, name_to_lookup: &str) -> bool {
+    element
+        .find_attribute_by_name(name_to_lookup)
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| !value.trim().is_empty())
+}
+
+fn is_aria_hidden(element: &AnyHtmlTag) -> bool {
+    element
+        .find_attribute_by_name("aria-hidden")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.text() == "true")
+}
+
+/// Returns `true` if the content of the `<object>` element can be read, because it's
+/// displayed when the object can't be.
+fn has_accessible_child(element: &AnyHtmlTag) -> bool {
+    element
+        .parent::<HtmlElement>()
+        .is_some_and(|element| !element.children().is_empty())
+}
@@ -0,0 +1,75 @@
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, Ast, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_html_syntax::AnyHtmlTag;
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Enforce that `html` element has `lang` attribute.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <html></html>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <html lang=""></html>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <html lang="en"></html>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 3.1.1](https://www.w3.org/WAI/WCAG21/Understanding/language-of-page)
+    ///
+    pub UseHtmlLang {
+        version: "next",
+        name: "useHtmlLang",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("html-has-lang")],
+        recommended: true,
+    }
+}
+
+impl Rule for UseHtmlLang {
+    type Query = Ast<AnyHtmlTag>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        if !element.has_name("html") {
+            return None;
+        }
+
+        let has_lang = element
+            .find_attribute_by_name("lang")
+            .and_then(|attribute| attribute.value())
+            .is_some_and(|value| !value.trim().is_empty());
+        (!has_lang).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().syntax().text_trimmed_range(),
+            markup! {
+                "Provide a "<Emphasis>"lang"</Emphasis>" attribute when using the "<Emphasis>"html"</Emphasis>" element."
+            }
+        ).note(
+            markup! {
+                "Setting a "<Emphasis>"lang"</Emphasis>" attribute on HTML document elements configures the language"
+                "used by screen readers when no user default is specified."
+            }
+        ))
+    }
+}
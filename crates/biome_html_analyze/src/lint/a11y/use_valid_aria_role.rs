@@ -0,0 +1,137 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_aria::{AriaRoles, ValidAriaRoleOptions};
+use biome_console::markup;
+use biome_html_syntax::{AnyHtmlTag, HtmlAttribute};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Elements with ARIA roles must use a valid, non-abstract ARIA role.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <div role="datepicker"></div>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div role="range"></div>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div role=""></div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <div role="button"></div>
+    /// ```
+    ///
+    /// ```html
+    /// <div></div>
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowInvalidRoles": ["invalid-role", "text"],
+    ///         "ignoreNonDom": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// With `ignoreNonDom`, the roles of the custom elements, such as `<my-element>`, aren't checked.
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    /// ## Resources
+    ///
+    /// - [Chrome Audit Rules, AX_ARIA_01](https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_aria_01)
+    /// - [DPUB-ARIA roles](https://www.w3.org/TR/dpub-aria-1.0/)
+    /// - [MDN: Using ARIA: Roles, states, and properties](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Techniques)
+    ///
+    pub UseValidAriaRole {
+        version: "next",
+        name: "useValidAriaRole",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("aria-role")],
+        recommended: true,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseValidAriaRole {
+    type Query = Ast<AnyHtmlTag>;
+    type State = HtmlAttribute;
+    type Signals = Option<Self::State>;
+    type Options = Box<ValidAriaRoleOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        let options = ctx.options();
+
+        if options.ignore_non_dom && element.is_custom_element() {
+            return None;
+        }
+
+        let role_attribute = element.find_attribute_by_name("role")?;
+        let role_attribute_value = role_attribute.value()?;
+        let mut roles = role_attribute_value.split_ascii_whitespace().peekable();
+
+        // An empty role isn't valid
+        if roles.peek().is_none() {
+            return Some(role_attribute);
+        }
+
+        let is_valid = roles.all(|role| {
+            options
+                .allow_invalid_roles
+                .iter()
+                .any(|allowed| allowed == role)
+                || AriaRoles.get_role(role).is_some()
+        });
+
+        (!is_valid).then_some(role_attribute)
+    }
+
+    fn diagnostic(
+        _ctx: &RuleContext<Self>,
+        role_attribute: &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                role_attribute.range(),
+                markup! {
+                    "Enforce that elements with ARIA roles must use a valid, non-abstract ARIA role."
+                },
+            )
+            .note(markup! {
+                "Check "<Hyperlink href="https://www.w3.org/TR/wai-aria/#namefromauthor">"WAI-ARIA"</Hyperlink>" for valid roles or provide options accordingly."
+            })
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, role_attribute: &Self::State) -> Option<HtmlRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.remove_node(role_attribute.clone());
+        Some(HtmlRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the invalid "<Emphasis>"role"</Emphasis>" attribute.\n Check the list of all "<Hyperlink href="https://www.w3.org/TR/wai-aria/#role_definitions">"valid"</Hyperlink>" role attributes." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
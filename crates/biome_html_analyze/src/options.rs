@@ -0,0 +1,14 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoAccessKey = <lint::a11y::no_access_key::NoAccessKey as biome_analyze::Rule>::Options;
+pub type NoAutofocus = <lint::a11y::no_autofocus::NoAutofocus as biome_analyze::Rule>::Options;
+pub type NoDistractingElements =
+    <lint::a11y::no_distracting_elements::NoDistractingElements as biome_analyze::Rule>::Options;
+pub type UseAltText = <lint::a11y::use_alt_text::UseAltText as biome_analyze::Rule>::Options;
+pub type UseButtonType =
+    <lint::a11y::use_button_type::UseButtonType as biome_analyze::Rule>::Options;
+pub type UseHtmlLang = <lint::a11y::use_html_lang::UseHtmlLang as biome_analyze::Rule>::Options;
+pub type UseValidAriaRole =
+    <lint::a11y::use_valid_aria_role::UseValidAriaRole as biome_analyze::Rule>::Options;
@@ -0,0 +1,79 @@
+use biome_analyze::{ApplySuppression, SuppressionAction};
+use biome_html_syntax::{AnyHtmlElement, HtmlLanguage};
+use biome_rowan::{AstNode, BatchMutation, SyntaxToken, TriviaPieceKind};
+
+pub(crate) struct HtmlSuppressionAction;
+
+impl SuppressionAction for HtmlSuppressionAction {
+    type Language = HtmlLanguage;
+
+    fn find_token_to_apply_suppression(
+        &self,
+        token: SyntaxToken<Self::Language>,
+    ) -> Option<ApplySuppression<Self::Language>> {
+        // The suppression comments of HTML apply to the element that follows them,
+        // so the comment goes before the element that contains the diagnostic
+        let element = token
+            .ancestors()
+            .filter_map(AnyHtmlElement::cast)
+            .find(|element| {
+                matches!(
+                    element,
+                    AnyHtmlElement::HtmlElement(_) | AnyHtmlElement::HtmlSelfClosingElement(_)
+                )
+            })?;
+
+        Some(ApplySuppression {
+            token_has_trailing_comments: false,
+            token_to_apply_suppression: element.syntax().first_token()?,
+            should_insert_leading_newline: false,
+        })
+    }
+
+    fn apply_suppression(
+        &self,
+        mutation: &mut BatchMutation<Self::Language>,
+        apply_suppression: ApplySuppression<Self::Language>,
+        suppression_text: &str,
+        suppression_reason: &str,
+    ) {
+        let ApplySuppression {
+            token_to_apply_suppression,
+            ..
+        } = apply_suppression;
+
+        let suppression_comment = format!("<!-- {suppression_text}: {suppression_reason} -->");
+        let leading_trivia: Vec<_> = token_to_apply_suppression
+            .leading_trivia()
+            .pieces()
+            .collect();
+        // The comment gets its own line when the element starts a line, and the
+        // element keeps the indentation of that line. Otherwise, the comment stays
+        // inline, so the whitespace of the content doesn't change.
+        let starts_line = token_to_apply_suppression.prev_token().is_none()
+            || leading_trivia.iter().any(|piece| piece.is_newline());
+        let indentation = leading_trivia
+            .iter()
+            .rev()
+            .take_while(|piece| piece.is_whitespace())
+            .collect::<Vec<_>>();
+
+        let mut trivia: Vec<_> = leading_trivia
+            .iter()
+            .map(|piece| (piece.kind(), piece.text()))
+            .collect();
+        trivia.push((
+            TriviaPieceKind::MultiLineComment,
+            suppression_comment.as_str(),
+        ));
+        if starts_line {
+            trivia.push((TriviaPieceKind::Newline, "\n"));
+            for piece in indentation.iter().rev() {
+                trivia.push((TriviaPieceKind::Whitespace, piece.text()));
+            }
+        }
+
+        let new_token = token_to_apply_suppression.with_leading_trivia(trivia);
+        mutation.replace_token_discard_trivia(token_to_apply_suppression, new_token);
+    }
+}
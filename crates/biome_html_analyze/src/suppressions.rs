@@ -0,0 +1,349 @@
+use biome_analyze::{
+    AnalyzerSignal, ControlFlow, DiagnosticSignal, MetadataRegistry, RuleFilter,
+    SuppressionDiagnostic as AnalyzerSuppressionDiagnostic,
+};
+use biome_diagnostics::{category, Category, Diagnostic, DiagnosticExt};
+use biome_html_syntax::{
+    AnyHtmlElement, HtmlComment, HtmlLanguage, HtmlRoot, HtmlSyntaxNode, HtmlSyntaxToken,
+};
+use biome_rowan::{AstNode, TextRange, TextSize};
+use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic, SuppressionScope};
+
+/// The suppression comments of an HTML document.
+///
+/// HTML comments are nodes of the syntax tree rather than trivia, so the
+/// analyzer can't find them while it visits the tokens. They're collected
+/// here instead, and the signals of the rules are checked against them
+/// before being emitted:
+///
+/// - `<!-- biome-ignore lint: reason -->` suppresses the diagnostics of the element that follows the comment;
+/// - `<!-- biome-ignore-all lint: reason -->` suppresses the diagnostics of the whole document,
+///   it must be placed before the first element;
+/// - `<!-- biome-ignore-start lint: reason -->` suppresses the diagnostics until the matching
+///   `<!-- biome-ignore-end lint: reason -->` comment, or until the end of the document.
+#[derive(Debug, Default)]
+pub(crate) struct HtmlSuppressions {
+    suppressions: Vec<HtmlSuppression>,
+    /// Diagnostics of the malformed or misplaced suppression comments
+    diagnostics: Vec<AnalyzerSuppressionDiagnostic>,
+    /// Parse errors of the suppression comments, along with the range of the comment
+    parse_errors: Vec<(SuppressionDiagnostic, TextRange)>,
+}
+
+#[derive(Debug)]
+struct HtmlSuppression {
+    scope: SuppressionScope,
+    /// Range of the suppression comment
+    comment_range: TextRange,
+    /// Start of the source text this comment is suppressing lint rules for
+    start: TextSize,
+    /// End of the source text this comment is suppressing lint rules for, or
+    /// `None` for a range that isn't closed
+    end: Option<TextSize>,
+    /// The rules and groups suppressed by the comment, all the rules are
+    /// suppressed when it's empty
+    suppressed_rules: Vec<RuleFilter<'static>>,
+    did_suppress_signal: bool,
+}
+
+impl HtmlSuppression {
+    fn covers(&self, range: TextRange) -> bool {
+        self.start <= range.start() && self.end.map_or(true, |end| range.start() < end)
+    }
+
+    fn suppresses(&self, category: &Category) -> bool {
+        let Some(rule) = category.name().strip_prefix("lint/") else {
+            return false;
+        };
+        let (group, rule) = rule.split_once('/').unwrap_or((rule, ""));
+        self.suppressed_rules.is_empty()
+            || self.suppressed_rules.iter().any(|filter| match *filter {
+                RuleFilter::Group(filter_group) => filter_group == group,
+                RuleFilter::Rule(filter_group, filter_rule) => {
+                    filter_group == group && filter_rule == rule
+                }
+            })
+    }
+}
+
+impl HtmlSuppressions {
+    /// Collects the suppression comments of `root`, the rules named by the comments are
+    /// looked up in `metadata`.
+    pub(crate) fn from_root(root: &HtmlRoot, metadata: &MetadataRegistry) -> Self {
+        let mut suppressions = Self::default();
+        for element in root
+            .syntax()
+            .descendants_with_tokens(biome_rowan::Direction::Next)
+        {
+            match element {
+                biome_rowan::SyntaxElement::Node(node) => {
+                    if let Some(comment) = HtmlComment::cast(node) {
+                        let text = comment.syntax().text_trimmed().to_string();
+                        let covered_range = next_element_range(&comment);
+                        suppressions.push_comment(
+                            &text,
+                            comment.syntax().text_trimmed_range(),
+                            covered_range,
+                            is_at_top_of_document(comment.syntax()),
+                            metadata,
+                        );
+                    }
+                }
+                biome_rowan::SyntaxElement::Token(token) => {
+                    // The suppression comments added by the code actions are trivia
+                    // of the first token of the suppressed element
+                    for piece in token.leading_trivia().pieces() {
+                        if let Some(comment) = piece.as_comments() {
+                            suppressions.push_comment(
+                                comment.text(),
+                                piece.text_range(),
+                                element_starting_at(&token),
+                                token.prev_token().is_none(),
+                                metadata,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        suppressions
+    }
+
+    fn push_comment(
+        &mut self,
+        text: &str,
+        comment_range: TextRange,
+        covered_range: Option<TextRange>,
+        is_at_top_of_document: bool,
+        metadata: &MetadataRegistry,
+    ) {
+        for result in parse_suppression_comment(text) {
+            let suppression = match result {
+                Ok(suppression) => suppression,
+                Err(diagnostic) => {
+                    self.parse_errors.push((diagnostic, comment_range));
+                    continue;
+                }
+            };
+
+            let mut suppress_all = false;
+            let mut suppressed_rules = Vec::new();
+            for (key, value) in suppression.categories {
+                let rule = if key == category!("lint") {
+                    match value {
+                        Some(value) => value,
+                        None => {
+                            suppress_all = true;
+                            continue;
+                        }
+                    }
+                } else {
+                    match key.name().strip_prefix("lint/") {
+                        Some(rule) => rule,
+                        None => continue,
+                    }
+                };
+
+                let filter = match rule.split_once('/') {
+                    None => metadata.find_group(rule).map(RuleFilter::from),
+                    Some((group, rule)) => metadata.find_rule(group, rule).map(RuleFilter::from),
+                };
+                match filter {
+                    Some(filter) => suppressed_rules.push(filter),
+                    None => {
+                        let diagnostic = match rule.split_once('/') {
+                            Some((group, rule)) => AnalyzerSuppressionDiagnostic::new(
+                                category!("suppressions/unknownRule"),
+                                comment_range,
+                                format_args!(
+                                    "Unknown lint rule {group}/{rule} in suppression comment"
+                                ),
+                            ),
+                            None => AnalyzerSuppressionDiagnostic::new(
+                                category!("suppressions/unknownGroup"),
+                                comment_range,
+                                format_args!(
+                                    "Unknown lint rule group {rule} in suppression comment"
+                                ),
+                            ),
+                        };
+                        self.diagnostics.push(diagnostic);
+                    }
+                }
+            }
+
+            if suppress_all {
+                suppressed_rules.clear();
+            } else if suppressed_rules.is_empty() {
+                continue;
+            }
+
+            let (start, end) = match suppression.scope {
+                SuppressionScope::Line => match covered_range {
+                    Some(range) => (range.start(), Some(range.end())),
+                    // Nothing follows the comment
+                    None => (comment_range.end(), Some(comment_range.end())),
+                },
+                SuppressionScope::File => {
+                    if !is_at_top_of_document {
+                        self.diagnostics.push(AnalyzerSuppressionDiagnostic::new(
+                            category!("suppressions/incorrect"),
+                            comment_range,
+                            "Top-level suppressions must be placed at the top of the file",
+                        ));
+                        continue;
+                    }
+                    (TextSize::from(0), None)
+                }
+                SuppressionScope::RangeStart => (comment_range.start(), None),
+                SuppressionScope::RangeEnd => {
+                    // Close the innermost range suppressing the same set of rules
+                    let start = self.suppressions.iter_mut().rev().find(|start| {
+                        start.scope == SuppressionScope::RangeStart
+                            && start.end.is_none()
+                            && start.suppressed_rules == suppressed_rules
+                    });
+                    match start {
+                        Some(start) => start.end = Some(comment_range.end()),
+                        None => self.diagnostics.push(AnalyzerSuppressionDiagnostic::new(
+                            category!("suppressions/incorrect"),
+                            comment_range,
+                            "This suppression closes a range that was never opened, add a matching biome-ignore-start comment before it",
+                        )),
+                    }
+                    continue;
+                }
+            };
+
+            self.suppressions.push(HtmlSuppression {
+                scope: suppression.scope,
+                comment_range,
+                start,
+                end,
+                suppressed_rules,
+                did_suppress_signal: false,
+            });
+        }
+    }
+
+    /// Returns `true` if the lint diagnostic of `signal` is suppressed by a comment
+    pub(crate) fn is_suppressed(&mut self, signal: &dyn AnalyzerSignal<HtmlLanguage>) -> bool {
+        if self.suppressions.is_empty() {
+            return false;
+        }
+        let Some(diagnostic) = signal.diagnostic() else {
+            return false;
+        };
+        let (Some(category), Some(range)) = (diagnostic.category(), diagnostic.location().span)
+        else {
+            return false;
+        };
+
+        match self
+            .suppressions
+            .iter_mut()
+            .find(|suppression| suppression.covers(range) && suppression.suppresses(category))
+        {
+            Some(suppression) => {
+                suppression.did_suppress_signal = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Emits the diagnostics of the malformed and misplaced suppression comments
+    pub(crate) fn emit_diagnostics<B>(
+        &self,
+        range: Option<TextRange>,
+        emit_signal: &mut dyn FnMut(&dyn AnalyzerSignal<HtmlLanguage>) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        for (diagnostic, comment_range) in &self.parse_errors {
+            if !range_match(range, *comment_range) {
+                continue;
+            }
+            let signal = DiagnosticSignal::new(move || {
+                let location = diagnostic.location();
+                let span = location
+                    .span
+                    .map_or(*comment_range, |span| span + comment_range.start());
+                diagnostic.clone().with_file_span(span)
+            });
+            emit_signal(&signal)?;
+        }
+
+        for diagnostic in &self.diagnostics {
+            if !range_match(range, diagnostic.location().span.unwrap_or_default()) {
+                continue;
+            }
+            let signal = DiagnosticSignal::new(move || diagnostic.clone());
+            emit_signal(&signal)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+
+    /// Emits a diagnostic for each suppression comment that didn't suppress any signal
+    pub(crate) fn emit_unused<B>(
+        &self,
+        emit_signal: &mut dyn FnMut(&dyn AnalyzerSignal<HtmlLanguage>) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        for suppression in &self.suppressions {
+            if suppression.did_suppress_signal {
+                continue;
+            }
+            let comment_range = suppression.comment_range;
+            let signal = DiagnosticSignal::new(move || {
+                AnalyzerSuppressionDiagnostic::new(
+                    category!("suppressions/unused"),
+                    comment_range,
+                    "Suppression comment is not being used",
+                )
+            });
+            emit_signal(&signal)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+/// Returns the range of the element that follows `comment`, skipping the
+/// other comments so that several suppression comments can be stacked.
+fn next_element_range(comment: &HtmlComment) -> Option<TextRange> {
+    comment
+        .syntax()
+        .siblings(biome_rowan::Direction::Next)
+        .skip(1)
+        .filter_map(AnyHtmlElement::cast)
+        .find(|element| !matches!(element, AnyHtmlElement::HtmlComment(_)))
+        .map(|element| element.syntax().text_trimmed_range())
+}
+
+/// Returns the range of the outermost element that starts with `token`
+fn element_starting_at(token: &HtmlSyntaxToken) -> Option<TextRange> {
+    token
+        .ancestors()
+        .take_while(|node| {
+            node.first_token()
+                .is_some_and(|first_token| first_token == *token)
+        })
+        .filter(|node| AnyHtmlElement::can_cast(node.kind()))
+        .last()
+        .map(|node| node.text_trimmed_range())
+}
+
+/// Returns `true` if only the doctype or other comments precede `node`
+fn is_at_top_of_document(node: &HtmlSyntaxNode) -> bool {
+    node.parent().is_some_and(|list| {
+        list.parent()
+            .is_some_and(|root| HtmlRoot::can_cast(root.kind()))
+    }) && node
+        .siblings(biome_rowan::Direction::Prev)
+        .skip(1)
+        .all(|sibling| HtmlComment::can_cast(sibling.kind()))
+}
+
+fn range_match(filter: Option<TextRange>, range: TextRange) -> bool {
+    filter.map_or(true, |filter| filter.intersect(range).is_some())
+}
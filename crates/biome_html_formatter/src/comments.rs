@@ -9,7 +9,7 @@ use biome_formatter::{
 };
 use biome_html_syntax::HtmlLanguage;
 use biome_rowan::{SyntaxTriviaPieceComments, TextLen};
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 use crate::context::HtmlFormatContext;
 
@@ -87,6 +87,7 @@ impl CommentStyle for HtmlCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Line)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
@@ -0,0 +1,107 @@
+use crate::{
+    HtmlAttribute, HtmlAttributeList, HtmlName, HtmlOpeningElement, HtmlSelfClosingElement,
+    HtmlString, HtmlSyntaxToken,
+};
+use biome_rowan::{declare_node_union, AstNodeList, SyntaxResult, TextRange, TextSize, TokenText};
+
+declare_node_union! {
+    /// The tag that holds the name and the attributes of an element.
+    ///
+    /// ```html
+    /// <button type="submit">Send</button>
+    /// ^^^^^^^^^^^^^^^^^^^^^^
+    /// <img src="image.png" />
+    /// ^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    pub AnyHtmlTag = HtmlOpeningElement | HtmlSelfClosingElement
+}
+
+impl AnyHtmlTag {
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        match self {
+            AnyHtmlTag::HtmlOpeningElement(element) => element.name(),
+            AnyHtmlTag::HtmlSelfClosingElement(element) => element.name(),
+        }
+    }
+
+    pub fn attributes(&self) -> HtmlAttributeList {
+        match self {
+            AnyHtmlTag::HtmlOpeningElement(element) => element.attributes(),
+            AnyHtmlTag::HtmlSelfClosingElement(element) => element.attributes(),
+        }
+    }
+
+    pub fn name_value_token(&self) -> Option<HtmlSyntaxToken> {
+        self.name().ok()?.value_token().ok()
+    }
+
+    /// Returns `true` if the name of the element is `name`, ignoring the case
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name_value_token()
+            .is_some_and(|token| token.text_trimmed().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns `true` if the element is a custom element, e.g. `<my-element>`
+    pub fn is_custom_element(&self) -> bool {
+        self.name_value_token()
+            .is_some_and(|token| token.text_trimmed().contains('-'))
+    }
+
+    /// Returns the first attribute named `name`, ignoring the case
+    pub fn find_attribute_by_name(&self, name: &str) -> Option<HtmlAttribute> {
+        self.attributes().find_by_name(name)
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.find_attribute_by_name(name).is_some()
+    }
+}
+
+impl HtmlAttributeList {
+    /// Returns the first attribute named `name`, ignoring the case
+    pub fn find_by_name(&self, name: &str) -> Option<HtmlAttribute> {
+        self.iter().find_map(|attribute| {
+            let attribute = attribute.as_html_attribute()?;
+            attribute
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .eq_ignore_ascii_case(name)
+                .then(|| attribute.clone())
+        })
+    }
+}
+
+impl HtmlAttribute {
+    /// Returns the value of the attribute without its quotes, or `None` when the
+    /// attribute doesn't have a value.
+    ///
+    /// ```html
+    /// <input type="checkbox" checked>
+    ///              ^^^^^^^^
+    /// ```
+    pub fn value(&self) -> Option<TokenText> {
+        self.initializer()?.value().ok()?.inner_string_text().ok()
+    }
+}
+
+impl HtmlString {
+    /// Returns the text of the string without its quotes.
+    ///
+    /// The values of the attributes are not always quoted, e.g. `<input type=text>`.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        let token = self.value_token()?;
+        let text = token.token_text_trimmed();
+        let is_quoted = text.len() >= TextSize::from(2)
+            && ((text.starts_with('"') && text.ends_with('"'))
+                || (text.starts_with('\'') && text.ends_with('\'')));
+        if is_quoted {
+            let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
+            Ok(text.slice(range))
+        } else {
+            Ok(text)
+        }
+    }
+}
@@ -2,13 +2,14 @@
 
 use crate::suppression_action::JsSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    SuppressionKind,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal,
+    AnalyzerSuppression, ControlFlow, InspectMatcher, LanguageRoot, MatchQueryParams,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
 };
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Error as DiagnosticError};
 use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_module_graph::ModuleGraph;
 use biome_project::PackageJson;
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use std::ops::Deref;
@@ -28,6 +29,7 @@ pub mod utils;
 
 pub use crate::registry::visit_registry;
 pub use crate::services::control_flow::ControlFlowGraph;
+pub use crate::services::module_graph::{uses_module_graph, ModuleGraphServices};
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -43,6 +45,7 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
 /// processed by the lint rules registry
+#[allow(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
@@ -50,6 +53,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    module_graph: Arc<ModuleGraph>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -59,16 +63,20 @@ where
 {
     fn parse_linter_suppression_comment(
         text: &str,
-    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+    ) -> Vec<Result<AnalyzerSuppression, SuppressionDiagnostic>> {
         let mut result = Vec::new();
 
         for comment in parse_suppression_comment(text) {
-            let categories = match comment {
+            let (categories, scope) = match comment {
                 Ok(comment) => {
+                    let scope = comment.scope;
                     if comment.is_legacy {
-                        result.push(Ok(SuppressionKind::Deprecated));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Deprecated,
+                            scope,
+                        )));
                     }
-                    comment.categories
+                    (comment.categories, scope)
                 }
                 Err(err) => {
                     result.push(Err(err));
@@ -79,17 +87,29 @@ where
             for (key, value) in categories {
                 if key == category!("lint") {
                     if let Some(value) = value {
-                        result.push(Ok(SuppressionKind::MaybeLegacy(value)));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::MaybeLegacy(value),
+                            scope,
+                        )));
                     } else {
-                        result.push(Ok(SuppressionKind::Everything));
+                        result.push(Ok(AnalyzerSuppression::new(
+                            SuppressionKind::Everything,
+                            scope,
+                        )));
                     }
                 } else {
                     let category = key.name();
                     if let Some(rule) = category.strip_prefix("lint/") {
                         if let Some(instance) = value {
-                            result.push(Ok(SuppressionKind::RuleInstance(rule, instance)));
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::RuleInstance(rule, instance),
+                                scope,
+                            )));
                         } else {
-                            result.push(Ok(SuppressionKind::Rule(rule)));
+                            result.push(Ok(AnalyzerSuppression::new(
+                                SuppressionKind::Rule(rule),
+                                scope,
+                            )));
                         }
                     }
                 }
@@ -124,6 +144,7 @@ where
     services.insert_service(Arc::new(AriaRoles));
     services.insert_service(Arc::new(AriaProperties));
     services.insert_service(Arc::new(manifest));
+    services.insert_service(module_graph);
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -145,6 +166,7 @@ pub fn analyze<'a, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    module_graph: Arc<ModuleGraph>,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -158,6 +180,7 @@ where
         options,
         source_type,
         manifest,
+        module_graph,
         emit_signal,
     )
 }
@@ -211,6 +234,7 @@ mod tests {
                 dependencies,
                 ..Default::default()
             }),
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -303,6 +327,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -389,6 +414,7 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
@@ -4,10 +4,10 @@ use biome_analyze::{
     RuleSource,
 };
 use biome_console::markup;
-use biome_deserialize_macros::Deserializable;
 use biome_js_syntax::jsx_ext::AnyJsxElement;
 use biome_rowan::{AstNode, BatchMutationExt};
-use serde::{Deserialize, Serialize};
+
+pub use biome_aria::ValidAriaRoleOptions;
 
 declare_lint_rule! {
     /// Elements with ARIA roles must use a valid, non-abstract ARIA role.
@@ -74,14 +74,6 @@ declare_lint_rule! {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
-#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
-pub struct ValidAriaRoleOptions {
-    pub allow_invalid_roles: Vec<String>,
-    pub ignore_non_dom: bool,
-}
-
 impl Rule for UseValidAriaRole {
     type Query = Aria<AnyJsxElement>;
     type State = ();
@@ -8,6 +8,7 @@ pub mod no_dynamic_namespace_import_access;
 pub mod no_enum;
 pub mod no_exported_imports;
 pub mod no_head_element;
+pub mod no_import_cycles;
 pub mod no_irregular_whitespace;
 pub mod no_nested_ternary;
 pub mod no_octal_escape;
@@ -18,6 +19,7 @@ pub mod no_secrets;
 pub mod no_static_element_interactions;
 pub mod no_substr;
 pub mod no_template_curly_in_string;
+pub mod no_unused_exports;
 pub mod no_useless_escape_in_regex;
 pub mod use_adjacent_overload_signatures;
 pub mod use_aria_props_supported_by_role;
@@ -41,6 +43,7 @@ declare_lint_group! {
             self :: no_enum :: NoEnum ,
             self :: no_exported_imports :: NoExportedImports ,
             self :: no_head_element :: NoHeadElement ,
+            self :: no_import_cycles :: NoImportCycles ,
             self :: no_irregular_whitespace :: NoIrregularWhitespace ,
             self :: no_nested_ternary :: NoNestedTernary ,
             self :: no_octal_escape :: NoOctalEscape ,
@@ -51,6 +54,7 @@ declare_lint_group! {
             self :: no_static_element_interactions :: NoStaticElementInteractions ,
             self :: no_substr :: NoSubstr ,
             self :: no_template_curly_in_string :: NoTemplateCurlyInString ,
+            self :: no_unused_exports :: NoUnusedExports ,
             self :: no_useless_escape_in_regex :: NoUselessEscapeInRegex ,
             self :: use_adjacent_overload_signatures :: UseAdjacentOverloadSignatures ,
             self :: use_aria_props_supported_by_role :: UseAriaPropsSupportedByRole ,
@@ -0,0 +1,209 @@
+use crate::services::module_graph::ModuleGraph;
+use biome_analyze::{context::RuleContext, declare_lint_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::AnyJsImportLike;
+use biome_module_graph::{normalize_path, ImportKind, ModuleImport};
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+declare_lint_rule! {
+    /// Prevent import cycles.
+    ///
+    /// This rule reports an import when following the imports of the imported
+    /// module, and of the modules that it imports, leads back to the module
+    /// that contains the import.
+    ///
+    /// Import cycles cause the imported variables to be `undefined` when a module
+    /// of the cycle is evaluated before the modules that it depends on.
+    /// Move the code shared by the modules of a cycle to a separate module to break the cycle.
+    ///
+    /// Dynamic imports and type-only imports are ignored, because they aren't
+    /// evaluated when the module is loaded.
+    ///
+    /// The imports are resolved using the module graph of the project: relative
+    /// imports, `index` files, the `exports` and `main` fields of the `package.json`
+    /// of the packages of the project, and the `paths` and `baseUrl` options of
+    /// `tsconfig.json` are supported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// **`foo.js`**
+    ///
+    /// ```js
+    /// import { baz } from "./baz.js";
+    ///
+    /// export function foo() {
+    ///     baz();
+    /// }
+    /// ```
+    ///
+    /// **`bar.js`**
+    ///
+    /// ```js
+    /// import { foo } from "./foo.js";
+    ///
+    /// export function bar() {
+    ///     foo();
+    /// }
+    /// ```
+    ///
+    /// **`baz.js`**
+    ///
+    /// ```js
+    /// import { bar } from "./bar.js";
+    ///
+    /// export function baz() {
+    ///     bar();
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// **`foo.js`**
+    ///
+    /// ```js
+    /// import { baz } from "./baz.js";
+    ///
+    /// export function foo() {
+    ///     baz();
+    /// }
+    /// ```
+    ///
+    /// **`baz.js`**
+    ///
+    /// ```js
+    /// export function baz() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    pub NoImportCycles {
+        version: "next",
+        name: "noImportCycles",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-cycle")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoImportCycles {
+    type Query = ModuleGraph<AnyJsImportLike>;
+    /// The modules of the cycle, starting with the module of the import
+    type State = Vec<PathBuf>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let import = ModuleImport::from_import_like(ctx.query())?;
+        if !is_evaluated_on_load(&import) {
+            return None;
+        }
+
+        let file_path = normalize_path(ctx.file_path());
+        let imported = ctx.resolve(&file_path, &import.specifier)?;
+        find_cycle(ctx, &file_path, imported)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, cycle: &Self::State) -> Option<RuleDiagnostic> {
+        let import = ctx.query();
+        // The paths are printed relative to the closest common directory of the modules
+        let directory = common_directory(cycle);
+        let modules: Vec<_> = cycle
+            .iter()
+            .chain(cycle.first())
+            .map(|path| {
+                path.strip_prefix(&directory)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                import.module_name_token()?.text_trimmed_range(),
+                markup! {
+                    "This import is part of a cycle."
+                },
+            )
+            .footer_list(
+                markup! { "The cycle goes through the following modules:" },
+                &modules,
+            )
+            .note(markup! {
+                "Import cycles can cause variables to be "<Emphasis>"undefined"</Emphasis>" when the modules are evaluated. Move the code shared by these modules to a separate module."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if the module of `import` is evaluated when the importing
+/// module is loaded
+fn is_evaluated_on_load(import: &ModuleImport) -> bool {
+    import.kind != ImportKind::Dynamic && !import.is_type_only
+}
+
+/// Follows the imports of `imported` to find the shortest path back to `file_path`
+fn find_cycle(
+    ctx: &RuleContext<NoImportCycles>,
+    file_path: &Path,
+    imported: PathBuf,
+) -> Option<Vec<PathBuf>> {
+    // Maps each visited module to the module that imports it
+    let mut importers: FxHashMap<PathBuf, Option<PathBuf>> = FxHashMap::default();
+    importers.insert(imported.clone(), None);
+    let mut queue = VecDeque::from([imported]);
+
+    while let Some(module) = queue.pop_front() {
+        if module == file_path {
+            let mut cycle = vec![file_path.to_path_buf()];
+            let mut importer = importers.get(&module).cloned().flatten();
+            let mut path = Vec::new();
+            while let Some(module) = importer {
+                importer = importers.get(&module).cloned().flatten();
+                path.push(module);
+            }
+            cycle.extend(path.into_iter().rev());
+            return Some(cycle);
+        }
+
+        let Some(module_info) = ctx.module_info(&module) else {
+            continue;
+        };
+        for import in &module_info.imports {
+            if !is_evaluated_on_load(import) {
+                continue;
+            }
+            let Some(resolved) = ctx.resolve(&module, &import.specifier) else {
+                continue;
+            };
+            if !importers.contains_key(&resolved) {
+                importers.insert(resolved.clone(), Some(module.clone()));
+                queue.push_back(resolved);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the closest directory that contains all the `paths`
+fn common_directory(paths: &[PathBuf]) -> PathBuf {
+    let mut directory = paths
+        .first()
+        .and_then(|path| path.parent())
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    for path in paths {
+        while !path.starts_with(&directory) {
+            if !directory.pop() {
+                break;
+            }
+        }
+    }
+    directory
+}
@@ -0,0 +1,313 @@
+use crate::services::module_graph::SemanticModuleGraph;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_lint_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::CanBeImportedExported;
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
+use biome_js_syntax::{
+    inner_string_text, AnyJsBindingPattern, AnyJsDeclarationClause, AnyJsExportClause, JsExport,
+    JsExportNamedShorthandSpecifier, JsExportNamedSpecifier, JsFileSource, JsIdentifierExpression,
+    JsModule, JsModuleItemList, JsSyntaxKind, JsSyntaxNode,
+};
+use biome_module_graph::{normalize_path, ImportedSymbols};
+use biome_rowan::{
+    chain_trivia_pieces, trim_leading_trivia_pieces, AstNode, AstSeparatedList, BatchMutationExt,
+    TextRange,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+declare_lint_rule! {
+    /// Disallow exports that aren't imported by any module of the project.
+    ///
+    /// An export that isn't imported anywhere is dead code that the project
+    /// keeps maintaining. Removing the `export` keyword makes it visible to
+    /// the other rules, e.g. [noUnusedVariables](https://biomejs.dev/linter/rules/no-unused-variables),
+    /// that can then report the code that is no longer used.
+    ///
+    /// The imports of all the files processed by Biome are taken into account,
+    /// including type-only imports and dynamic imports.
+    /// A module imported with a namespace import, `export * from`, or `require()`
+    /// is considered entirely used.
+    ///
+    /// The exports of the entry points of the project are never reported.
+    /// The entry points are the modules declared by the `main`, `bin` and `exports`
+    /// fields of the closest `package.json`, and the modules listed in the
+    /// [`entryPoints`](#entrypoints) option.
+    ///
+    /// TypeScript declaration files, and the files that aren't part of the
+    /// module graph of the project, are ignored.
+    ///
+    /// The rule only reports exports when all the files of the project are
+    /// processed, e.g. with `biome lint .`. It doesn't report anything when
+    /// only some files are processed, e.g. with `biome lint src/utils.js` or
+    /// `--changed`, `--staged` and `--changed-lines`, nor in the editor, because
+    /// the importers of a module may not be part of the module graph.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// **`utils.js`**
+    ///
+    /// ```js
+    /// export function used() {}
+    ///
+    /// export function unused() {}
+    /// ```
+    ///
+    /// **`main.js`**
+    ///
+    /// ```js
+    /// import { used } from "./utils.js";
+    ///
+    /// used();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// **`utils.js`**
+    ///
+    /// ```js
+    /// export function used() {}
+    /// ```
+    ///
+    /// **`main.js`**
+    ///
+    /// ```js
+    /// import { used } from "./utils.js";
+    ///
+    /// used();
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `entryPoints`
+    ///
+    /// A list of paths of modules whose exports are used outside of the project,
+    /// in addition to the entry points declared in `package.json`.
+    /// A module matches a path when its path ends with the given path.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "entryPoints": ["src/index.ts", "scripts/build.js"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoUnusedExports {
+        version: "next",
+        name: "noUnusedExports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-unused-modules")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Deserializable, Eq, PartialEq, Serialize, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NoUnusedExportsOptions {
+    /// Paths of the modules whose exports are used outside of the project
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    entry_points: Vec<String>,
+}
+
+/// An export of a binding that isn't imported
+pub struct UnusedExport {
+    /// The name of the export
+    name: String,
+    /// The range of the exported binding, or of the specifier that exports it
+    range: TextRange,
+    /// The export statement of the binding, when the binding is the only one
+    /// declared by the statement
+    export: Option<JsExport>,
+}
+
+impl Rule for NoUnusedExports {
+    type Query = SemanticModuleGraph<AnyJsIdentifierBinding>;
+    type State = UnusedExport;
+    type Signals = Vec<Self::State>;
+    type Options = NoUnusedExportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let binding = ctx.query();
+        let model = ctx.model();
+        if !binding.is_exported(model)
+            || ctx
+                .source_type::<JsFileSource>()
+                .language()
+                .is_definition_file()
+        {
+            return Vec::new();
+        }
+
+        let file_path = normalize_path(ctx.file_path());
+        // Without all the modules of the project, e.g. when the graph only
+        // contains the files opened in an editor or the files passed to the
+        // CLI, every export that is imported by a module outside of the graph
+        // would be reported
+        if !ctx.module_graph().is_complete()
+            || ctx.module_graph().module_info(&file_path).is_none()
+            || is_entry_point(ctx, &file_path)
+        {
+            return Vec::new();
+        }
+        let imported_symbols = ctx
+            .module_graph()
+            .imported_symbols(&file_path)
+            .unwrap_or(ImportedSymbols::Named(Vec::new()));
+        if imported_symbols == ImportedSymbols::All {
+            return Vec::new();
+        }
+
+        let mut unused_exports = Vec::new();
+        let Ok(name_token) = binding.name_token() else {
+            return unused_exports;
+        };
+        let name = inner_string_text(&name_token);
+
+        // `export function f() {}` or `export default function f() {}`
+        if let Some(export) = binding
+            .declaration()
+            .and_then(|declaration| declaration.export())
+            .filter(is_module_export)
+        {
+            let is_default = matches!(
+                export.export_clause(),
+                Ok(AnyJsExportClause::JsExportDefaultDeclarationClause(_))
+            );
+            let exported_name = if is_default { "default" } else { name.text() };
+            if !imported_symbols.contains(exported_name) {
+                let exports_only_binding = !is_default && declares_single_binding(&export);
+                unused_exports.push(UnusedExport {
+                    name: exported_name.to_string(),
+                    range: binding.range(),
+                    export: exports_only_binding.then_some(export),
+                });
+            }
+        }
+
+        // `export { f }`, `export { f as g }` or `export default f`
+        for reference in model.as_binding(binding).all_references() {
+            let Some((exported_name, range)) = exported_reference(reference.syntax()) else {
+                continue;
+            };
+            if !imported_symbols.contains(&exported_name) {
+                unused_exports.push(UnusedExport {
+                    name: exported_name,
+                    range,
+                    export: None,
+                });
+            }
+        }
+
+        unused_exports
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The export "<Emphasis>{name}</Emphasis>" isn't imported by any module of the project."
+                },
+            )
+            .note(markup! {
+                "Unused exports are dead code, unless they are used outside of the project. Add the module to the "<Emphasis>"entryPoints"</Emphasis>" option if its exports are used outside of the project."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let export = state.export.as_ref()?;
+        let export_token = export.export_token().ok()?;
+        let next_token = export_token.next_token()?;
+        let new_next_token = next_token.prepend_trivia_pieces(chain_trivia_pieces(
+            export_token.leading_trivia().pieces(),
+            trim_leading_trivia_pieces(export_token.trailing_trivia().pieces()),
+        ));
+        let mut mutation = ctx.root().begin();
+        mutation.remove_token(export_token);
+        mutation.replace_token_discard_trivia(next_token, new_next_token);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().applicability(),
+            markup! { "Remove the "<Emphasis>"export"</Emphasis>" keyword." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the module at `file_path` is an entry point of its
+/// package or is listed in the `entryPoints` option
+fn is_entry_point(ctx: &RuleContext<NoUnusedExports>, file_path: &Path) -> bool {
+    ctx.options()
+        .entry_points
+        .iter()
+        .any(|entry_point| file_path.ends_with(normalize_path(Path::new(entry_point))))
+        || ctx.module_graph().is_entry_point(file_path)
+}
+
+/// Returns `true` if `export` is a statement of the module, and not of a
+/// namespace or of an ambient module
+fn is_module_export(export: &JsExport) -> bool {
+    export
+        .parent::<JsModuleItemList>()
+        .and_then(|list| list.parent::<JsModule>())
+        .is_some()
+}
+
+/// Returns `true` if `export` declares a single binding, so that removing the
+/// `export` keyword doesn't affect other exports
+fn declares_single_binding(export: &JsExport) -> bool {
+    match export.export_clause() {
+        Ok(AnyJsExportClause::AnyJsDeclarationClause(
+            AnyJsDeclarationClause::JsVariableDeclarationClause(clause),
+        )) => clause.declaration().is_ok_and(|declaration| {
+            let declarators = declaration.declarators();
+            declarators.len() == 1
+                && declarators.first().is_some_and(|declarator| {
+                    declarator
+                        .and_then(|declarator| declarator.id())
+                        .is_ok_and(|id| matches!(id, AnyJsBindingPattern::AnyJsBinding(_)))
+                })
+        }),
+        Ok(AnyJsExportClause::AnyJsDeclarationClause(_)) => true,
+        _ => false,
+    }
+}
+
+/// Returns the exported name and the range of the export specifier when
+/// `reference` exports a binding
+fn exported_reference(reference: &JsSyntaxNode) -> Option<(String, TextRange)> {
+    let parent = reference.parent()?;
+    if let Some(specifier) = JsExportNamedShorthandSpecifier::cast_ref(&parent) {
+        let name = specifier.name().ok()?.value_token().ok()?;
+        let export = specifier.syntax().ancestors().find_map(JsExport::cast)?;
+        return is_module_export(&export)
+            .then(|| (name.text_trimmed().to_string(), specifier.range()));
+    }
+    if let Some(specifier) = JsExportNamedSpecifier::cast_ref(&parent) {
+        let name = specifier.exported_name().ok()?.value().ok()?;
+        let export = specifier.syntax().ancestors().find_map(JsExport::cast)?;
+        return is_module_export(&export)
+            .then(|| (inner_string_text(&name).to_string(), specifier.range()));
+    }
+    let expression = JsIdentifierExpression::cast(parent)?;
+    let clause = expression.syntax().parent()?;
+    if clause.kind() != JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE {
+        return None;
+    }
+    let export = JsExport::cast(clause.parent()?)?;
+    is_module_export(&export).then(|| (String::from("default"), expression.range()))
+}
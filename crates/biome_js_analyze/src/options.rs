@@ -121,6 +121,8 @@ pub type NoImplicitBoolean =
     <lint::style::no_implicit_boolean::NoImplicitBoolean as biome_analyze::Rule>::Options;
 pub type NoImportAssign =
     <lint::suspicious::no_import_assign::NoImportAssign as biome_analyze::Rule>::Options;
+pub type NoImportCycles =
+    <lint::nursery::no_import_cycles::NoImportCycles as biome_analyze::Rule>::Options;
 pub type NoInferrableTypes =
     <lint::style::no_inferrable_types::NoInferrableTypes as biome_analyze::Rule>::Options;
 pub type NoInnerDeclarations =
@@ -228,6 +230,8 @@ pub type NoUnsafeFinally =
 pub type NoUnsafeNegation =
     <lint::suspicious::no_unsafe_negation::NoUnsafeNegation as biome_analyze::Rule>::Options;
 pub type NoUnsafeOptionalChaining = < lint :: correctness :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedExports =
+    <lint::nursery::no_unused_exports::NoUnusedExports as biome_analyze::Rule>::Options;
 pub type NoUnusedFunctionParameters = < lint :: correctness :: no_unused_function_parameters :: NoUnusedFunctionParameters as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedImports =
     <lint::correctness::no_unused_imports::NoUnusedImports as biome_analyze::Rule>::Options;
@@ -0,0 +1,203 @@
+use crate::registry::visit_registry;
+use crate::services::semantic::{SemanticModelBuilderVisitor, SemanticServices};
+use biome_analyze::{
+    AddVisitor, AnalysisFilter, FromServices, GroupCategory, MissingServicesDiagnostic, Phase,
+    Phases, QueryKey, Queryable, RegistryVisitor, Rule, RuleGroup, RuleKey, ServiceBag,
+    SyntaxVisitor,
+};
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
+use biome_module_graph::{ImportedSymbols, ModuleInfo};
+use biome_rowan::AstNode;
+use std::any::TypeId;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct ModuleGraphServices {
+    pub(crate) module_graph: Arc<biome_module_graph::ModuleGraph>,
+}
+
+impl ModuleGraphServices {
+    /// Resolves the module imported with `specifier` by the module at `importer`
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        self.module_graph.resolve(importer, specifier)
+    }
+
+    /// Returns the information of the module at `path`, if it's part of the graph
+    pub fn module_info(&self, path: &Path) -> Option<Arc<ModuleInfo>> {
+        self.module_graph.module_info(path)
+    }
+
+    /// Returns the exports of the module at `path` that are imported by the
+    /// other modules of the graph, or `None` if no module imports it
+    pub fn imported_symbols(&self, path: &Path) -> Option<ImportedSymbols> {
+        self.module_graph.imported_symbols(path)
+    }
+
+    /// Returns `true` if the graph contains all the modules of the project
+    pub fn is_complete(&self) -> bool {
+        self.module_graph.is_complete()
+    }
+
+    /// Returns `true` if the module at `path` is an entry point of its package
+    pub fn is_entry_point(&self, path: &Path) -> bool {
+        self.module_graph.is_entry_point(path)
+    }
+}
+
+impl FromServices for ModuleGraphServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, MissingServicesDiagnostic> {
+        let module_graph: &Arc<biome_module_graph::ModuleGraph> =
+            services.get_service().ok_or_else(|| {
+                MissingServicesDiagnostic::new(rule_key.rule_name(), &["ModuleGraph"])
+            })?;
+
+        Ok(Self {
+            module_graph: module_graph.clone(),
+        })
+    }
+}
+
+impl Phase for ModuleGraphServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the module graph** to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct ModuleGraph<N>(pub N);
+
+impl<N> Queryable for ModuleGraph<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = ModuleGraphServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
+
+/// The services of the rules that use both the semantic model and the module graph
+pub struct SemanticModuleGraphServices {
+    semantic_services: SemanticServices,
+    module_graph_services: ModuleGraphServices,
+}
+
+impl SemanticModuleGraphServices {
+    pub fn model(&self) -> &SemanticModel {
+        self.semantic_services.model()
+    }
+
+    pub fn module_graph(&self) -> &ModuleGraphServices {
+        &self.module_graph_services
+    }
+}
+
+impl FromServices for SemanticModuleGraphServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, MissingServicesDiagnostic> {
+        Ok(Self {
+            semantic_services: SemanticServices::from_services(rule_key, services)?,
+            module_graph_services: ModuleGraphServices::from_services(rule_key, services)?,
+        })
+    }
+}
+
+impl Phase for SemanticModuleGraphServices {
+    fn phase() -> Phases {
+        Phases::Semantic
+    }
+}
+
+/// Query type usable by lint rules **that use the semantic model and the module graph**
+/// to match on specific [AstNode] types
+#[derive(Clone)]
+pub struct SemanticModuleGraph<N>(pub N);
+
+impl<N> Queryable for SemanticModuleGraph<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Input = JsSyntaxNode;
+    type Output = N;
+
+    type Language = JsLanguage;
+    type Services = SemanticModuleGraphServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, root: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, || SemanticModelBuilderVisitor::new(root));
+        analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
+
+/// Returns `true` if one of the rules enabled by `filter` queries the module
+/// graph. The modules of the project only need to be added to the graph before
+/// the analysis in that case.
+pub fn uses_module_graph(filter: &AnalysisFilter) -> bool {
+    let mut visitor = ModuleGraphRulesVisitor {
+        filter,
+        uses_module_graph: false,
+    };
+    visit_registry(&mut visitor);
+    visitor.uses_module_graph
+}
+
+struct ModuleGraphRulesVisitor<'a, 'b> {
+    filter: &'b AnalysisFilter<'a>,
+    uses_module_graph: bool,
+}
+
+impl RegistryVisitor<JsLanguage> for ModuleGraphRulesVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if self.filter.match_category::<C>() {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = JsLanguage>>(&mut self) {
+        if self.filter.match_group::<G>() {
+            G::record_rules(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
+    {
+        let services = TypeId::of::<<R::Query as Queryable>::Services>();
+        if (services == TypeId::of::<ModuleGraphServices>()
+            || services == TypeId::of::<SemanticModuleGraphServices>())
+            && self.filter.match_rule::<R>()
+        {
+            self.uses_module_graph = true;
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use crate::utils::batch::JsBatchMutation;
-use biome_analyze::{ApplySuppression, SuppressionAction};
+use biome_analyze::{
+    merge_leading_suppression, merge_suppression_in_trivia, ApplySuppression, SuppressionAction,
+};
 use biome_js_factory::make::{jsx_expression_child, jsx_ident, jsx_text, token};
 use biome_js_syntax::jsx_ext::AnyJsxElement;
 use biome_js_syntax::{
     AnyJsxChild, JsLanguage, JsSyntaxKind, JsSyntaxToken, JsxChildList, JsxElement,
-    JsxOpeningElement, JsxSelfClosingElement, JsxText, T,
+    JsxExpressionChild, JsxOpeningElement, JsxSelfClosingElement, JsxText, T,
 };
 use biome_rowan::{AstNode, BatchMutation, TriviaPieceKind};
 
@@ -45,6 +47,61 @@ fn make_indentation_from_jsx_element(current_element: &JsxText) -> JsxText {
     }
 }
 
+/// Adds the category of `suppression_text` to the `{/* biome-ignore ... */}` child placed on
+/// the line that precedes the JSX child of `token`.
+fn merge_jsx_suppression(
+    mutation: &mut BatchMutation<JsLanguage>,
+    token: &JsSyntaxToken,
+    suppression_text: &str,
+) -> bool {
+    let Some(parent) = token.parent() else {
+        return false;
+    };
+    let child = if JsxOpeningElement::can_cast(parent.kind()) {
+        parent.parent()
+    } else {
+        Some(parent)
+    };
+    let Some(child) = child.filter(|child| {
+        child
+            .parent()
+            .is_some_and(|list| JsxChildList::can_cast(list.kind()))
+    }) else {
+        return false;
+    };
+
+    let mut previous = if JsxText::can_cast(child.kind()) {
+        Some(child)
+    } else {
+        child.prev_sibling()
+    };
+    // The suppression comment and the child are separated by a newline and the indentation
+    if let Some(text) = previous.clone().and_then(JsxText::cast) {
+        let is_line_break = text.value_token().is_ok_and(|value| {
+            value.text().trim().is_empty() && value.text().matches('\n').count() == 1
+        });
+        if !is_line_break {
+            return false;
+        }
+        previous = text.syntax().prev_sibling();
+    }
+
+    let Some(l_curly) = previous
+        .and_then(JsxExpressionChild::cast)
+        .and_then(|expression| expression.l_curly_token().ok())
+    else {
+        return false;
+    };
+    let Some(trivia) = merge_suppression_in_trivia(&l_curly.trailing_trivia(), suppression_text, 0)
+    else {
+        return false;
+    };
+    let new_l_curly =
+        l_curly.with_trailing_trivia(trivia.iter().map(|(kind, text)| (*kind, text.as_str())));
+    mutation.replace_token_discard_trivia(l_curly, new_l_curly);
+    true
+}
+
 pub struct JsSuppressionAction;
 
 impl SuppressionAction for JsSuppressionAction {
@@ -115,6 +172,17 @@ impl SuppressionAction for JsSuppressionAction {
         Some(apply_suppression)
     }
 
+    fn merge_suppression(
+        &self,
+        mutation: &mut BatchMutation<Self::Language>,
+        apply_suppression: &ApplySuppression<Self::Language>,
+        suppression_text: &str,
+    ) -> bool {
+        let token = &apply_suppression.token_to_apply_suppression;
+        merge_jsx_suppression(mutation, token, suppression_text)
+            || merge_leading_suppression(mutation, token, suppression_text)
+    }
+
     /// Considering that the detection of suppression comments in the linter is "line based", the function starts
     /// querying the node covered by the text range of the diagnostic, until it finds the first token that has a newline
     /// among its leading trivia.
@@ -129,6 +197,7 @@ impl SuppressionAction for JsSuppressionAction {
         mutation: &mut BatchMutation<Self::Language>,
         apply_suppression: ApplySuppression<Self::Language>,
         suppression_text: &str,
+        suppression_reason: &str,
     ) {
         let ApplySuppression {
             token_to_apply_suppression,
@@ -156,7 +225,7 @@ impl SuppressionAction for JsSuppressionAction {
                 let jsx_comment = jsx_expression_child(
                     token(T!['{']).with_trailing_trivia([(
                         TriviaPieceKind::SingleLineComment,
-                        format!("/* {suppression_text}: <explanation> */").as_str(),
+                        format!("/* {suppression_text}: {suppression_reason} */").as_str(),
                     )]),
                     token(T!['}']),
                 )
@@ -193,7 +262,7 @@ impl SuppressionAction for JsSuppressionAction {
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {suppression_text}: <explanation>").as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -201,7 +270,7 @@ impl SuppressionAction for JsSuppressionAction {
                     new_token = new_token.with_leading_trivia([
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {suppression_text}: <explanation>").as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -216,7 +285,7 @@ impl SuppressionAction for JsSuppressionAction {
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {suppression_text}: <explanation>").as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -225,7 +294,7 @@ impl SuppressionAction for JsSuppressionAction {
                         (TriviaPieceKind::Newline, "\n"),
                         (
                             TriviaPieceKind::SingleLineComment,
-                            format!("// {suppression_text}: <explanation>").as_str(),
+                            format!("// {suppression_text}: {suppression_reason}").as_str(),
                         ),
                         (TriviaPieceKind::Newline, "\n"),
                     ])
@@ -234,12 +303,12 @@ impl SuppressionAction for JsSuppressionAction {
                 new_token = new_token.with_trailing_trivia([
                     (
                         TriviaPieceKind::SingleLineComment,
-                        format!("// {suppression_text}: <explanation>").as_str(),
+                        format!("// {suppression_text}: {suppression_reason}").as_str(),
                     ),
                     (TriviaPieceKind::Newline, "\n"),
                 ])
             } else {
-                let comment = format!("// {suppression_text}: <explanation>");
+                let comment = format!("// {suppression_text}: {suppression_reason}");
                 let mut trivia = vec![
                     (TriviaPieceKind::SingleLineComment, comment.as_str()),
                     (TriviaPieceKind::Newline, "\n"),
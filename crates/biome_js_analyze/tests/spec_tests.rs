@@ -3,6 +3,7 @@ use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{JsFileSource, JsLanguage, ModuleKind};
+use biome_module_graph::{ModuleGraph, ModuleInfo};
 use biome_project::PackageType;
 use biome_rowan::AstNode;
 use biome_test_utils::{
@@ -11,7 +12,13 @@ use biome_test_utils::{
     scripts_from_json, write_analyzer_snapshot, CheckActionType,
 };
 use std::ops::Deref;
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
+use std::sync::Arc;
+use std::{
+    ffi::OsStr,
+    fs::{read_dir, read_to_string},
+    path::Path,
+    slice,
+};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_suppression_test, "module"}
@@ -105,6 +112,7 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let manifest = load_manifest(input_file, &mut diagnostics);
+    let module_graph = load_module_graph(input_file);
 
     if let Some(manifest) = &manifest {
         if manifest.r#type == Some(PackageType::Commonjs) &&
@@ -120,8 +128,14 @@ pub(crate) fn analyze_and_snap(
     //
     let options = create_analyzer_options(input_file, &mut diagnostics);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        Arc::new(module_graph),
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -177,7 +191,8 @@ pub(crate) fn analyze_and_snap(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -194,6 +209,29 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Builds the module graph of the JavaScript files of the directory of the
+/// test file, so the rules can resolve the imports between the test files
+fn load_module_graph(input_file: &Path) -> ModuleGraph {
+    let module_graph = ModuleGraph::default();
+    let Some(Ok(entries)) = input_file.parent().map(read_dir) else {
+        return module_graph;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Ok(source_type) = JsFileSource::try_from(path.as_path()) else {
+            continue;
+        };
+        let Ok(content) = read_to_string(&path) else {
+            continue;
+        };
+        let parsed = parse(&content, source_type, JsParserOptions::default());
+        module_graph.update_module(&path, ModuleInfo::from_root(&parsed.tree()));
+    }
+    module_graph.set_complete();
+
+    module_graph
+}
+
 fn check_code_action(
     path: &Path,
     source: &str,
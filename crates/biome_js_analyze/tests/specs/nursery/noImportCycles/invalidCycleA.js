@@ -0,0 +1,5 @@
+import { b } from "./invalidCycleB.js";
+
+export function a() {
+	return b();
+}
@@ -0,0 +1,5 @@
+import { c } from "./invalidCycleC";
+
+export function b() {
+	return c();
+}
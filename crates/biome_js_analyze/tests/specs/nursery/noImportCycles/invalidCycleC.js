@@ -0,0 +1,5 @@
+export * from "./invalidCycleA.js";
+
+export function c() {
+	return 0;
+}
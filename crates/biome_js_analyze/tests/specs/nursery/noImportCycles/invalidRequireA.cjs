@@ -0,0 +1,3 @@
+const { b } = require("./invalidRequireB.cjs");
+
+module.exports = { a: () => b() };
@@ -0,0 +1,3 @@
+const { a } = require("./invalidRequireA");
+
+module.exports = { b: () => a() };
@@ -0,0 +1,3 @@
+import * as self from "./invalidSelfImport.js";
+
+export const value = self;
@@ -0,0 +1,7 @@
+/* should not generate diagnostics */
+import { a } from "./invalidCycleA.js";
+import { load } from "./validDynamicImportA.js";
+import lodash from "lodash";
+import { missing } from "./missing.js";
+
+export { a, load, lodash, missing };
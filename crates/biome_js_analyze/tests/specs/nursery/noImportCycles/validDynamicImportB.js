@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+import { load } from "./validDynamicImportA.js";
+
+export const reload = load;
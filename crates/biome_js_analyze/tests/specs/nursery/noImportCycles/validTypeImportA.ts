@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+import { b } from "./validTypeImportB";
+
+export type A = { name: string };
+
+export const a = b;
@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+import type { A } from "./validTypeImportA";
+
+export const b: A = { name: "b" };
@@ -0,0 +1,12 @@
+export function unused() {}
+
+export class Unused {}
+
+export const a = 1, b = 2;
+
+export const { c, d } = {};
+
+const e = 1;
+export { e, e as f, e as "g" };
+
+export default function named() {}
@@ -0,0 +1,5 @@
+export type Unused = string;
+
+export interface UnusedInterface {}
+
+export enum UnusedEnum {}
@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+import named, { used, renamed as other, alias } from "./validUsed.js";
+import type { Used, UsedInterface } from "./validTypes";
+import * as namespace from "./validNamespace.js";
+const required = require("./validRequired.js");
+
+function local() {}
+
+namespace Internal {
+    export const x = 1;
+}
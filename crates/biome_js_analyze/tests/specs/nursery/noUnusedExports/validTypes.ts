@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+export type Used = string;
+
+export interface UsedInterface {}
@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+export function used() {}
+
+export const renamed = 1;
+
+const value = 1;
+export { value as alias };
+
+export default function named() {}
@@ -0,0 +1,6 @@
+// biome-ignore-all lint/suspicious/noDoubleEquals: generated code
+a == b;
+
+function f() {
+	return c == d;
+}
@@ -0,0 +1,3 @@
+a == b;
+// biome-ignore-all lint/suspicious/noDoubleEquals: must be at the top
+c == d;
@@ -0,0 +1,2 @@
+// biome-ignore-all lint/suspicious/noDoubleEquals: nothing to suppress
+a === b;
@@ -0,0 +1,4 @@
+a == b;
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: never closed
+c == d;
@@ -0,0 +1,15 @@
+a == b;
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: legacy comparisons
+c == d;
+e == f;
+// biome-ignore-end lint/suspicious/noDoubleEquals: legacy comparisons
+
+g == h;
+
+// biome-ignore-start lint/suspicious/noDoubleEquals: never used
+i === j;
+// biome-ignore-end lint/suspicious/noDoubleEquals: never used
+
+// biome-ignore-end lint/suspicious/noDoubleEquals: no matching start
+k == l;
@@ -0,0 +1,4 @@
+function f(a, b) {
+    // biome-ignore lint/suspicious/noDebugger: legacy code
+    debugger; return a == b;
+}
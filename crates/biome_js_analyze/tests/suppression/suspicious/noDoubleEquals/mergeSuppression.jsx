@@ -0,0 +1,6 @@
+let a = (
+    <div>
+        {/* biome-ignore lint/a11y/useAltText: legacy code */}
+        <img src="image.png" onClick={() => a == b} />
+    </div>
+);
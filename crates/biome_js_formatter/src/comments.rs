@@ -18,7 +18,7 @@ use biome_js_syntax::{
     JsVariableDeclarator, JsWhileStatement, TsInterfaceDeclaration, TsMappedType,
 };
 use biome_rowan::{AstNode, SyntaxNodeOptionExt, SyntaxTriviaPieceComments, TextLen};
-use biome_suppression::parse_suppression_comment;
+use biome_suppression::{parse_suppression_comment, SuppressionScope};
 
 pub type JsComments = Comments<JsLanguage>;
 
@@ -79,6 +79,7 @@ impl CommentStyle for JsCommentStyle {
     fn is_suppression(text: &str) -> bool {
         parse_suppression_comment(text)
             .filter_map(Result::ok)
+            .filter(|suppression| suppression.scope == SuppressionScope::Line)
             .flat_map(|suppression| suppression.categories)
             .any(|(key, _)| key == category!("format"))
     }
@@ -172,6 +172,12 @@ pub struct JsFormatOptions {
 
     /// Attribute position style. By default auto.
     attribute_position: AttributePosition,
+
+    /// The tags of the template literals that contain GraphQL. Defaults to `gql` and `graphql`.
+    graphql_tags: Vec<String>,
+
+    /// Whether to format the CSS of the CSS-in-JS templates, e.g. `` styled.div`color: red;` ``. Defaults to true.
+    format_embedded_css: bool,
 }
 
 impl JsFormatOptions {
@@ -191,6 +197,8 @@ impl JsFormatOptions {
             bracket_spacing: BracketSpacing::default(),
             bracket_same_line: BracketSameLine::default(),
             attribute_position: AttributePosition::default(),
+            graphql_tags: vec!["gql".to_string(), "graphql".to_string()],
+            format_embedded_css: true,
         }
     }
 
@@ -259,6 +267,16 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_graphql_tags(mut self, graphql_tags: Vec<String>) -> Self {
+        self.graphql_tags = graphql_tags;
+        self
+    }
+
+    pub fn with_format_embedded_css(mut self, format_embedded_css: bool) -> Self {
+        self.format_embedded_css = format_embedded_css;
+        self
+    }
+
     pub fn set_arrow_parentheses(&mut self, arrow_parentheses: ArrowParentheses) {
         self.arrow_parentheses = arrow_parentheses;
     }
@@ -310,6 +328,14 @@ impl JsFormatOptions {
         self.semicolons = semicolons;
     }
 
+    pub fn set_graphql_tags(&mut self, graphql_tags: Vec<String>) {
+        self.graphql_tags = graphql_tags;
+    }
+
+    pub fn set_format_embedded_css(&mut self, format_embedded_css: bool) {
+        self.format_embedded_css = format_embedded_css;
+    }
+
     pub fn arrow_parentheses(&self) -> ArrowParentheses {
         self.arrow_parentheses
     }
@@ -353,6 +379,14 @@ impl JsFormatOptions {
     pub fn attribute_position(&self) -> AttributePosition {
         self.attribute_position
     }
+
+    pub fn graphql_tags(&self) -> &[String] {
+        &self.graphql_tags
+    }
+
+    pub fn format_embedded_css(&self) -> bool {
+        self.format_embedded_css
+    }
 }
 
 impl FormatOptions for JsFormatOptions {
@@ -1,4 +1,5 @@
 use crate::context::trailing_commas::FormatTrailingCommas;
+use crate::context::JsFormatOptions;
 use crate::js::bindings::parameters::has_only_simple_parameters;
 use crate::js::expressions::call_arguments::GroupedCallArgumentLayout;
 use crate::prelude::*;
@@ -98,7 +99,7 @@ impl FormatNodeRule<JsArrowFunctionExpression> for FormatJsArrowFunctionExpressi
                     ) => !f.comments().has_leading_own_line_comment(body.syntax()),
                     AnyJsExpression(JsxTagExpression(_)) => true,
                     AnyJsExpression(JsTemplateExpression(template)) => {
-                        is_multiline_template_starting_on_same_line(template)
+                        is_multiline_template_starting_on_same_line(template, f.options())
                     }
                     AnyJsExpression(JsSequenceExpression(sequence)) => {
                         let has_comment = f.context().comments().has_comments(sequence.syntax());
@@ -824,7 +825,20 @@ impl ArrowFunctionLayout {
 }
 
 /// Returns `true` if the template contains any new lines inside of its text chunks.
-fn template_literal_contains_new_line(template: &JsTemplateExpression) -> bool {
+///
+/// The content of a CSS-in-JS template is always formatted on its own lines, unless
+/// the formatting of the CSS-in-JS templates is disabled.
+fn template_literal_contains_new_line(
+    template: &JsTemplateExpression,
+    options: &JsFormatOptions,
+) -> bool {
+    if options.format_embedded_css()
+        && template.css_template_kind().is_some()
+        && !template.elements().is_empty()
+    {
+        return true;
+    }
+
     template.elements().iter().any(|element| match element {
         AnyJsTemplateElement::JsTemplateChunkElement(chunk) => chunk
             .template_chunk_token()
@@ -859,8 +873,11 @@ fn template_literal_contains_new_line(template: &JsTemplateExpression) -> bool {
 /// ```
 ///
 /// Returns `false` because the template isn't on the same line as the '+' token.
-pub(crate) fn is_multiline_template_starting_on_same_line(template: &JsTemplateExpression) -> bool {
-    let contains_new_line = template_literal_contains_new_line(template);
+pub(crate) fn is_multiline_template_starting_on_same_line(
+    template: &JsTemplateExpression,
+    options: &JsFormatOptions,
+) -> bool {
+    let contains_new_line = template_literal_contains_new_line(template, options);
 
     let starts_on_same_line = template.syntax().first_token().map_or(false, |token| {
         for piece in token.leading_trivia().pieces() {
@@ -1,4 +1,5 @@
 use crate::context::trailing_commas::FormatTrailingCommas;
+use crate::context::JsFormatOptions;
 use crate::js::bindings::parameters::has_only_simple_parameters;
 use crate::js::declarations::function_declaration::FormatFunctionOptions;
 use crate::js::expressions::arrow_function_expression::{
@@ -54,7 +55,7 @@ impl FormatNodeRule<JsCallArguments> for FormatJsCallArguments {
                 });
 
         if is_commonjs_or_amd_call?
-            || is_multiline_template_only_args(node)
+            || is_multiline_template_only_args(node, f.options())
             || is_react_hook_with_deps_array(node, f.comments())
             || is_test_call?
         {
@@ -1188,13 +1189,13 @@ fn is_commonjs_or_amd_call(
 }
 
 /// Returns `true` if `arguments` contains a single [multiline template literal argument that starts on its own ](is_multiline_template_starting_on_same_line).
-fn is_multiline_template_only_args(arguments: &JsCallArguments) -> bool {
+fn is_multiline_template_only_args(arguments: &JsCallArguments, options: &JsFormatOptions) -> bool {
     let args = arguments.args();
 
     match args.first() {
         Some(Ok(AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsTemplateExpression(
             template,
-        )))) if args.len() == 1 => is_multiline_template_starting_on_same_line(&template),
+        )))) if args.len() == 1 => is_multiline_template_starting_on_same_line(&template, options),
         _ => false,
     }
 }
@@ -68,8 +68,18 @@ impl AnyJsTemplate {
         match self {
             AnyJsTemplate::JsTemplateExpression(template) => {
                 let is_test_each_pattern = template.is_test_each_pattern();
+                let is_graphql_template = template.tag_name().is_some_and(|name| {
+                    f.options()
+                        .graphql_tags()
+                        .iter()
+                        .any(|tag| tag == name.text())
+                });
                 let options = FormatJsTemplateElementListOptions {
                     is_test_each_pattern,
+                    css_template_kind: template
+                        .css_template_kind()
+                        .filter(|_| f.options().format_embedded_css()),
+                    is_graphql_template,
                 };
 
                 write!(f, [template.elements().format().with_options(options)])
@@ -2,10 +2,12 @@ use crate::context::TabWidth;
 use crate::js::auxiliary::template_chunk_element::AnyTemplateChunkElement;
 use crate::js::auxiliary::template_element::{AnyTemplateElement, TemplateElementOptions};
 use crate::prelude::*;
+use crate::utils::css_template::CssTemplate;
+use crate::utils::graphql_template::GraphqlTemplate;
 use crate::utils::test_each_template::EachTemplateTable;
 use biome_formatter::FormatRuleWithOptions;
 use biome_js_syntax::{
-    AnyJsTemplateElement, AnyTsTemplateElement, JsLanguage, JsTemplateElementList,
+    AnyJsTemplateElement, AnyTsTemplateElement, CssTemplateKind, JsLanguage, JsTemplateElementList,
     TsTemplateElementList,
 };
 use biome_rowan::{declare_node_union, AstNodeListIterator};
@@ -30,16 +32,34 @@ impl FormatRule<JsTemplateElementList> for FormatJsTemplateElementList {
 
     fn fmt(&self, node: &JsTemplateElementList, f: &mut JsFormatter) -> FormatResult<()> {
         if self.options.is_test_each_pattern {
-            EachTemplateTable::from(node, f)?.fmt(f)
-        } else {
-            AnyTemplateElementList::JsTemplateElementList(node.clone()).fmt(f)
+            return EachTemplateTable::from(node, f)?.fmt(f);
         }
+
+        if let Some(kind) = self.options.css_template_kind {
+            if let Some(template) = CssTemplate::from(node, kind, f)? {
+                return template.fmt(f);
+            }
+        }
+
+        if self.options.is_graphql_template {
+            if let Some(template) = GraphqlTemplate::from(node, f)? {
+                return template.fmt(f);
+            }
+        }
+
+        AnyTemplateElementList::JsTemplateElementList(node.clone()).fmt(f)
     }
 }
 
 #[derive(Debug, Copy, Clone, Default)]
 pub(crate) struct FormatJsTemplateElementListOptions {
     pub(crate) is_test_each_pattern: bool,
+
+    /// The kind of CSS embedded in the template, if it's a CSS-in-JS template
+    pub(crate) css_template_kind: Option<CssTemplateKind>,
+
+    /// Whether the template is tagged with one of the GraphQL tags
+    pub(crate) is_graphql_template: bool,
 }
 
 pub(crate) enum AnyTemplateElementList {
@@ -0,0 +1,309 @@
+use crate::context::JsFormatOptions;
+use crate::js::auxiliary::template_element::TemplateElementOptions;
+use crate::prelude::*;
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_parser::{parse_css, CssParserOptions};
+use biome_css_syntax::CssFileSource;
+use biome_formatter::{
+    format_args, write, CstFormatContext, FormatOptions, IndentStyle, LineEnding,
+};
+use biome_js_syntax::{
+    AnyJsTemplateElement, CssTemplateKind, CssTemplatePlaceholder, JsSyntaxKind, JsTemplateElement,
+    JsTemplateElementList, JsTemplateExpression,
+};
+use biome_rowan::{AstNode, Direction};
+use biome_text_size::TextSize;
+
+/// Prefix of the identifiers that replace the interpolations of the template
+/// while the CSS is formatted.
+const PLACEHOLDER_PREFIX: &str = "biome-placeholder-";
+
+/// The CSS of a CSS-in-JS template, formatted by the CSS formatter.
+///
+/// The interpolations are replaced by placeholders before formatting the CSS,
+/// and the formatted interpolations are put back in the result.
+///
+/// ```javascript
+/// const Button = styled.button`
+///     color: ${(props) => props.color};
+///     ${mixin};
+/// `;
+/// ```
+#[derive(Debug)]
+pub(crate) struct CssTemplate {
+    /// The formatted CSS, with a `\0` in place of each interpolation
+    code: String,
+    interpolations: Vec<JsTemplateElement>,
+    start: TextSize,
+}
+
+impl CssTemplate {
+    /// Formats the CSS of the template `list`.
+    ///
+    /// Returns `None` when the template can't be formatted as CSS, e.g. when it
+    /// has syntax errors. Nothing is written to the formatter in this case.
+    pub(crate) fn from(
+        list: &JsTemplateElementList,
+        kind: CssTemplateKind,
+        f: &mut JsFormatter,
+    ) -> FormatResult<Option<Self>> {
+        let Some(template) = list.syntax().parent().and_then(JsTemplateExpression::cast) else {
+            return Ok(None);
+        };
+        let elements: Vec<_> = list.iter().collect();
+        for element in &elements {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    // Guarding against skipped token trivia on the chunks that are removed
+                    if chunk
+                        .syntax()
+                        .first_leading_trivia()
+                        .is_some_and(|trivia| trivia.has_skipped())
+                        || chunk
+                            .template_chunk_token()?
+                            .text_trimmed()
+                            .contains(PLACEHOLDER_PREFIX)
+                    {
+                        return Ok(None);
+                    }
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    if has_multiline_template(element) {
+                        return Ok(None);
+                    }
+                }
+            }
+        }
+
+        let Some(source) = template
+            .css_template_source(kind, |index, _| std::format!("{PLACEHOLDER_PREFIX}{index}"))
+        else {
+            return Ok(None);
+        };
+
+        let mut code = if source.content(kind).trim().is_empty() {
+            String::new()
+        } else {
+            match format_css(&source.source, kind, f.options()) {
+                Some(code) => code,
+                None => return Ok(None),
+            }
+        };
+
+        // The placeholders are marked from the last one, so that `biome-placeholder-1`
+        // doesn't match the start of `biome-placeholder-10`. The semicolon added after
+        // a placeholder in statement position is part of its text, and is removed too.
+        for placeholder in source.placeholders.iter().rev() {
+            if code.matches(&placeholder.text).count() != 1 {
+                return Ok(None);
+            }
+            let position = code.find(&placeholder.text).unwrap_or_default();
+            let end = position + placeholder.text.len();
+
+            // The semicolon added at the end of `${property}: ${value}` is removed too
+            if is_declaration_without_semicolon(&source.source, placeholder) {
+                if let Some(semicolon) = code[end..]
+                    .find([';', '{', '}'])
+                    .map(|offset| end + offset)
+                    .filter(|&index| code[index..].starts_with(';'))
+                {
+                    code.remove(semicolon);
+                }
+            }
+            code.replace_range(position..end, "\0");
+        }
+
+        // The CSS can be formatted, the chunks are removed and the interpolations
+        // are formatted in place of their placeholders.
+        for element in &elements {
+            if let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element {
+                f.context()
+                    .comments()
+                    .mark_suppression_checked(chunk.syntax());
+
+                write!(f, [format_removed(&chunk.template_chunk_token()?)])?;
+            }
+        }
+
+        Ok(Some(CssTemplate {
+            code,
+            interpolations: source
+                .placeholders
+                .into_iter()
+                .map(|placeholder| placeholder.element)
+                .collect(),
+            start: list.range().start(),
+        }))
+    }
+}
+
+impl Format<JsFormatContext> for CssTemplate {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        if self.code.is_empty() {
+            return Ok(());
+        }
+
+        let content = format_with(|f: &mut JsFormatter| {
+            let indent_width = f.options().indent_width().value().max(1) as usize;
+            let indent_unit = indent_unit(f.options());
+            let mut interpolations = self.interpolations.iter();
+            let mut is_first = true;
+            let mut has_empty_line = false;
+            for line in self.code.lines() {
+                if line.trim().is_empty() {
+                    has_empty_line = !is_first;
+                    continue;
+                }
+                let line_break = format_with(|f: &mut JsFormatter| {
+                    if is_first {
+                        Ok(())
+                    } else if has_empty_line {
+                        write!(f, [empty_line()])
+                    } else {
+                        write!(f, [hard_line_break()])
+                    }
+                });
+
+                // The lines of an interpolation that breaks are indented like the CSS line
+                let indentation = &line[..line.len() - line.trim_start().len()];
+                let level = indentation.matches('\t').count()
+                    + indentation.matches(' ').count() / indent_width;
+
+                let line_interpolations: Vec<_> = interpolations
+                    .by_ref()
+                    .take(line.matches('\0').count())
+                    .collect();
+                let line = format_with(|f: &mut JsFormatter| {
+                    for (index, part) in line.split('\0').enumerate() {
+                        if index > 0 {
+                            let Some(interpolation) = line_interpolations.get(index - 1) else {
+                                return Err(FormatError::SyntaxError);
+                            };
+                            let options = TemplateElementOptions::default();
+                            write_indented(
+                                f,
+                                level,
+                                &interpolation.format().with_options(options),
+                            )?;
+                        }
+                        if !part.is_empty() {
+                            write!(f, [dynamic_text(part, self.start)])?;
+                        }
+                    }
+                    Ok(())
+                });
+
+                // The lines printed verbatim by the CSS formatter, e.g. the lines of a
+                // suppressed rule, keep the indentation of the template
+                if indentation.replace(&indent_unit, "").is_empty() {
+                    write!(f, [line_break, line])?;
+                } else {
+                    write!(f, [dedent_to_root(&format_args![line_break, line])])?;
+                }
+                is_first = false;
+                has_empty_line = false;
+            }
+            Ok(())
+        });
+
+        write!(f, [block_indent(&content)])
+    }
+}
+
+/// Writes `content` indented by `level` indentation levels.
+fn write_indented(
+    f: &mut JsFormatter,
+    level: usize,
+    content: &dyn Format<JsFormatContext>,
+) -> FormatResult<()> {
+    if level == 0 {
+        write!(f, [content])
+    } else {
+        write!(
+            f,
+            [indent(&format_with(|f| write_indented(
+                f,
+                level - 1,
+                content
+            )))]
+        )
+    }
+}
+
+/// Formats the `source` of a template of the given `kind` with the CSS formatter,
+/// and returns the formatted content of the template.
+fn format_css(source: &str, kind: CssTemplateKind, options: &JsFormatOptions) -> Option<String> {
+    let parse = parse_css(
+        source,
+        CssParserOptions::default().allow_wrong_line_comments(),
+    );
+    if parse.has_errors() {
+        return None;
+    }
+
+    let css_options = CssFormatOptions::new(CssFileSource::css())
+        .with_indent_style(options.indent_style())
+        .with_indent_width(options.indent_width())
+        .with_line_width(options.line_width())
+        .with_line_ending(LineEnding::Lf)
+        .with_quote_style(options.quote_style());
+    let indent_unit = indent_unit(options);
+
+    let printed = biome_css_formatter::format_node(css_options, &parse.syntax())
+        .ok()?
+        .print()
+        .ok()?;
+    unwrap(printed.as_code(), kind, &indent_unit)
+}
+
+/// Returns the text of an indentation level.
+fn indent_unit(options: &JsFormatOptions) -> String {
+    match options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(options.indent_width().value() as usize),
+    }
+}
+
+/// Removes the wrapper rule from the formatted `code`, and the indentation
+/// of its content.
+fn unwrap(code: &str, kind: CssTemplateKind, indent_unit: &str) -> Option<String> {
+    let content = match kind {
+        CssTemplateKind::Declarations => code.strip_prefix("a {\n")?.strip_suffix("}\n")?,
+        CssTemplateKind::Keyframes => code.strip_prefix("@keyframes a {\n")?.strip_suffix("}\n")?,
+        CssTemplateKind::StyleSheet => return Some(code.to_string()),
+    };
+
+    let mut result = String::with_capacity(content.len());
+    for line in content.lines() {
+        result.push_str(line.strip_prefix(indent_unit).unwrap_or(line));
+        result.push('\n');
+    }
+    Some(result)
+}
+
+/// Returns `true` if the `placeholder` is the property of a declaration without
+/// a semicolon, the last one of its block, e.g. `${property}: ${value}`.
+fn is_declaration_without_semicolon(source: &str, placeholder: &CssTemplatePlaceholder) -> bool {
+    let rest = &source[usize::from(placeholder.range.end())..];
+    placeholder.is_statement
+        && !placeholder.text.starts_with('@')
+        && rest
+            .find([';', '{', '}'])
+            .map_or(true, |index| rest[index..].starts_with('}'))
+}
+
+/// Returns `true` if the interpolation contains a template literal with a
+/// line break, whose content would change if the interpolation is indented.
+pub(crate) fn has_multiline_template(element: &JsTemplateElement) -> bool {
+    element
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .any(|token| {
+            token.kind() == JsSyntaxKind::TEMPLATE_CHUNK
+                && token.text_trimmed().contains('\n')
+                && !token
+                    .parent()
+                    .and_then(|parent| parent.ancestors().find_map(JsTemplateExpression::cast))
+                    .is_some_and(|template| template.css_template_kind().is_some())
+        })
+}
@@ -0,0 +1,250 @@
+use crate::js::auxiliary::template_element::TemplateElementOptions;
+use crate::prelude::*;
+use crate::utils::css_template::has_multiline_template;
+use crate::JsFormatOptions;
+use biome_formatter::{write, CstFormatContext, FormatOptions, LineEnding};
+use biome_graphql_formatter::context::GraphqlFormatOptions;
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::GraphqlFileSource;
+use biome_js_syntax::{AnyJsTemplateElement, JsTemplateElement, JsTemplateElementList};
+use biome_rowan::AstNode;
+use biome_text_size::TextSize;
+
+/// The GraphQL of a template tagged with one of the GraphQL tags, formatted by
+/// the GraphQL formatter.
+///
+/// The interpolations are only supported between the definitions of the document,
+/// where they usually insert fragments. Each of them is printed on its own line.
+///
+/// ```javascript
+/// const query = gql`
+///     query User {
+///         user(id: 5) { ...UserDetails }
+///     }
+///     ${USER_DETAILS_FRAGMENT}
+/// `;
+/// ```
+#[derive(Debug)]
+pub(crate) struct GraphqlTemplate {
+    parts: Vec<GraphqlTemplatePart>,
+    start: TextSize,
+}
+
+#[derive(Debug)]
+enum GraphqlTemplatePart {
+    /// A blank line between two parts
+    Empty,
+    /// Formatted GraphQL or comments
+    Text(String),
+    Interpolation(JsTemplateElement),
+}
+
+impl GraphqlTemplate {
+    /// Formats the GraphQL of the template `list`.
+    ///
+    /// Returns `None` when the template can't be formatted as GraphQL, e.g. when it
+    /// has syntax errors or an interpolation inside a definition. Nothing is
+    /// written to the formatter in this case.
+    pub(crate) fn from(
+        list: &JsTemplateElementList,
+        f: &mut JsFormatter,
+    ) -> FormatResult<Option<Self>> {
+        let mut texts = vec![String::new()];
+        let mut interpolations = Vec::new();
+        for element in list {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    let token = chunk.template_chunk_token()?;
+                    // Escape sequences would need to be cooked before parsing the GraphQL
+                    if token.text_trimmed().contains('\\')
+                        || chunk
+                            .syntax()
+                            .first_leading_trivia()
+                            .is_some_and(|trivia| trivia.has_skipped())
+                    {
+                        return Ok(None);
+                    }
+                    if let Some(text) = texts.last_mut() {
+                        text.push_str(token.text_trimmed());
+                    }
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    if has_multiline_template(&element) {
+                        return Ok(None);
+                    }
+                    interpolations.push(element);
+                    texts.push(String::new());
+                }
+            }
+        }
+
+        let mut parts = Vec::new();
+        let is_empty = texts.len() == 1 && texts[0].trim().is_empty();
+        if !is_empty {
+            let last_index = texts.len() - 1;
+            let mut interpolations = interpolations.iter();
+            for (index, text) in texts.iter().enumerate() {
+                let is_first = index == 0;
+                let is_last = index == last_index;
+                let lines: Vec<_> = text.split('\n').collect();
+                let is_blank = |line: &&str| line.trim().is_empty();
+                let starts_with_blank_line = lines.len() > 2 && lines[..2].iter().all(is_blank);
+                let ends_with_blank_line =
+                    lines.len() > 2 && lines[lines.len() - 2..].iter().all(is_blank);
+
+                // An interpolation in a comment is part of the comment
+                if !is_last && lines.last().is_some_and(|line| line.contains('#')) {
+                    return Ok(None);
+                }
+
+                let is_comments_only = lines.iter().all(|line| {
+                    let line = line.trim_start();
+                    line.is_empty() || line.starts_with('#')
+                });
+                let text = if is_comments_only {
+                    format_comments(&lines)
+                } else {
+                    match format_graphql(text, f.options()) {
+                        Some(code) => Some(code),
+                        None => return Ok(None),
+                    }
+                };
+
+                if let Some(text) = text {
+                    if !is_first && starts_with_blank_line {
+                        parts.push(GraphqlTemplatePart::Empty);
+                    }
+                    parts.push(GraphqlTemplatePart::Text(text));
+                    if !is_last && ends_with_blank_line {
+                        parts.push(GraphqlTemplatePart::Empty);
+                    }
+                } else if !is_first && !is_last && starts_with_blank_line {
+                    parts.push(GraphqlTemplatePart::Empty);
+                }
+
+                if let Some(interpolation) = interpolations.next() {
+                    parts.push(GraphqlTemplatePart::Interpolation(interpolation.clone()));
+                }
+            }
+        }
+
+        // The GraphQL can be formatted, the chunks are removed and the interpolations
+        // are formatted on their own lines.
+        for element in list {
+            if let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element {
+                f.context()
+                    .comments()
+                    .mark_suppression_checked(chunk.syntax());
+
+                write!(f, [format_removed(&chunk.template_chunk_token()?)])?;
+            }
+        }
+
+        Ok(Some(GraphqlTemplate {
+            parts,
+            start: list.range().start(),
+        }))
+    }
+}
+
+impl Format<JsFormatContext> for GraphqlTemplate {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        if self.parts.is_empty() {
+            return Ok(());
+        }
+
+        let content = format_with(|f: &mut JsFormatter| {
+            let mut is_first = true;
+            let mut has_empty_line = false;
+            for part in &self.parts {
+                let write_separator = |f: &mut JsFormatter| {
+                    if is_first {
+                        Ok(())
+                    } else if has_empty_line {
+                        write!(f, [empty_line()])
+                    } else {
+                        write!(f, [hard_line_break()])
+                    }
+                };
+
+                match part {
+                    GraphqlTemplatePart::Empty => {
+                        has_empty_line = true;
+                        continue;
+                    }
+                    GraphqlTemplatePart::Text(text) => {
+                        let mut is_first_line = true;
+                        let mut has_empty_line_in_text = false;
+                        for line in text.lines() {
+                            if line.trim().is_empty() {
+                                has_empty_line_in_text = !is_first_line;
+                                continue;
+                            }
+                            if is_first_line {
+                                write_separator(f)?;
+                            } else if has_empty_line_in_text {
+                                write!(f, [empty_line()])?;
+                            } else {
+                                write!(f, [hard_line_break()])?;
+                            }
+                            write!(f, [dynamic_text(line, self.start)])?;
+                            is_first_line = false;
+                            has_empty_line_in_text = false;
+                        }
+                    }
+                    GraphqlTemplatePart::Interpolation(interpolation) => {
+                        write_separator(f)?;
+                        let options = TemplateElementOptions::default();
+                        write!(f, [interpolation.format().with_options(options)])?;
+                    }
+                }
+                is_first = false;
+                has_empty_line = false;
+            }
+            Ok(())
+        });
+
+        write!(f, [block_indent(&content)])
+    }
+}
+
+/// Formats the GraphQL `source` with the GraphQL formatter.
+fn format_graphql(source: &str, options: &JsFormatOptions) -> Option<String> {
+    let parse = parse_graphql(source);
+    if parse.has_errors() {
+        return None;
+    }
+
+    let graphql_options = GraphqlFormatOptions::new(GraphqlFileSource::default())
+        .with_indent_style(options.indent_style())
+        .with_indent_width(options.indent_width())
+        .with_line_width(options.line_width())
+        .with_line_ending(LineEnding::Lf)
+        .with_bracket_spacing(options.bracket_spacing());
+
+    let printed = biome_graphql_formatter::format_node(graphql_options, &parse.syntax())
+        .ok()?
+        .print()
+        .ok()?;
+    Some(printed.into_code())
+}
+
+/// Returns the comments of the `lines` of a text made of comments and whitespace,
+/// keeping up to one blank line between the comments.
+fn format_comments(lines: &[&str]) -> Option<String> {
+    let mut result = String::new();
+    let mut previous_line = None;
+    for line in lines.iter().map(|line| line.trim()) {
+        if !line.is_empty() {
+            if !result.is_empty() {
+                if previous_line == Some("") {
+                    result.push('\n');
+                }
+                result.push('\n');
+            }
+            result.push_str(line);
+        }
+        previous_line = Some(line);
+    }
+    (!result.is_empty()).then_some(result)
+}
@@ -1,7 +1,9 @@
 pub(crate) mod array;
 mod assignment_like;
 mod conditional;
+pub(crate) mod css_template;
 mod format_binary_like_expression;
+pub(crate) mod graphql_template;
 pub mod string_utils;
 
 pub(crate) mod format_class;
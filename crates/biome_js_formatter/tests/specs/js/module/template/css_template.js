@@ -0,0 +1,42 @@
+const Button = styled.button`
+      color:   ${props => props.color};
+  background:white;
+  ${mixin};
+    ${otherMixin}
+  &:hover{color:red}
+
+  margin: ${space}px ${  space * 2 }px;
+`;
+
+const Link = styled(Button).attrs({ href: "#" })`text-decoration:none`;
+
+const base = css`
+  display:flex;
+  /* comment */
+  .child{flex:1}
+`;
+
+const fadeIn = keyframes`
+  from{opacity:0}
+  to{opacity:1}
+`;
+
+const GlobalStyle = createGlobalStyle`
+  body{margin:0}
+  a{color:${(props) => props.theme.link}}
+`;
+
+const Title = styled.h1`${title}`;
+
+const Empty = styled.div``;
+
+const Invalid = styled.div`
+  color: red
+  {{
+`;
+
+function Component() {
+  return css`
+    color:red;
+  `;
+}
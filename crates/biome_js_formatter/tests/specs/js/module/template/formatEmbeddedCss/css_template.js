@@ -0,0 +1,9 @@
+const Button = styled.button`
+      color:   ${props => props.color};
+  background:white;
+  ${mixin};
+`;
+
+const Link = styled(Button).attrs({ href: "#" })`text-decoration:none`;
+
+const base = css`display:flex;`;
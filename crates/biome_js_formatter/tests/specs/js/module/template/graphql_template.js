@@ -0,0 +1,35 @@
+const query = gql`
+      {
+    user(   id :   5  )  {
+      firstName
+
+      lastName
+    }
+  }
+`;
+
+const withFragments = graphql`
+query User { user(id:5){ ...UserDetails ...Friends } }
+
+${USER_DETAILS_FRAGMENT}${FRIENDS_FRAGMENT}
+`;
+
+// Interpolations inside a definition are left as is
+const withArgument = gql`
+query User {
+  user(id:${id}){ name }
+}
+`;
+
+const comments = gql`
+  # comment   
+
+  # after a blank line
+  query { user { name } } # trailing
+`;
+
+const empty = gql`   `;
+
+const notGraphql = sql`
+  select   *   from users
+`;
@@ -0,0 +1,205 @@
+use crate::Replacements;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, JsBinaryExpression, JsBinaryOperator, JsBooleanLiteralExpression,
+    JsComputedMemberAssignment, JsComputedMemberExpression, JsIdentifierExpression, JsSyntaxKind,
+    JsSyntaxNode, JsSyntaxToken, JsUnaryExpression, JsUnaryOperator,
+};
+use biome_rowan::{AstNode, Direction, WalkEvent};
+
+/// Applies the syntactic compressions that don't change the behavior of the code:
+/// - `true` and `false` become `!0` and `!1`;
+/// - the global `undefined` becomes `void 0`;
+/// - decimal numbers are written in their shortest form, like `.5` or `1e3`;
+/// - `a["b"]` becomes `a.b`.
+pub(crate) fn compress(root: &AnyJsRoot, model: &SemanticModel, replacements: &mut Replacements) {
+    for event in root.syntax().preorder_with_tokens(Direction::Next) {
+        let WalkEvent::Enter(element) = event else {
+            continue;
+        };
+        match element {
+            biome_rowan::NodeOrToken::Node(node) => compress_node(&node, model, replacements),
+            biome_rowan::NodeOrToken::Token(token) => {
+                if token.kind() == JsSyntaxKind::JS_NUMBER_LITERAL {
+                    if let Some(number) = compress_number(token.text_trimmed()) {
+                        replace(&token, &number, replacements);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn compress_node(node: &JsSyntaxNode, model: &SemanticModel, replacements: &mut Replacements) {
+    if let Some(literal) = JsBooleanLiteralExpression::cast_ref(node) {
+        if let Ok(token) = literal.value_token() {
+            if accepts_unary_expression(node) {
+                let text = if token.kind() == JsSyntaxKind::TRUE_KW {
+                    "!0"
+                } else {
+                    "!1"
+                };
+                replace(&token, text, replacements);
+            }
+        }
+    } else if let Some(expression) = JsIdentifierExpression::cast_ref(node) {
+        let Ok(name) = expression.name() else {
+            return;
+        };
+        let Ok(token) = name.value_token() else {
+            return;
+        };
+        let is_global_undefined =
+            token.text_trimmed() == "undefined" && model.binding(&name).is_none();
+        let in_unary_expression = node
+            .parent()
+            .is_some_and(|parent| JsUnaryExpression::can_cast(parent.kind()));
+        if is_global_undefined && !in_unary_expression && accepts_unary_expression(node) {
+            replace(&token, "void 0", replacements);
+        }
+    } else if let Some(member) = JsComputedMemberExpression::cast_ref(node) {
+        if let (Ok(l_brack), Ok(member_expression), Ok(r_brack)) = (
+            member.l_brack_token(),
+            member.member(),
+            member.r_brack_token(),
+        ) {
+            let separator = if member.optional_chain_token().is_some() {
+                ""
+            } else {
+                "."
+            };
+            compress_computed_member(
+                &l_brack,
+                &member_expression,
+                &r_brack,
+                separator,
+                replacements,
+            );
+        }
+    } else if let Some(member) = JsComputedMemberAssignment::cast_ref(node) {
+        if let (Ok(l_brack), Ok(member_expression), Ok(r_brack)) = (
+            member.l_brack_token(),
+            member.member(),
+            member.r_brack_token(),
+        ) {
+            compress_computed_member(&l_brack, &member_expression, &r_brack, ".", replacements);
+        }
+    }
+}
+
+/// Turns `["name"]` into `.name` when `name` is a valid identifier.
+fn compress_computed_member(
+    l_brack: &JsSyntaxToken,
+    member: &AnyJsExpression,
+    r_brack: &JsSyntaxToken,
+    separator: &str,
+    replacements: &mut Replacements,
+) {
+    let Some(literal) = member
+        .as_any_js_literal_expression()
+        .and_then(|literal| literal.as_js_string_literal_expression())
+    else {
+        return;
+    };
+    let Ok(token) = literal.value_token() else {
+        return;
+    };
+    let text = token.text_trimmed();
+    let name = &text[1..text.len() - 1];
+    if !is_identifier_name(name) {
+        return;
+    }
+    replace(l_brack, separator, replacements);
+    replace(&token, name, replacements);
+    replace(r_brack, "", replacements);
+}
+
+/// Returns `true` if the expression `node` can be replaced with a unary expression without parentheses.
+fn accepts_unary_expression(node: &JsSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        // `a ** b` doesn't accept a unary expression on its left side
+        JsSyntaxKind::JS_BINARY_EXPRESSION => {
+            JsBinaryExpression::cast(parent).is_some_and(|binary| {
+                binary.operator() != Ok(JsBinaryOperator::Exponent)
+                    || binary.left().is_ok_and(|left| left.syntax() != node)
+            })
+        }
+        JsSyntaxKind::JS_UNARY_EXPRESSION => JsUnaryExpression::cast(parent)
+            .is_some_and(|unary| unary.operator() != Ok(JsUnaryOperator::Delete)),
+        JsSyntaxKind::JS_INITIALIZER_CLAUSE
+        | JsSyntaxKind::JS_RETURN_STATEMENT
+        | JsSyntaxKind::JS_THROW_STATEMENT
+        | JsSyntaxKind::JS_EXPRESSION_STATEMENT
+        | JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+        | JsSyntaxKind::JS_ARRAY_ELEMENT_LIST
+        | JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER
+        | JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION
+        | JsSyntaxKind::JS_CONDITIONAL_EXPRESSION
+        | JsSyntaxKind::JS_LOGICAL_EXPRESSION
+        | JsSyntaxKind::JS_SEQUENCE_EXPRESSION
+        | JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+        | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+        | JsSyntaxKind::JS_YIELD_ARGUMENT
+        | JsSyntaxKind::JSX_EXPRESSION_ATTRIBUTE_VALUE
+        | JsSyntaxKind::JSX_EXPRESSION_CHILD => true,
+        _ => false,
+    }
+}
+
+/// Returns the shortest form of a decimal number literal, or [None] if it's already the shortest
+/// or isn't a plain decimal number.
+fn compress_number(text: &str) -> Option<String> {
+    if !text
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || matches!(byte, b'.' | b'_'))
+    {
+        return None;
+    }
+    // Legacy octal literals, like `017`
+    if text.starts_with('0') && text.len() > 1 && !text[1..].starts_with('.') {
+        return None;
+    }
+
+    let text_without_separators = text.replace('_', "");
+    let (integer, fraction) = match text_without_separators.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction.trim_end_matches('0')),
+        None => (text_without_separators.as_str(), ""),
+    };
+    let integer = integer.trim_start_matches('0');
+
+    let number = if fraction.is_empty() {
+        let digits = integer.trim_end_matches('0');
+        let zeros = integer.len() - digits.len();
+        if integer.is_empty() {
+            String::from("0")
+        } else if zeros > 2 {
+            format!("{digits}e{zeros}")
+        } else {
+            integer.to_string()
+        }
+    } else {
+        format!("{integer}.{fraction}")
+    };
+
+    (number.len() < text.len()).then_some(number)
+}
+
+/// Returns `true` if `name` can follow a `.` in a member access.
+fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || matches!(first, '_' | '$'))
+        && chars.all(|char| char.is_ascii_alphanumeric() || matches!(char, '_' | '$'))
+}
+
+fn replace(token: &JsSyntaxToken, text: &str, replacements: &mut Replacements) {
+    // A renamed binding keeps its new name
+    let start = token.text_trimmed_range().start();
+    if !replacements.contains(start) {
+        replacements.replace(start, text);
+    }
+}
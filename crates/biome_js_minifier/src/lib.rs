@@ -0,0 +1,131 @@
+//! Biome's JavaScript minifier.
+//!
+//! The minifier works on the syntax tree of a file that doesn't contain syntax errors, in three steps:
+//! - local bindings are given shorter names, using the [SemanticModel] to find their references;
+//! - a few syntactic compressions are applied to literals and member accesses;
+//! - the tokens are printed without whitespaces and comments, except the `/*! ... */` license comments.
+//!
+//! The minified code is parsed again before being returned, so that a bug in the minifier
+//! surfaces as a [MinifyError] instead of broken code.
+
+mod compress;
+mod mangle;
+mod printer;
+
+use crate::printer::Printer;
+use biome_console::fmt::Formatter;
+use biome_console::markup;
+use biome_diagnostics::{category, Category, Diagnostic, Severity};
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_syntax::{AnyJsRoot, JsFileSource, TextSize};
+use biome_rowan::{AstNode, SyntaxKind};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Minifies the code of `root`, and returns the minified code.
+///
+/// The tree must be a JavaScript tree without syntax errors.
+pub fn minify(root: &AnyJsRoot, source_type: JsFileSource) -> Result<String, MinifyError> {
+    if source_type.is_typescript() {
+        return Err(MinifyError::UnsupportedLanguage);
+    }
+    if root
+        .syntax()
+        .descendants()
+        .any(|node| node.kind().is_bogus())
+    {
+        return Err(MinifyError::SyntaxErrors);
+    }
+
+    let model = semantic_model(root, SemanticModelOptions::default());
+    let mut replacements = Replacements::default();
+    mangle::mangle(root, &model, source_type, &mut replacements);
+    compress::compress(root, &model, &mut replacements);
+
+    let code = Printer::new(&replacements).print(root);
+
+    let parsed = parse(&code, source_type, JsParserOptions::default());
+    if parsed.has_errors() {
+        return Err(MinifyError::InvalidOutput);
+    }
+
+    Ok(code)
+}
+
+/// The text that replaces some tokens of the tree when the tree is printed.
+///
+/// Tokens are identified by the start of their trimmed range. An empty replacement removes the token.
+#[derive(Debug, Default)]
+pub(crate) struct Replacements {
+    tokens: FxHashMap<TextSize, String>,
+}
+
+impl Replacements {
+    pub(crate) fn replace(&mut self, start: TextSize, text: impl Into<String>) {
+        self.tokens.insert(start, text.into());
+    }
+
+    pub(crate) fn get(&self, start: TextSize) -> Option<&str> {
+        self.tokens.get(&start).map(String::as_str)
+    }
+
+    pub(crate) fn contains(&self, start: TextSize) -> bool {
+        self.tokens.contains_key(&start)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum MinifyError {
+    /// The file contains syntax errors
+    SyntaxErrors,
+    /// The file isn't a JavaScript file
+    UnsupportedLanguage,
+    /// The minified code doesn't parse
+    InvalidOutput,
+}
+
+impl fmt::Display for MinifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MinifyError::SyntaxErrors => {
+                write!(f, "the file contains syntax errors")
+            }
+            MinifyError::UnsupportedLanguage => {
+                write!(f, "only JavaScript files can be minified")
+            }
+            MinifyError::InvalidOutput => {
+                write!(f, "the minified code contains syntax errors")
+            }
+        }
+    }
+}
+
+impl Diagnostic for MinifyError {
+    fn category(&self) -> Option<&'static Category> {
+        Some(category!("minify"))
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn message(&self, fmt: &mut Formatter<'_>) -> std::io::Result<()> {
+        match self {
+            MinifyError::SyntaxErrors => fmt.write_markup(markup! {
+                "The file can't be minified because it contains syntax errors."
+            }),
+            MinifyError::UnsupportedLanguage => fmt.write_markup(markup! {
+                "Only JavaScript files can be minified. The types of a TypeScript file can be removed with the "<Emphasis>"stripTypes"</Emphasis>" transformation."
+            }),
+            MinifyError::InvalidOutput => fmt.write_markup(markup! {
+                "The minified code contains syntax errors. This is a bug in Biome's minifier, please report it."
+            }),
+        }
+    }
+
+    fn description(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{self}")
+    }
+}
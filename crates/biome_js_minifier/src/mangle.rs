@@ -0,0 +1,306 @@
+use crate::Replacements;
+use biome_js_semantic::{Binding, Scope, SemanticModel};
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, JsCallExpression, JsFileSource, JsIdentifierBinding, JsSyntaxKind,
+    JsSyntaxNode, JsWithStatement, TextRange,
+};
+use biome_rowan::{AstNode, SyntaxNodeCast};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Words that can't be used as the name of a binding, or that would be confusing as such.
+const RESERVED_NAMES: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "undefined",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+const NAME_START: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$";
+const NAME_CONTINUE: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$0123456789";
+
+/// Gives shorter names to the local bindings of `root`.
+///
+/// Every scope names its bindings starting from the shortest name again, skipping the names that
+/// its ancestor scopes gave to their bindings: sibling scopes can't see each other's bindings,
+/// so they can reuse the same names.
+///
+/// The following bindings keep their name:
+/// - the bindings of the global scope of a script, because they are global variables;
+/// - the exported bindings;
+/// - the bindings of the scopes that contain a direct `eval` or a `with` statement, and of their
+///   ancestors, because the code can reach them by name;
+/// - the bindings referenced by a JSX element, because the casing of the name of a JSX element
+///   tells apart components and intrinsic elements.
+pub(crate) fn mangle(
+    root: &AnyJsRoot,
+    model: &SemanticModel,
+    source_type: JsFileSource,
+    replacements: &mut Replacements,
+) {
+    // The scopes are created in the order they are entered, so parents come before their children.
+    let scopes: Vec<Scope> = model.scopes().collect();
+    let index_of: FxHashMap<TextRange, usize> = scopes
+        .iter()
+        .enumerate()
+        .map(|(index, scope)| (scope.range(), index))
+        .collect();
+    let parents: Vec<Option<usize>> = scopes
+        .iter()
+        .map(|scope| {
+            scope
+                .parent()
+                .and_then(|parent| index_of.get(&parent.range()).copied())
+        })
+        .collect();
+
+    let mut frozen = vec![false; scopes.len()];
+    for node in root.syntax().descendants() {
+        if JsWithStatement::can_cast(node.kind()) || is_direct_eval(&node, model) {
+            for scope in model.scope(&node).ancestors() {
+                if let Some(index) = index_of.get(&scope.range()) {
+                    frozen[*index] = true;
+                }
+            }
+        }
+    }
+
+    // Names that must never be given to a binding: globals and the names of the bindings that are kept.
+    let mut reserved: FxHashSet<String> = model
+        .all_unresolved_references()
+        .map(|reference| reference.syntax().text_trimmed().to_string())
+        .chain(
+            model
+                .all_global_references()
+                .map(|reference| reference.syntax().text_trimmed().to_string()),
+        )
+        .collect();
+
+    let mut groups_by_scope = Vec::with_capacity(scopes.len());
+    let mut kept_by_scope: Vec<FxHashSet<String>> = Vec::with_capacity(scopes.len());
+    for (index, scope) in scopes.iter().enumerate() {
+        let is_global_script_scope = index == 0 && !source_type.is_module();
+        // The parameters are bound in the scope of the function, and the body has its own scope:
+        // a `var` of the body that redeclares a parameter is the same variable.
+        let parameters_scope =
+            parents[index].filter(|_| scope.syntax().kind() == JsSyntaxKind::JS_FUNCTION_BODY);
+        let mut groups: Vec<BindingGroup> = Vec::new();
+        for binding in scope.bindings() {
+            let Some(name) = binding_name(&binding) else {
+                continue;
+            };
+            let group = match groups.iter_mut().find(|group| group.name == name) {
+                Some(group) => group,
+                None => {
+                    let parameter = parameters_scope
+                        .filter(|parent| scopes[*parent].get_binding(&name).is_some());
+                    let renamable = !frozen[index]
+                        && !is_global_script_scope
+                        && parameter.map_or(true, |parent| !kept_by_scope[parent].contains(&name));
+                    groups.push(BindingGroup {
+                        name,
+                        bindings: Vec::new(),
+                        occurrences: 0,
+                        renamable,
+                        parameter,
+                    });
+                    groups.last_mut().unwrap()
+                }
+            };
+            group.occurrences += 1 + binding.all_references().count();
+            group.renamable &= is_renamable(&binding, model);
+            group.bindings.push(binding);
+        }
+
+        let mut kept = FxHashSet::default();
+        for group in &groups {
+            if !group.renamable {
+                reserved.insert(group.name.clone());
+                kept.insert(group.name.clone());
+            }
+        }
+        kept_by_scope.push(kept);
+        groups.retain(|group| group.renamable);
+        // The most used bindings get the shortest names
+        groups.sort_by(|a, b| b.occurrences.cmp(&a.occurrences));
+        groups_by_scope.push(groups);
+    }
+
+    let mut assigned: Vec<FxHashMap<String, String>> = vec![FxHashMap::default(); scopes.len()];
+    for (index, groups) in groups_by_scope.iter().enumerate() {
+        let mut names = NameGenerator::default();
+        for group in groups {
+            let parameter_name = group
+                .parameter
+                .and_then(|parent| assigned[parent].get(&group.name))
+                .cloned();
+            let name = parameter_name.unwrap_or_else(|| loop {
+                let name = names.next();
+                let is_taken = reserved.contains(&name)
+                    || RESERVED_NAMES.contains(&name.as_str())
+                    || std::iter::successors(Some(index), |index| parents[*index]).any(
+                        |ancestor| {
+                            assigned[ancestor]
+                                .values()
+                                .any(|new_name| *new_name == name)
+                        },
+                    );
+                if !is_taken {
+                    break name;
+                }
+            });
+            if name != group.name {
+                for binding in &group.bindings {
+                    rename_binding(binding, &group.name, &name, replacements);
+                }
+            }
+            assigned[index].insert(group.name.clone(), name);
+        }
+    }
+}
+
+/// The bindings of a scope that share the same name, like the redeclarations of a `var`.
+struct BindingGroup {
+    name: String,
+    bindings: Vec<Binding>,
+    occurrences: usize,
+    renamable: bool,
+    /// The scope of the parameter that this group redeclares
+    parameter: Option<usize>,
+}
+
+fn binding_name(binding: &Binding) -> Option<String> {
+    match binding.tree() {
+        AnyJsIdentifierBinding::JsIdentifierBinding(binding) => {
+            Some(binding.name_token().ok()?.text_trimmed().to_string())
+        }
+        _ => None,
+    }
+}
+
+fn is_renamable(binding: &Binding, model: &SemanticModel) -> bool {
+    let Some(identifier) = binding.syntax().clone().cast::<JsIdentifierBinding>() else {
+        return false;
+    };
+    !model.is_exported(&identifier)
+        && binding
+            .all_references()
+            .all(|reference| reference.syntax().kind() != JsSyntaxKind::JSX_REFERENCE_IDENTIFIER)
+}
+
+/// Returns `true` if `node` is a call to the global `eval`, which can access the local bindings.
+fn is_direct_eval(node: &JsSyntaxNode, model: &SemanticModel) -> bool {
+    let Some(call) = JsCallExpression::cast_ref(node) else {
+        return false;
+    };
+    let Ok(AnyJsExpression::JsIdentifierExpression(callee)) = call.callee() else {
+        return false;
+    };
+    callee.name().is_ok_and(|name| {
+        name.value_token()
+            .is_ok_and(|token| token.text_trimmed() == "eval")
+            && model.binding(&name).is_none()
+    })
+}
+
+fn rename_binding(binding: &Binding, name: &str, new_name: &str, replacements: &mut Replacements) {
+    rename_identifier(binding.syntax(), name, new_name, replacements);
+    for reference in binding.all_references() {
+        rename_identifier(reference.syntax(), name, new_name, replacements);
+    }
+}
+
+/// Renames the identifier `node`, expanding the shorthand syntaxes where the name is also a property name.
+fn rename_identifier(
+    node: &JsSyntaxNode,
+    name: &str,
+    new_name: &str,
+    replacements: &mut Replacements,
+) {
+    let Some(token) = node.first_token() else {
+        return;
+    };
+    let text = match node.parent().map(|parent| parent.kind()) {
+        Some(
+            JsSyntaxKind::JS_SHORTHAND_PROPERTY_OBJECT_MEMBER
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+            | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_SHORTHAND_PROPERTY,
+        ) => format!("{name}:{new_name}"),
+        Some(JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER) => {
+            format!("{name} as {new_name}")
+        }
+        Some(JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER) => {
+            format!("{new_name} as {name}")
+        }
+        _ => new_name.to_string(),
+    };
+    replacements.replace(token.text_trimmed_range().start(), text);
+}
+
+/// Generates the names `a`, `b`, ..., `$`, `aa`, `ab`, ...
+#[derive(Default)]
+struct NameGenerator {
+    next: usize,
+}
+
+impl NameGenerator {
+    fn next(&mut self) -> String {
+        let mut index = self.next;
+        self.next += 1;
+
+        let mut name = String::new();
+        name.push(NAME_START[index % NAME_START.len()] as char);
+        index /= NAME_START.len();
+        while index > 0 {
+            index -= 1;
+            name.push(NAME_CONTINUE[index % NAME_CONTINUE.len()] as char);
+            index /= NAME_CONTINUE.len();
+        }
+        name
+    }
+}
@@ -0,0 +1,175 @@
+use crate::Replacements;
+use biome_js_syntax::{AnyJsRoot, JsSyntaxKind, JsSyntaxToken, T};
+use biome_rowan::{AstNode, Direction, NodeOrToken, SyntaxTriviaPiece, WalkEvent};
+
+/// Prints the tokens of a tree with the least whitespaces possible.
+///
+/// The semicolons that end statements are only printed when another token follows them,
+/// and the semicolons that the automatic semicolon insertion added to the original code are printed too.
+pub(crate) struct Printer<'a> {
+    replacements: &'a Replacements,
+    code: String,
+    /// The kind of the last printed token
+    last_kind: Option<JsSyntaxKind>,
+    /// A statement ended, and its semicolon must be printed before the next token
+    pending_semicolon: bool,
+}
+
+impl<'a> Printer<'a> {
+    pub(crate) fn new(replacements: &'a Replacements) -> Self {
+        Self {
+            replacements,
+            code: String::new(),
+            last_kind: None,
+            pending_semicolon: false,
+        }
+    }
+
+    pub(crate) fn print(mut self, root: &AnyJsRoot) -> String {
+        for event in root.syntax().preorder_with_tokens(Direction::Next) {
+            match event {
+                WalkEvent::Enter(NodeOrToken::Token(token)) => self.print_token(&token),
+                WalkEvent::Leave(NodeOrToken::Node(node)) => {
+                    if has_optional_semicolon(node.kind())
+                        && node.last_token().is_some_and(|token| token.kind() != T![;])
+                    {
+                        self.pending_semicolon = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.code
+    }
+
+    fn print_token(&mut self, token: &JsSyntaxToken) {
+        for piece in token.leading_trivia().pieces() {
+            self.print_comment(&piece);
+        }
+
+        if token.kind() == T![;] && self.is_statement_semicolon(token) {
+            self.pending_semicolon = true;
+        } else if token.kind() != JsSyntaxKind::EOF {
+            let start = token.text_trimmed_range().start();
+            let text = self
+                .replacements
+                .get(start)
+                .unwrap_or_else(|| token.text_trimmed());
+            if !text.is_empty() {
+                if self.pending_semicolon && token.kind() != T!['}'] {
+                    self.write(";", T![;]);
+                }
+                self.pending_semicolon = false;
+                self.write(text, token.kind());
+            }
+        }
+
+        for piece in token.trailing_trivia().pieces() {
+            self.print_comment(&piece);
+        }
+    }
+
+    /// Returns `true` if `token` ends a statement, or is a statement on its own that can be removed.
+    fn is_statement_semicolon(&self, token: &JsSyntaxToken) -> bool {
+        let Some(parent) = token.parent() else {
+            return false;
+        };
+        match parent.kind() {
+            JsSyntaxKind::JS_EMPTY_STATEMENT => parent.parent().is_some_and(|list| {
+                matches!(
+                    list.kind(),
+                    JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST
+                )
+            }),
+            JsSyntaxKind::JS_EMPTY_CLASS_MEMBER => true,
+            kind => has_optional_semicolon(kind),
+        }
+    }
+
+    /// Prints the license comments, written as `/*! ... */`
+    fn print_comment(&mut self, piece: &SyntaxTriviaPiece<biome_js_syntax::JsLanguage>) {
+        if !piece.is_comments() || !piece.text().starts_with("/*!") {
+            return;
+        }
+        let is_banner = self.last_kind.is_none();
+        if self.pending_semicolon {
+            self.write(";", T![;]);
+            self.pending_semicolon = false;
+        }
+        self.code.push_str(piece.text());
+        if is_banner {
+            self.code.push('\n');
+        }
+    }
+
+    fn write(&mut self, text: &str, kind: JsSyntaxKind) {
+        if let (Some(last_kind), Some(last), Some(next)) = (
+            self.last_kind,
+            self.code.chars().last(),
+            text.chars().next(),
+        ) {
+            if needs_space(last_kind, &self.code, last, next) {
+                self.code.push(' ');
+            }
+        }
+        self.code.push_str(text);
+        self.last_kind = Some(kind);
+    }
+}
+
+/// Returns `true` if the nodes of this kind end with a semicolon that can be omitted in the source code.
+fn has_optional_semicolon(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_DIRECTIVE
+            | JsSyntaxKind::JS_EXPRESSION_STATEMENT
+            | JsSyntaxKind::JS_VARIABLE_STATEMENT
+            | JsSyntaxKind::JS_DO_WHILE_STATEMENT
+            | JsSyntaxKind::JS_BREAK_STATEMENT
+            | JsSyntaxKind::JS_CONTINUE_STATEMENT
+            | JsSyntaxKind::JS_RETURN_STATEMENT
+            | JsSyntaxKind::JS_THROW_STATEMENT
+            | JsSyntaxKind::JS_DEBUGGER_STATEMENT
+            | JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+            | JsSyntaxKind::JS_VARIABLE_DECLARATION_CLAUSE
+            | JsSyntaxKind::JS_IMPORT
+            | JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE
+            | JsSyntaxKind::JS_EXPORT_NAMED_CLAUSE
+            | JsSyntaxKind::JS_EXPORT_FROM_CLAUSE
+            | JsSyntaxKind::JS_EXPORT_NAMED_FROM_CLAUSE
+    )
+}
+
+/// Returns `true` if printing `next` right after the code, which ends with `last`, would merge them
+/// in a different token.
+fn needs_space(last_kind: JsSyntaxKind, code: &str, last: char, next: char) -> bool {
+    match (last, next) {
+        (last, next) if is_word_char(last) && is_word_char(next) => true,
+        // `a + +b`, `a - --b`
+        ('+', '+') | ('-', '-') => true,
+        // `a / /b/`, `/a/ * b`
+        ('/', '/' | '*') => true,
+        // `a < !--b`, `a-- > b`
+        ('<', '!') => true,
+        ('-', '>') => code.ends_with("--"),
+        // `a ? .5 : b`
+        ('?', '.') => true,
+        _ => match last_kind {
+            // The flags of a regular expression, like `/a/ in b`
+            JsSyntaxKind::JS_REGEX_LITERAL => is_word_char(next),
+            // `1 .toString()`
+            JsSyntaxKind::JS_NUMBER_LITERAL => {
+                next == '.'
+                    && code
+                        .rsplit(|char: char| !is_word_char(char) && char != '.')
+                        .next()
+                        .is_some_and(|number| number.bytes().all(|byte| byte.is_ascii_digit()))
+            }
+            _ => false,
+        },
+    }
+}
+
+fn is_word_char(char: char) -> bool {
+    char.is_ascii_alphanumeric() || matches!(char, '_' | '$' | '\\' | '#') || !char.is_ascii()
+}
@@ -0,0 +1,48 @@
+use biome_js_minifier::minify;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use biome_rowan::AstNode;
+use biome_test_utils::{assert_errors_are_absent, register_leak_checker};
+use std::fmt::Write;
+use std::{ffi::OsStr, fs::read_to_string, path::Path};
+
+tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,mjs}", crate::run_test, "module"}
+
+fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
+    register_leak_checker();
+
+    let input_file = Path::new(input);
+    let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+    let extension = input_file
+        .extension()
+        .and_then(OsStr::to_str)
+        .unwrap_or_default();
+
+    let input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {input_file:?}: {err:?}"));
+    let source_type: JsFileSource = input_file.try_into().unwrap();
+
+    let parsed = parse(&input_code, source_type, JsParserOptions::default());
+    assert_errors_are_absent(parsed.tree().syntax(), parsed.diagnostics(), input_file);
+
+    let output = minify(&parsed.tree(), source_type)
+        .unwrap_or_else(|err| panic!("failed to minify {input_file:?}: {err}"));
+
+    let mut snapshot = String::new();
+    writeln!(snapshot, "# Input").unwrap();
+    writeln!(snapshot, "```{extension}").unwrap();
+    writeln!(snapshot, "{input_code}").unwrap();
+    writeln!(snapshot, "```").unwrap();
+    writeln!(snapshot).unwrap();
+    writeln!(snapshot, "# Output").unwrap();
+    writeln!(snapshot, "```{extension}").unwrap();
+    writeln!(snapshot, "{output}").unwrap();
+    writeln!(snapshot, "```").unwrap();
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => input_file.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot, file_name);
+    });
+}
@@ -0,0 +1,16 @@
+import { Fragment } from "react";
+
+function Component({ items }) {
+	const Item = ({ label }) => <li className="item">{label}</li>;
+	const visible = true;
+	return (
+		<Fragment>
+			<ul hidden={!visible}>
+				{items.map((item) => <Item key={item} label={item} />)}
+			</ul>
+			Some text
+		</Fragment>
+	);
+}
+
+export default Component;
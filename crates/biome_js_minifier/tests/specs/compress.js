@@ -0,0 +1,14 @@
+const a = true;
+const b = [false, undefined];
+const c = true.toString();
+const d = false ** 2;
+const e = typeof undefined;
+const f = { key: undefined, 1000: 0.50, "property": 10.0 };
+const g = f["property"] + f["key-with-dash"] + f?.["key"];
+f["key"] = 1_000_000;
+
+function shadowed(undefined) {
+	return undefined;
+}
+
+export { a, b, c, d, e, g, shadowed };
@@ -0,0 +1,13 @@
+const moduleConstant = 1;
+
+function usesEval(code) {
+	const reachable = moduleConstant;
+	return eval(code);
+}
+
+function withoutEval(parameter) {
+	const local = parameter;
+	return local;
+}
+
+export { usesEval, withoutEval };
@@ -0,0 +1,17 @@
+function redeclaredParameter(value) {
+	var value;
+	return value;
+}
+
+function hoistedFromBlock(condition) {
+	if (condition) {
+		var hoisted = 1;
+	}
+	{
+		let blockScoped = 2;
+		hoisted += blockScoped;
+	}
+	return hoisted;
+}
+
+export { redeclaredParameter, hoistedFromBlock };
@@ -0,0 +1,30 @@
+import { readFile } from "node:fs";
+import defaultExport from "module";
+
+const localConstant = 1;
+export const exported = localConstant;
+
+function outer(firstParameter, secondParameter) {
+	const inner = firstParameter + secondParameter;
+	const { shorthand, renamed: other = 2 } = firstParameter;
+	var hoisted = 1;
+	var hoisted = 2;
+	function nested(value) {
+		return value + inner + hoisted + window.location;
+	}
+	return { inner, shorthand, other, nested };
+}
+
+function sibling(value) {
+	let result;
+	({ result } = value);
+	return result;
+}
+
+try {
+	outer(readFile, defaultExport);
+} catch (error) {
+	sibling(error);
+}
+
+export { outer as renamedOuter };
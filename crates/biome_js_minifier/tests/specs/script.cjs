@@ -0,0 +1,12 @@
+var globalVariable = 1;
+function globalFunction(parameter) {
+	var local = parameter * 2;
+	with (Math) {
+		return max(local, parameter);
+	}
+}
+
+function otherFunction(parameter) {
+	const local = parameter;
+	return local;
+}
@@ -0,0 +1,46 @@
+/*! my-library v1.0.0 | MIT License */
+
+// A regular comment
+"use strict"
+
+/**
+ * Documentation comments are removed
+ */
+const a = 1
+const b = a
+++b
+;[a, b].forEach(console.log)
+
+let c = a + +b - -a
+let d = a++ + ++c
+let e = a-- - --c
+let f = /ab+c/g instanceof RegExp
+let g = 1 .toString() + 1.5.toFixed()
+
+function f1() {
+	if (a) return
+	a
+}
+
+class A {
+	x = 1
+	y
+	;[b] = 2
+	static method() {}
+}
+
+do a++
+while (a < 10)
+
+for (;;) {
+	;
+}
+
+if (a);
+label: for (const x of []) {
+	continue label
+}
+
+const template = `
+	line ${a}
+`
@@ -11,8 +11,8 @@ use crate::{
     JsLiteralMemberName, JsLogicalExpression, JsNewExpression, JsNumberLiteralExpression,
     JsObjectExpression, JsPostUpdateExpression, JsPreUpdateExpression, JsReferenceIdentifier,
     JsRegexLiteralExpression, JsStaticMemberExpression, JsStringLiteralExpression, JsSyntaxKind,
-    JsSyntaxNode, JsSyntaxToken, JsTemplateChunkElement, JsTemplateExpression, JsUnaryExpression,
-    JsWhileStatement, OperatorPrecedence, TsStringLiteralType, T,
+    JsSyntaxNode, JsSyntaxToken, JsTemplateChunkElement, JsTemplateElement, JsTemplateExpression,
+    JsUnaryExpression, JsWhileStatement, OperatorPrecedence, TsStringLiteralType, T,
 };
 use biome_rowan::{
     declare_node_union, AstNode, AstNodeList, AstSeparatedList, NodeOrToken, SyntaxNodeCast,
@@ -815,6 +815,217 @@ impl JsTemplateExpression {
 
         true
     }
+
+    /// Returns the name of the tag of this template, if the tag is an identifier.
+    ///
+    /// ```javascript
+    /// gql`query { user }`; // Some("gql")
+    /// styled.div`color: red;`; // None
+    /// `text`; // None
+    /// ```
+    pub fn tag_name(&self) -> Option<TokenText> {
+        let tag = self.tag()?;
+        let identifier = tag.as_js_identifier_expression()?;
+        identifier.name().ok()?.name().ok()
+    }
+
+    /// Returns the kind of CSS embedded in this template, if it's tagged with a
+    /// CSS-in-JS function of [styled-components] or [emotion].
+    ///
+    /// ```javascript
+    /// css`color: red;`; // Declarations
+    /// styled.div`color: red;`; // Declarations
+    /// styled(Button)`color: red;`; // Declarations
+    /// styled.div.attrs({ role: "button" })`color: red;`; // Declarations
+    /// keyframes`from { opacity: 0; }`; // Keyframes
+    /// createGlobalStyle`body { margin: 0; }`; // StyleSheet
+    /// ```
+    ///
+    /// [styled-components]: https://styled-components.com
+    /// [emotion]: https://emotion.sh
+    pub fn css_template_kind(&self) -> Option<CssTemplateKind> {
+        let tag = self.tag()?;
+        match &tag {
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let name = identifier.name().ok()?.value_token().ok()?;
+                match name.text_trimmed() {
+                    "css" => Some(CssTemplateKind::Declarations),
+                    "keyframes" => Some(CssTemplateKind::Keyframes),
+                    "createGlobalStyle" | "injectGlobal" => Some(CssTemplateKind::StyleSheet),
+                    _ => None,
+                }
+            }
+            _ => is_styled_tag(&tag).then_some(CssTemplateKind::Declarations),
+        }
+    }
+
+    /// Returns the CSS of this CSS-in-JS template of the given `kind`, wrapped in the rule
+    /// of [CssTemplateKind::wrapper] so that the CSS parser accepts it.
+    ///
+    /// The interpolations are replaced by placeholders, whose identifier is returned by
+    /// `placeholder` from the index of the interpolation and the length of the interpolation
+    /// that isn't taken by the `@` and `;` around the identifier:
+    /// - an interpolation followed by a semicolon is replaced by an at-rule, e.g. `${mixin};`
+    ///   by `@placeholder;`;
+    /// - an interpolation alone on its line in place of a declaration is replaced by an
+    ///   at-rule too, e.g. `${mixin}` by `@placeholder;`;
+    /// - any other interpolation is replaced by an identifier, e.g. `${color}` by `placeholder`.
+    ///
+    /// Returns `None` when the template has several interpolations in place of declarations
+    /// on the same line, e.g. `${mixin} ${otherMixin}`.
+    pub fn css_template_source(
+        &self,
+        kind: CssTemplateKind,
+        mut placeholder: impl FnMut(usize, usize) -> String,
+    ) -> Option<CssTemplateSource> {
+        let (prefix, suffix) = kind.wrapper();
+        let elements: Vec<_> = self.elements().into_iter().collect();
+
+        let mut source = String::from(prefix);
+        let mut placeholders = Vec::new();
+        for (index, element) in elements.iter().enumerate() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    source.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    let len = usize::from(element.syntax().text_trimmed_range().len());
+                    let next_chunk = match elements.get(index + 1) {
+                        Some(AnyJsTemplateElement::JsTemplateChunkElement(chunk)) => {
+                            Some(chunk.template_chunk_token().ok()?)
+                        }
+                        _ => None,
+                    };
+                    let rest = next_chunk
+                        .as_ref()
+                        .map(|chunk| chunk.text_trimmed().trim_start_matches([' ', '\t']));
+                    // Whether the interpolation is the last one of its line
+                    let is_line_end = match rest {
+                        Some("") => elements.get(index + 2).is_none(),
+                        Some(rest) => rest.starts_with(['\n', '\r', '}']),
+                        None => elements.get(index + 1).is_none(),
+                    };
+                    let is_statement = source
+                        .trim_end()
+                        .chars()
+                        .last()
+                        .map_or(true, |last| matches!(last, '{' | '}' | ';'));
+
+                    let text = if is_statement && rest.is_some_and(|rest| rest.starts_with(';')) {
+                        std::format!(
+                            "@{}",
+                            placeholder(placeholders.len(), len.saturating_sub(1))
+                        )
+                    } else if is_statement && is_line_end {
+                        std::format!(
+                            "@{};",
+                            placeholder(placeholders.len(), len.saturating_sub(2))
+                        )
+                    } else if is_statement && rest.is_some_and(str::is_empty) {
+                        return None;
+                    } else {
+                        placeholder(placeholders.len(), len)
+                    };
+                    let start = TextSize::from(source.len() as u32);
+                    source.push_str(&text);
+                    placeholders.push(CssTemplatePlaceholder {
+                        range: TextRange::at(start, TextSize::from(text.len() as u32)),
+                        text,
+                        element: element.clone(),
+                        is_statement,
+                    });
+                }
+            }
+        }
+        source.push_str(suffix);
+
+        Some(CssTemplateSource {
+            source,
+            placeholders,
+        })
+    }
+}
+
+/// The content of a CSS-in-JS template, see [JsTemplateExpression::css_template_kind]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CssTemplateKind {
+    /// Declarations and nested rules, e.g. `` css`color: red;` ``
+    Declarations,
+    /// Keyframe blocks, e.g. `` keyframes`from { opacity: 0; }` ``
+    Keyframes,
+    /// A whole style sheet, e.g. `` createGlobalStyle`body { margin: 0; }` ``
+    StyleSheet,
+}
+
+impl CssTemplateKind {
+    /// Returns the start and the end of the rule that wraps the content of a template
+    /// of this kind, so that the CSS parser accepts it.
+    pub const fn wrapper(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Declarations => ("a{", "\n}"),
+            Self::Keyframes => ("@keyframes a{", "\n}"),
+            Self::StyleSheet => ("", ""),
+        }
+    }
+}
+
+/// The CSS of a CSS-in-JS template, see [JsTemplateExpression::css_template_source]
+#[derive(Debug, Clone)]
+pub struct CssTemplateSource {
+    /// The CSS of the template, wrapped in the rule of [CssTemplateKind::wrapper]
+    pub source: String,
+    /// The placeholders of the interpolations, in the order of the template
+    pub placeholders: Vec<CssTemplatePlaceholder>,
+}
+
+impl CssTemplateSource {
+    /// Returns the content of the template, without the wrapping rule
+    pub fn content(&self, kind: CssTemplateKind) -> &str {
+        let (prefix, suffix) = kind.wrapper();
+        &self.source[prefix.len()..self.source.len() - suffix.len()]
+    }
+}
+
+/// An interpolation of a CSS-in-JS template, replaced by a placeholder in its CSS
+#[derive(Debug, Clone)]
+pub struct CssTemplatePlaceholder {
+    /// The text of the placeholder, e.g. `@placeholder;` or `placeholder`
+    pub text: String,
+    /// The range of the placeholder in the CSS
+    pub range: TextRange,
+    /// The interpolation replaced by the placeholder
+    pub element: JsTemplateElement,
+    /// Whether the interpolation is in place of a declaration or a rule
+    pub is_statement: bool,
+}
+
+/// Returns `true` for the tags `styled.div`, `styled(Component)` and the
+/// `attrs` and `withConfig` calls chained to them.
+fn is_styled_tag(tag: &AnyJsExpression) -> bool {
+    let is_styled = |expression: SyntaxResult<AnyJsExpression>| {
+        expression.is_ok_and(|expression| {
+            expression
+                .as_js_identifier_expression()
+                .and_then(|identifier| identifier.name().ok())
+                .is_some_and(|name| name.has_name("styled"))
+        })
+    };
+
+    match tag {
+        AnyJsExpression::JsStaticMemberExpression(member) => is_styled(member.object()),
+        AnyJsExpression::JsCallExpression(call) => match call.callee() {
+            Ok(AnyJsExpression::JsStaticMemberExpression(member)) => {
+                member.member().is_ok_and(|member| {
+                    member
+                        .as_js_name()
+                        .and_then(|name| name.value_token().ok())
+                        .is_some_and(|name| matches!(name.text_trimmed(), "attrs" | "withConfig"))
+                }) && member.object().is_ok_and(|object| is_styled_tag(&object))
+            }
+            callee => is_styled(callee),
+        },
+        _ => false,
+    }
 }
 
 impl JsRegexLiteralExpression {
@@ -1,3 +1,4 @@
+pub use biome_rowan::EmbeddingKind;
 use biome_rowan::FileSourceError;
 use biome_string_case::StrLikeExtension;
 use std::{borrow::Cow, ffi::OsStr, path::Path};
@@ -110,30 +111,6 @@ impl Language {
         )
     }
 }
-#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(
-    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
-)]
-pub enum EmbeddingKind {
-    Astro,
-    Vue,
-    Svelte,
-    #[default]
-    None,
-}
-
-impl EmbeddingKind {
-    pub const fn is_astro(&self) -> bool {
-        matches!(self, EmbeddingKind::Astro)
-    }
-    pub const fn is_vue(&self) -> bool {
-        matches!(self, EmbeddingKind::Vue)
-    }
-    pub const fn is_svelte(&self) -> bool {
-        matches!(self, EmbeddingKind::Svelte)
-    }
-}
-
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -66,6 +66,7 @@ where
             _: &mut BatchMutation<Self::Language>,
             _: ApplySuppression<Self::Language>,
             _: &str,
+            _: &str,
         ) {
             unreachable!("")
         }
@@ -73,7 +74,8 @@ where
     let mut analyzer = Analyzer::new(
         METADATA.deref(),
         InspectMatcher::new(registry, inspect_matcher),
-        |_| -> Vec<Result<_, Infallible>> { unreachable!() },
+        // Transformations can't be suppressed, comments are never parsed as suppressions
+        |_| -> Vec<Result<_, Infallible>> { Vec::new() },
         Box::new(TestAction),
         &mut emit_signal,
     );
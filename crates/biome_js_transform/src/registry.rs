@@ -1,3 +1,5 @@
+use crate::transformers::jsx::TransformJsx;
+use crate::transformers::strip_types::StripTypes;
 use crate::transformers::ts_enum::TsEnum;
 use biome_analyze::{GroupCategory, RegistryVisitor, RuleCategory, RuleGroup};
 use biome_js_syntax::JsLanguage;
@@ -20,7 +22,9 @@ impl RuleGroup for TransformationGroup {
     const NAME: &'static str = "transformations";
 
     fn record_rules<V: RegistryVisitor<Self::Language> + ?Sized>(registry: &mut V) {
+        registry.record_rule::<StripTypes>();
         registry.record_rule::<TsEnum>();
+        registry.record_rule::<TransformJsx>();
     }
 }
 
@@ -0,0 +1,640 @@
+use crate::transformers::replace_root;
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::options::JsxRuntime;
+use biome_analyze::{Ast, Rule};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsRoot, AnyJsxAttribute, AnyJsxAttributeName, AnyJsxAttributeValue,
+    AnyJsxChild, AnyJsxElementName, AnyJsxTag, JsFileSource, JsSyntaxKind, JsSyntaxNode,
+    JsxAttributeList, JsxChildList, JsxTagExpression,
+};
+use biome_rowan::{AstNode, AstNodeList, Direction, TextRange, WalkEvent};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+mod entities;
+
+declare_transformation! {
+    /// Transform the JSX elements and fragments into function calls.
+    ///
+    /// With the automatic runtime, the elements become calls to the `jsx` and `jsxs`
+    /// functions of `react/jsx-runtime`, which are imported at the top of the file.
+    /// Like Babel, an element with a `key` after a spread attribute, e.g.
+    /// `<div {...props} key="1" />`, becomes a call to the `createElement` function of
+    /// `react`, to keep the order of the properties. With the classic runtime, the
+    /// elements become calls to `React.createElement`.
+    ///
+    /// The runtime is the one of the `javascript.jsxRuntime` option: `transparent` selects
+    /// the automatic runtime, and `reactClassic` the classic one. The pragma comments of
+    /// the file take precedence over the configuration:
+    ///
+    /// - `@jsxRuntime classic` or `@jsxRuntime automatic` selects the runtime;
+    /// - `@jsx h` and `@jsxFrag Fragment` set the functions of the classic runtime;
+    /// - `@jsxImportSource preact` sets the module of the automatic runtime.
+    pub(crate) TransformJsx {
+        version: "next",
+        name: "transformJsx",
+        language: "jsx",
+    }
+}
+
+/// How the JSX elements are transformed
+#[derive(Debug)]
+pub enum JsxTransformRuntime {
+    /// `React.createElement("div", null, child)`
+    Classic {
+        /// The function that creates the elements, `React.createElement` by default
+        factory: String,
+        /// The component of the fragments, `React.Fragment` by default
+        fragment: String,
+    },
+    /// `_jsx("div", { children: child })`
+    Automatic {
+        /// The module that exports the `jsx-runtime`, `react` by default
+        import_source: String,
+    },
+}
+
+impl Rule for TransformJsx {
+    type Query = Ast<AnyJsRoot>;
+    type State = JsxTransformRuntime;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query().syntax();
+        if !root
+            .descendants()
+            .any(|node| node.kind() == JsSyntaxKind::JSX_TAG_EXPRESSION)
+        {
+            return None;
+        }
+
+        let pragmas = JsxPragmas::from_root(root);
+        let is_classic = match pragmas.runtime.as_deref() {
+            Some("classic") => true,
+            Some("automatic") => false,
+            _ if pragmas.import_source.is_some() => false,
+            _ if pragmas.factory.is_some() || pragmas.fragment.is_some() => true,
+            _ => ctx.jsx_runtime() == JsxRuntime::ReactClassic,
+        };
+        let runtime = if is_classic {
+            JsxTransformRuntime::Classic {
+                factory: pragmas
+                    .factory
+                    .unwrap_or_else(|| "React.createElement".to_string()),
+                fragment: pragmas
+                    .fragment
+                    .unwrap_or_else(|| "React.Fragment".to_string()),
+            }
+        } else {
+            JsxTransformRuntime::Automatic {
+                import_source: pragmas.import_source.unwrap_or_else(|| "react".to_string()),
+            }
+        };
+        Some(runtime)
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let root = ctx.query();
+        let source_type = *ctx.source_type::<JsFileSource>();
+        let code = root.syntax().to_string();
+
+        let mut printer = JsxPrinter {
+            code: &code,
+            runtime: state,
+            helpers: JsxHelpers::new(root.syntax()),
+        };
+        let mut output = String::with_capacity(code.len());
+        printer.print_node(root.syntax(), &mut output);
+
+        if let JsxTransformRuntime::Automatic { import_source } = state {
+            let import = printer
+                .helpers
+                .to_import(import_source, source_type.is_script());
+            // After the directives and the comments at the top of the file
+            let position = match root {
+                AnyJsRoot::JsModule(module) => module
+                    .items()
+                    .first()
+                    .map(|item| item.syntax().text_trimmed_range().start()),
+                AnyJsRoot::JsScript(script) => script
+                    .statements()
+                    .first()
+                    .map(|statement| statement.syntax().text_trimmed_range().start()),
+                AnyJsRoot::JsExpressionSnipped(_) => None,
+            }?;
+            // The JSX elements are only in the items, so the code before them is unchanged
+            output.insert_str(usize::from(position), &import);
+        }
+
+        replace_root(root, &output, source_type)
+    }
+}
+
+/// The pragma comments that configure the transformation
+#[derive(Debug, Default)]
+struct JsxPragmas {
+    runtime: Option<String>,
+    factory: Option<String>,
+    fragment: Option<String>,
+    import_source: Option<String>,
+}
+
+impl JsxPragmas {
+    fn from_root(root: &JsSyntaxNode) -> Self {
+        let mut pragmas = Self::default();
+        let comments = root
+            .descendants_tokens(Direction::Next)
+            .flat_map(|token| token.leading_trivia().pieces())
+            .filter_map(|piece| piece.as_comments());
+        for comment in comments {
+            let text = comment.text();
+            let mut rest = text;
+            while let Some(index) = rest.find("@jsx") {
+                rest = &rest[index + 1..];
+                let mut words = rest.split_whitespace();
+                let (Some(name), Some(value)) = (words.next(), words.next()) else {
+                    continue;
+                };
+                let value = value.trim_end_matches("*/").to_string();
+                if value.is_empty() {
+                    continue;
+                }
+                match name {
+                    "jsx" => pragmas.factory = Some(value),
+                    "jsxFrag" => pragmas.fragment = Some(value),
+                    "jsxRuntime" => pragmas.runtime = Some(value),
+                    "jsxImportSource" => pragmas.import_source = Some(value),
+                    _ => {}
+                }
+            }
+        }
+        pragmas
+    }
+}
+
+/// A function of the automatic runtime, imported with a local name
+#[derive(Debug)]
+struct JsxHelper {
+    /// The name exported by the runtime
+    name: &'static str,
+    /// The local name of the import, which doesn't collide with the names of the file
+    local_name: String,
+    is_used: bool,
+}
+
+impl JsxHelper {
+    /// Returns the first name among `_name`, `_name2`, `_name3`, etc. that isn't in `names`,
+    /// like the unique identifiers generated by Babel
+    fn new(name: &'static str, names: &HashSet<String>) -> Self {
+        let mut local_name = format!("_{name}");
+        let mut index = 2;
+        while names.contains(&local_name) {
+            local_name = format!("_{name}{index}");
+            index += 1;
+        }
+        Self {
+            name,
+            local_name,
+            is_used: false,
+        }
+    }
+
+    /// Marks the function as used, and returns its local name
+    fn use_name(&mut self) -> String {
+        self.is_used = true;
+        self.local_name.clone()
+    }
+}
+
+/// The functions of the automatic runtime used by the file
+#[derive(Debug)]
+struct JsxHelpers {
+    jsx: JsxHelper,
+    jsxs: JsxHelper,
+    fragment: JsxHelper,
+    /// `createElement`, exported by the module of the runtime itself
+    create_element: JsxHelper,
+}
+
+impl JsxHelpers {
+    fn new(root: &JsSyntaxNode) -> Self {
+        let names = root
+            .descendants_tokens(Direction::Next)
+            .filter(|token| matches!(token.kind(), JsSyntaxKind::IDENT | JsSyntaxKind::JSX_IDENT))
+            .map(|token| token.text_trimmed().to_string())
+            .collect();
+        Self {
+            jsx: JsxHelper::new("jsx", &names),
+            jsxs: JsxHelper::new("jsxs", &names),
+            fragment: JsxHelper::new("Fragment", &names),
+            create_element: JsxHelper::new("createElement", &names),
+        }
+    }
+
+    /// Returns the imports of the functions used by the file:
+    /// `import { jsx as _jsx } from "react/jsx-runtime";`
+    fn to_import(&self, import_source: &str, is_script: bool) -> String {
+        let runtime = [&self.jsx, &self.jsxs, &self.fragment];
+        let mut import = print_import(&runtime, &format!("{import_source}/jsx-runtime"), is_script);
+        import.push_str(&print_import(
+            &[&self.create_element],
+            import_source,
+            is_script,
+        ));
+        import
+    }
+}
+
+/// Returns the import of the `helpers` used by the file from `source`, if any
+fn print_import(helpers: &[&JsxHelper], source: &str, is_script: bool) -> String {
+    let separator = if is_script { ": " } else { " as " };
+    let specifiers = helpers
+        .iter()
+        .filter(|helper| helper.is_used)
+        .map(|helper| format!("{}{separator}{}", helper.name, helper.local_name))
+        .collect::<Vec<_>>();
+    if specifiers.is_empty() {
+        return String::new();
+    }
+    let specifiers = specifiers.join(", ");
+    let source = quote(source);
+    if is_script {
+        format!("const {{ {specifiers} }} = require({source});\n")
+    } else {
+        format!("import {{ {specifiers} }} from {source};\n")
+    }
+}
+
+struct JsxPrinter<'a> {
+    /// The code of the file
+    code: &'a str,
+    runtime: &'a JsxTransformRuntime,
+    helpers: JsxHelpers,
+}
+
+impl JsxPrinter<'_> {
+    /// Prints the code of `node`, with its JSX elements transformed
+    fn print_node(&mut self, node: &JsSyntaxNode, output: &mut String) {
+        let range = if node.parent().is_some() {
+            node.text_trimmed_range()
+        } else {
+            node.text_range()
+        };
+        let mut position = range.start();
+        let mut preorder = node.preorder();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(node) = event else {
+                continue;
+            };
+            let Some(tag) = JsxTagExpression::cast_ref(&node).and_then(|tag| tag.tag().ok()) else {
+                continue;
+            };
+            let tag_range = node.text_trimmed_range();
+            output.push_str(&self.code[TextRange::new(position, tag_range.start())]);
+            self.print_tag(&tag, output);
+            position = tag_range.end();
+            preorder.skip_subtree();
+        }
+        output.push_str(&self.code[TextRange::new(position, range.end())]);
+    }
+
+    fn print_expression(&mut self, expression: &AnyJsExpression) -> String {
+        let mut output = String::new();
+        self.print_node(expression.syntax(), &mut output);
+        output
+    }
+
+    fn print_tag(&mut self, tag: &AnyJsxTag, output: &mut String) {
+        let (element_type, attributes, children) = match tag {
+            AnyJsxTag::JsxElement(element) => {
+                let Ok(opening) = element.opening_element() else {
+                    return;
+                };
+                (
+                    opening.name().ok().map(|name| print_element_name(&name)),
+                    Some(opening.attributes()),
+                    Some(element.children()),
+                )
+            }
+            AnyJsxTag::JsxSelfClosingElement(element) => (
+                element.name().ok().map(|name| print_element_name(&name)),
+                Some(element.attributes()),
+                None,
+            ),
+            AnyJsxTag::JsxFragment(fragment) => (None, None, Some(fragment.children())),
+        };
+        let JsxProperties {
+            mut properties,
+            key,
+            has_key_after_spread,
+        } = match attributes {
+            Some(attributes) => self.print_attributes(&attributes),
+            None => JsxProperties::default(),
+        };
+        let children = match children {
+            Some(children) => self.print_children(&children),
+            None => Vec::new(),
+        };
+
+        match self.runtime {
+            JsxTransformRuntime::Classic { factory, fragment } => {
+                let element_type = element_type.unwrap_or_else(|| fragment.clone());
+                let mut arguments = vec![element_type, print_object(&properties, "null")];
+                arguments.extend(children.into_iter().map(|child| child.code));
+                write!(output, "{factory}({})", arguments.join(", ")).unwrap();
+            }
+            JsxTransformRuntime::Automatic { .. } => {
+                let element_type = element_type.unwrap_or_else(|| self.helpers.fragment.use_name());
+                if has_key_after_spread {
+                    let mut arguments = vec![element_type, print_object(&properties, "null")];
+                    arguments.extend(children.into_iter().map(|child| child.code));
+                    let function = self.helpers.create_element.use_name();
+                    write!(output, "{function}({})", arguments.join(", ")).unwrap();
+                    return;
+                }
+                let key = key.map(|(index, key)| {
+                    properties.remove(index);
+                    key
+                });
+                let is_static = children.len() > 1 || children.iter().any(|child| child.is_spread);
+                if is_static {
+                    let children: Vec<_> = children.into_iter().map(|child| child.code).collect();
+                    properties.push(format!("children: [{}]", children.join(", ")));
+                } else if let Some(child) = children.into_iter().next() {
+                    properties.push(format!("children: {}", child.code));
+                }
+                let function = if is_static {
+                    self.helpers.jsxs.use_name()
+                } else {
+                    self.helpers.jsx.use_name()
+                };
+                let mut arguments = vec![element_type, print_object(&properties, "{}")];
+                arguments.extend(key);
+                write!(output, "{function}({})", arguments.join(", ")).unwrap();
+            }
+        }
+    }
+
+    fn print_attributes(&mut self, attributes: &JsxAttributeList) -> JsxProperties {
+        let mut result = JsxProperties::default();
+        let mut has_spread = false;
+        for attribute in attributes {
+            match attribute {
+                AnyJsxAttribute::JsxAttribute(attribute) => {
+                    let Ok(name) = attribute.name() else {
+                        continue;
+                    };
+                    let name = match name {
+                        AnyJsxAttributeName::JsxName(name) => {
+                            let Ok(token) = name.value_token() else {
+                                continue;
+                            };
+                            let name = token.text_trimmed().to_string();
+                            if name.contains('-') {
+                                quote(&name)
+                            } else {
+                                name
+                            }
+                        }
+                        AnyJsxAttributeName::JsxNamespaceName(name) => {
+                            quote(&name.syntax().text_trimmed().to_string())
+                        }
+                    };
+                    let value = match attribute
+                        .initializer()
+                        .and_then(|initializer| initializer.value().ok())
+                    {
+                        None => "true".to_string(),
+                        Some(AnyJsxAttributeValue::JsxString(string)) => {
+                            let Ok(token) = string.value_token() else {
+                                continue;
+                            };
+                            let text = token.text_trimmed();
+                            quote(&decode_entities(&text[1..text.len() - 1]))
+                        }
+                        Some(AnyJsxAttributeValue::JsxExpressionAttributeValue(value)) => {
+                            let Ok(expression) = value.expression() else {
+                                continue;
+                            };
+                            self.print_expression(&expression)
+                        }
+                        Some(AnyJsxAttributeValue::AnyJsxTag(tag)) => {
+                            let mut output = String::new();
+                            self.print_tag(&tag, &mut output);
+                            output
+                        }
+                    };
+                    if name == "key" {
+                        if has_spread {
+                            result.has_key_after_spread = true;
+                        } else {
+                            result.key = Some((result.properties.len(), value.clone()));
+                        }
+                    }
+                    result.properties.push(format!("{name}: {value}"));
+                }
+                AnyJsxAttribute::JsxSpreadAttribute(attribute) => {
+                    let Ok(argument) = attribute.argument() else {
+                        continue;
+                    };
+                    has_spread = true;
+                    result
+                        .properties
+                        .push(format!("...{}", self.print_expression(&argument)));
+                }
+            }
+        }
+        result
+    }
+
+    fn print_children(&mut self, children: &JsxChildList) -> Vec<JsxChild> {
+        let mut result = Vec::new();
+        for child in children {
+            let child = match child {
+                AnyJsxChild::JsxText(text) => {
+                    let Ok(token) = text.value_token() else {
+                        continue;
+                    };
+                    let text = clean_text(token.text());
+                    if text.is_empty() {
+                        continue;
+                    }
+                    JsxChild::new(quote(&decode_entities(&text)))
+                }
+                AnyJsxChild::JsxExpressionChild(child) => {
+                    // `{/* comment */}` has no expression
+                    let Some(expression) = child.expression() else {
+                        continue;
+                    };
+                    JsxChild::new(self.print_expression(&expression))
+                }
+                AnyJsxChild::JsxSpreadChild(child) => {
+                    let Ok(expression) = child.expression() else {
+                        continue;
+                    };
+                    JsxChild {
+                        code: format!("...{}", self.print_expression(&expression)),
+                        is_spread: true,
+                    }
+                }
+                AnyJsxChild::JsxElement(element) => {
+                    let mut output = String::new();
+                    self.print_tag(&AnyJsxTag::JsxElement(element), &mut output);
+                    JsxChild::new(output)
+                }
+                AnyJsxChild::JsxSelfClosingElement(element) => {
+                    let mut output = String::new();
+                    self.print_tag(&AnyJsxTag::JsxSelfClosingElement(element), &mut output);
+                    JsxChild::new(output)
+                }
+                AnyJsxChild::JsxFragment(fragment) => {
+                    let mut output = String::new();
+                    self.print_tag(&AnyJsxTag::JsxFragment(fragment), &mut output);
+                    JsxChild::new(output)
+                }
+            };
+            result.push(child);
+        }
+        result
+    }
+}
+
+/// The properties printed from the attributes of an element
+#[derive(Default)]
+struct JsxProperties {
+    properties: Vec<String>,
+    /// The index of the `key` property and its value, which the automatic runtime passes
+    /// as an argument
+    key: Option<(usize, String)>,
+    /// A `key` follows a spread attribute, e.g. `<div {...props} key="1" />`
+    has_key_after_spread: bool,
+}
+
+struct JsxChild {
+    code: String,
+    /// `{...children}`
+    is_spread: bool,
+}
+
+impl JsxChild {
+    fn new(code: String) -> Self {
+        Self {
+            code,
+            is_spread: false,
+        }
+    }
+}
+
+/// The intrinsic elements are strings, the components are references
+fn print_element_name(name: &AnyJsxElementName) -> String {
+    match name {
+        AnyJsxElementName::JsxName(_) | AnyJsxElementName::JsxNamespaceName(_) => {
+            quote(&name.syntax().text_trimmed().to_string())
+        }
+        AnyJsxElementName::JsxReferenceIdentifier(_) | AnyJsxElementName::JsxMemberName(_) => {
+            name.syntax().text_trimmed().to_string()
+        }
+    }
+}
+
+fn print_object(properties: &[String], empty: &str) -> String {
+    if properties.is_empty() {
+        empty.to_string()
+    } else {
+        format!("{{ {} }}", properties.join(", "))
+    }
+}
+
+/// Removes the whitespace of a JSX text the way React does: the lines are trimmed,
+/// the empty lines are removed, and the remaining lines are joined with a space
+fn clean_text(text: &str) -> String {
+    let lines: Vec<_> = text
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+    let last_non_empty_line = lines
+        .iter()
+        .rposition(|line| line.contains(|c: char| c != ' ' && c != '\t'))
+        .unwrap_or_default();
+    let mut result = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let is_first_line = index == 0;
+        let is_last_line = index == lines.len() - 1;
+        let mut line = *line;
+        if !is_first_line {
+            line = line.trim_start_matches([' ', '\t']);
+        }
+        if !is_last_line {
+            line = line.trim_end_matches([' ', '\t']);
+        }
+        if line.is_empty() {
+            continue;
+        }
+        result.push_str(&line.replace('\t', " "));
+        if index != last_non_empty_line {
+            result.push(' ');
+        }
+    }
+    result
+}
+
+/// Decodes the HTML entities of a JSX text, e.g. `&amp;`, `&hellip;` or `&#123;`
+fn decode_entities(text: &str) -> Cow<str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let character = match entity.strip_prefix('#') {
+                Some(code) => code
+                    .strip_prefix(['x', 'X'])
+                    .map_or_else(
+                        || code.parse().ok(),
+                        |hex| u32::from_str_radix(hex, 16).ok(),
+                    )
+                    .and_then(char::from_u32),
+                None => entities::named_entity(entity),
+            };
+            character.map(|character| (character, end))
+        });
+        match decoded {
+            Some((character, end)) => {
+                result.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    Cow::Owned(result)
+}
+
+/// Returns `text` as a JavaScript string literal
+fn quote(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\u{2028}' => result.push_str("\\u2028"),
+            '\u{2029}' => result.push_str("\\u2029"),
+            character => result.push(character),
+        }
+    }
+    result.push('"');
+    result
+}
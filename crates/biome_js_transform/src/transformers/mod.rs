@@ -1 +1,27 @@
+use crate::JsBatchMutation;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::{AnyJsRoot, JsFileSource};
+use biome_rowan::BatchMutationExt;
+
+pub(crate) mod jsx;
+pub(crate) mod strip_types;
 pub(crate) mod ts_enum;
+
+/// Replaces the whole tree with the parsed `code`, for the transformations that rewrite the text of the file.
+///
+/// The code is parsed with the source type of the file, so that the constructs that a transformation
+/// leaves untouched can be handled by the other transformations. Returns [None] if `code` doesn't parse.
+pub(crate) fn replace_root(
+    root: &AnyJsRoot,
+    code: &str,
+    source_type: JsFileSource,
+) -> Option<JsBatchMutation> {
+    let parsed = parse(code, source_type, JsParserOptions::default());
+    if parsed.has_errors() {
+        return None;
+    }
+
+    let mut mutation = root.clone().begin();
+    mutation.replace_node(root.clone(), parsed.tree());
+    Some(mutation)
+}
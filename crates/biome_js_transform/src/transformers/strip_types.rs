@@ -0,0 +1,535 @@
+use crate::transformers::replace_root;
+use crate::{declare_transformation, JsBatchMutation};
+use biome_analyze::context::RuleContext;
+use biome_analyze::{Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_diagnostics::category;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsExportClause, AnyJsImportClause, AnyJsRoot, AnyTsType,
+    JsArrowFunctionExpression, JsClassDeclaration, JsClassExportDefaultDeclaration, JsExport,
+    JsExportNamedFromSpecifier, JsExportNamedShorthandSpecifier, JsExportNamedSpecifier,
+    JsFileSource, JsFormalParameter, JsImport, JsMethodClassMember, JsNamedImportSpecifier,
+    JsShorthandNamedImportSpecifier, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TsEnumDeclaration,
+    TsImportEqualsDeclaration, TsModuleDeclaration, T,
+};
+use biome_rowan::{AstNode, TextRange, TextSize, WalkEvent};
+
+declare_transformation! {
+    /// Strip the TypeScript syntax from a file, so that it can run as JavaScript.
+    ///
+    /// The type annotations, the type declarations, the type-only imports and exports,
+    /// the `declare` statements, the non-null assertions and the `as` and `satisfies`
+    /// expressions are replaced with whitespace, so the lines and the columns of the code don't change.
+    ///
+    /// The TypeScript constructs that have a runtime behavior can't be erased: the enums,
+    /// the namespaces that contain values, the parameter properties, `import x = require()`
+    /// and `export =`. They are left untouched and reported.
+    pub(crate) StripTypes {
+        version: "next",
+        name: "stripTypes",
+        language: "ts",
+    }
+}
+
+#[derive(Debug)]
+pub enum StripTypesState {
+    /// The TypeScript syntax to replace with whitespace
+    Erase(ErasedSyntax),
+    /// A TypeScript construct that can't be erased
+    Unsupported(UnsupportedSyntax, TextRange),
+}
+
+#[derive(Debug, Default)]
+pub struct ErasedSyntax {
+    /// The ranges of the code that are replaced with whitespace
+    ranges: Vec<TextRange>,
+    /// The characters that are written over the whitespace, to keep the meaning of the code
+    characters: Vec<(TextSize, u8)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum UnsupportedSyntax {
+    Enum,
+    Namespace,
+    ParameterProperty,
+    ImportEquals,
+    ExportAssignment,
+}
+
+impl Rule for StripTypes {
+    type Query = Ast<AnyJsRoot>;
+    type State = StripTypesState;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let language = ctx.source_type::<JsFileSource>().language();
+        if !language.is_typescript() || language.is_definition_file() {
+            return Vec::new();
+        }
+
+        let root = ctx.query().syntax();
+        let mut visitor = TypeStripping::new(root.to_string());
+        let mut preorder = root.preorder();
+        while let Some(event) = preorder.next() {
+            if let WalkEvent::Enter(node) = event {
+                if !visitor.visit(&node) {
+                    preorder.skip_subtree();
+                }
+            }
+        }
+
+        let mut signals: Vec<_> = visitor
+            .unsupported
+            .into_iter()
+            .map(|(syntax, range)| StripTypesState::Unsupported(syntax, range))
+            .collect();
+        if !visitor.erased.ranges.is_empty() {
+            signals.push(StripTypesState::Erase(visitor.erased));
+        }
+        signals
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let StripTypesState::Unsupported(syntax, range) = state else {
+            return None;
+        };
+        let diagnostic = match syntax {
+            UnsupportedSyntax::Enum => RuleDiagnostic::new(
+                category!("transform"),
+                range,
+                markup! {
+                    "This enum can't be erased because it has a runtime behavior."
+                },
+            )
+            .note(markup! {
+                "Apply the "<Emphasis>"transformEnum"</Emphasis>" transformation too, or replace the enum with an object."
+            }),
+            UnsupportedSyntax::Namespace => RuleDiagnostic::new(
+                category!("transform"),
+                range,
+                markup! {
+                    "This namespace can't be erased because it contains values."
+                },
+            )
+            .note(markup! {
+                "Replace the namespace with an object or with a module."
+            }),
+            UnsupportedSyntax::ParameterProperty => RuleDiagnostic::new(
+                category!("transform"),
+                range,
+                markup! {
+                    "This parameter property can't be erased because it assigns a property of the class."
+                },
+            )
+            .note(markup! {
+                "Declare the property in the body of the class, and assign it in the constructor."
+            }),
+            UnsupportedSyntax::ImportEquals => RuleDiagnostic::new(
+                category!("transform"),
+                range,
+                markup! {
+                    "This "<Emphasis>"import ="</Emphasis>" declaration can't be erased because it imports a value."
+                },
+            )
+            .note(markup! {
+                "Use an "<Emphasis>"import"</Emphasis>" declaration instead."
+            }),
+            UnsupportedSyntax::ExportAssignment => RuleDiagnostic::new(
+                category!("transform"),
+                range,
+                markup! {
+                    "This "<Emphasis>"export ="</Emphasis>" assignment can't be erased because it exports a value."
+                },
+            )
+            .note(markup! {
+                "Use an "<Emphasis>"export default"</Emphasis>" declaration instead."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let StripTypesState::Erase(erased) = state else {
+            return None;
+        };
+        let root = ctx.query();
+        let mut code = root.syntax().to_string().into_bytes();
+        for range in &erased.ranges {
+            for byte in &mut code[usize::from(range.start())..usize::from(range.end())] {
+                // Keeping the line breaks keeps the lines of the code
+                if !matches!(byte, b'\n' | b'\r') {
+                    *byte = b' ';
+                }
+            }
+        }
+        for (position, character) in &erased.characters {
+            code[usize::from(*position)] = *character;
+        }
+        // The bytes of the multi-byte characters are all replaced, so the code stays valid UTF-8
+        let code = String::from_utf8(code).ok()?;
+
+        replace_root(root, &code, *ctx.source_type::<JsFileSource>())
+    }
+}
+
+/// Collects the TypeScript syntax of a file
+struct TypeStripping {
+    /// The code of the file
+    code: String,
+    erased: ErasedSyntax,
+    unsupported: Vec<(UnsupportedSyntax, TextRange)>,
+}
+
+impl TypeStripping {
+    fn new(code: String) -> Self {
+        Self {
+            code,
+            erased: ErasedSyntax::default(),
+            unsupported: Vec::new(),
+        }
+    }
+
+    /// Collects the syntax to erase from `node`, and returns `false` when the
+    /// descendants of `node` don't need to be visited
+    fn visit(&mut self, node: &JsSyntaxNode) -> bool {
+        match node.kind() {
+            JsSyntaxKind::TS_TYPE_ANNOTATION
+            | JsSyntaxKind::TS_RETURN_TYPE_ANNOTATION
+            | JsSyntaxKind::TS_TYPE_PARAMETERS
+            | JsSyntaxKind::TS_TYPE_ARGUMENTS
+            | JsSyntaxKind::TS_IMPLEMENTS_CLAUSE
+            | JsSyntaxKind::TS_DEFINITE_VARIABLE_ANNOTATION
+            | JsSyntaxKind::TS_DEFINITE_PROPERTY_ANNOTATION
+            | JsSyntaxKind::TS_OPTIONAL_PROPERTY_ANNOTATION
+            | JsSyntaxKind::TS_ACCESSIBILITY_MODIFIER
+            | JsSyntaxKind::TS_READONLY_MODIFIER
+            | JsSyntaxKind::TS_OVERRIDE_MODIFIER => {
+                self.erase(node.text_trimmed_range());
+                false
+            }
+            JsSyntaxKind::TS_INTERFACE_DECLARATION
+            | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+            | JsSyntaxKind::TS_DECLARE_STATEMENT
+            | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+            | JsSyntaxKind::TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION
+            | JsSyntaxKind::TS_EXTERNAL_MODULE_DECLARATION
+            | JsSyntaxKind::TS_GLOBAL_DECLARATION
+            | JsSyntaxKind::TS_EXPORT_DECLARE_CLAUSE
+            | JsSyntaxKind::TS_EXPORT_AS_NAMESPACE_CLAUSE
+            | JsSyntaxKind::TS_PROPERTY_SIGNATURE_CLASS_MEMBER
+            | JsSyntaxKind::TS_INITIALIZED_PROPERTY_SIGNATURE_CLASS_MEMBER
+            | JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER
+            | JsSyntaxKind::TS_GETTER_SIGNATURE_CLASS_MEMBER
+            | JsSyntaxKind::TS_SETTER_SIGNATURE_CLASS_MEMBER
+            | JsSyntaxKind::TS_INDEX_SIGNATURE_CLASS_MEMBER
+            | JsSyntaxKind::TS_CONSTRUCTOR_SIGNATURE_CLASS_MEMBER => {
+                self.erase_statement(node);
+                false
+            }
+            JsSyntaxKind::TS_MODULE_DECLARATION => {
+                let Some(module) = TsModuleDeclaration::cast_ref(node) else {
+                    return false;
+                };
+                if is_instantiated(&module) {
+                    // `namespace Name`, without the body
+                    let range = match module.name() {
+                        Ok(name) => TextRange::new(
+                            node.text_trimmed_range().start(),
+                            name.syntax().text_trimmed_range().end(),
+                        ),
+                        Err(_) => node.text_trimmed_range(),
+                    };
+                    self.unsupported.push((UnsupportedSyntax::Namespace, range));
+                } else {
+                    self.erase_statement(node);
+                }
+                false
+            }
+            JsSyntaxKind::TS_ENUM_DECLARATION => {
+                // `enum Name`, without the members
+                let range = match TsEnumDeclaration::cast_ref(node).map(|e| e.id()) {
+                    Some(Ok(id)) => TextRange::new(
+                        node.text_trimmed_range().start(),
+                        id.syntax().text_trimmed_range().end(),
+                    ),
+                    _ => node.text_trimmed_range(),
+                };
+                self.unsupported.push((UnsupportedSyntax::Enum, range));
+                false
+            }
+            JsSyntaxKind::TS_IMPORT_EQUALS_DECLARATION => {
+                let is_type_only = TsImportEqualsDeclaration::cast_ref(node)
+                    .is_some_and(|declaration| declaration.type_token().is_some());
+                if is_type_only {
+                    self.erase_statement(node);
+                } else {
+                    self.unsupported
+                        .push((UnsupportedSyntax::ImportEquals, node.text_trimmed_range()));
+                }
+                false
+            }
+            JsSyntaxKind::TS_EXPORT_ASSIGNMENT_CLAUSE => {
+                let range = node.parent().map_or(node.text_trimmed_range(), |export| {
+                    export.text_trimmed_range()
+                });
+                self.unsupported
+                    .push((UnsupportedSyntax::ExportAssignment, range));
+                false
+            }
+            JsSyntaxKind::TS_PROPERTY_PARAMETER => {
+                self.unsupported.push((
+                    UnsupportedSyntax::ParameterProperty,
+                    node.text_trimmed_range(),
+                ));
+                true
+            }
+            // The modifiers of a parameter property are kept, because the parameter property is reported
+            JsSyntaxKind::TS_PROPERTY_PARAMETER_MODIFIER_LIST => false,
+            JsSyntaxKind::TS_THIS_PARAMETER => {
+                self.erase_list_element(node);
+                false
+            }
+            JsSyntaxKind::TS_AS_EXPRESSION
+            | JsSyntaxKind::TS_SATISFIES_EXPRESSION
+            | JsSyntaxKind::TS_AS_ASSIGNMENT
+            | JsSyntaxKind::TS_SATISFIES_ASSIGNMENT => {
+                // `value as Type`, only the expression is kept
+                if let Some(expression) = node.first_child() {
+                    self.erase(TextRange::new(
+                        expression.text_trimmed_range().end(),
+                        node.text_trimmed_range().end(),
+                    ));
+                }
+                true
+            }
+            JsSyntaxKind::TS_NON_NULL_ASSERTION_EXPRESSION
+            | JsSyntaxKind::TS_NON_NULL_ASSERTION_ASSIGNMENT => {
+                if let Some(token) = node.last_token() {
+                    self.erase_token(&token);
+                }
+                true
+            }
+            JsSyntaxKind::TS_TYPE_ASSERTION_EXPRESSION
+            | JsSyntaxKind::TS_TYPE_ASSERTION_ASSIGNMENT => {
+                // `<Type>value`, only the expression is kept
+                if let Some(expression) = node.last_child() {
+                    self.erase(TextRange::new(
+                        node.text_trimmed_range().start(),
+                        expression.text_trimmed_range().start(),
+                    ));
+                }
+                true
+            }
+            JsSyntaxKind::JS_IMPORT => {
+                let is_type_only = JsImport::cast_ref(node)
+                    .and_then(|import| import.import_clause().ok())
+                    .is_some_and(|clause| match clause {
+                        AnyJsImportClause::JsImportNamedClause(clause) => {
+                            clause.type_token().is_some()
+                        }
+                        AnyJsImportClause::JsImportDefaultClause(clause) => {
+                            clause.type_token().is_some()
+                        }
+                        AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                            clause.type_token().is_some()
+                        }
+                        AnyJsImportClause::JsImportBareClause(_)
+                        | AnyJsImportClause::JsImportCombinedClause(_) => false,
+                    });
+                if is_type_only {
+                    self.erase_statement(node);
+                    return false;
+                }
+                true
+            }
+            JsSyntaxKind::JS_EXPORT => {
+                if JsExport::cast_ref(node).is_some_and(|export| is_type_only_export(&export)) {
+                    self.erase_statement(node);
+                    return false;
+                }
+                true
+            }
+            JsSyntaxKind::JS_NAMED_IMPORT_SPECIFIER
+            | JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER
+            | JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER
+            | JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+            | JsSyntaxKind::JS_EXPORT_NAMED_FROM_SPECIFIER => {
+                if specifier_type_token(node).is_some() {
+                    self.erase_list_element(node);
+                }
+                false
+            }
+            JsSyntaxKind::JS_FORMAL_PARAMETER => {
+                if let Some(token) =
+                    JsFormalParameter::cast_ref(node).and_then(|p| p.question_mark_token())
+                {
+                    self.erase_token(&token);
+                }
+                true
+            }
+            JsSyntaxKind::JS_METHOD_CLASS_MEMBER => {
+                if let Some(token) =
+                    JsMethodClassMember::cast_ref(node).and_then(|m| m.question_mark_token())
+                {
+                    self.erase_token(&token);
+                }
+                true
+            }
+            JsSyntaxKind::JS_CLASS_DECLARATION => {
+                if let Some(token) =
+                    JsClassDeclaration::cast_ref(node).and_then(|c| c.abstract_token())
+                {
+                    self.erase_token(&token);
+                }
+                true
+            }
+            JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => {
+                if let Some(token) =
+                    JsClassExportDefaultDeclaration::cast_ref(node).and_then(|c| c.abstract_token())
+                {
+                    self.erase_token(&token);
+                }
+                true
+            }
+            JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION => {
+                if let Some(arrow) = JsArrowFunctionExpression::cast_ref(node) {
+                    self.keep_arrow_on_parameters_line(&arrow);
+                }
+                true
+            }
+            // The types are only visited inside expressions that are already erased
+            kind => !AnyTsType::can_cast(kind),
+        }
+    }
+
+    /// Erases a `=>` return type that contains a line break:
+    /// `(a): \n T => a` would become `(a) \n => a`, which is invalid,
+    /// so the `)` is moved before the `=>` instead.
+    fn keep_arrow_on_parameters_line(&mut self, arrow: &JsArrowFunctionExpression) {
+        let (Some(annotation), Ok(AnyJsArrowFunctionParameters::JsParameters(parameters))) =
+            (arrow.return_type_annotation(), arrow.parameters())
+        else {
+            return;
+        };
+        let (Ok(r_paren), Ok(fat_arrow)) = (parameters.r_paren_token(), arrow.fat_arrow_token())
+        else {
+            return;
+        };
+        let range = TextRange::new(
+            r_paren.text_trimmed_range().end(),
+            fat_arrow.text_trimmed_range().start(),
+        );
+        if !self.code[range].contains(['\n', '\r']) {
+            return;
+        }
+        self.erase_token(&r_paren);
+        let annotation_end = annotation.syntax().text_trimmed_range().end();
+        self.erased
+            .characters
+            .push((annotation_end - TextSize::from(1), b')'));
+    }
+
+    fn erase(&mut self, range: TextRange) {
+        self.erased.ranges.push(range);
+    }
+
+    fn erase_token(&mut self, token: &JsSyntaxToken) {
+        self.erase(token.text_trimmed_range());
+    }
+
+    /// Erases a statement or a class member. A `;` is written in its place,
+    /// so that the erased code doesn't join the previous and the next statements
+    fn erase_statement(&mut self, node: &JsSyntaxNode) {
+        let mut node = node.clone();
+        while let Some(parent) = node.parent().filter(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_EXPORT | JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+            )
+        }) {
+            node = parent;
+        }
+        let range = node.text_trimmed_range();
+        self.erase(range);
+        self.erased.characters.push((range.start(), b';'));
+    }
+
+    /// Erases an element of a separated list, along with its trailing comma
+    fn erase_list_element(&mut self, node: &JsSyntaxNode) {
+        let mut range = node.text_trimmed_range();
+        if let Some(comma) = node
+            .next_sibling_or_token()
+            .and_then(|element| element.into_token())
+            .filter(|token| token.kind() == T![,])
+        {
+            range = range.cover(comma.text_trimmed_range());
+        }
+        self.erase(range);
+    }
+}
+
+/// Returns `true` if the namespace contains values, and can't be erased
+fn is_instantiated(module: &TsModuleDeclaration) -> bool {
+    let Ok(body) = module.body() else {
+        return false;
+    };
+    body.items()
+        .into_iter()
+        .any(|item| !is_type_only_statement(item.syntax()))
+}
+
+fn is_type_only_statement(node: &JsSyntaxNode) -> bool {
+    match node.kind() {
+        JsSyntaxKind::TS_INTERFACE_DECLARATION
+        | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_STATEMENT
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+        | JsSyntaxKind::TS_EXPORT_DECLARE_CLAUSE
+        | JsSyntaxKind::JS_EMPTY_STATEMENT => true,
+        JsSyntaxKind::TS_MODULE_DECLARATION => {
+            TsModuleDeclaration::cast_ref(node).is_some_and(|module| !is_instantiated(&module))
+        }
+        JsSyntaxKind::TS_IMPORT_EQUALS_DECLARATION => TsImportEqualsDeclaration::cast_ref(node)
+            .is_some_and(|declaration| declaration.type_token().is_some()),
+        JsSyntaxKind::JS_EXPORT => JsExport::cast_ref(node).is_some_and(|export| {
+            is_type_only_export(&export)
+                || export
+                    .export_clause()
+                    .is_ok_and(|clause| is_type_only_statement(clause.syntax()))
+        }),
+        _ => false,
+    }
+}
+
+/// Returns `true` for `export type { A }`, `export type { A } from "mod"` and `export type * from "mod"`
+fn is_type_only_export(export: &JsExport) -> bool {
+    match export.export_clause() {
+        Ok(AnyJsExportClause::JsExportNamedClause(clause)) => clause.type_token().is_some(),
+        Ok(AnyJsExportClause::JsExportNamedFromClause(clause)) => clause.type_token().is_some(),
+        Ok(AnyJsExportClause::JsExportFromClause(clause)) => clause.type_token().is_some(),
+        _ => false,
+    }
+}
+
+/// Returns the `type` token of `import { type A }` and `export { type A }`
+fn specifier_type_token(node: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    match node.kind() {
+        JsSyntaxKind::JS_NAMED_IMPORT_SPECIFIER => {
+            JsNamedImportSpecifier::cast_ref(node)?.type_token()
+        }
+        JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER => {
+            JsShorthandNamedImportSpecifier::cast_ref(node)?.type_token()
+        }
+        JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER => {
+            JsExportNamedSpecifier::cast_ref(node)?.type_token()
+        }
+        JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER => {
+            JsExportNamedShorthandSpecifier::cast_ref(node)?.type_token()
+        }
+        JsSyntaxKind::JS_EXPORT_NAMED_FROM_SPECIFIER => {
+            JsExportNamedFromSpecifier::cast_ref(node)?.type_token()
+        }
+        _ => None,
+    }
+}
@@ -16,9 +16,9 @@ use biome_js_syntax::{
     AnyJsExpression, AnyJsFormalParameter, AnyJsLiteralExpression, AnyJsModuleItem, AnyJsParameter,
     AnyJsStatement, JsAssignmentExpression, JsComputedMemberAssignment, JsExpressionStatement,
     JsFunctionExpression, JsInitializerClause, JsLogicalExpression, JsModuleItemList,
-    JsStatementList, JsVariableStatement, TsEnumDeclaration, T,
+    JsStatementList, JsSyntaxKind, JsSyntaxToken, JsVariableStatement, TsEnumDeclaration, T,
 };
-use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
 
 declare_transformation! {
     /// Transform a TypeScript [TsEnumDeclaration]
@@ -58,22 +58,27 @@ impl Rule for TsEnum {
 
     fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
         let node = ctx.query();
+        let module_list = JsModuleItemList::cast(node.syntax().parent()?)?;
+        let leading_trivia = node.syntax().first_leading_trivia()?;
         let mut mutation = node.clone().begin();
-        let parent = node.syntax().parent();
-        if let Some(parent) = parent {
-            if let Some(module_list) = JsModuleItemList::cast(parent) {
-                let variable = make_variable(state);
-                let function = make_function_caller(state);
-                let statements = vec![
-                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsVariableStatement(variable)),
-                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsExpressionStatement(
-                        function,
-                    )),
-                ];
-                let new_modules_list = js_module_item_list(statements);
-                mutation.replace_node(module_list, new_modules_list);
+
+        let mut items = Vec::with_capacity(module_list.len() + 1);
+        for item in module_list.iter() {
+            if item.syntax() != node.syntax() {
+                items.push(item);
+                continue;
             }
+            let variable =
+                make_variable(state).with_leading_trivia_pieces(leading_trivia.pieces())?;
+            let function = make_function_caller(state);
+            items.push(AnyJsModuleItem::AnyJsStatement(
+                AnyJsStatement::JsVariableStatement(variable),
+            ));
+            items.push(AnyJsModuleItem::AnyJsStatement(
+                AnyJsStatement::JsExpressionStatement(function),
+            ));
         }
+        mutation.replace_node(module_list, js_module_item_list(items));
 
         Some(mutation)
     }
@@ -105,7 +110,7 @@ fn make_variable(node: &TsEnumMembers) -> JsVariableStatement {
 
 fn make_function_caller(node: &TsEnumMembers) -> JsExpressionStatement {
     let callee = js_parenthesized_expression(
-        token(T!['(']),
+        token(T!['(']).with_leading_trivia([(TriviaPieceKind::Newline, "\n")]),
         AnyJsExpression::JsFunctionExpression(make_function(node)),
         token(T![')']),
     );
@@ -142,15 +147,24 @@ fn make_function(node: &TsEnumMembers) -> JsFunctionExpression {
         )],
         [],
     );
-    let parameters = js_parameters(token(T!['(']), parameters_list, token(T![')']));
+    let parameters = js_parameters(
+        token(T!['(']),
+        parameters_list,
+        token(T![')']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    );
 
     let body = js_function_body(
         token(T!['{']),
         js_directive_list([]),
         make_members(node),
-        token(T!['}']),
+        token(T!['}']).with_leading_trivia([(TriviaPieceKind::Newline, "\n")]),
     );
-    js_function_expression(token(T![function]), parameters, body).build()
+    js_function_expression(
+        token(T![function]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        parameters,
+        body,
+    )
+    .build()
 }
 
 fn make_members(ts_enum: &TsEnumMembers) -> JsStatementList {
@@ -181,7 +195,7 @@ fn make_logical_expression(node: &TsEnumMembers) -> JsLogicalExpression {
         AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsIdentifierAssignment(
             js_identifier_assignment(ident(node.name.as_str())),
         )),
-        token(T![=]),
+        spaced_token(T![=]),
         AnyJsExpression::JsObjectExpression(js_object_expression(
             token(T!['{']),
             js_object_member_list([], []),
@@ -197,7 +211,7 @@ fn make_logical_expression(node: &TsEnumMembers) -> JsLogicalExpression {
 
     js_logical_expression(
         AnyJsExpression::JsIdentifierExpression(left),
-        token(T![||]),
+        spaced_token(T![||]),
         AnyJsExpression::JsParenthesizedExpression(right),
     )
 }
@@ -209,7 +223,10 @@ fn make_high_order_assignment(
 ) -> JsExpressionStatement {
     let left = js_computed_member_assignment(
         AnyJsExpression::JsIdentifierExpression(js_identifier_expression(js_reference_identifier(
-            ident(enum_name),
+            ident(enum_name).with_leading_trivia([
+                (TriviaPieceKind::Newline, "\n"),
+                (TriviaPieceKind::Whitespace, "\t"),
+            ]),
         ))),
         token(T!['[']),
         AnyJsExpression::JsAssignmentExpression(make_assignment_expression_from_member(
@@ -223,7 +240,7 @@ fn make_high_order_assignment(
 
     let expression = js_assignment_expression(
         AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsComputedMemberAssignment(left)),
-        token(T![=]),
+        spaced_token(T![=]),
         AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
             right,
         )),
@@ -247,7 +264,7 @@ fn make_assignment_expression_from_member(
 
     js_assignment_expression(
         AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsComputedMemberAssignment(left)),
-        token(T![=]),
+        spaced_token(T![=]),
         member_value,
     )
 }
@@ -271,3 +288,10 @@ fn make_computed_member_assignment(
         token(T![']']),
     )
 }
+
+/// Creates a token surrounded by whitespace, e.g. ` = `
+fn spaced_token(kind: JsSyntaxKind) -> JsSyntaxToken {
+    token(kind)
+        .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
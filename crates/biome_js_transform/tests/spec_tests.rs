@@ -1,6 +1,4 @@
 use biome_analyze::{AnalysisFilter, AnalyzerTransformation, ControlFlow, Never, RuleFilter};
-use biome_js_formatter::context::JsFormatOptions;
-use biome_js_formatter::format_node;
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_rowan::AstNode;
@@ -106,6 +104,13 @@ pub(crate) fn analyze_and_snap(
     let mut transformations = vec![];
     let (_, errors) =
         biome_js_transform::transform(&root, filter, &options, source_type, |event| {
+            if let Some(diagnostic) = event.diagnostic() {
+                diagnostics.push(diagnostic_to_string(
+                    file_name,
+                    input_code,
+                    diagnostic.into(),
+                ));
+            }
             for transformation in event.transformations() {
                 check_transformation(
                     input_file,
@@ -114,11 +119,10 @@ pub(crate) fn analyze_and_snap(
                     &transformation,
                     parser_options.clone(),
                 );
+                // The transformations are snapshotted as they are emitted, because
+                // formatting the code would hide the changes to the whitespace
                 let node = transformation.mutation.commit();
-
-                let formatted = format_node(JsFormatOptions::new(source_type), &node).unwrap();
-
-                transformations.push(formatted.print().unwrap().as_code().to_string());
+                transformations.push(node.to_string());
             }
             ControlFlow::<Never>::Continue(())
         });
@@ -130,6 +134,7 @@ pub(crate) fn analyze_and_snap(
     write_transformation_snapshot(
         snapshot,
         input_code,
+        diagnostics.as_slice(),
         transformations.as_slice(),
         source_type.file_extension(),
     );
@@ -0,0 +1,16 @@
+let count: number = 0;
+let name!: string;
+const ids: Array<string> = [];
+
+function sum<T extends number>(this: Window, a: T, b?: number): number {
+	return a + (b ?? 0);
+}
+
+const double = <T,>(value: T): T => value;
+
+const parse = (text: string):
+	Record<string, unknown> => JSON.parse(text);
+
+try {
+	sum(1);
+} catch (error: unknown) {}
@@ -0,0 +1,13 @@
+abstract class Shape implements Drawable, Serializable {
+	declare id: string;
+	private readonly sides: number = 0;
+	protected static count?: number;
+	public override name!: string;
+	[key: string]: unknown;
+	abstract area(): number;
+	draw(): void;
+	draw(context?: Context): void {}
+	optional?(): void {}
+}
+
+export default abstract class extends Base<Shape> {}
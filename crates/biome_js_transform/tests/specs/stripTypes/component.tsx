@@ -0,0 +1,5 @@
+type Props = { title: string };
+
+export function Title({ title }: Props) {
+	return <h1 className={title as string}>{title}</h1>;
+}
@@ -0,0 +1,24 @@
+import { run } from "./run";
+interface Options {
+	verbose: boolean;
+}
+type Mode = "fast" | "slow"
+declare const VERSION: string;
+declare global {
+	interface Window {
+		options: Options;
+	}
+}
+namespace Types {
+	export type Id = string;
+}
+export type { Options };
+export interface Exported {}
+export declare function debug(message: string): void;
+function overloaded(value: string): string;
+function overloaded(value: number): number;
+function overloaded(value: unknown) {
+	return value;
+}
+type Hazard = string
+;[1, 2].forEach(run)
@@ -0,0 +1,8 @@
+const element = document.getElementById("root")!;
+const config = { port: 3000 } satisfies Config;
+const value = (input as unknown) as string;
+const legacy = <number>input;
+const factory = create<string>;
+const list = new Map<string, number>();
+element!.textContent = value;
+(value as any) = 1;
@@ -0,0 +1,11 @@
+import type { Options } from "./options";
+import { type Mode, run, type Level } from "./run";
+import { type Only } from "./only";
+import * as types from "./types";
+export { type Mode, run };
+export type * from "./types";
+export type { Options as Settings } from "./options";
+import type Default from "./default";
+import type Equals = require("./equals");
+
+run(types);
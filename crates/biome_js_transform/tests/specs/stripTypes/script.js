@@ -0,0 +1,2 @@
+/* should not generate diagnostics */
+let count = 0;
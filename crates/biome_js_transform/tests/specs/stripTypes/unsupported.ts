@@ -0,0 +1,16 @@
+enum Direction {
+	Up,
+	Down,
+}
+
+namespace Values {
+	export const answer: number = 42;
+}
+
+class Point {
+	constructor(private readonly x: number, y: number) {}
+}
+
+import fs = require("fs");
+
+export = Point;
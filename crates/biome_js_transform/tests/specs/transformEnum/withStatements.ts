@@ -0,0 +1,9 @@
+import { log } from "./log";
+
+// The status of a job
+enum Status {
+	Enabled,
+	Disabled
+}
+
+log(Status.Enabled);
@@ -0,0 +1,25 @@
+"use client";
+// The list of the items
+import { Item } from "./item";
+
+export function List({ items, selected, ...props }) {
+	return (
+		<ul className="list" aria-label="Items" {...props}>
+			{items.map((item) => (
+				<Item key={item.id} selected={item.id === selected} {...item} />
+			))}
+			<li hidden>
+				No more items
+			</li>
+		</ul>
+	);
+}
+
+export const Empty = () => <></>;
+export const Title = () => (
+	<>
+		<h1>Tom &amp; Jerry&#33;</h1>
+		{/* The title */}
+		<svg:rect xlink:href="#id" />
+	</>
+);
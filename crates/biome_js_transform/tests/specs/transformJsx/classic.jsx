@@ -0,0 +1,9 @@
+import React from "react";
+
+export const Link = ({ href, children }) => (
+	<a href={href} target="_blank" data-external>
+		{children} <span>↗</span>
+	</a>
+);
+
+export const Group = () => <><Link.Icon /></>;
@@ -0,0 +1,5 @@
+export const Quote = () => (
+	<p title="&laquo;Hello&raquo;">
+		Wait&hellip; &ldquo;Caf&eacute;&rdquo; &mdash; 10&euro; &copy;&nbsp;2024 &#x1F600; &unknown; & more
+	</p>
+);
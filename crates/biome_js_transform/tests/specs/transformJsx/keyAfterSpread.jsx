@@ -0,0 +1,7 @@
+export const Items = ({ items, props }) => (
+	<ul>
+		<li key="first" {...props}>First</li>
+		<li {...props} key="second">Second</li>
+		<li {...props} key="third" />
+	</ul>
+);
@@ -0,0 +1,14 @@
+import { _jsx, _jsx2 } from "./helpers";
+
+const _Fragment = "fragment";
+const _createElement = () => {};
+
+export const List = ({ props, _jsxs }) => (
+	<>
+		<_jsx value={_jsx2(_jsxs)} />
+		<div {...props} key="1">
+			<span />
+			<span />
+		</div>
+	</>
+);
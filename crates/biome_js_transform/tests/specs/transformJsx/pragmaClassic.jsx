@@ -0,0 +1,5 @@
+/** @jsx h */
+/** @jsxFrag Fragment */
+import { h, Fragment } from "preact";
+
+export const App = () => <><p>Hello</p></>;
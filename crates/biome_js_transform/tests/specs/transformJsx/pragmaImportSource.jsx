@@ -0,0 +1,3 @@
+/** @jsxImportSource preact */
+
+export const App = () => <p>Hello, {name}</p>;
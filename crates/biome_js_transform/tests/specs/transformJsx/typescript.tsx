@@ -0,0 +1,3 @@
+export function Select<T>({ value }: { value: T }) {
+	return <Option<T> value={value} label={String(value)} />;
+}
@@ -1,26 +1,77 @@
 use biome_analyze::{ApplySuppression, SuppressionAction};
 use biome_json_syntax::JsonLanguage;
-use biome_rowan::{BatchMutation, SyntaxToken};
+use biome_rowan::{BatchMutation, SyntaxToken, TriviaPieceKind};
 
-pub(crate) struct JsonSuppressionAction;
+/// Comments aren't part of the JSON grammar, so the suppression comments can only be
+/// added to the files that allow comments, like `.jsonc` files or the files parsed
+/// with `json.parser.allowComments`.
+pub(crate) struct JsonSuppressionAction {
+    pub(crate) allow_comments: bool,
+}
 
 impl SuppressionAction for JsonSuppressionAction {
     type Language = JsonLanguage;
 
     fn find_token_to_apply_suppression(
         &self,
-        _original_token: SyntaxToken<Self::Language>,
+        token: SyntaxToken<Self::Language>,
     ) -> Option<ApplySuppression<Self::Language>> {
-        // TODO: property implement. Look for the JsSuppressionAction for an example
-        None
+        if !self.allow_comments {
+            return None;
+        }
+
+        let mut current_token = token;
+        // The suppression is a single line comment, so it goes before the first token of the line
+        loop {
+            let trivia = current_token.leading_trivia();
+            if trivia.pieces().any(|trivia| trivia.kind().is_newline()) {
+                break;
+            } else if let Some(prev_token) = current_token.prev_token() {
+                current_token = prev_token
+            } else {
+                break;
+            }
+        }
+
+        Some(ApplySuppression {
+            token_has_trailing_comments: false,
+            token_to_apply_suppression: current_token,
+            should_insert_leading_newline: false,
+        })
     }
 
     fn apply_suppression(
         &self,
-        _mutation: &mut BatchMutation<Self::Language>,
-        _apply_suppression: ApplySuppression<Self::Language>,
-        _suppression_text: &str,
+        mutation: &mut BatchMutation<Self::Language>,
+        apply_suppression: ApplySuppression<Self::Language>,
+        suppression_text: &str,
+        suppression_reason: &str,
     ) {
-        unreachable!("find_token_to_apply_suppression return None")
+        let ApplySuppression {
+            token_to_apply_suppression,
+            ..
+        } = apply_suppression;
+
+        let suppression_comment = format!("// {suppression_text}: {suppression_reason}");
+        let mut trivia = vec![
+            (
+                TriviaPieceKind::SingleLineComment,
+                suppression_comment.as_str(),
+            ),
+            (TriviaPieceKind::Newline, "\n"),
+        ];
+        let leading_whitespace: Vec<_> = token_to_apply_suppression
+            .leading_trivia()
+            .pieces()
+            .filter(|p| p.is_whitespace())
+            .collect();
+
+        for w in leading_whitespace.iter() {
+            trivia.push((TriviaPieceKind::Whitespace, w.text()));
+        }
+        let new_token = token_to_apply_suppression
+            .with_leading_trivia(trivia)
+            .trim_trailing_trivia();
+        mutation.replace_token_transfer_trivia(token_to_apply_suppression, new_token);
     }
 }
@@ -1,4 +1,7 @@
 pub(crate) mod analysis;
 pub(crate) mod formatting;
+pub(crate) mod hover;
+pub(crate) mod references;
 pub(crate) mod rename;
+pub(crate) mod structure;
 pub(crate) mod text_document;
@@ -250,6 +250,8 @@ fn fix_all(
             .with_lint()
             .with_action()
             .build(),
+        suppression_reason: None,
+        profile: false,
     })?;
 
     if fixed.actions.is_empty() {
@@ -0,0 +1,413 @@
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use crate::utils;
+use anyhow::Context;
+use biome_analyze::{ActionCategory, RuleCategoriesBuilder, RuleMetadata};
+use biome_configuration::PartialConfiguration;
+use biome_diagnostics::{Applicability, Category, Diagnostic};
+use biome_fs::{BiomePath, ConfigName};
+use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_json_syntax::{JsonMember, JsonMemberName, JsonSyntaxKind};
+use biome_rowan::{AstNode, TextRange, TextSize};
+use biome_service::documentation::Doc;
+use biome_service::workspace::{
+    FeaturesBuilder, GetFileContentParams, PullActionsParams, PullDiagnosticsParams,
+    SupportsFeatureParams,
+};
+use biome_service::WorkspaceError;
+use biome_suppression::{parse_suppression_comment, Suppression, SuppressionScope};
+use schemars::schema::{RootSchema, Schema, SchemaObject, SingleOrVec};
+use std::ffi::OsStr;
+use std::sync::LazyLock;
+use tower_lsp::lsp_types::{Hover, HoverContents, HoverParams, MarkupContent, MarkupKind};
+
+/// The schema of the configuration file, used to document its options
+static CONFIGURATION_SCHEMA: LazyLock<RootSchema> =
+    LazyLock::new(|| schemars::schema_for!(PartialConfiguration));
+
+/// The documentation shown when hovering a range of the document
+struct HoverDocumentation {
+    range: TextRange,
+    markdown: String,
+}
+
+/// Documents what's under the cursor:
+/// - a `biome-ignore` comment shows the rules it suppresses;
+/// - a key of the configuration file shows the description of its option;
+/// - a range with diagnostics shows the rules that emitted them, their sources and their fixes.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn hover(session: &Session, params: HoverParams) -> Result<Option<Hover>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    let content = session.workspace.get_file_content(GetFileContentParams {
+        path: biome_path.clone(),
+    })?;
+
+    let mut documentation = suppression_documentation(&content, offset);
+    if documentation.is_none() && is_configuration_file(&biome_path) {
+        documentation = configuration_documentation(&content, offset);
+    }
+    if documentation.is_none() {
+        documentation = diagnostics_documentation(session, &biome_path, offset)?;
+    }
+
+    let Some(documentation) = documentation else {
+        return Ok(None);
+    };
+
+    let range = to_proto::range(&doc.line_index, documentation.range, position_encoding)?;
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: documentation.markdown,
+        }),
+        range: Some(range),
+    }))
+}
+
+fn is_configuration_file(path: &BiomePath) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .is_some_and(|file_name| ConfigName::file_names().contains(&file_name))
+}
+
+/// Documents the lint rules that emitted a diagnostic at `offset`
+fn diagnostics_documentation(
+    session: &Session,
+    path: &BiomePath,
+    offset: TextSize,
+) -> Result<Option<HoverDocumentation>, LspError> {
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        path: path.clone(),
+        features: FeaturesBuilder::new().with_linter().build(),
+    })?;
+    if !file_features.supports_lint() || session.configuration_status().is_error() {
+        return Ok(None);
+    }
+
+    let result = match session.workspace.pull_diagnostics(PullDiagnosticsParams {
+        path: path.clone(),
+        categories: RuleCategoriesBuilder::default().with_lint().build(),
+        max_diagnostics: u64::MAX,
+        only: Vec::new(),
+        skip: Vec::new(),
+        profile: false,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::FileIgnored(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut range: Option<TextRange> = None;
+    let mut sections = Vec::new();
+    for diagnostic in result.diagnostics {
+        let Some(span) = diagnostic.location().span else {
+            continue;
+        };
+        let Some(category) = diagnostic.category() else {
+            continue;
+        };
+        if !span.contains_inclusive(offset) {
+            continue;
+        }
+        let Some((group, metadata)) = rule_metadata(category.name()) else {
+            continue;
+        };
+
+        let actions = session.workspace.pull_actions(PullActionsParams {
+            path: path.clone(),
+            range: Some(span),
+            skip: Vec::new(),
+            only: Vec::new(),
+        })?;
+        let fixes: Vec<_> = actions
+            .actions
+            .into_iter()
+            .filter(|action| {
+                matches!(action.category, ActionCategory::QuickFix)
+                    && action
+                        .rule_name
+                        .as_ref()
+                        .is_some_and(|(action_group, rule)| {
+                            action_group == group && rule == metadata.name
+                        })
+            })
+            .map(|action| {
+                let title = utils::print_markup(&action.suggestion.msg);
+                match action.suggestion.applicability {
+                    Applicability::Always => format!("- {title}"),
+                    Applicability::MaybeIncorrect => format!("- {title} (unsafe)"),
+                }
+            })
+            .collect();
+
+        let mut section = rule_documentation(category, &metadata);
+        if !fixes.is_empty() {
+            section.push_str("\n\n**Fixes**\n");
+            section.push_str(&fixes.join("\n"));
+        }
+        sections.push(section);
+        range = Some(range.map_or(span, |range| range.cover(span)));
+    }
+
+    Ok(range.map(|range| HoverDocumentation {
+        range,
+        markdown: sections.join("\n\n---\n\n"),
+    }))
+}
+
+/// Documents the `biome-ignore` comment that contains `offset`
+fn suppression_documentation(content: &str, offset: TextSize) -> Option<HoverDocumentation> {
+    let cursor = usize::from(offset);
+    let line_start = content
+        .get(..cursor)?
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    let line_end = content
+        .get(cursor..)?
+        .find('\n')
+        .map_or(content.len(), |index| cursor + index);
+    let line = content[line_start..line_end].trim_end();
+
+    let mut openings: Vec<_> = ["//", "/*", "#", "<!--"]
+        .into_iter()
+        .flat_map(|opening| line.match_indices(opening))
+        .collect();
+    openings.sort_unstable();
+
+    openings.into_iter().find_map(|(start, opening)| {
+        let end = match opening {
+            "/*" => line[start..].find("*/").map(|index| start + index + 2),
+            "<!--" => line[start..].find("-->").map(|index| start + index + 3),
+            _ => None,
+        }
+        .unwrap_or(line.len());
+        if !(line_start + start..=line_start + end).contains(&cursor) {
+            return None;
+        }
+
+        let suppression = parse_suppression_comment(&line[start..end]).find_map(Result::ok)?;
+        let range = TextRange::new(
+            TextSize::try_from(line_start + start).ok()?,
+            TextSize::try_from(line_start + end).ok()?,
+        );
+        Some(HoverDocumentation {
+            range,
+            markdown: suppression_markdown(&suppression),
+        })
+    })
+}
+
+fn suppression_markdown(suppression: &Suppression) -> String {
+    let names: Vec<_> = suppression
+        .categories
+        .iter()
+        .map(|(category, value)| match value {
+            Some(value) => format!("{}/{value}", category.name()),
+            None => category.name().to_string(),
+        })
+        .collect();
+    let list = names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut markdown = match suppression.scope {
+        SuppressionScope::Line => format!("Suppresses {list} on the next line."),
+        SuppressionScope::File => format!("Suppresses {list} in the whole file."),
+        SuppressionScope::RangeStart => {
+            format!("Suppresses {list} until the matching `biome-ignore-end` comment.")
+        }
+        SuppressionScope::RangeEnd => {
+            format!("Ends the suppression of {list} started by a `biome-ignore-start` comment.")
+        }
+    };
+    let reason = suppression.reason.trim();
+    if !reason.is_empty() && reason != "<explanation>" {
+        markdown.push_str("\n\n**Reason**: ");
+        markdown.push_str(reason);
+    }
+
+    for name in &names {
+        if let Some((category, metadata)) = rule_category(name) {
+            markdown.push_str("\n\n---\n\n");
+            markdown.push_str(&rule_documentation(category, &metadata));
+        }
+    }
+
+    markdown
+}
+
+/// Documents the option of the configuration file named by the key that contains `offset`
+fn configuration_documentation(content: &str, offset: TextSize) -> Option<HoverDocumentation> {
+    let parse = parse_json(
+        content,
+        JsonParserOptions::default()
+            .with_allow_comments()
+            .with_allow_trailing_commas(),
+    );
+    let token = parse
+        .syntax()
+        .token_at_offset(offset)
+        .find(|token| token.kind() == JsonSyntaxKind::JSON_STRING_LITERAL)?;
+    let name = token.parent().and_then(JsonMemberName::cast)?;
+
+    let mut keys = Vec::new();
+    for member in name.syntax().ancestors().filter_map(JsonMember::cast) {
+        keys.push(member.name().ok()?.inner_string_text().ok()?.to_string());
+    }
+    keys.reverse();
+
+    // The rules are documented with their metadata, like the diagnostics they emit
+    if let [.., rules, group, rule] = keys.as_slice() {
+        if rules == "rules" {
+            if let Some((category, metadata)) = rule_category(&format!("lint/{group}/{rule}")) {
+                return Some(HoverDocumentation {
+                    range: token.text_trimmed_range(),
+                    markdown: rule_documentation(category, &metadata),
+                });
+            }
+        }
+    }
+
+    let description = option_description(&keys)?;
+    Some(HoverDocumentation {
+        range: token.text_trimmed_range(),
+        markdown: description.to_string(),
+    })
+}
+
+/// Returns the description of the option at the path `keys` of the configuration schema
+fn option_description(keys: &[String]) -> Option<&'static str> {
+    let root = &*CONFIGURATION_SCHEMA;
+    let mut object = &root.schema;
+    let mut description = None;
+    for key in keys {
+        let property = find_property(root, object, key)?;
+        object = resolve(root, property)?;
+        description = metadata_description(property).or_else(|| metadata_description(object));
+    }
+    description
+}
+
+fn metadata_description(schema: &SchemaObject) -> Option<&str> {
+    schema.metadata.as_ref()?.description.as_deref()
+}
+
+/// Finds the schema of the property `key` in `object`, the items of its arrays, and its subschemas
+fn find_property<'a>(
+    root: &'a RootSchema,
+    object: &'a SchemaObject,
+    key: &str,
+) -> Option<&'a SchemaObject> {
+    if let Some(Schema::Object(property)) = object
+        .object
+        .as_ref()
+        .and_then(|validation| validation.properties.get(key))
+    {
+        return Some(property);
+    }
+    if let Some(SingleOrVec::Single(items)) = object
+        .array
+        .as_ref()
+        .and_then(|validation| validation.items.as_ref())
+    {
+        if let Some(property) = as_object(items)
+            .and_then(|items| resolve(root, items))
+            .and_then(|items| find_property(root, items, key))
+        {
+            return Some(property);
+        }
+    }
+    let subschemas = object.subschemas.as_ref()?;
+    [&subschemas.all_of, &subschemas.any_of, &subschemas.one_of]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|schema| resolve(root, as_object(schema)?))
+        .find_map(|schema| find_property(root, schema, key))
+}
+
+/// Follows the reference of `schema` to its definition
+fn resolve<'a>(root: &'a RootSchema, schema: &'a SchemaObject) -> Option<&'a SchemaObject> {
+    let Some(reference) = &schema.reference else {
+        return Some(schema);
+    };
+    match root
+        .definitions
+        .get(reference.strip_prefix("#/definitions/")?)?
+    {
+        Schema::Object(definition) => Some(definition),
+        Schema::Bool(_) => None,
+    }
+}
+
+fn as_object(schema: &Schema) -> Option<&SchemaObject> {
+    match schema {
+        Schema::Object(object) => Some(object),
+        Schema::Bool(_) => None,
+    }
+}
+
+/// Returns the group and the metadata of the lint rule of the diagnostic category `name`
+fn rule_metadata(name: &str) -> Option<(&str, RuleMetadata)> {
+    let mut segments = name.strip_prefix("lint/")?.split('/');
+    let group = segments.next()?;
+    let rule = segments.next()?;
+    match rule.parse::<Doc>() {
+        Ok(Doc::Rule(metadata)) => Some((group, metadata)),
+        _ => None,
+    }
+}
+
+/// Returns the diagnostic category named `name` and the metadata of its lint rule
+fn rule_category(name: &str) -> Option<(&'static Category, RuleMetadata)> {
+    let category = name.parse::<&'static Category>().ok()?;
+    let (_, metadata) = rule_metadata(category.name())?;
+    Some((category, metadata))
+}
+
+/// Documents a rule with the description of its documentation and the rules it's inspired from
+fn rule_documentation(category: &Category, metadata: &RuleMetadata) -> String {
+    let mut markdown = match category.link() {
+        Some(link) => format!("### [{}]({link})", category.name()),
+        None => format!("### {}", category.name()),
+    };
+
+    let description = metadata
+        .docs
+        .lines()
+        .map(str::trim_start)
+        .take_while(|line| !line.starts_with("## "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    markdown.push_str("\n\n");
+    markdown.push_str(description.trim());
+
+    if !metadata.sources.is_empty() {
+        let sources = metadata
+            .sources
+            .iter()
+            .map(|source| {
+                format!(
+                    "[{}]({}) ({source})",
+                    source.to_namespaced_rule_name(),
+                    source.to_rule_url()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        markdown.push_str("\n\nSame as ");
+        markdown.push_str(&sources);
+    }
+
+    markdown
+}
@@ -0,0 +1,203 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto};
+use crate::diagnostics::LspError;
+use crate::documents::Document;
+use crate::session::Session;
+use anyhow::Context;
+use biome_fs::BiomePath;
+use biome_service::workspace::{
+    CloseFileParams, GetFileContentParams, GetSymbolReferencesParams, GetSymbolReferencesResult,
+    OpenFileParams, SymbolLocation,
+};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    self as lsp, DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams,
+    GotoDefinitionParams, GotoDefinitionResponse, Location, ReferenceParams, Url,
+};
+
+/// Returns the declaration of the symbol at the cursor position.
+///
+/// An imported symbol resolves to its declaration in the imported module when the module can be
+/// resolved, and to the local import binding otherwise. The imported module doesn't need to be
+/// open: it's read from the file system when it isn't.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let Some((doc, result)) = symbol_references(session, &url, position)? else {
+        return Ok(None);
+    };
+
+    let imported_location = match result.imported_declaration {
+        Some(imported_declaration) => location_in_module(session, imported_declaration)?,
+        None => imported_location_on_disk(session, &url, position, result.import_candidates)?,
+    };
+    if let Some(location) = imported_location {
+        return Ok(Some(GotoDefinitionResponse::Scalar(location)));
+    }
+
+    let Some(declaration) = result.declaration else {
+        return Ok(None);
+    };
+    let range = to_proto::range(&doc.line_index, declaration, session.position_encoding())?;
+    Ok(Some(GotoDefinitionResponse::Scalar(Location::new(
+        url, range,
+    ))))
+}
+
+/// Returns the references to the symbol at the cursor position in the document
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let Some((doc, result)) = symbol_references(session, &url, position)? else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let declaration = result
+        .declaration
+        .filter(|_| params.context.include_declaration);
+    let locations = declaration
+        .into_iter()
+        .chain(
+            result
+                .references
+                .into_iter()
+                .map(|reference| reference.range),
+        )
+        .map(|range| {
+            let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+            Ok(Location::new(url.clone(), range))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Some(locations))
+}
+
+/// Highlights the declaration of the symbol at the cursor position and its references in the
+/// document. The declaration and the assignments are highlighted as writes.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_highlight(
+    session: &Session,
+    params: DocumentHighlightParams,
+) -> Result<Option<Vec<DocumentHighlight>>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let Some((doc, result)) = symbol_references(session, &url, position)? else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let highlights = result
+        .declaration
+        .map(|range| (range, DocumentHighlightKind::WRITE))
+        .into_iter()
+        .chain(result.references.into_iter().map(|reference| {
+            let kind = if reference.is_write {
+                DocumentHighlightKind::WRITE
+            } else {
+                DocumentHighlightKind::READ
+            };
+            (reference.range, kind)
+        }))
+        .map(|(range, kind)| {
+            Ok(DocumentHighlight {
+                range: to_proto::range(&doc.line_index, range, position_encoding)?,
+                kind: Some(kind),
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Some(highlights))
+}
+
+/// Queries the workspace for the references of the symbol at `position`, or returns `None` if
+/// the language of the document doesn't support them
+fn symbol_references(
+    session: &Session,
+    url: &Url,
+    position: lsp::Position,
+) -> Result<Option<(Document, GetSymbolReferencesResult)>, LspError> {
+    let biome_path = session.file_path(url)?;
+    let doc = session.document(url)?;
+    let symbol_at = from_proto::offset(&doc.line_index, position, session.position_encoding())
+        .with_context(|| format!("failed to access position {position:?} in document {url}"))?;
+
+    match session
+        .workspace
+        .get_symbol_references(GetSymbolReferencesParams {
+            path: biome_path,
+            symbol_at,
+        }) {
+        Ok(result) => Ok(Some((doc, result))),
+        Err(WorkspaceError::SourceFileNotSupported(_)) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Returns the location of the declaration of an imported symbol, when the imported module isn't
+/// open. The first candidate that exists on the file system is open in the workspace for the
+/// time of the query.
+fn imported_location_on_disk(
+    session: &Session,
+    url: &Url,
+    position: lsp::Position,
+    candidates: Vec<BiomePath>,
+) -> Result<Option<Location>, LspError> {
+    let Some(path) = candidates
+        .into_iter()
+        .find(|path| session.fs.path_is_file(path.as_path()))
+    else {
+        return Ok(None);
+    };
+    let Ok(content) = session.fs.read_file_from_path(&path.to_path_buf()) else {
+        return Ok(None);
+    };
+
+    session.workspace.open_file(OpenFileParams {
+        path: path.clone(),
+        content,
+        version: 0,
+        document_file_source: None,
+    })?;
+    let location = symbol_references(session, url, position).and_then(|result| {
+        match result.and_then(|(_, result)| result.imported_declaration) {
+            Some(imported_declaration) => location_in_module(session, imported_declaration),
+            None => Ok(None),
+        }
+    });
+    session.workspace.close_file(CloseFileParams { path })?;
+
+    location
+}
+
+/// Converts the location of a declaration in another module. The location is converted using the
+/// content of the module when it's open, otherwise only its start can be converted.
+fn location_in_module(
+    session: &Session,
+    location: SymbolLocation,
+) -> Result<Option<Location>, LspError> {
+    let Ok(url) = Url::from_file_path(location.path.as_path()) else {
+        return Ok(None);
+    };
+
+    let range = match session.workspace.get_file_content(GetFileContentParams {
+        path: location.path,
+    }) {
+        Ok(content) => to_proto::range(
+            &LineIndex::new(&content),
+            location.range,
+            session.position_encoding(),
+        )?,
+        Err(_) => lsp::Range::default(),
+    };
+
+    Ok(Some(Location::new(url, range)))
+}
@@ -0,0 +1,195 @@
+use crate::converters::line_index::LineIndex;
+use crate::converters::{from_proto, to_proto, PositionEncoding};
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_service::workspace::{
+    DocumentSymbol, DocumentSymbolKind, FoldingRangeKind, GetDocumentSymbolsParams,
+    GetFoldingRangesParams, GetSelectionRangesParams,
+};
+use biome_service::WorkspaceError;
+use tower_lsp::lsp_types::{
+    self as lsp, DocumentSymbolParams, DocumentSymbolResponse, FoldingRange, FoldingRangeParams,
+    SelectionRange, SelectionRangeParams, SymbolKind,
+};
+
+/// Returns the outline of the document: the symbols it declares, nested in
+/// the symbols that contain them
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_symbol(
+    session: &Session,
+    params: DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+
+    let result = match session
+        .workspace
+        .get_document_symbols(GetDocumentSymbolsParams { path: biome_path })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let symbols = result
+        .symbols
+        .into_iter()
+        .map(|symbol| to_lsp_symbol(&doc.line_index, symbol, session.position_encoding()))
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+#[allow(deprecated)]
+fn to_lsp_symbol(
+    line_index: &LineIndex,
+    symbol: DocumentSymbol,
+    position_encoding: PositionEncoding,
+) -> anyhow::Result<lsp::DocumentSymbol> {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| to_lsp_symbol(line_index, child, position_encoding))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(lsp::DocumentSymbol {
+        name: symbol.name,
+        detail: None,
+        kind: to_lsp_symbol_kind(symbol.kind),
+        tags: None,
+        deprecated: None,
+        range: to_proto::range(line_index, symbol.range, position_encoding)?,
+        selection_range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+        children: (!children.is_empty()).then_some(children),
+    })
+}
+
+fn to_lsp_symbol_kind(kind: DocumentSymbolKind) -> SymbolKind {
+    match kind {
+        DocumentSymbolKind::Module => SymbolKind::MODULE,
+        DocumentSymbolKind::Namespace => SymbolKind::NAMESPACE,
+        DocumentSymbolKind::Class => SymbolKind::CLASS,
+        DocumentSymbolKind::Method => SymbolKind::METHOD,
+        DocumentSymbolKind::Property => SymbolKind::PROPERTY,
+        DocumentSymbolKind::Field => SymbolKind::FIELD,
+        DocumentSymbolKind::Constructor => SymbolKind::CONSTRUCTOR,
+        DocumentSymbolKind::Enum => SymbolKind::ENUM,
+        DocumentSymbolKind::Interface => SymbolKind::INTERFACE,
+        DocumentSymbolKind::Function => SymbolKind::FUNCTION,
+        DocumentSymbolKind::Variable => SymbolKind::VARIABLE,
+        DocumentSymbolKind::Constant => SymbolKind::CONSTANT,
+        DocumentSymbolKind::String => SymbolKind::STRING,
+        DocumentSymbolKind::Number => SymbolKind::NUMBER,
+        DocumentSymbolKind::Boolean => SymbolKind::BOOLEAN,
+        DocumentSymbolKind::Array => SymbolKind::ARRAY,
+        DocumentSymbolKind::Object => SymbolKind::OBJECT,
+        DocumentSymbolKind::Null => SymbolKind::NULL,
+        DocumentSymbolKind::EnumMember => SymbolKind::ENUM_MEMBER,
+        DocumentSymbolKind::Struct => SymbolKind::STRUCT,
+        DocumentSymbolKind::Operator => SymbolKind::OPERATOR,
+        DocumentSymbolKind::TypeParameter => SymbolKind::TYPE_PARAMETER,
+    }
+}
+
+/// Returns the regions of the document that can be folded
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_range(
+    session: &Session,
+    params: FoldingRangeParams,
+) -> Result<Option<Vec<FoldingRange>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+
+    let result = match session
+        .workspace
+        .get_folding_ranges(GetFoldingRangesParams { path: biome_path })
+    {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut ranges = Vec::with_capacity(result.ranges.len());
+    for folding_range in result.ranges {
+        let range = to_proto::range(
+            &doc.line_index,
+            folding_range.range,
+            session.position_encoding(),
+        )?;
+        let (end_line, kind) = match folding_range.kind {
+            // The line of the closing delimiter stays visible when a block is folded
+            FoldingRangeKind::Block => (range.end.line.saturating_sub(1), None),
+            FoldingRangeKind::Comment => (range.end.line, Some(lsp::FoldingRangeKind::Comment)),
+            FoldingRangeKind::Imports => (range.end.line, Some(lsp::FoldingRangeKind::Imports)),
+        };
+        if end_line <= range.start.line {
+            continue;
+        }
+
+        ranges.push(FoldingRange {
+            start_line: range.start.line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        });
+    }
+
+    Ok(Some(ranges))
+}
+
+/// Returns, for each position, the ranges of the syntax nodes that contain it,
+/// so that the selection can be expanded from the innermost to the outermost
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn selection_range(
+    session: &Session,
+    params: SelectionRangeParams,
+) -> Result<Option<Vec<SelectionRange>>, LspError> {
+    let url = params.text_document.uri;
+    let biome_path = session.file_path(&url)?;
+    let doc = session.document(&url)?;
+    let position_encoding = session.position_encoding();
+
+    let positions = params
+        .positions
+        .iter()
+        .map(|position| {
+            from_proto::offset(&doc.line_index, *position, position_encoding).with_context(|| {
+                format!("failed to access position {position:?} in document {url}")
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let result = match session
+        .workspace
+        .get_selection_ranges(GetSelectionRangesParams {
+            path: biome_path,
+            positions,
+        }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut selection_ranges = Vec::with_capacity(params.positions.len());
+    for (position, ranges) in params.positions.into_iter().zip(result.ranges) {
+        let mut selection_range: Option<SelectionRange> = None;
+        // The outermost range is the parent of all the others
+        for range in ranges.into_iter().rev() {
+            selection_range = Some(SelectionRange {
+                range: to_proto::range(&doc.line_index, range, position_encoding)?,
+                parent: selection_range.map(Box::new),
+            });
+        }
+        selection_ranges.push(selection_range.unwrap_or(SelectionRange {
+            range: lsp::Range::new(position, position),
+            parent: None,
+        }));
+    }
+
+    Ok(Some(selection_ranges))
+}
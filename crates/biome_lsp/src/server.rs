@@ -455,6 +455,70 @@ impl LanguageServer for LSPServer {
         self.map_op_error(result).await
     }
 
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::hover::hover(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::references::goto_definition(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::references::references(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> LspResult<Option<Vec<DocumentHighlight>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::references::document_highlight(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::structure::document_symbol(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::structure::folding_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LspResult<Option<Vec<SelectionRange>>> {
+        biome_diagnostics::panic::catch_unwind(move || {
+            handlers::structure::selection_range(&self.session, params).map_err(into_lsp_error)
+        })
+        .map_err(into_lsp_error)?
+    }
+
     async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
         biome_diagnostics::panic::catch_unwind(move || {
             let rename_enabled = self
@@ -626,6 +690,9 @@ impl ServerFactory {
         workspace_method!(builder, unregister_project_folder);
         workspace_method!(builder, open_file);
         workspace_method!(builder, set_manifest_for_project);
+        workspace_method!(builder, update_module_graph);
+        workspace_method!(builder, set_module_graph_complete);
+        workspace_method!(builder, is_module_graph_required);
         workspace_method!(builder, get_syntax_tree);
         workspace_method!(builder, get_control_flow_graph);
         workspace_method!(builder, get_formatter_ir);
@@ -638,8 +705,17 @@ impl ServerFactory {
         workspace_method!(builder, format_range);
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
+        workspace_method!(builder, transform_file);
+        workspace_method!(builder, minify_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, get_symbol_references);
+        workspace_method!(builder, get_document_symbols);
+        workspace_method!(builder, get_folding_ranges);
+        workspace_method!(builder, get_selection_ranges);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, parse_pattern);
+        workspace_method!(builder, search_pattern);
+        workspace_method!(builder, drop_pattern);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
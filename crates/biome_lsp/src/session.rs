@@ -14,9 +14,8 @@ use biome_fs::{BiomePath, FileSystem};
 use biome_service::configuration::{
     load_configuration, load_editorconfig, LoadedConfiguration, PartialConfigurationExt,
 };
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    FeaturesBuilder, GetFileContentParams, PullDiagnosticsParams, RegisterProjectFolderParams,
+    FeaturesBuilder, PullDiagnosticsParams, RegisterProjectFolderParams,
     SetManifestForProjectParams, SupportsFeatureParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
@@ -26,7 +25,6 @@ use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
 use rustc_hash::FxHashMap;
 use serde_json::Value;
-use std::ffi::OsStr;
 use std::path::PathBuf;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU8};
@@ -345,19 +343,10 @@ impl Session {
                 max_diagnostics: u64::MAX,
                 only: Vec::new(),
                 skip: Vec::new(),
+                profile: false,
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
-            let content = self.workspace.get_file_content(GetFileContentParams {
-                path: biome_path.clone(),
-            })?;
-            let offset = match biome_path.extension().map(OsStr::as_encoded_bytes) {
-                Some(b"vue") => VueFileHandler::start(content.as_str()),
-                Some(b"astro") => AstroFileHandler::start(content.as_str()),
-                Some(b"svelte") => SvelteFileHandler::start(content.as_str()),
-                _ => None,
-            };
-
             result
                 .diagnostics
                 .into_iter()
@@ -367,7 +356,6 @@ impl Session {
                         &url,
                         &doc.line_index,
                         self.position_encoding(),
-                        offset,
                     ) {
                         Ok(diag) => Some(diag),
                         Err(err) => {
@@ -9,14 +9,14 @@ use biome_diagnostics::termcolor::NoColor;
 use biome_diagnostics::{
     Applicability, {Diagnostic, DiagnosticTags, Location, PrintDescription, Severity, Visit},
 };
-use biome_rowan::{TextRange, TextSize};
+use biome_rowan::TextSize;
 use biome_service::workspace::CodeAction;
 use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
 use std::any::Any;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
-use std::ops::{Add, Range};
+use std::ops::Range;
 use std::{io, mem};
 use tower_lsp::jsonrpc::Error as LspError;
 use tower_lsp::lsp_types;
@@ -182,19 +182,10 @@ pub(crate) fn diagnostic_to_lsp<D: Diagnostic>(
     url: &lsp::Url,
     line_index: &LineIndex,
     position_encoding: PositionEncoding,
-    offset: Option<u32>,
 ) -> Result<lsp::Diagnostic> {
     let location = diagnostic.location();
 
     let span = location.span.context("diagnostic location has no span")?;
-    let span = if let Some(offset) = offset {
-        TextRange::new(
-            span.start().add(TextSize::from(offset)),
-            span.end().add(TextSize::from(offset)),
-        )
-    } else {
-        span
-    };
     let span = to_proto::range(line_index, span, position_encoding)
         .context("failed to convert diagnostic span to LSP range")?;
 
@@ -296,7 +287,7 @@ impl Visit for RelatedInformationVisitor<'_> {
 }
 
 /// Convert a piece of markup into a String
-fn print_markup(markup: &MarkupBuf) -> String {
+pub(crate) fn print_markup(markup: &MarkupBuf) -> String {
     let mut message = Termcolor(NoColor::new(Vec::new()));
     fmt::Display::fmt(markup, &mut Formatter::new(&mut message))
         // SAFETY: Writing to a memory buffer should never fail
@@ -2546,3 +2546,588 @@ async fn multiple_projects() -> Result<()> {
 
     Ok(())
 }
+
+async fn hover(server: &mut Server, uri: Url, line: u32, character: u32) -> Result<lsp::Hover> {
+    server
+        .request(
+            "textDocument/hover",
+            "hover",
+            lsp::HoverParams {
+                text_document_position_params: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri },
+                    position: Position { line, character },
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?
+        .context("hover returned None")
+}
+
+fn hover_markdown(hover: &lsp::Hover) -> &str {
+    match &hover.contents {
+        lsp::HoverContents::Markup(content) => content.value.as_str(),
+        contents => panic!("unexpected hover contents {contents:?}"),
+    }
+}
+
+#[tokio::test]
+async fn hover_diagnostic() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a === -0) {}").await?;
+
+    let res = hover(&mut server, url!("document.js"), 0, 6).await?;
+    let markdown = hover_markdown(&res);
+
+    assert!(markdown.starts_with(
+        "### [lint/suspicious/noCompareNegZero](https://biomejs.dev/linter/rules/no-compare-neg-zero)"
+    ));
+    assert!(markdown.contains("Disallow comparing against `-0`"));
+    assert!(markdown.contains(
+        "Same as [no-compare-neg-zero](https://eslint.org/docs/latest/rules/no-compare-neg-zero) (ESLint)"
+    ));
+    assert!(markdown.ends_with("**Fixes**\n- Replace -0 with 0"));
+    assert_eq!(
+        res.range,
+        Some(Range {
+            start: Position {
+                line: 0,
+                character: 3,
+            },
+            end: Position {
+                line: 0,
+                character: 11,
+            },
+        })
+    );
+
+    let res: Option<Option<lsp::Hover>> = server
+        .request(
+            "textDocument/hover",
+            "hover_outside_diagnostic",
+            lsp::HoverParams {
+                text_document_position_params: lsp::TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: url!("document.js"),
+                    },
+                    position: Position {
+                        line: 0,
+                        character: 14,
+                    },
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+            },
+        )
+        .await?;
+    assert_eq!(res.flatten(), None);
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hover_suppression_comment() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("a();\n// biome-ignore lint/suspicious/noDebugger: testing\ndebugger;\n")
+        .await?;
+
+    let res = hover(&mut server, url!("document.js"), 1, 20).await?;
+    let markdown = hover_markdown(&res);
+
+    assert!(markdown.starts_with(
+        "Suppresses `lint/suspicious/noDebugger` on the next line.\n\n**Reason**: testing\n\n---\n\n### [lint/suspicious/noDebugger]"
+    ));
+    assert!(markdown.contains("Disallow the use of `debugger`"));
+    assert_eq!(
+        res.range,
+        Some(Range {
+            start: Position {
+                line: 1,
+                character: 0,
+            },
+            end: Position {
+                line: 1,
+                character: 51,
+            },
+        })
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hover_configuration_key() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    let config = r#"{
+    "formatter": {
+        "indentStyle": "tab"
+    },
+    "linter": {
+        "rules": {
+            "suspicious": {
+                "noDebugger": "off"
+            }
+        }
+    }
+}"#;
+    server
+        .open_named_document(config, url!("biome.json"), "json")
+        .await?;
+
+    let res = hover(&mut server, url!("biome.json"), 2, 12).await?;
+    assert_eq!(hover_markdown(&res), "The indent style.");
+    assert_eq!(
+        res.range,
+        Some(Range {
+            start: Position {
+                line: 2,
+                character: 8,
+            },
+            end: Position {
+                line: 2,
+                character: 21,
+            },
+        })
+    );
+
+    let res = hover(&mut server, url!("biome.json"), 7, 20).await?;
+    assert!(hover_markdown(&res).starts_with("### [lint/suspicious/noDebugger]"));
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+fn text_document_position(uri: Url, line: u32, character: u32) -> lsp::TextDocumentPositionParams {
+    lsp::TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier { uri },
+        position: Position { line, character },
+    }
+}
+
+fn range(line: u32, start: u32, end: u32) -> Range {
+    Range {
+        start: Position {
+            line,
+            character: start,
+        },
+        end: Position {
+            line,
+            character: end,
+        },
+    }
+}
+
+#[tokio::test]
+async fn pull_references_and_highlights() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("let b = 1;\nb = b + 1;\nfunction f(b) { return b; }\n")
+        .await?;
+
+    let res: lsp::GotoDefinitionResponse = server
+        .request(
+            "textDocument/definition",
+            "goto_definition",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: text_document_position(url!("document.js"), 1, 4),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("definition returned None")?;
+    assert_eq!(
+        res,
+        lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(
+            url!("document.js"),
+            range(0, 4, 5)
+        ))
+    );
+
+    let res: Vec<lsp::Location> = server
+        .request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position: text_document_position(url!("document.js"), 0, 4),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+                context: lsp::ReferenceContext {
+                    include_declaration: true,
+                },
+            },
+        )
+        .await?
+        .context("references returned None")?;
+    assert_eq!(
+        res,
+        vec![
+            lsp::Location::new(url!("document.js"), range(0, 4, 5)),
+            lsp::Location::new(url!("document.js"), range(1, 0, 1)),
+            lsp::Location::new(url!("document.js"), range(1, 4, 5)),
+        ]
+    );
+
+    let res: Vec<lsp::DocumentHighlight> = server
+        .request(
+            "textDocument/documentHighlight",
+            "document_highlight",
+            lsp::DocumentHighlightParams {
+                text_document_position_params: text_document_position(url!("document.js"), 2, 23),
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("documentHighlight returned None")?;
+    assert_eq!(
+        res,
+        vec![
+            lsp::DocumentHighlight {
+                range: range(2, 11, 12),
+                kind: Some(lsp::DocumentHighlightKind::WRITE),
+            },
+            lsp::DocumentHighlight {
+                range: range(2, 23, 24),
+                kind: Some(lsp::DocumentHighlightKind::READ),
+            },
+        ]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn goto_definition_of_imported_symbol() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("import { a as b } from \"./module.js\";\nb();\n")
+        .await?;
+
+    let params = lsp::GotoDefinitionParams {
+        text_document_position_params: text_document_position(url!("document.js"), 1, 0),
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+        },
+        partial_result_params: lsp::PartialResultParams {
+            partial_result_token: None,
+        },
+    };
+
+    // The imported module isn't known, the import binding is the definition
+    let res: lsp::GotoDefinitionResponse = server
+        .request("textDocument/definition", "goto_import", params.clone())
+        .await?
+        .context("definition returned None")?;
+    assert_eq!(
+        res,
+        lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(
+            url!("document.js"),
+            range(0, 14, 15)
+        ))
+    );
+
+    server
+        .open_named_document(
+            "const c = 1;\nexport function a() {}\n",
+            url!("module.js"),
+            "javascript",
+        )
+        .await?;
+
+    let res: lsp::GotoDefinitionResponse = server
+        .request("textDocument/definition", "goto_export", params)
+        .await?
+        .context("definition returned None")?;
+    assert_eq!(
+        res,
+        lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(
+            url!("module.js"),
+            range(1, 16, 17)
+        ))
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn goto_definition_of_symbol_imported_from_closed_module() -> Result<()> {
+    let factory = ServerFactory::default();
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        url!("module.ts").to_file_path().unwrap(),
+        "const c = 1;\nexport function a() {}\n",
+    );
+    let (service, client) = factory
+        .create_with_fs(None, DynRef::Owned(Box::new(fs)))
+        .into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("import { a as b } from \"./module.js\";\nb();\n")
+        .await?;
+
+    let params = lsp::GotoDefinitionParams {
+        text_document_position_params: text_document_position(url!("document.js"), 1, 0),
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+        },
+        partial_result_params: lsp::PartialResultParams {
+            partial_result_token: None,
+        },
+    };
+    let res: lsp::GotoDefinitionResponse = server
+        .request("textDocument/definition", "goto_export", params)
+        .await?
+        .context("definition returned None")?;
+    assert_eq!(
+        res,
+        lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(
+            url!("module.ts"),
+            range(1, 16, 17)
+        ))
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn pull_document_structure() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create(None).into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("import a from \"a\";\nimport b from \"b\";\nclass A {\n  m() {}\n}\n")
+        .await?;
+
+    let res: lsp::DocumentSymbolResponse = server
+        .request(
+            "textDocument/documentSymbol",
+            "document_symbol",
+            lsp::DocumentSymbolParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("documentSymbol returned None")?;
+    assert_eq!(
+        res,
+        lsp::DocumentSymbolResponse::Nested(vec![lsp::DocumentSymbol {
+            name: String::from("A"),
+            detail: None,
+            kind: lsp::SymbolKind::CLASS,
+            tags: None,
+            deprecated: None,
+            range: lsp::Range::new(Position::new(2, 0), Position::new(4, 1)),
+            selection_range: range(2, 6, 7),
+            children: Some(vec![lsp::DocumentSymbol {
+                name: String::from("m"),
+                detail: None,
+                kind: lsp::SymbolKind::METHOD,
+                tags: None,
+                deprecated: None,
+                range: range(3, 2, 8),
+                selection_range: range(3, 2, 3),
+                children: None,
+            }]),
+        }])
+    );
+
+    let res: Vec<lsp::FoldingRange> = server
+        .request(
+            "textDocument/foldingRange",
+            "folding_range",
+            lsp::FoldingRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("foldingRange returned None")?;
+    assert_eq!(
+        res,
+        vec![
+            lsp::FoldingRange {
+                start_line: 0,
+                start_character: None,
+                end_line: 1,
+                end_character: None,
+                kind: Some(lsp::FoldingRangeKind::Imports),
+                collapsed_text: None,
+            },
+            lsp::FoldingRange {
+                start_line: 2,
+                start_character: None,
+                end_line: 3,
+                end_character: None,
+                kind: None,
+                collapsed_text: None,
+            },
+        ]
+    );
+
+    let res: Vec<lsp::SelectionRange> = server
+        .request(
+            "textDocument/selectionRange",
+            "selection_range",
+            lsp::SelectionRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: url!("document.js"),
+                },
+                positions: vec![Position::new(3, 2)],
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: lsp::PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("selectionRange returned None")?;
+    assert_eq!(res.len(), 1);
+    let mut ranges = Vec::new();
+    let mut selection_range = Some(&res[0]);
+    while let Some(current) = selection_range {
+        ranges.push(current.range);
+        selection_range = current.parent.as_deref();
+    }
+    assert_eq!(ranges[0], range(3, 2, 3));
+    assert_eq!(ranges[1], range(3, 2, 8));
+    assert!(ranges.contains(&lsp::Range::new(Position::new(2, 0), Position::new(4, 1))));
+    assert_eq!(
+        ranges.last().map(|range| range.start),
+        Some(Position::new(0, 0))
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
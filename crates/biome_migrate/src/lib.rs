@@ -74,6 +74,7 @@ where
             _: &mut BatchMutation<Self::Language>,
             _: ApplySuppression<Self::Language>,
             _: &str,
+            _: &str,
         ) {
             unreachable!("")
         }
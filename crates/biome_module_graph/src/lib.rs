@@ -0,0 +1,13 @@
+//! The module graph of a project.
+//!
+//! The graph stores the imports of each JavaScript module of the project, and
+//! the `package.json` and `tsconfig.json` manifests needed to resolve them to
+//! the imported modules. It lets the analyzer rules reason across files, e.g.
+//! to find import cycles.
+
+mod module_graph;
+mod module_info;
+mod resolver;
+
+pub use module_graph::{normalize_path, ModuleGraph};
+pub use module_info::{ImportKind, ImportedSymbols, ModuleImport, ModuleInfo};
@@ -0,0 +1,239 @@
+use crate::{ImportedSymbols, ModuleInfo};
+use biome_project::{PackageJson, TsConfigJson};
+use dashmap::DashMap;
+use rustc_hash::FxHashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// The exports used by the importers of each module, indexed by the path of
+/// the imported module
+type ImportedSymbolsIndex = FxHashMap<PathBuf, ImportedSymbols>;
+
+/// The modules of a project, along with the manifests used to resolve the
+/// imports between them.
+///
+/// The graph is shared between the workspace, that keeps it up to date as the
+/// files of the project are scanned or changed, and the analyzer rules, that
+/// can query it from their services.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    /// The information collected from each module, indexed by path
+    pub(crate) modules: DashMap<PathBuf, Arc<ModuleInfo>>,
+    /// The `package.json` manifests, indexed by the directory that contains them
+    pub(crate) manifests: DashMap<PathBuf, Arc<PackageJson>>,
+    /// The `tsconfig.json` files, indexed by the directory that contains them
+    pub(crate) tsconfigs: DashMap<PathBuf, Arc<TsConfigJson>>,
+    /// The exports used by the importers of each module, computed lazily and
+    /// discarded every time the graph changes
+    imported_symbols: RwLock<Option<Arc<ImportedSymbolsIndex>>>,
+    /// Whether the graph contains all the modules of the project
+    complete: AtomicBool,
+}
+
+impl ModuleGraph {
+    /// Inserts or replaces the information of the module at `path`
+    pub fn update_module(&self, path: &Path, module_info: ModuleInfo) {
+        let path = normalize_path(path);
+        // Modules are updated every time they are parsed, most of the time
+        // without any change to their imports
+        if self
+            .modules
+            .get(&path)
+            .is_some_and(|existing| **existing == module_info)
+        {
+            return;
+        }
+        self.modules.insert(path, Arc::new(module_info));
+        self.invalidate_imported_symbols();
+    }
+
+    /// Inserts or replaces the `package.json` at `path`
+    pub fn update_manifest(&self, path: &Path, manifest: PackageJson) {
+        if let Some(directory) = path.parent() {
+            self.manifests
+                .insert(normalize_path(directory), Arc::new(manifest));
+            self.invalidate_imported_symbols();
+        }
+    }
+
+    /// Inserts or replaces the `tsconfig.json` at `path`
+    pub fn update_tsconfig(&self, path: &Path, tsconfig: TsConfigJson) {
+        if let Some(directory) = path.parent() {
+            self.tsconfigs
+                .insert(normalize_path(directory), Arc::new(tsconfig));
+            self.invalidate_imported_symbols();
+        }
+    }
+
+    /// Removes the module at `path` from the graph
+    pub fn remove_module(&self, path: &Path) {
+        if self.modules.remove(&normalize_path(path)).is_some() {
+            self.invalidate_imported_symbols();
+        }
+    }
+
+    /// Marks the graph as complete: every JavaScript module of the project,
+    /// i.e. every file under the root of the project that isn't ignored by the
+    /// settings, has been added to the graph.
+    ///
+    /// It must only be called once all the files of the project have been
+    /// scanned. Adding a single module, or the modules of some of the files of
+    /// the project, doesn't make the graph complete.
+    pub fn set_complete(&self) {
+        self.complete.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the graph contains all the modules of the project.
+    ///
+    /// When the graph isn't complete, e.g. when it only contains the files
+    /// opened in an editor, a module that isn't imported by any module of the
+    /// graph may still be imported by another module of the project.
+    pub fn is_complete(&self) -> bool {
+        self.complete.load(Ordering::Relaxed)
+    }
+
+    /// Returns the information of the module at `path`, if it's part of the graph
+    pub fn module_info(&self, path: &Path) -> Option<Arc<ModuleInfo>> {
+        self.modules
+            .get(&normalize_path(path))
+            .map(|module_info| module_info.clone())
+    }
+
+    /// Returns `true` if the module at `path` is part of the graph
+    pub fn contains_module(&self, path: &Path) -> bool {
+        self.modules.contains_key(&normalize_path(path))
+    }
+
+    /// Returns the paths of all the modules of the graph, sorted
+    pub fn module_paths(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<_> = self
+            .modules
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+        paths.sort_unstable();
+        paths
+    }
+
+    /// Returns the exports of the module at `path` that are imported by the
+    /// other modules of the graph, or `None` if no module imports it.
+    ///
+    /// Type-only imports and dynamic imports are included.
+    pub fn imported_symbols(&self, path: &Path) -> Option<ImportedSymbols> {
+        let index = self.imported_symbols_index();
+        index.get(&normalize_path(path)).cloned()
+    }
+
+    fn imported_symbols_index(&self) -> Arc<ImportedSymbolsIndex> {
+        if let Some(index) = self
+            .imported_symbols
+            .read()
+            .ok()
+            .and_then(|index| index.clone())
+        {
+            return index;
+        }
+
+        // The modules are collected first, because the resolver reads the
+        // modules while the index is built
+        let modules: Vec<_> = self
+            .modules
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+        let mut index = ImportedSymbolsIndex::default();
+        for (path, module_info) in modules {
+            for import in &module_info.imports {
+                let Some(imported) = self.resolve(&path, &import.specifier) else {
+                    continue;
+                };
+                index
+                    .entry(imported)
+                    .or_insert_with(|| ImportedSymbols::Named(Vec::new()))
+                    .extend(&import.symbols);
+            }
+        }
+
+        let index = Arc::new(index);
+        if let Ok(mut cached) = self.imported_symbols.write() {
+            *cached = Some(index.clone());
+        }
+        index
+    }
+
+    fn invalidate_imported_symbols(&self) {
+        if let Ok(mut cached) = self.imported_symbols.write() {
+            *cached = None;
+        }
+    }
+}
+
+/// Lexically normalizes `path`, removing the `.` components and resolving the
+/// `..` components when possible. Symbolic links aren't followed.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => normalized.push(component),
+            },
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ImportedSymbols, ModuleGraph, ModuleInfo};
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+    use std::path::Path;
+
+    fn update_module(graph: &ModuleGraph, path: &str, source: &str) {
+        let parsed = parse(source, JsFileSource::ts(), JsParserOptions::default());
+        graph.update_module(Path::new(path), ModuleInfo::from_root(&parsed.tree()));
+    }
+
+    #[test]
+    fn collects_imported_symbols() {
+        let graph = ModuleGraph::default();
+        update_module(&graph, "/project/a.ts", "export const a = 1, b = 2;");
+        update_module(&graph, "/project/b.ts", "export default 1;");
+        update_module(&graph, "/project/c.ts", "export const c = 1;");
+        update_module(
+            &graph,
+            "/project/main.ts",
+            r#"import { a } from "./a";
+import type { b } from "./a.js";
+export * from "./b";"#,
+        );
+
+        let imported_symbols = |path: &str| graph.imported_symbols(Path::new(path));
+        assert_eq!(
+            imported_symbols("/project/a.ts"),
+            Some(ImportedSymbols::Named(vec![
+                String::from("a"),
+                String::from("b")
+            ]))
+        );
+        assert_eq!(
+            imported_symbols("/project/b.ts"),
+            Some(ImportedSymbols::All)
+        );
+        assert_eq!(imported_symbols("/project/c.ts"), None);
+
+        // The index is rebuilt when a module changes
+        update_module(&graph, "/project/other.ts", r#"import { c } from "./c";"#);
+        assert_eq!(
+            imported_symbols("/project/c.ts"),
+            Some(ImportedSymbols::Named(vec![String::from("c")]))
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use biome_js_syntax::{
+    inner_string_text, AnyJsCombinedSpecifier, AnyJsImportClause, AnyJsImportLike,
+    AnyJsNamedImportSpecifier, AnyJsRoot, JsExportFromClause, JsExportNamedFromClause,
+    JsNamedImportSpecifiers, TsExternalModuleReference, TsImportEqualsDeclaration,
+};
+use biome_rowan::{AstNode, TextRange};
+
+/// The information collected from a JavaScript module to build the module graph
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ModuleInfo {
+    /// The imports of the module, in source order
+    pub imports: Vec<ModuleImport>,
+}
+
+impl ModuleInfo {
+    /// Collects the static imports, the re-exports, the dynamic imports and
+    /// the `require` calls of the module
+    pub fn from_root(root: &AnyJsRoot) -> Self {
+        let imports = root
+            .syntax()
+            .descendants()
+            .filter_map(AnyJsImportLike::cast)
+            .filter_map(|import| ModuleImport::from_import_like(&import))
+            .collect();
+
+        Self { imports }
+    }
+}
+
+/// A single import of a module
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ModuleImport {
+    /// The specifier of the import, e.g. `./utils` in `import { a } from "./utils"`
+    pub specifier: String,
+    /// The range of the specifier, quotes included
+    pub range: TextRange,
+    /// The syntax of the import
+    pub kind: ImportKind,
+    /// Whether the import only imports types, e.g. `import type { A } from "./a"`
+    pub is_type_only: bool,
+    /// The exports of the imported module that are used by the import
+    pub symbols: ImportedSymbols,
+}
+
+impl ModuleImport {
+    /// Returns the import of `import`, or `None` if it doesn't import a module,
+    /// e.g. `declare module "foo" {}`
+    pub fn from_import_like(import: &AnyJsImportLike) -> Option<Self> {
+        // `declare module "foo" {}` declares a module, it doesn't import it
+        if import.is_in_ts_module_declaration() {
+            return None;
+        }
+
+        let specifier = import.inner_string_text()?.text().to_string();
+        let range = import.module_name_token()?.text_trimmed_range();
+        let (kind, is_type_only, symbols) = match import {
+            AnyJsImportLike::JsModuleSource(source) => {
+                let parent = source.syntax().parent()?;
+                if let Some(clause) = AnyJsImportClause::cast_ref(&parent) {
+                    let symbols = ImportedSymbols::from_import_clause(&clause);
+                    (ImportKind::Static, clause.type_token().is_some(), symbols)
+                } else if let Some(clause) = JsExportFromClause::cast_ref(&parent) {
+                    let is_type_only = clause.type_token().is_some();
+                    (ImportKind::ReExport, is_type_only, ImportedSymbols::All)
+                } else if let Some(clause) = JsExportNamedFromClause::cast_ref(&parent) {
+                    let names = clause
+                        .specifiers()
+                        .into_iter()
+                        .filter_map(|specifier| {
+                            let name = specifier.ok()?.source_name().ok()?.value().ok()?;
+                            Some(inner_string_text(&name).to_string())
+                        })
+                        .collect();
+                    let is_type_only = clause.type_token().is_some();
+                    (
+                        ImportKind::ReExport,
+                        is_type_only,
+                        ImportedSymbols::Named(names),
+                    )
+                } else if TsExternalModuleReference::can_cast(parent.kind()) {
+                    let is_type_only = parent
+                        .parent()
+                        .and_then(TsImportEqualsDeclaration::cast)
+                        .is_some_and(|declaration| declaration.type_token().is_some());
+                    (ImportKind::Static, is_type_only, ImportedSymbols::All)
+                } else {
+                    (ImportKind::Static, false, ImportedSymbols::All)
+                }
+            }
+            AnyJsImportLike::JsCallExpression(_) => {
+                (ImportKind::Require, false, ImportedSymbols::All)
+            }
+            AnyJsImportLike::JsImportCallExpression(_) => {
+                (ImportKind::Dynamic, false, ImportedSymbols::All)
+            }
+        };
+
+        Some(Self {
+            specifier,
+            range,
+            kind,
+            is_type_only,
+            symbols,
+        })
+    }
+}
+
+/// The exports of a module that are used by an import
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ImportedSymbols {
+    /// All the exports may be used, e.g. `import * as a from "./a"`,
+    /// `export * from "./a"` or `require("./a")`
+    All,
+    /// Only the listed exports are used, e.g. `import a, { b } from "./a"`
+    /// uses `default` and `b`. A side-effect import such as `import "./a"`
+    /// doesn't use any export.
+    Named(Vec<String>),
+}
+
+impl ImportedSymbols {
+    fn from_import_clause(clause: &AnyJsImportClause) -> Self {
+        match clause {
+            AnyJsImportClause::JsImportBareClause(_) => Self::Named(Vec::new()),
+            AnyJsImportClause::JsImportDefaultClause(_) => {
+                Self::Named(vec![String::from("default")])
+            }
+            AnyJsImportClause::JsImportNamespaceClause(_) => Self::All,
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                clause.named_specifiers().map_or(Self::All, |specifiers| {
+                    Self::from_named_specifiers(&specifiers)
+                })
+            }
+            AnyJsImportClause::JsImportCombinedClause(clause) => match clause.specifier() {
+                Ok(AnyJsCombinedSpecifier::JsNamedImportSpecifiers(specifiers)) => {
+                    let mut symbols = Self::from_named_specifiers(&specifiers);
+                    symbols.insert("default");
+                    symbols
+                }
+                _ => Self::All,
+            },
+        }
+    }
+
+    fn from_named_specifiers(specifiers: &JsNamedImportSpecifiers) -> Self {
+        let names = specifiers
+            .specifiers()
+            .into_iter()
+            .filter_map(|specifier| {
+                let name = match specifier.ok()? {
+                    AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                        specifier.name().ok()?.value().ok()?
+                    }
+                    AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                        specifier
+                            .local_name()
+                            .ok()?
+                            .as_js_identifier_binding()?
+                            .name_token()
+                            .ok()?
+                    }
+                    AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => return None,
+                };
+                Some(inner_string_text(&name).to_string())
+            })
+            .collect();
+        Self::Named(names)
+    }
+
+    /// Returns `true` if the export `name` is used
+    pub fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Named(names) => names.iter().any(|imported| imported == name),
+        }
+    }
+
+    /// Adds the export `name` to the used exports
+    pub fn insert(&mut self, name: &str) {
+        if let Self::Named(names) = self {
+            if !names.iter().any(|imported| imported == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    /// Adds the exports used by `other` to the used exports
+    pub fn extend(&mut self, other: &Self) {
+        match other {
+            Self::All => *self = Self::All,
+            Self::Named(names) => {
+                for name in names {
+                    self.insert(name);
+                }
+            }
+        }
+    }
+}
+
+/// The syntax used to import a module
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ImportKind {
+    /// `import { a } from "./a"`, `import "./a"` or `import a = require("./a")`
+    Static,
+    /// `export { a } from "./a"` or `export * from "./a"`
+    ReExport,
+    /// `import("./a")`
+    Dynamic,
+    /// `require("./a")`
+    Require,
+}
+
+impl ImportKind {
+    /// Returns `true` for the imports that are evaluated when the importing
+    /// module is loaded
+    pub const fn is_static(&self) -> bool {
+        matches!(self, Self::Static | Self::ReExport)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ImportKind, ImportedSymbols, ModuleInfo};
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+
+    fn collect_imports(source: &str) -> Vec<(String, ImportKind, bool)> {
+        let parsed = parse(source, JsFileSource::ts(), JsParserOptions::default());
+        ModuleInfo::from_root(&parsed.tree())
+            .imports
+            .into_iter()
+            .map(|import| (import.specifier, import.kind, import.is_type_only))
+            .collect()
+    }
+
+    #[test]
+    fn collects_imports() {
+        let imports = collect_imports(
+            r#"
+import a from "./a";
+import type { B } from "./b";
+import "./c";
+import d = require("./d");
+export * from "./e";
+export type { F } from "./f";
+const g = require("./g");
+const h = await import("./h");
+declare module "i" {}
+"#,
+        );
+
+        assert_eq!(
+            imports,
+            vec![
+                (String::from("./a"), ImportKind::Static, false),
+                (String::from("./b"), ImportKind::Static, true),
+                (String::from("./c"), ImportKind::Static, false),
+                (String::from("./d"), ImportKind::Static, false),
+                (String::from("./e"), ImportKind::ReExport, false),
+                (String::from("./f"), ImportKind::ReExport, true),
+                (String::from("./g"), ImportKind::Require, false),
+                (String::from("./h"), ImportKind::Dynamic, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn collects_imported_symbols() {
+        let parsed = parse(
+            r#"
+import "./a";
+import b from "./b";
+import * as c from "./c";
+import { d, e as f, "g" as g } from "./d";
+import h, { i } from "./h";
+import j, * as k from "./j";
+export { l, m as n } from "./l";
+export * as o from "./o";
+require("./p");
+"#,
+            JsFileSource::ts(),
+            JsParserOptions::default(),
+        );
+        let symbols: Vec<_> = ModuleInfo::from_root(&parsed.tree())
+            .imports
+            .into_iter()
+            .map(|import| import.symbols)
+            .collect();
+        let named = |names: &[&str]| {
+            ImportedSymbols::Named(names.iter().map(|name| String::from(*name)).collect())
+        };
+
+        assert_eq!(
+            symbols,
+            vec![
+                named(&[]),
+                named(&["default"]),
+                ImportedSymbols::All,
+                named(&["d", "e", "g"]),
+                named(&["i", "default"]),
+                ImportedSymbols::All,
+                named(&["l", "m"]),
+                ImportedSymbols::All,
+                ImportedSymbols::All,
+            ]
+        );
+    }
+}
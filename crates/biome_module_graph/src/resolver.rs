@@ -0,0 +1,626 @@
+use crate::module_graph::normalize_path;
+use crate::ModuleGraph;
+use biome_project::{PackageJson, TsConfigJson};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+
+/// The extensions tried, in order, when an import omits the extension of the
+/// imported module
+const EXTENSIONS: [&str; 9] = ["ts", "tsx", "mts", "cts", "d.ts", "js", "jsx", "mjs", "cjs"];
+
+/// The conditions of the `exports` of a `package.json` that are matched when
+/// resolving a package, the first condition declared by the package wins
+const CONDITIONS: [&str; 6] = ["types", "import", "module", "require", "node", "default"];
+
+impl ModuleGraph {
+    /// Resolves the module imported with `specifier` by the module at `importer`.
+    ///
+    /// The resolution supports:
+    /// - relative and absolute paths, with or without extension;
+    /// - TypeScript imports that use the `.js` extension for `.ts` files;
+    /// - `index` files of directories;
+    /// - the `paths` and `baseUrl` options of the closest `tsconfig.json`;
+    /// - packages, using the `exports` and `main` fields of their `package.json`.
+    ///
+    /// Returns `None` if the imported module isn't part of the graph, for
+    /// instance a builtin module of Node.js or a package that isn't installed.
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+        self.resolve_with(importer, specifier, &|_| false)
+    }
+
+    /// Resolves the module imported with `specifier` by the module at `importer`,
+    /// like [ModuleGraph::resolve]. The paths for which `exists` returns `true`
+    /// are resolved as if they were part of the graph.
+    pub fn resolve_with(
+        &self,
+        importer: &Path,
+        specifier: &str,
+        exists: &dyn Fn(&Path) -> bool,
+    ) -> Option<PathBuf> {
+        let directory = importer.parent().unwrap_or(Path::new(""));
+
+        if is_relative_specifier(specifier) || Path::new(specifier).has_root() {
+            return self.resolve_path(&directory.join(specifier), exists);
+        }
+
+        self.resolve_tsconfig(directory, specifier, exists)
+            .or_else(|| self.resolve_package(directory, specifier, exists))
+    }
+
+    /// Returns the paths where the module imported with `specifier` by the
+    /// module at `importer` can be, in the order the resolution tries them.
+    ///
+    /// It's useful to find the imported module outside of the graph, e.g. on
+    /// the file system.
+    pub fn resolution_candidates(&self, importer: &Path, specifier: &str) -> Vec<PathBuf> {
+        let candidates = RefCell::new(Vec::new());
+        let resolved = self.resolve_with(importer, specifier, &|path| {
+            candidates.borrow_mut().push(path.to_path_buf());
+            false
+        });
+        let mut candidates = candidates.into_inner();
+        // The modules of the graph and the targets of the `exports` of a package
+        // are resolved without being tried
+        candidates.extend(resolved);
+        candidates
+    }
+
+    /// Whether the module at `path` is part of the graph, or `exists` returns `true`
+    fn contains(&self, path: &Path, exists: &dyn Fn(&Path) -> bool) -> bool {
+        self.modules.contains_key(path) || exists(path)
+    }
+
+    /// Resolves `path` as a file, then as a directory
+    fn resolve_path(&self, path: &Path, exists: &dyn Fn(&Path) -> bool) -> Option<PathBuf> {
+        let path = normalize_path(path);
+        self.resolve_file(&path, exists)
+            .or_else(|| self.resolve_directory(&path, exists))
+    }
+
+    /// Resolves `path` as a module, trying the known extensions when the
+    /// module isn't part of the graph
+    fn resolve_file(&self, path: &Path, exists: &dyn Fn(&Path) -> bool) -> Option<PathBuf> {
+        if self.contains(path, exists) {
+            return Some(path.to_path_buf());
+        }
+
+        // TypeScript allows importing `./a.ts` with `./a.js`
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        let typescript_extensions: &[&str] = match extension {
+            Some("js") => &["ts", "tsx", "d.ts"],
+            Some("jsx") => &["tsx"],
+            Some("mjs") => &["mts", "d.mts"],
+            Some("cjs") => &["cts", "d.cts"],
+            _ => &[],
+        };
+        for typescript_extension in typescript_extensions {
+            let candidate = path.with_extension(typescript_extension);
+            if self.contains(&candidate, exists) {
+                return Some(candidate);
+            }
+        }
+
+        let path = path.as_os_str();
+        EXTENSIONS.iter().find_map(|extension| {
+            let mut candidate = path.to_os_string();
+            candidate.push(".");
+            candidate.push(extension);
+            let candidate = PathBuf::from(candidate);
+            self.contains(&candidate, exists).then_some(candidate)
+        })
+    }
+
+    /// Resolves `path` as a directory, using the `main` field of its
+    /// `package.json` or its `index` file
+    fn resolve_directory(&self, path: &Path, exists: &dyn Fn(&Path) -> bool) -> Option<PathBuf> {
+        let main = self
+            .manifests
+            .get(path)
+            .and_then(|manifest| manifest.main.clone());
+        if let Some(main) = main {
+            let main = normalize_path(&path.join(main));
+            if let Some(resolved) = self
+                .resolve_file(&main, exists)
+                .or_else(|| self.resolve_index(&main, exists))
+            {
+                return Some(resolved);
+            }
+        }
+
+        self.resolve_index(path, exists)
+    }
+
+    /// Resolves the `index` file of the directory at `path`
+    fn resolve_index(&self, path: &Path, exists: &dyn Fn(&Path) -> bool) -> Option<PathBuf> {
+        EXTENSIONS.iter().find_map(|extension| {
+            let candidate = path.join(format!("index.{extension}"));
+            self.contains(&candidate, exists).then_some(candidate)
+        })
+    }
+
+    /// Resolves `specifier` using the `paths` and `baseUrl` options of the
+    /// closest `tsconfig.json`
+    fn resolve_tsconfig(
+        &self,
+        directory: &Path,
+        specifier: &str,
+        exists: &dyn Fn(&Path) -> bool,
+    ) -> Option<PathBuf> {
+        let (tsconfig_directory, tsconfig) = self.find_closest(directory, |directory| {
+            self.tsconfigs
+                .get(directory)
+                .map(|tsconfig| tsconfig.clone())
+        })?;
+        let TsConfigJson { compiler_options } = tsconfig.as_ref();
+        let base_url = compiler_options
+            .base_url
+            .as_ref()
+            .map(|base_url| tsconfig_directory.join(base_url));
+
+        // The pattern with the longest prefix wins, an exact match always wins
+        let paths = compiler_options
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| match pattern.split_once('*') {
+                None => (pattern == specifier).then_some((usize::MAX, "", targets)),
+                Some((prefix, suffix)) => {
+                    let matched = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                    Some((prefix.len(), matched, targets))
+                }
+            })
+            .max_by_key(|(prefix_len, _, _)| *prefix_len);
+
+        if let Some((_, matched, targets)) = paths {
+            let paths_base = base_url.as_deref().unwrap_or(&tsconfig_directory);
+            let resolved = targets.iter().find_map(|target| {
+                self.resolve_path(&paths_base.join(target.replace('*', matched)), exists)
+            });
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+
+        base_url.and_then(|base_url| self.resolve_path(&base_url.join(specifier), exists))
+    }
+
+    /// Resolves `specifier` as a package installed in a `node_modules`
+    /// directory, or as a package of the project
+    fn resolve_package(
+        &self,
+        directory: &Path,
+        specifier: &str,
+        exists: &dyn Fn(&Path) -> bool,
+    ) -> Option<PathBuf> {
+        let (name, subpath) = split_package_specifier(specifier)?;
+
+        let installed = self.find_closest(directory, |directory| {
+            let package_directory = directory.join("node_modules").join(name);
+            self.manifests
+                .get(&package_directory)
+                .map(|manifest| (package_directory, manifest.clone()))
+        });
+        if let Some((_, (package_directory, manifest))) = installed {
+            return self.resolve_package_subpath(&package_directory, &manifest, &subpath, exists);
+        }
+
+        // The packages of the project, e.g. the packages of a monorepo
+        let local = self.manifests.iter().find_map(|entry| {
+            (entry.value().name.as_deref() == Some(name))
+                .then(|| (entry.key().clone(), entry.value().clone()))
+        });
+        let (package_directory, manifest) = local?;
+        self.resolve_package_subpath(&package_directory, &manifest, &subpath, exists)
+    }
+
+    /// Resolves the `subpath` of the package at `directory`
+    fn resolve_package_subpath(
+        &self,
+        directory: &Path,
+        manifest: &PackageJson,
+        subpath: &str,
+        exists: &dyn Fn(&Path) -> bool,
+    ) -> Option<PathBuf> {
+        if let Some(exports) = &manifest.exports {
+            // The exports are exact paths, they don't need to be part of the graph
+            let target = exports.resolve(subpath, &CONDITIONS)?;
+            let target = normalize_path(&directory.join(target));
+            return Some(self.resolve_file(&target, exists).unwrap_or(target));
+        }
+
+        if subpath == "." {
+            self.resolve_directory(directory, exists)
+        } else {
+            self.resolve_path(&directory.join(subpath), exists)
+        }
+    }
+
+    /// Returns `true` if the module at `path` is an entry point of its package,
+    /// declared by the `main`, `bin` or `exports` fields of the closest
+    /// `package.json`. When the package declares neither `main` nor `exports`,
+    /// its `index` file is the entry point.
+    pub fn is_entry_point(&self, path: &Path) -> bool {
+        let path = normalize_path(path);
+        let Some(closest) = path.parent().and_then(|directory| {
+            self.find_closest(directory, |directory| {
+                self.manifests
+                    .get(directory)
+                    .map(|manifest| manifest.clone())
+            })
+        }) else {
+            return false;
+        };
+        let (directory, manifest) = closest;
+
+        let mut targets: Vec<&str> = manifest.main.as_deref().into_iter().collect();
+        if let Some(exports) = &manifest.exports {
+            targets.extend(exports.targets());
+        } else if manifest.main.is_none()
+            && self.resolve_index(&directory, &|_| false).as_ref() == Some(&path)
+        {
+            return true;
+        }
+        if let Some(bin) = &manifest.bin {
+            targets.extend(bin.paths());
+        }
+
+        targets
+            .into_iter()
+            .any(|target| match target.split_once('*') {
+                // Subpath patterns, e.g. `./features/*.js`, are matched without
+                // the extensions, because the sources can be TypeScript files
+                Some((prefix, suffix)) => {
+                    let Some(relative) = path
+                        .strip_prefix(&directory)
+                        .ok()
+                        .and_then(|relative| relative.to_str())
+                    else {
+                        return false;
+                    };
+                    let relative = relative.replace('\\', "/");
+                    let prefix = prefix.trim_start_matches("./");
+                    strip_extension(&relative)
+                        .strip_prefix(prefix)
+                        .is_some_and(|matched| matched.ends_with(strip_extension(suffix)))
+                }
+                None => {
+                    let target = normalize_path(&directory.join(target));
+                    self.resolve_file(&target, &|_| false)
+                        .or_else(|| self.resolve_index(&target, &|_| false))
+                        .is_some_and(|resolved| resolved == path)
+                }
+            })
+    }
+
+    /// Returns the first value returned by `find` for `directory` and its
+    /// ancestors, along with the directory that matched
+    fn find_closest<T>(
+        &self,
+        directory: &Path,
+        find: impl Fn(&Path) -> Option<T>,
+    ) -> Option<(PathBuf, T)> {
+        let directory = normalize_path(directory);
+        directory
+            .ancestors()
+            .find_map(|ancestor| find(ancestor).map(|value| (ancestor.to_path_buf(), value)))
+    }
+}
+
+fn is_relative_specifier(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+}
+
+/// Removes the extension of the last segment of `path`, if any
+fn strip_extension(path: &str) -> &str {
+    match path.rfind('.') {
+        Some(index) if !path[index..].contains('/') => &path[..index],
+        _ => path,
+    }
+}
+
+/// Splits a package specifier into the name of the package and the subpath
+/// of the package, e.g. `@scope/package/feature` into `@scope/package` and
+/// `./feature`
+fn split_package_specifier(specifier: &str) -> Option<(&str, String)> {
+    let separator = if specifier.starts_with('@') {
+        let (scope, rest) = specifier.split_once('/')?;
+        rest.find('/').map(|index| scope.len() + 1 + index)
+    } else {
+        specifier.find('/')
+    };
+
+    match separator {
+        Some(index) => {
+            let (name, subpath) = specifier.split_at(index);
+            Some((name, format!(".{subpath}")))
+        }
+        None => Some((specifier, String::from("."))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_package_specifier;
+    use crate::{ModuleGraph, ModuleInfo};
+    use biome_project::{CompilerOptions, PackageBin, PackageExports, PackageJson, TsConfigJson};
+    use std::path::{Path, PathBuf};
+
+    fn graph_with_modules(paths: &[&str]) -> ModuleGraph {
+        let graph = ModuleGraph::default();
+        for path in paths {
+            graph.update_module(Path::new(path), ModuleInfo::default());
+        }
+        graph
+    }
+
+    fn resolve(graph: &ModuleGraph, importer: &str, specifier: &str) -> Option<PathBuf> {
+        graph.resolve(Path::new(importer), specifier)
+    }
+
+    #[test]
+    fn resolves_relative_imports() {
+        let graph = graph_with_modules(&[
+            "/project/src/a.ts",
+            "/project/src/b.js",
+            "/project/src/utils/index.ts",
+            "/project/lib/c.mts",
+        ]);
+
+        assert_eq!(
+            resolve(&graph, "/project/src/b.js", "./a"),
+            Some(PathBuf::from("/project/src/a.ts"))
+        );
+        assert_eq!(
+            resolve(&graph, "/project/src/b.js", "./a.js"),
+            Some(PathBuf::from("/project/src/a.ts"))
+        );
+        assert_eq!(
+            resolve(&graph, "/project/src/a.ts", "./b.js"),
+            Some(PathBuf::from("/project/src/b.js"))
+        );
+        assert_eq!(
+            resolve(&graph, "/project/src/a.ts", "./utils"),
+            Some(PathBuf::from("/project/src/utils/index.ts"))
+        );
+        assert_eq!(resolve(&graph, "/project/src/utils/index.ts", ".."), None);
+        assert_eq!(
+            resolve(&graph, "/project/src/a.ts", "../lib/c.mjs"),
+            Some(PathBuf::from("/project/lib/c.mts"))
+        );
+        assert_eq!(resolve(&graph, "/project/src/a.ts", "./missing"), None);
+    }
+
+    #[test]
+    fn resolves_relative_paths() {
+        let graph = graph_with_modules(&["src/a.js", "src/nested/b.js"]);
+
+        assert_eq!(
+            resolve(&graph, "src/nested/b.js", "../a"),
+            Some(PathBuf::from("src/a.js"))
+        );
+        assert_eq!(
+            resolve(&graph, "./src/a.js", "./nested/b"),
+            Some(PathBuf::from("src/nested/b.js"))
+        );
+    }
+
+    #[test]
+    fn resolves_modules_outside_of_the_graph() {
+        let graph = graph_with_modules(&["/project/src/a.js"]);
+
+        let candidates = graph.resolution_candidates(Path::new("/project/src/a.js"), "./b.js");
+        assert_eq!(
+            candidates[..4],
+            [
+                PathBuf::from("/project/src/b.js"),
+                PathBuf::from("/project/src/b.ts"),
+                PathBuf::from("/project/src/b.tsx"),
+                PathBuf::from("/project/src/b.d.ts"),
+            ]
+        );
+        assert!(candidates.contains(&PathBuf::from("/project/src/b.js/index.ts")));
+
+        assert_eq!(
+            graph.resolve_with(Path::new("/project/src/a.js"), "./b", &|path| {
+                path == Path::new("/project/src/b.tsx")
+            }),
+            Some(PathBuf::from("/project/src/b.tsx"))
+        );
+    }
+
+    #[test]
+    fn resolves_tsconfig_paths() {
+        let graph = graph_with_modules(&[
+            "/project/src/components/button.tsx",
+            "/project/src/services/api.ts",
+            "/project/vendor/services/legacy.ts",
+            "/project/src/main.ts",
+        ]);
+        graph.update_tsconfig(
+            Path::new("/project/tsconfig.json"),
+            TsConfigJson {
+                compiler_options: CompilerOptions {
+                    base_url: Some(String::from("src")),
+                    paths: [
+                        (
+                            String::from("@components/*"),
+                            vec![String::from("components/*")],
+                        ),
+                        (
+                            String::from("@services/*"),
+                            vec![
+                                String::from("services/*"),
+                                String::from("../vendor/services/*"),
+                            ],
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                },
+            },
+        );
+
+        assert_eq!(
+            resolve(&graph, "/project/src/main.ts", "@components/button"),
+            Some(PathBuf::from("/project/src/components/button.tsx"))
+        );
+        assert_eq!(
+            resolve(&graph, "/project/src/main.ts", "@services/legacy"),
+            Some(PathBuf::from("/project/vendor/services/legacy.ts"))
+        );
+        assert_eq!(
+            resolve(&graph, "/project/src/main.ts", "services/api"),
+            Some(PathBuf::from("/project/src/services/api.ts"))
+        );
+        assert_eq!(resolve(&graph, "/project/src/main.ts", "react"), None);
+    }
+
+    #[test]
+    fn resolves_packages() {
+        let graph = graph_with_modules(&[
+            "/project/packages/ui/src/index.ts",
+            "/project/packages/ui/src/button.ts",
+            "/project/packages/utils/lib/main.js",
+            "/project/app/main.ts",
+        ]);
+        graph.update_manifest(
+            Path::new("/project/packages/ui/package.json"),
+            PackageJson {
+                name: Some(String::from("@acme/ui")),
+                exports: Some(PackageExports::Map(vec![
+                    (
+                        String::from("."),
+                        PackageExports::Map(vec![
+                            (
+                                String::from("import"),
+                                PackageExports::Path(String::from("./src/index.ts")),
+                            ),
+                            (
+                                String::from("default"),
+                                PackageExports::Path(String::from("./dist/index.js")),
+                            ),
+                        ]),
+                    ),
+                    (
+                        String::from("./*"),
+                        PackageExports::Path(String::from("./src/*.ts")),
+                    ),
+                    (String::from("./internal/*"), PackageExports::Null),
+                ])),
+                ..Default::default()
+            },
+        );
+        graph.update_manifest(
+            Path::new("/project/packages/utils/package.json"),
+            PackageJson {
+                name: Some(String::from("utils")),
+                main: Some(String::from("lib/main")),
+                ..Default::default()
+            },
+        );
+        graph.update_manifest(
+            Path::new("/project/node_modules/react/package.json"),
+            PackageJson {
+                name: Some(String::from("react")),
+                main: Some(String::from("index.js")),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            resolve(&graph, "/project/app/main.ts", "@acme/ui"),
+            Some(PathBuf::from("/project/packages/ui/src/index.ts"))
+        );
+        assert_eq!(
+            resolve(&graph, "/project/app/main.ts", "@acme/ui/button"),
+            Some(PathBuf::from("/project/packages/ui/src/button.ts"))
+        );
+        assert_eq!(
+            resolve(&graph, "/project/app/main.ts", "@acme/ui/internal/secret"),
+            None
+        );
+        assert_eq!(
+            resolve(&graph, "/project/app/main.ts", "utils"),
+            Some(PathBuf::from("/project/packages/utils/lib/main.js"))
+        );
+        // Installed packages aren't part of the graph
+        assert_eq!(resolve(&graph, "/project/app/main.ts", "react"), None);
+    }
+
+    #[test]
+    fn detects_entry_points() {
+        let graph = graph_with_modules(&[
+            "/project/packages/ui/src/index.ts",
+            "/project/packages/ui/src/features/button.ts",
+            "/project/packages/ui/src/utils.ts",
+            "/project/packages/cli/bin/cli.js",
+            "/project/packages/cli/src/main.js",
+            "/project/packages/lib/index.js",
+        ]);
+        graph.update_manifest(
+            Path::new("/project/packages/ui/package.json"),
+            PackageJson {
+                exports: Some(PackageExports::Map(vec![
+                    (
+                        String::from("."),
+                        PackageExports::Path(String::from("./src/index.js")),
+                    ),
+                    (
+                        String::from("./features/*"),
+                        PackageExports::Path(String::from("./src/features/*.js")),
+                    ),
+                ])),
+                ..Default::default()
+            },
+        );
+        graph.update_manifest(
+            Path::new("/project/packages/cli/package.json"),
+            PackageJson {
+                main: Some(String::from("src/main")),
+                bin: Some(PackageBin::Map(vec![(
+                    String::from("cli"),
+                    String::from("./bin/cli.js"),
+                )])),
+                ..Default::default()
+            },
+        );
+        graph.update_manifest(
+            Path::new("/project/packages/lib/package.json"),
+            PackageJson::default(),
+        );
+
+        let is_entry_point = |path: &str| graph.is_entry_point(Path::new(path));
+        assert!(is_entry_point("/project/packages/ui/src/index.ts"));
+        assert!(is_entry_point(
+            "/project/packages/ui/src/features/button.ts"
+        ));
+        assert!(!is_entry_point("/project/packages/ui/src/utils.ts"));
+        assert!(is_entry_point("/project/packages/cli/bin/cli.js"));
+        assert!(is_entry_point("/project/packages/cli/src/main.js"));
+        assert!(is_entry_point("/project/packages/lib/index.js"));
+        assert!(!is_entry_point("/other/index.js"));
+    }
+
+    #[test]
+    fn splits_package_specifiers() {
+        assert_eq!(
+            split_package_specifier("react"),
+            Some(("react", String::from(".")))
+        );
+        assert_eq!(
+            split_package_specifier("react/jsx-runtime"),
+            Some(("react", String::from("./jsx-runtime")))
+        );
+        assert_eq!(
+            split_package_specifier("@scope/package"),
+            Some(("@scope/package", String::from(".")))
+        );
+        assert_eq!(
+            split_package_specifier("@scope/package/feature/a"),
+            Some(("@scope/package", String::from("./feature/a")))
+        );
+        assert_eq!(split_package_specifier("@scope"), None);
+    }
+}
@@ -1,8 +1,10 @@
 mod package_json;
 mod tsconfig_json;
 
-pub use crate::node_js_project::package_json::{Dependencies, PackageJson, PackageType};
-use crate::node_js_project::tsconfig_json::TsConfigJson;
+pub use crate::node_js_project::package_json::{
+    Dependencies, PackageBin, PackageExports, PackageJson, PackageType,
+};
+pub use crate::node_js_project::tsconfig_json::{CompilerOptions, TsConfigJson};
 use crate::{Manifest, Project, ProjectAnalyzeDiagnostic, ProjectAnalyzeResult, LICENSE_LIST};
 use biome_rowan::Language;
 use std::path::{Path, PathBuf};
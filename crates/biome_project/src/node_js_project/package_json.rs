@@ -19,6 +19,12 @@ pub struct PackageJson {
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
     pub r#type: Option<PackageType>,
+    /// The entry point of the package, used when `exports` isn't defined
+    pub main: Option<String>,
+    /// The entry points of the package, see [PackageExports]
+    pub exports: Option<PackageExports>,
+    /// The executables of the package, see [PackageBin]
+    pub bin: Option<PackageBin>,
 }
 
 impl Manifest for PackageJson {
@@ -136,6 +142,15 @@ impl DeserializationVisitor for PackageJsonVisitor {
                 "type" => {
                     result.r#type = Deserializable::deserialize(&value, &key_text, diagnostics);
                 }
+                "main" => {
+                    result.main = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "bin" => {
+                    result.bin = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Biome deems important
@@ -176,3 +191,224 @@ impl PackageType {
         matches!(self, Self::Module)
     }
 }
+
+/// The value of the `exports` field of a `package.json`, or one of its nested
+/// values.
+///
+/// See <https://nodejs.org/api/packages.html#package-entry-points>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PackageExports {
+    /// A path relative to the package, e.g. `"./dist/index.js"`
+    Path(String),
+    /// A list of fallbacks, the first one that resolves is used
+    Fallbacks(Vec<PackageExports>),
+    /// Either a map of subpaths, when the keys start with `.`, or a map of
+    /// conditions such as `import`, `require` and `default`. The order of the
+    /// entries is preserved, because the first matching condition wins.
+    Map(Vec<(String, PackageExports)>),
+    /// `null`, the subpath isn't exported
+    Null,
+}
+
+impl PackageExports {
+    /// Returns the path exported by the package for `subpath`, e.g. `.` or
+    /// `./feature`, using the first entry that matches one of `conditions`.
+    pub fn resolve(&self, subpath: &str, conditions: &[&str]) -> Option<String> {
+        match self {
+            Self::Map(entries) if entries.iter().any(|(key, _)| key.starts_with('.')) => {
+                if let Some((_, target)) = entries.iter().find(|(key, _)| key == subpath) {
+                    return target.resolve_target(None, conditions);
+                }
+
+                // Subpath patterns, the pattern with the longest prefix wins
+                entries
+                    .iter()
+                    .filter_map(|(key, target)| {
+                        let (prefix, suffix) = key.split_once('*')?;
+                        let matched = subpath.strip_prefix(prefix)?.strip_suffix(suffix)?;
+                        Some((prefix.len(), matched, target))
+                    })
+                    .max_by_key(|(prefix_len, _, _)| *prefix_len)
+                    .and_then(|(_, matched, target)| {
+                        target.resolve_target(Some(matched), conditions)
+                    })
+            }
+            // Sugar for `{ ".": <exports> }`
+            _ if subpath == "." => self.resolve_target(None, conditions),
+            _ => None,
+        }
+    }
+
+    /// Returns all the paths that can be exported, including the subpath
+    /// patterns, regardless of the conditions
+    pub fn targets(&self) -> Vec<&str> {
+        match self {
+            Self::Path(path) => vec![path.as_str()],
+            Self::Fallbacks(fallbacks) => fallbacks.iter().flat_map(Self::targets).collect(),
+            Self::Map(entries) => entries
+                .iter()
+                .flat_map(|(_, target)| target.targets())
+                .collect(),
+            Self::Null => Vec::new(),
+        }
+    }
+
+    /// Resolves a target of the exports, replacing `*` with `matched` when
+    /// the target comes from a subpath pattern
+    fn resolve_target(&self, matched: Option<&str>, conditions: &[&str]) -> Option<String> {
+        match self {
+            Self::Path(path) => Some(match matched {
+                Some(matched) => path.replace('*', matched),
+                None => path.clone(),
+            }),
+            Self::Fallbacks(fallbacks) => fallbacks
+                .iter()
+                .find_map(|fallback| fallback.resolve_target(matched, conditions)),
+            Self::Map(entries) => entries
+                .iter()
+                .filter(|(condition, _)| conditions.contains(&condition.as_str()))
+                .find_map(|(_, target)| target.resolve_target(matched, conditions)),
+            Self::Null => None,
+        }
+    }
+}
+
+impl Deserializable for PackageExports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageExportsVisitor, name, diagnostics)
+    }
+}
+
+struct PackageExportsVisitor;
+impl DeserializationVisitor for PackageExportsVisitor {
+    type Output = PackageExports;
+
+    const EXPECTED_TYPE: DeserializableTypes = DeserializableTypes::STR
+        .union(DeserializableTypes::ARRAY)
+        .union(DeserializableTypes::MAP)
+        .union(DeserializableTypes::NULL);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Null)
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::Path(value.text().to_string()))
+    }
+
+    fn visit_array(
+        self,
+        items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let fallbacks = items
+            .flatten()
+            .filter_map(|item| Deserializable::deserialize(&item, name, diagnostics))
+            .collect();
+        Some(PackageExports::Fallbacks(fallbacks))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(value) = Deserializable::deserialize(&value, &key_text, diagnostics) {
+                entries.push((key_text.text().to_string(), value));
+            }
+        }
+        Some(PackageExports::Map(entries))
+    }
+}
+
+/// The value of the `bin` field of a `package.json`.
+///
+/// See <https://docs.npmjs.com/cli/configuring-npm/package-json#bin>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PackageBin {
+    /// A single executable, named after the package
+    Path(String),
+    /// A map of executable names to paths
+    Map(Vec<(String, String)>),
+}
+
+impl PackageBin {
+    /// Returns the paths of the executables, relative to the package
+    pub fn paths(&self) -> Vec<&str> {
+        match self {
+            Self::Path(path) => vec![path.as_str()],
+            Self::Map(entries) => entries.iter().map(|(_, path)| path.as_str()).collect(),
+        }
+    }
+}
+
+impl Deserializable for PackageBin {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageBinVisitor, name, diagnostics)
+    }
+}
+
+struct PackageBinVisitor;
+impl DeserializationVisitor for PackageBinVisitor {
+    type Output = PackageBin;
+
+    const EXPECTED_TYPE: DeserializableTypes =
+        DeserializableTypes::STR.union(DeserializableTypes::MAP);
+
+    fn visit_str(
+        self,
+        value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageBin::Path(value.text().to_string()))
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut entries = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if let Some(path) = Text::deserialize(&value, &key_text, diagnostics) {
+                entries.push((key_text.text().to_string(), path.text().to_string()));
+            }
+        }
+        Some(PackageBin::Map(entries))
+    }
+}
@@ -8,8 +8,19 @@ use rustc_hash::FxHashMap;
 #[derive(Debug, Default, Clone, Deserializable)]
 #[deserializable(unknown_fields = "allow")]
 pub struct TsConfigJson {
-    base_url: Option<String>,
-    paths: FxHashMap<String, Vec<String>>,
+    pub compiler_options: CompilerOptions,
+}
+
+/// The options of `compilerOptions` that affect how modules are resolved
+#[derive(Debug, Default, Clone, Deserializable)]
+#[deserializable(unknown_fields = "allow")]
+pub struct CompilerOptions {
+    /// The directory used to resolve non-relative module names, relative to
+    /// the `tsconfig.json`
+    pub base_url: Option<String>,
+    /// Mapping of module names to locations, relative to `baseUrl` if it's
+    /// defined, otherwise to the `tsconfig.json`
+    pub paths: FxHashMap<String, Vec<String>>,
 }
 
 impl Manifest for TsConfigJson {
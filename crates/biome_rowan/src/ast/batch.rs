@@ -309,6 +309,11 @@ where
         });
     }
 
+    /// Returns `true` when no change was pushed to the mutation
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
     /// Returns the range of the document modified by this mutation along with
     /// a list of individual text edits to be performed on the source code, or
     /// [None] if the mutation is empty
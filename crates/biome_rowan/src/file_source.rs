@@ -1,5 +1,34 @@
 use std::fmt::Display;
 
+/// The kind of document a source is embedded in, e.g. the `<script>` or
+/// `<style>` block of an Astro, HTML, Svelte or Vue file
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, Copy, Eq, PartialEq, Hash)]
+pub enum EmbeddingKind {
+    Astro,
+    Html,
+    Svelte,
+    Vue,
+    #[default]
+    None,
+}
+
+impl EmbeddingKind {
+    pub const fn is_astro(&self) -> bool {
+        matches!(self, EmbeddingKind::Astro)
+    }
+    pub const fn is_html(&self) -> bool {
+        matches!(self, EmbeddingKind::Html)
+    }
+    pub const fn is_svelte(&self) -> bool {
+        matches!(self, EmbeddingKind::Svelte)
+    }
+    pub const fn is_vue(&self) -> bool {
+        matches!(self, EmbeddingKind::Vue)
+    }
+}
+
 /// Errors around the construct of the source type
 #[derive(Debug)]
 pub enum FileSourceError {
@@ -13,6 +13,7 @@ use biome_formatter::{FormatError, PrintError};
 use biome_fs::{BiomePath, FileSystemDiagnostic};
 use biome_grit_patterns::CompileError;
 use biome_js_analyze::utils::rename::RenameError;
+use biome_js_minifier::MinifyError;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::ffi::OsStr;
@@ -48,6 +49,8 @@ pub enum WorkspaceError {
     Configuration(ConfigurationDiagnostic),
     /// Error thrown when Biome cannot rename a symbol.
     RenameError(RenameError),
+    /// Error thrown when Biome cannot minify a file.
+    MinifyError(MinifyError),
     /// Error emitted by the underlying transport layer for a remote Workspace
     TransportError(TransportError),
     /// Emitted when the file is ignored and should not be processed
@@ -62,6 +65,8 @@ pub enum WorkspaceError {
     ProtectedFile(ProtectedFile),
     /// Error when searching for a pattern
     SearchError(SearchError),
+    /// The code of the script blocks of a file couldn't be written back to the file
+    ScriptBlocksNotFound(ScriptBlocksNotFound),
 }
 
 impl WorkspaceError {
@@ -107,6 +112,10 @@ impl WorkspaceError {
             verbose_advice: ProtectedFileAdvice,
         })
     }
+
+    pub fn script_blocks_not_found(path: String) -> Self {
+        Self::ScriptBlocksNotFound(ScriptBlocksNotFound { path })
+    }
 }
 
 impl Error for WorkspaceError {}
@@ -135,6 +144,12 @@ impl From<FormatError> for WorkspaceError {
     }
 }
 
+impl From<MinifyError> for WorkspaceError {
+    fn from(err: MinifyError) -> Self {
+        Self::MinifyError(err)
+    }
+}
+
 impl From<TransportError> for WorkspaceError {
     fn from(err: TransportError) -> Self {
         Self::TransportError(err)
@@ -245,6 +260,19 @@ pub struct FileIgnored {
     path: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "internalError/fs",
+    message(
+        message("The changes to the script blocks of "{self.path}" couldn't be written back, because the blocks couldn't be found in the changed code."),
+        description = "The changes to the script blocks of {path} couldn't be written back, because the blocks couldn't be found in the changed code."
+    )
+)]
+pub struct ScriptBlocksNotFound {
+    #[location(resource)]
+    path: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FileTooLarge {
     path: String,
@@ -1,6 +1,7 @@
 use biome_analyze::{GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleMetadata};
 use biome_css_syntax::CssLanguage;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use std::{collections::BTreeMap, str::FromStr};
@@ -39,6 +40,8 @@ impl LintRulesVisitor {
             rules_metadata: BTreeMap::new(),
         };
 
+        // The HTML rules ported from JSX are visited first, so the original rules take precedence
+        biome_html_analyze::visit_registry(&mut visitor);
         biome_graphql_analyze::visit_registry(&mut visitor);
         biome_css_analyze::visit_registry(&mut visitor);
         biome_json_analyze::visit_registry(&mut visitor);
@@ -114,3 +117,19 @@ impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
         }
     }
 }
+
+impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.rules_metadata.insert(R::METADATA.name, R::METADATA);
+    }
+
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+}
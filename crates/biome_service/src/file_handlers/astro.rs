@@ -10,14 +10,19 @@ use crate::workspace::{
 use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
-use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+use biome_js_syntax::{EmbeddingKind, Language, LanguageVariant, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
-use regex::{Matches, Regex, RegexBuilder};
+use regex::{Regex, RegexBuilder};
+use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::LazyLock;
 
-use super::SearchCapabilities;
+use super::embedded::{
+    join_script_blocks, parse_script_blocks, script_blocks, script_blocks_start, ScriptBlock,
+    SCRIPT_FENCE,
+};
+use super::{SearchCapabilities, StructureCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct AstroFileHandler;
@@ -30,40 +35,54 @@ pub static ASTRO_FENCE: LazyLock<Regex> = LazyLock::new(|| {
 });
 
 impl AstroFileHandler {
-    /// It extracts the JavaScript code contained in the frontmatter of an Astro file
+    /// It extracts the JavaScript code contained in the frontmatter and the `<script>` tags of an Astro file.
+    /// They are joined in a single document that keeps their offsets.
     ///
-    /// If the frontmatter doesn't exist, an empty string is returned.
-    pub fn input(text: &str) -> &str {
-        let mut matches = Self::matches(text);
-        match (matches.next(), matches.next()) {
-            (Some(start), Some(end)) => &text[start.end()..end.start()],
-            _ => "",
-        }
+    /// If the frontmatter and the `<script>` tags don't exist, an empty string is returned.
+    pub fn input(text: &str) -> Cow<str> {
+        join_script_blocks(text, &Self::script_blocks(text))
     }
 
-    /// Returns the start byte offset of the Astro fence
+    /// Returns the start byte offset of the frontmatter, or of the first `<script>` tag
     pub fn start(input: &str) -> Option<u32> {
-        ASTRO_FENCE.find_iter(input).next().map(|m| m.end() as u32)
+        script_blocks_start(input, EmbeddingKind::Astro)
     }
 
-    fn matches(input: &str) -> Matches {
-        ASTRO_FENCE.find_iter(input)
+    /// Returns the range of the content of the frontmatter, after the line break
+    /// of the opening fence
+    pub(crate) fn frontmatter(text: &str) -> Option<Range<usize>> {
+        let mut matches = ASTRO_FENCE.find_iter(text);
+        let (start, end) = (matches.next()?, matches.next()?);
+        let after_fence = &text[start.end()..end.start()];
+        let line_break = after_fence.len()
+            - after_fence
+                .strip_prefix('\n')
+                .or_else(|| after_fence.strip_prefix("\r\n"))
+                .unwrap_or(after_fence)
+                .len();
+        Some(start.end() + line_break..end.start())
     }
 
-    /// It takes the original content of an Astro file, and new output of an Astro file. The output is only the content contained inside the
-    /// Astro fences. The function replaces `output` inside those fences.
-    pub fn output(input: &str, output: &str) -> String {
-        let mut matches = Self::matches(input);
-        if let (Some(start), Some(end)) = (matches.next(), matches.next()) {
-            format!(
-                "{}{}{}",
-                &input[..start.end() + 1],
-                output.trim_start(),
-                &input[end.start()..]
-            )
-        } else {
-            input.to_string()
-        }
+    /// Returns the frontmatter and the `<script>` tags of an Astro file, in source order.
+    /// They contain TypeScript.
+    pub(crate) fn script_blocks(text: &str) -> Vec<ScriptBlock> {
+        let frontmatter = Self::frontmatter(text);
+        let frontmatter_end = frontmatter.as_ref().map_or(0, |range| range.end);
+        let scripts = script_blocks(&SCRIPT_FENCE, text)
+            .into_iter()
+            .map(|block| block.range)
+            .filter(|range| range.start >= frontmatter_end);
+        frontmatter
+            .into_iter()
+            .chain(scripts)
+            .map(|range| ScriptBlock {
+                range,
+                language: Language::TypeScript {
+                    definition_file: false,
+                },
+                variant: LanguageVariant::Standard,
+            })
+            .collect()
     }
 }
 
@@ -80,40 +99,40 @@ impl ExtensionHandler for AstroFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                symbol_references: None,
                 fix_all: Some(fix_all),
+                transform: None,
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                minify: None,
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
+            structure: StructureCapabilities {
+                document_symbols: None,
+                folding_ranges: None,
+                selection_ranges: None,
+            },
         }
     }
 }
 
 fn parse(
     _rome_path: &BiomePath,
-    file_source: DocumentFileSource,
+    _file_source: DocumentFileSource,
     text: &str,
     _settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let frontmatter = AstroFileHandler::input(text);
-    let parse = parse_js_with_cache(
-        frontmatter,
-        file_source
-            .to_js_file_source()
-            .unwrap_or(JsFileSource::ts()),
-        JsParserOptions::default(),
-        cache,
-    );
+    let (any_parse, file_source) = parse_script_blocks(text, EmbeddingKind::Astro, cache);
 
     ParseResult {
-        any_parse: parse.into(),
-        language: Some(JsFileSource::astro().into()),
+        any_parse,
+        language: Some(file_source.into()),
     }
 }
 
@@ -1,6 +1,7 @@
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, ExtensionHandler, FixAllParams,
-    LintParams, LintResults, ParseResult, SearchCapabilities,
+    code_actions_with_analyzer, fix_all_with_analyzer, lint_with_analyzer, structure,
+    CodeActionsParams, ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult,
+    SearchCapabilities, StructureCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -12,29 +13,24 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, DocumentFileSource, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult,
+    DocumentFileSource, DocumentSymbol, DocumentSymbolKind, FixFileResult, GetSyntaxTreeResult,
     OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
 use biome_analyze::options::PreferredQuote;
-use biome_analyze::{
-    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never,
-    RuleCategoriesBuilder, RuleCategory, RuleError,
-};
+use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
 use biome_css_analyze::analyze;
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::format_node;
 use biome_css_parser::CssParserOptions;
-use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
-use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
+use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxKind, CssSyntaxNode};
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, Direction, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
-use std::borrow::Cow;
 use tracing::{debug_span, error, info, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -152,6 +148,8 @@ impl ServiceLanguage for CssLanguage {
         AnalyzerOptions {
             configuration,
             file_path: file_path.to_path_buf(),
+            suppression_reason: None,
+            profiler: None,
         }
     }
 }
@@ -172,15 +170,23 @@ impl ExtensionHandler for CssFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                symbol_references: None,
                 fix_all: Some(fix_all),
+                transform: None,
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                minify: None,
             },
             search: SearchCapabilities { search: None },
+            structure: StructureCapabilities {
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(structure::folding_ranges::<CssLanguage>),
+                selection_ranges: Some(structure::selection_ranges::<CssLanguage>),
+            },
         }
     }
 }
@@ -192,24 +198,33 @@ fn parse(
     settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let mut options = CssParserOptions {
+    let options = parser_options(biome_path, settings);
+    let parse = biome_css_parser::parse_css_with_cache(text, cache, options);
+    ParseResult {
+        any_parse: parse.into(),
+        language: None,
+    }
+}
+
+/// Returns the options of the CSS parser for the file at `biome_path`
+pub(crate) fn parser_options(
+    biome_path: &BiomePath,
+    settings: Option<&Settings>,
+) -> CssParserOptions {
+    let options = CssParserOptions {
         allow_wrong_line_comments: settings
             .and_then(|s| s.languages.css.parser.allow_wrong_line_comments)
             .unwrap_or_default(),
         css_modules: settings
             .and_then(|s| s.languages.css.parser.css_modules)
             .unwrap_or_default(),
-        grit_metavariables: false,
+        ..CssParserOptions::default()
     };
-    if let Some(settings) = settings {
-        options = settings
+    match settings {
+        Some(settings) => settings
             .override_settings
-            .to_override_css_parser_options(biome_path, options);
-    }
-    let parse = biome_css_parser::parse_css_with_cache(text, cache, options);
-    ParseResult {
-        any_parse: parse.into(),
-        language: None,
+            .to_override_css_parser_options(biome_path, options),
+        None => options,
     }
 }
 
@@ -308,108 +323,14 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting CSS file", path =? params.path, language =? params.language).in_scope(
         move || {
-            let workspace_settings = &params.workspace;
-            let analyzer_options =
-                workspace_settings.analyzer_options::<CssLanguage>(params.path, &params.language);
-            let tree = params.parse.tree();
-
-            let has_only_filter = !params.only.is_empty();
-            let rules = params
-                .workspace
-                .settings()
-                .as_ref()
-                .and_then(|settings| settings.as_linter_rules(params.path.as_path()));
-
-            let (enabled_rules, disabled_rules) =
-                AnalyzerVisitorBuilder::new(params.workspace.settings())
-                    .with_syntax_rules()
-                    .with_linter_rules(&params.only, &params.skip, params.path.as_path())
-                    .with_assists_rules(&params.only, &params.skip, params.path.as_path())
-                    .finish();
-            let mut diagnostics = params.parse.into_diagnostics();
-
-            let filter = AnalysisFilter {
-                categories: params.categories,
-                enabled_rules: Some(enabled_rules.as_slice()),
-                disabled_rules: &disabled_rules,
-                range: None,
-            };
-
-            // Do not report unused suppression comment diagnostics if:
-            // - it is a syntax-only analyzer pass, or
-            // - if a single rule is run.
-            let ignores_suppression_comment =
-                !filter.categories.contains(RuleCategory::Lint) || has_only_filter;
-
-            let mut diagnostic_count = diagnostics.len() as u32;
-            let mut errors = diagnostics
-                .iter()
-                .filter(|diag| diag.severity() <= Severity::Error)
-                .count();
-
-            info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
-
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
-
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
-                        }
-
-                        let error = diagnostic.with_severity(severity);
-
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
-                    }
-                }
-
-                ControlFlow::<Never>::Continue(())
-            });
-
-            diagnostics.extend(
-                analyze_diagnostics
-                    .into_iter()
-                    .map(biome_diagnostics::serde::Diagnostic::new)
-                    .collect::<Vec<_>>(),
-            );
-            let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
-
-            LintResults {
-                diagnostics,
-                errors,
-                skipped_diagnostics,
-            }
+            let tree: CssRoot = params.parse.tree();
+            let file_source = params.language.to_css_file_source().unwrap_or_default();
+            lint_with_analyzer(params, |filter, options, emit_signal| {
+                analyze(&tree, filter, options, file_source, emit_signal).1
+            })
         },
     )
 }
@@ -422,192 +343,98 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
 
 #[tracing::instrument(level = "debug", skip(params))]
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    let CodeActionsParams {
-        parse,
-        range,
-        workspace,
-        path,
-        manifest: _,
-        language,
-        only,
-        skip,
-    } = params;
-    debug_span!("Code actions CSS", range =? range, path =? path).in_scope(move || {
-        let tree = parse.tree();
-        trace_span!("Parsed file", tree =? tree).in_scope(move || {
-            let Some(_) = language.to_css_file_source() else {
-                error!("Could not determine the file source of the file");
-                return PullActionsResult {
-                    actions: Vec::new(),
+    debug_span!("Code actions CSS", range =? params.range, path =? params.path).in_scope(
+        move || {
+            let tree: CssRoot = params.parse.tree();
+            trace_span!("Parsed file", tree =? tree).in_scope(move || {
+                let Some(file_source) = params.language.to_css_file_source() else {
+                    error!("Could not determine the file source of the file");
+                    return PullActionsResult {
+                        actions: Vec::new(),
+                    };
                 };
-            };
-
-            let analyzer_options = workspace.analyzer_options::<CssLanguage>(path, &language);
-            let mut actions = Vec::new();
-            let (enabled_rules, disabled_rules) =
-                AnalyzerVisitorBuilder::new(params.workspace.settings())
-                    .with_syntax_rules()
-                    .with_linter_rules(&only, &skip, params.path.as_path())
-                    .with_assists_rules(&only, &skip, params.path.as_path())
-                    .finish();
-
-            let filter = AnalysisFilter {
-                categories: RuleCategoriesBuilder::default()
-                    .with_syntax()
-                    .with_lint()
-                    .with_action()
-                    .build(),
-                enabled_rules: Some(enabled_rules.as_slice()),
-                disabled_rules: &disabled_rules,
-                range,
-            };
-
-            info!("CSS runs the analyzer");
-
-            analyze(&tree, filter, &analyzer_options, |signal| {
-                actions.extend(signal.actions().into_code_action_iter().map(|item| {
-                    CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
-                            .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        suggestion: item.suggestion,
-                    }
-                }));
-
-                ControlFlow::<Never>::Continue(())
-            });
-
-            PullActionsResult { actions }
-        })
-    })
+
+                info!("CSS runs the analyzer");
+
+                code_actions_with_analyzer(params, |filter, options, emit_signal| {
+                    analyze(&tree, filter, options, file_source, emit_signal);
+                })
+            })
+        },
+    )
 }
 
 /// If applies all the safe fixes to the given syntax tree.
 pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    let mut tree: CssRoot = params.parse.tree();
-    let Some(settings) = params.workspace.settings() else {
-        return Ok(FixFileResult {
-            actions: Vec::new(),
-            errors: 0,
-            skipped_suggested_fixes: 0,
-            code: tree.syntax().to_string(),
-        });
-    };
+    let tree: CssRoot = params.parse.tree();
+    let file_source = params
+        .document_file_source
+        .to_css_file_source()
+        .unwrap_or_default();
+    fix_all_with_analyzer(
+        &params,
+        tree,
+        |tree, filter, options, emit_signal| {
+            analyze(tree, filter, options, file_source, emit_signal).0
+        },
+        |tree| {
+            Ok(format_node(
+                params
+                    .workspace
+                    .format_options::<CssLanguage>(params.biome_path, &params.document_file_source),
+                tree.syntax(),
+            )?
+            .print()?
+            .into_code())
+        },
+    )
+}
 
-    // Compute final rules (taking `overrides` into account)
-    let rules = settings.as_linter_rules(params.biome_path.as_path());
-    let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
-        .with_syntax_rules()
-        .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
-        .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
-        .finish();
-
-    let filter = AnalysisFilter {
-        categories: RuleCategoriesBuilder::default()
-            .with_syntax()
-            .with_lint()
-            .build(),
-        enabled_rules: Some(enabled_rules.as_slice()),
-        disabled_rules: &disabled_rules,
-        range: None,
-    };
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: CssRoot = parse.tree();
+    rule_symbols(root.syntax())
+}
 
-    let mut actions = Vec::new();
-    let mut skipped_suggested_fixes = 0;
-    let mut errors: u16 = 0;
-    let analyzer_options = params
-        .workspace
-        .analyzer_options::<CssLanguage>(params.biome_path, &params.document_file_source);
-    loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
-                }
+/// Returns the rules declared by the descendants of `node`. The rules nested
+/// in a rule become its children.
+fn rule_symbols(node: &CssSyntaxNode) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for child in node.children() {
+        let kind = match child.kind() {
+            CssSyntaxKind::CSS_QUALIFIED_RULE | CssSyntaxKind::CSS_NESTED_QUALIFIED_RULE => {
+                DocumentSymbolKind::Class
             }
-
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
-
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
-                        }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
-                        }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
-                        }
-                    }
-                }
+            CssSyntaxKind::CSS_AT_RULE => DocumentSymbolKind::Module,
+            _ => {
+                symbols.extend(rule_symbols(&child));
+                continue;
             }
+        };
 
-            ControlFlow::Continue(())
+        // The name of a rule is its prelude, the selectors or the at-rule
+        // parameters that precede its block
+        let range = child.text_trimmed_range();
+        let prelude_end = child
+            .descendants_tokens(Direction::Next)
+            .find(|token| token.kind() == CssSyntaxKind::L_CURLY)
+            .and_then(|token| token.prev_token())
+            .map_or(range.end(), |token| token.text_trimmed_range().end());
+        let selection_range = TextRange::new(range.start(), prelude_end.max(range.start()));
+        let text = child.text_trimmed().to_string();
+        let name = text[..usize::from(selection_range.len())]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        symbols.push(DocumentSymbol {
+            name,
+            kind,
+            range,
+            selection_range,
+            children: rule_symbols(&child),
         });
-
-        match action {
-            Some(action) => {
-                if let (root, Some((range, _))) =
-                    action.mutation.commit_with_text_range_and_edit(true)
-                {
-                    tree = match CssRoot::cast(root) {
-                        Some(tree) => tree,
-                        None => {
-                            return Err(WorkspaceError::RuleError(
-                                RuleError::ReplacedRootWithNonRootError {
-                                    rule_name: action.rule_name.map(|(group, rule)| {
-                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
-                                    }),
-                                },
-                            ));
-                        }
-                    };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
-                }
-            }
-            None => {
-                let code = if params.should_format {
-                    format_node(
-                        params.workspace.format_options::<CssLanguage>(
-                            params.biome_path,
-                            &params.document_file_source,
-                        ),
-                        tree.syntax(),
-                    )?
-                    .print()?
-                    .into_code()
-                } else {
-                    tree.syntax().to_string()
-                };
-                return Ok(FixFileResult {
-                    code,
-                    skipped_suggested_fixes,
-                    actions,
-                    errors: errors.into(),
-                });
-            }
-        }
     }
+    symbols
 }
 
 #[cfg(test)]
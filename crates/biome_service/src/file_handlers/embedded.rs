@@ -0,0 +1,890 @@
+//! Extraction of the script and style blocks of the files that embed JavaScript
+//! and CSS, such as Vue and Svelte files.
+//!
+//! A file can contain several script blocks, e.g. `<script>` and `<script setup>`
+//! in Vue, `<script context="module">` and `<script>` in Svelte, or the frontmatter
+//! and the `<script>` tags of Astro. The blocks are joined in a single JavaScript
+//! document, so that the bindings of a block are visible from the other blocks.
+//! The text between two blocks is replaced with a comment followed by an empty
+//! statement, of the same length, so that the offsets of the document are the
+//! offsets of the file, shifted by the start of the first block.
+//!
+//! When the document is fixed, the code of each block is found again by looking
+//! for these comments in the syntax tree of the new document, and it's written
+//! back in the file. Each block is formatted separately.
+//!
+//! The `<style>` blocks are parsed, linted and formatted separately with the
+//! CSS tools. Their diagnostics are shifted by the start of the block, and their
+//! outputs are written back in the file.
+
+use crate::file_handlers::{
+    css, javascript, parse_lang_from_script_opening_tag, AstroFileHandler, FixAllParams,
+    LintParams, LintResults, SVELTE_FENCE, VUE_FENCE,
+};
+use crate::settings::{Settings, WorkspaceSettingsHandle};
+use crate::workspace::{DocumentFileSource, FixAction, FixFileResult};
+use crate::WorkspaceError;
+use biome_analyze::RuleCategoriesBuilder;
+use biome_css_parser::CssParserOptions;
+use biome_css_syntax::{CssFileSource, CssLanguage};
+use biome_diagnostics::serde::Diagnostic;
+use biome_fs::BiomePath;
+use biome_js_formatter::format_node;
+use biome_js_parser::{parse_js_with_cache, JsParserOptions};
+use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, LanguageVariant};
+use biome_parser::AnyParse;
+use biome_rowan::{Direction, NodeCache, TextRange, TextSize};
+use biome_text_edit::TextEdit;
+use regex::Regex;
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+/// The start of the comment that replaces the text between two script blocks
+const GAP_START: &str = "/*";
+
+/// The end of the comment that replaces the text between two script blocks,
+/// followed by the empty statement that keeps the statements of the blocks apart
+const GAP_END: &str = "*/;";
+
+// https://regex101.com/r/E4n4hh/6
+pub(crate) static SCRIPT_FENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#).unwrap()
+});
+
+// https://regex101.com/r/E4n4hh/6
+pub(crate) static STYLE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?ixs)(?<opening><style(?:\s.*?)?>)\r?\n(?<style>(?U:.*))</style>"#).unwrap()
+});
+
+/// An attribute of an opening tag, with an optional quoted or unquoted value
+static ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"\s(?<name>[^\s"'>/=]+)(?:\s*=\s*(?:"(?<double>[^"]*)"|'(?<single>[^']*)'|(?<unquoted>[^\s"'>]+)))?"#,
+    )
+    .unwrap()
+});
+
+/// The values of the `type` attribute of the `<script>` tags that contain JavaScript
+const JAVASCRIPT_TYPES: &[&str] = &[
+    "module",
+    "text/javascript",
+    "application/javascript",
+    "text/typescript",
+    "application/typescript",
+];
+
+/// Returns the value of the attribute `name` of `opening_tag`.
+///
+/// The value of an attribute without value, e.g. `scoped`, is an empty string.
+fn tag_attribute<'a>(opening_tag: &'a str, name: &str) -> Option<&'a str> {
+    let attributes = &opening_tag[opening_tag.find(char::is_whitespace)?..];
+    ATTRIBUTE.captures_iter(attributes).find_map(|captures| {
+        if !captures["name"].eq_ignore_ascii_case(name) {
+            return None;
+        }
+        let value = captures
+            .name("double")
+            .or_else(|| captures.name("single"))
+            .or_else(|| captures.name("unquoted"))
+            .map_or("", |value| value.as_str());
+        Some(value)
+    })
+}
+
+/// A script block of a file
+#[derive(Debug)]
+pub(crate) struct ScriptBlock {
+    /// The range of the content of the block in the file
+    pub(crate) range: Range<usize>,
+    /// The language of the block, e.g. TypeScript for `<script lang="ts">`
+    pub(crate) language: Language,
+    /// The variant of the block, e.g. JSX for `<script lang="jsx">`
+    pub(crate) variant: LanguageVariant,
+}
+
+impl ScriptBlock {
+    /// Returns the file source of the block, embedded in `embedding_kind`
+    fn file_source(&self, embedding_kind: EmbeddingKind) -> JsFileSource {
+        JsFileSource::from(self.language)
+            .with_variant(self.variant)
+            .with_embedding_kind(embedding_kind)
+    }
+}
+
+/// Returns the script blocks of `text` that contain JavaScript, in source order.
+///
+/// `fence` must capture the opening tag of the block in the `opening` group,
+/// and the content of the block in the `script` group.
+pub(crate) fn script_blocks(fence: &Regex, text: &str) -> Vec<ScriptBlock> {
+    fence
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let opening_tag = captures.name("opening")?.as_str();
+            let script = captures.name("script")?;
+            let is_javascript = tag_attribute(opening_tag, "type").map_or(true, |value| {
+                JAVASCRIPT_TYPES
+                    .iter()
+                    .any(|javascript_type| value.eq_ignore_ascii_case(javascript_type))
+            });
+            if !is_javascript {
+                return None;
+            }
+            let (language, variant) = parse_lang_from_script_opening_tag(opening_tag);
+            Some(ScriptBlock {
+                range: script.range(),
+                language,
+                variant,
+            })
+        })
+        .collect()
+}
+
+/// Returns the script blocks of a file embedded in `embedding_kind`, in source order
+pub(crate) fn embedded_script_blocks(
+    text: &str,
+    embedding_kind: EmbeddingKind,
+) -> Vec<ScriptBlock> {
+    match embedding_kind {
+        EmbeddingKind::Astro => AstroFileHandler::script_blocks(text),
+        EmbeddingKind::Vue => script_blocks(&VUE_FENCE, text),
+        EmbeddingKind::Svelte => script_blocks(&SVELTE_FENCE, text),
+        EmbeddingKind::Html | EmbeddingKind::None => Vec::new(),
+    }
+}
+
+/// Returns the JavaScript document made of the content of `blocks`.
+///
+/// If there's no script block, an empty string is returned.
+pub(crate) fn join_script_blocks<'a>(text: &'a str, blocks: &[ScriptBlock]) -> Cow<'a, str> {
+    match blocks {
+        [] => Cow::Borrowed(""),
+        [block] => Cow::Borrowed(&text[block.range.clone()]),
+        [first, .., last] => {
+            let mut document = String::with_capacity(last.range.end - first.range.start);
+            document.push_str(&text[first.range.clone()]);
+            for (gap, block) in script_block_gaps(text, blocks).zip(&blocks[1..]) {
+                document.push_str(&gap_comment(gap));
+                document.push_str(&text[block.range.clone()]);
+            }
+            Cow::Owned(document)
+        }
+    }
+}
+
+/// Returns the text between each pair of consecutive `blocks`
+fn script_block_gaps<'a>(
+    text: &'a str,
+    blocks: &'a [ScriptBlock],
+) -> impl Iterator<Item = &'a str> + 'a {
+    blocks
+        .windows(2)
+        .map(|pair| &text[pair[0].range.end..pair[1].range.start])
+}
+
+/// Returns a comment followed by an empty statement, of the same length as
+/// `gap`, that keeps the line breaks of `gap`
+fn gap_comment(gap: &str) -> String {
+    let padding = gap.len().saturating_sub(GAP_START.len() + GAP_END.len());
+    let mut comment = String::with_capacity(gap.len());
+    comment.push_str(GAP_START);
+    comment.extend(
+        gap.bytes()
+            .skip(GAP_START.len())
+            .take(padding)
+            .map(|byte| match byte {
+                b'\n' | b'\r' => byte as char,
+                _ => ' ',
+            }),
+    );
+    comment.push_str(GAP_END);
+    comment
+}
+
+/// Splits `document`, a JavaScript document created by [join_script_blocks]
+/// from the `blocks` of `text` and then changed, into the content of each block.
+///
+/// The gaps between the blocks are the comments of the syntax tree of `document`
+/// that have the text of the comments created by [join_script_blocks], in the
+/// same order, and are followed by an empty statement. The strings or the
+/// templates that contain the same text aren't gaps.
+///
+/// `None` is returned if a gap is missing, e.g. because a fix removed it.
+fn split_script_document<'a>(
+    document: &'a str,
+    text: &str,
+    blocks: &[ScriptBlock],
+    file_source: JsFileSource,
+) -> Option<Vec<&'a str>> {
+    if blocks.len() < 2 {
+        return Some(vec![document]);
+    }
+
+    let comments = script_block_gaps(text, blocks)
+        .map(|gap| {
+            let mut comment = gap_comment(gap);
+            comment.pop();
+            comment
+        })
+        .collect::<Vec<_>>();
+    let parse = biome_js_parser::parse(document, file_source, JsParserOptions::default());
+    let mut gaps: Vec<TextRange> = Vec::with_capacity(comments.len());
+    let pieces = parse
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .flat_map(|token| {
+            token
+                .leading_trivia()
+                .pieces()
+                .chain(token.trailing_trivia().pieces())
+        });
+    for piece in pieces {
+        let Some(comment) = comments.get(gaps.len()) else {
+            break;
+        };
+        let range = piece.text_range();
+        if piece.is_comments()
+            && piece.text() == comment
+            && document[usize::from(range.end())..].starts_with(';')
+        {
+            gaps.push(TextRange::new(
+                range.start(),
+                range.end() + TextSize::from(1),
+            ));
+        }
+    }
+    if gaps.len() != comments.len() {
+        return None;
+    }
+
+    let mut contents = Vec::with_capacity(blocks.len());
+    let mut previous_end = 0;
+    for gap in gaps {
+        contents.push(&document[previous_end..usize::from(gap.start())]);
+        previous_end = usize::from(gap.end());
+    }
+    contents.push(&document[previous_end..]);
+    Some(contents)
+}
+
+/// Replaces the content of `blocks` in `text` with their `outputs`
+fn replace_blocks(
+    text: &str,
+    blocks: impl IntoIterator<Item = Range<usize>>,
+    outputs: impl IntoIterator<Item = impl AsRef<str>>,
+) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut previous_end = 0;
+    for (range, output) in blocks.into_iter().zip(outputs) {
+        result.push_str(&text[previous_end..range.start]);
+        result.push_str(output.as_ref());
+        previous_end = range.end;
+    }
+    result.push_str(&text[previous_end..]);
+    result
+}
+
+/// Replaces the content of the script blocks of `text` with the content of
+/// the blocks of `document`, a JavaScript document created by
+/// [join_script_blocks] and then fixed or organized.
+///
+/// An error is returned if the content of a block can't be found in `document`.
+pub(crate) fn replace_script_blocks(
+    text: &str,
+    embedding_kind: EmbeddingKind,
+    biome_path: &BiomePath,
+    document: &str,
+) -> Result<String, WorkspaceError> {
+    let blocks = embedded_script_blocks(text, embedding_kind);
+    if blocks.is_empty() {
+        return Ok(text.to_string());
+    }
+
+    let file_source = script_blocks_file_source(&blocks, embedding_kind);
+    let mut contents = split_script_document(document, text, &blocks, file_source)
+        .ok_or_else(|| WorkspaceError::script_blocks_not_found(biome_path.display().to_string()))?;
+    // The frontmatter of an Astro file doesn't start with empty lines, e.g. when
+    // a fix removes its first statement
+    if embedding_kind.is_astro() && AstroFileHandler::frontmatter(text).is_some() {
+        contents[0] = contents[0].trim_start();
+    }
+    Ok(replace_blocks(
+        text,
+        blocks.into_iter().map(|block| block.range),
+        contents,
+    ))
+}
+
+/// Returns the file source of the JavaScript document made of `blocks`.
+///
+/// The blocks of a file use the same language, but if they don't, TypeScript
+/// and JSX win over JavaScript, because they are supersets of it.
+pub(crate) fn script_blocks_file_source(
+    blocks: &[ScriptBlock],
+    embedding_kind: EmbeddingKind,
+) -> JsFileSource {
+    let (language, variant) = blocks
+        .iter()
+        .map(|block| (block.language, block.variant))
+        .reduce(|(language, variant), (other_language, other_variant)| {
+            let language = if other_language.is_typescript() {
+                other_language
+            } else {
+                language
+            };
+            let variant = if other_variant.is_jsx() {
+                other_variant
+            } else {
+                variant
+            };
+            (language, variant)
+        })
+        .unwrap_or((Language::JavaScript, LanguageVariant::Standard));
+
+    JsFileSource::from(language)
+        .with_variant(variant)
+        .with_embedding_kind(embedding_kind)
+}
+
+/// Parses the script blocks of `text` as a single JavaScript document
+pub(crate) fn parse_script_blocks(
+    text: &str,
+    embedding_kind: EmbeddingKind,
+    cache: &mut NodeCache,
+) -> (AnyParse, JsFileSource) {
+    let blocks = embedded_script_blocks(text, embedding_kind);
+    let document = join_script_blocks(text, &blocks);
+    let file_source = script_blocks_file_source(&blocks, embedding_kind);
+    let parse = parse_js_with_cache(&document, file_source, JsParserOptions::default(), cache);
+    (parse.into(), file_source)
+}
+
+/// Formats each script block of `text` separately, with its own file source,
+/// and returns `text` with the formatted blocks.
+///
+/// The blocks with syntax errors are kept if formatting with errors is disabled.
+pub(crate) fn format_script_blocks(
+    text: &str,
+    embedding_kind: EmbeddingKind,
+    biome_path: &BiomePath,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let format_with_errors = settings
+        .settings()
+        .is_some_and(|settings| settings.formatter().format_with_errors);
+    let blocks = embedded_script_blocks(text, embedding_kind);
+    let mut outputs = Vec::with_capacity(blocks.len());
+    for block in &blocks {
+        let content = &text[block.range.clone()];
+        let file_source = block.file_source(embedding_kind);
+        let parse = biome_js_parser::parse(content, file_source, JsParserOptions::default());
+        if parse.has_errors() && !format_with_errors {
+            outputs.push(content.to_string());
+            continue;
+        }
+
+        let options = javascript::format_options(settings, biome_path, &file_source.into());
+        let formatted = format_node(options, &parse.syntax())?;
+        let printed = formatted
+            .print()
+            .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+        outputs.push(printed.into_code());
+    }
+
+    Ok(replace_blocks(
+        text,
+        blocks.into_iter().map(|block| block.range),
+        outputs,
+    ))
+}
+
+/// Writes the fixes of the script blocks, in the `result` of the JavaScript
+/// document of `text`, back in `text`, and formats the blocks if `params` asks
+/// for it.
+///
+/// The code of the returned result is the new content of the file.
+pub(crate) fn fix_script_blocks(
+    text: &str,
+    embedding_kind: EmbeddingKind,
+    params: &FixAllParams,
+    mut result: FixFileResult,
+) -> Result<FixFileResult, WorkspaceError> {
+    let mut code = replace_script_blocks(text, embedding_kind, params.biome_path, &result.code)?;
+    if params.should_format {
+        code = format_script_blocks(&code, embedding_kind, params.biome_path, params.workspace)?;
+    }
+    result.code = code;
+    Ok(result)
+}
+
+/// A style block of a file
+#[derive(Debug)]
+pub(crate) struct StyleBlock<'a> {
+    /// The opening tag of the block, e.g. `<style scoped>`
+    pub(crate) opening_tag: &'a str,
+    /// The range of the content of the block in the file
+    pub(crate) range: Range<usize>,
+}
+
+impl StyleBlock<'_> {
+    /// Returns the value of the attribute `name` of the opening tag.
+    ///
+    /// The value of an attribute without value, e.g. `scoped`, is an empty string.
+    fn attribute(&self, name: &str) -> Option<&str> {
+        tag_attribute(self.opening_tag, name)
+    }
+
+    /// Whether the block contains CSS, i.e. it doesn't have a `lang` attribute
+    /// or its value is `css`
+    fn is_css(&self) -> bool {
+        self.attribute("lang")
+            .map_or(true, |lang| lang.eq_ignore_ascii_case("css"))
+    }
+
+    /// Enables the parsing of the features that `embedding_kind` adds to CSS:
+    /// - `:global()` in Svelte and Astro;
+    /// - `:deep()`, `:slotted()` and `:global()` in the `<style scoped>` blocks of Vue;
+    /// - the CSS Modules in the `<style module>` blocks of Vue.
+    fn parser_options(
+        &self,
+        embedding_kind: EmbeddingKind,
+        options: CssParserOptions,
+    ) -> CssParserOptions {
+        match embedding_kind {
+            EmbeddingKind::Vue => {
+                if self.attribute("scoped").is_some() {
+                    options.allow_css_modules().allow_vue_scoped_styles()
+                } else if self.attribute("module").is_some() {
+                    options.allow_css_modules()
+                } else {
+                    options
+                }
+            }
+            EmbeddingKind::Svelte | EmbeddingKind::Astro => options.allow_css_modules(),
+            EmbeddingKind::Html | EmbeddingKind::None => options,
+        }
+    }
+}
+
+/// Returns the style blocks of `text` that contain CSS, in source order
+pub(crate) fn style_blocks(text: &str) -> Vec<StyleBlock> {
+    STYLE_FENCE
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let opening_tag = captures.name("opening")?.as_str();
+            let style = captures.name("style")?;
+            Some(StyleBlock {
+                opening_tag,
+                range: style.range(),
+            })
+        })
+        .filter(StyleBlock::is_css)
+        .collect()
+}
+
+/// Returns the file source of the style blocks of a file embedded in `embedding_kind`
+fn style_blocks_file_source(embedding_kind: EmbeddingKind) -> DocumentFileSource {
+    CssFileSource::css()
+        .with_embedding_kind(embedding_kind)
+        .into()
+}
+
+/// Parses the content of the style `block` of `text`
+fn parse_style_block(
+    text: &str,
+    block: &StyleBlock,
+    embedding_kind: EmbeddingKind,
+    biome_path: &BiomePath,
+    settings: Option<&Settings>,
+) -> AnyParse {
+    let options = block.parser_options(embedding_kind, css::parser_options(biome_path, settings));
+    biome_css_parser::parse_css(&text[block.range.clone()], options).into()
+}
+
+/// Formats the content of the style `block` of `text`.
+///
+/// The content is kept if it has syntax errors and formatting with errors is
+/// disabled.
+fn format_style_block(
+    text: &str,
+    block: &StyleBlock,
+    embedding_kind: EmbeddingKind,
+    biome_path: &BiomePath,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let content = &text[block.range.clone()];
+    let parse = parse_style_block(text, block, embedding_kind, biome_path, settings.settings());
+    let format_with_errors = settings
+        .settings()
+        .is_some_and(|settings| settings.formatter().format_with_errors);
+    if parse.has_errors() && !format_with_errors {
+        return Ok(content.to_string());
+    }
+
+    let options = settings
+        .format_options::<CssLanguage>(biome_path, &style_blocks_file_source(embedding_kind));
+    let formatted = biome_css_formatter::format_node(options, &parse.syntax())?;
+    let printed = formatted
+        .print()
+        .map_err(|error| WorkspaceError::FormatError(error.into()))?;
+    Ok(indent_style_block(content, printed.as_code()))
+}
+
+/// Indents the `output` of a style block like its `content`: the lines are
+/// indented like the first line of the content, and the closing tag keeps its
+/// indentation.
+fn indent_style_block(content: &str, output: &str) -> String {
+    if output.trim().is_empty() {
+        return content.to_string();
+    }
+
+    let indentation = content
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .map_or("", |line| &line[..line.len() - line.trim_start().len()]);
+    let closing_indentation = content
+        .rsplit_once('\n')
+        .map_or(content, |(_, last_line)| last_line);
+    let closing_indentation = if closing_indentation.trim().is_empty() {
+        closing_indentation
+    } else {
+        ""
+    };
+
+    let mut result = String::with_capacity(output.len() + closing_indentation.len());
+    for line in output.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            result.push_str(indentation);
+        }
+        result.push_str(line);
+    }
+    result.push_str(closing_indentation);
+    result
+}
+
+/// Returns the start of the document made of the script blocks of `text`.
+///
+/// The diagnostics of the script blocks are shifted by this offset.
+pub(crate) fn script_blocks_start(text: &str, embedding_kind: EmbeddingKind) -> Option<u32> {
+    embedded_script_blocks(text, embedding_kind)
+        .first()
+        .map(|block| block.range.start as u32)
+}
+
+/// Maps the `diagnostics` of the JavaScript document made of the script blocks
+/// of `text` to `text`.
+///
+/// Their spans are shifted by the start of the first block, and the diffs of
+/// their code suggestions are computed again against `text`, so that they show
+/// the lines of the file instead of the gaps and the lines of the document.
+pub(crate) fn map_script_blocks_diagnostics(
+    text: &str,
+    embedding_kind: EmbeddingKind,
+    biome_path: &BiomePath,
+    diagnostics: Vec<Diagnostic>,
+) -> Vec<Diagnostic> {
+    let blocks = embedded_script_blocks(text, embedding_kind);
+    let Some(first_block) = blocks.first() else {
+        return diagnostics;
+    };
+    let offset = TextSize::from(first_block.range.start as u32);
+    let document = join_script_blocks(text, &blocks);
+    diagnostics
+        .into_iter()
+        .map(|diagnostic| {
+            diagnostic.with_offset(offset).with_mapped_diffs(|diff| {
+                let new_document = diff.new_string(&document);
+                // The diff is kept when the blocks can't be found in the new document
+                match replace_script_blocks(text, embedding_kind, biome_path, &new_document) {
+                    Ok(new_text) => TextEdit::from_unicode_words(text, &new_text),
+                    Err(_) => diff.clone(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Lints the style blocks of `text` with the `params` of the host file, and
+/// returns their diagnostics with the offsets of the file.
+///
+/// Only the syntax errors are reported when the CSS linter is disabled.
+pub(crate) fn lint_style_blocks(
+    text: &str,
+    embedding_kind: EmbeddingKind,
+    params: &LintParams,
+) -> LintResults {
+    let settings = params.workspace.settings();
+    let categories = if settings.is_some_and(Settings::css_linter_disabled) {
+        RuleCategoriesBuilder::default().with_syntax().build()
+    } else {
+        params.categories
+    };
+
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+    for block in style_blocks(text) {
+        let parse = parse_style_block(text, &block, embedding_kind, params.path, settings);
+        let max_diagnostics = params
+            .max_diagnostics
+            .saturating_sub(results.diagnostics.len() as u32);
+        let block_results = css::lint(LintParams {
+            parse,
+            language: style_blocks_file_source(embedding_kind),
+            max_diagnostics,
+            categories,
+            ..params.clone()
+        });
+
+        let offset = TextSize::from(block.range.start as u32);
+        results.diagnostics.extend(
+            block_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(offset)),
+        );
+        results.errors += block_results.errors;
+        results.skipped_diagnostics += block_results.skipped_diagnostics;
+    }
+    results
+}
+
+/// Applies the fixes to the style blocks of `text` with the `params` of the
+/// host file, and writes their outputs back in `text`, the code of `result`.
+///
+/// The style blocks with syntax errors are kept.
+pub(crate) fn fix_style_blocks(
+    embedding_kind: EmbeddingKind,
+    params: &FixAllParams,
+    mut result: FixFileResult,
+) -> Result<FixFileResult, WorkspaceError> {
+    let text = result.code.as_str();
+    let blocks = style_blocks(text);
+    if blocks.is_empty() {
+        return Ok(result);
+    }
+
+    let mut outputs = Vec::with_capacity(blocks.len());
+    for block in &blocks {
+        let content = &text[block.range.clone()];
+        let parse = parse_style_block(
+            text,
+            block,
+            embedding_kind,
+            params.biome_path,
+            params.workspace.settings(),
+        );
+        if parse.has_errors() {
+            outputs.push(content.to_string());
+            continue;
+        }
+
+        let block_result = css::fix_all(FixAllParams {
+            parse,
+            document_file_source: style_blocks_file_source(embedding_kind),
+            manifest: params.manifest.clone(),
+            module_graph: params.module_graph.clone(),
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+            profiler: params.profiler.clone(),
+            ..*params
+        })?;
+
+        let offset = TextSize::from(block.range.start as u32);
+        result
+            .actions
+            .extend(block_result.actions.into_iter().map(|action| FixAction {
+                range: action.range + offset,
+                ..action
+            }));
+        result.errors += block_result.errors;
+        result.skipped_suggested_fixes += block_result.skipped_suggested_fixes;
+        outputs.push(if params.should_format {
+            indent_style_block(content, &block_result.code)
+        } else {
+            block_result.code
+        });
+    }
+
+    result.code = replace_blocks(text, blocks.into_iter().map(|block| block.range), outputs);
+    Ok(result)
+}
+
+/// Formats the style blocks of `text`, the formatted content of a file of
+/// `embedding_kind`, and returns `text` with the formatted blocks.
+///
+/// The style blocks are kept when the CSS formatter is disabled.
+pub(crate) fn format_style_blocks(
+    text: String,
+    embedding_kind: EmbeddingKind,
+    biome_path: &BiomePath,
+    settings: &WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    if settings
+        .settings()
+        .is_some_and(Settings::css_formatter_disabled)
+    {
+        return Ok(text);
+    }
+
+    let blocks = style_blocks(&text);
+    if blocks.is_empty() {
+        return Ok(text);
+    }
+
+    let outputs = blocks
+        .iter()
+        .map(|block| format_style_block(&text, block, embedding_kind, biome_path, settings))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(replace_blocks(
+        &text,
+        blocks.into_iter().map(|block| block.range),
+        outputs,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        embedded_script_blocks, indent_style_block, join_script_blocks, replace_script_blocks,
+        script_blocks, style_blocks,
+    };
+    use crate::file_handlers::VUE_FENCE;
+    use biome_fs::BiomePath;
+    use biome_js_syntax::EmbeddingKind;
+
+    const VUE_FILE: &str = r#"<script>
+export default { name: "App" };
+</script>
+
+<template>
+  <p>{{ message }}</p>
+</template>
+
+<script setup lang="ts">
+const message = "Hello";
+</script>
+"#;
+
+    #[test]
+    fn joins_script_blocks_with_the_same_offsets() {
+        let blocks = script_blocks(&VUE_FENCE, VUE_FILE);
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks[1].language.is_typescript());
+
+        let document = join_script_blocks(VUE_FILE, &blocks);
+        let start = blocks[0].range.start;
+        let end = blocks[1].range.end;
+        assert_eq!(document.len(), end - start);
+
+        let offset = VUE_FILE.find("const message").unwrap();
+        assert!(document[offset - start..].starts_with("const message"));
+    }
+
+    #[test]
+    fn replaces_each_script_block() {
+        let path = BiomePath::new("file.vue");
+        let blocks = script_blocks(&VUE_FENCE, VUE_FILE);
+        let document = join_script_blocks(VUE_FILE, &blocks);
+        let output = document
+            .replace("\"App\"", "\"Main\"")
+            .replace("\"Hello\"", "\"Hi\"");
+
+        assert_eq!(
+            replace_script_blocks(VUE_FILE, EmbeddingKind::Vue, &path, &output).unwrap(),
+            VUE_FILE
+                .replace("\"App\"", "\"Main\"")
+                .replace("\"Hello\"", "\"Hi\"")
+        );
+        // An error is returned when the gap between the blocks is lost
+        assert!(
+            replace_script_blocks(VUE_FILE, EmbeddingKind::Vue, &path, "const message = 1;")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn doesnt_split_script_blocks_in_strings() {
+        let path = BiomePath::new("file.vue");
+        let blocks = script_blocks(&VUE_FENCE, VUE_FILE);
+        let document = join_script_blocks(VUE_FILE, &blocks);
+        let gap = &document[blocks[0].range.len()..blocks[1].range.start - blocks[0].range.start];
+        let name = format!("`{gap}`");
+        let output = document.replace("\"App\"", &name);
+
+        assert_eq!(
+            replace_script_blocks(VUE_FILE, EmbeddingKind::Vue, &path, &output).unwrap(),
+            VUE_FILE.replace("\"App\"", &name)
+        );
+    }
+
+    #[test]
+    fn finds_the_frontmatter_and_the_scripts_of_astro_files() {
+        let astro_file = r#"---
+const title = "Astro";
+---
+
+<h1>{title}</h1>
+
+<script>
+const heading = document.querySelector("h1");
+</script>
+
+<script type="application/json">
+{ "title": "Astro" }
+</script>
+"#;
+        let blocks = embedded_script_blocks(astro_file, EmbeddingKind::Astro);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(
+            &astro_file[blocks[0].range.clone()],
+            "const title = \"Astro\";\n"
+        );
+        assert_eq!(
+            &astro_file[blocks[1].range.clone()],
+            "const heading = document.querySelector(\"h1\");\n"
+        );
+        assert!(blocks[1].language.is_typescript());
+    }
+
+    const VUE_FILE_WITH_STYLES: &str = r#"<script setup>
+const color = "red";
+</script>
+
+<style scoped>
+.a { color: v-bind(color) }
+</style>
+
+<style lang="scss">
+.b { .c { color: red } }
+</style>
+
+<style module>
+.d { margin: 0 }
+</style>
+"#;
+
+    #[test]
+    fn finds_css_style_blocks() {
+        let blocks = style_blocks(VUE_FILE_WITH_STYLES);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].opening_tag, "<style scoped>");
+        assert_eq!(blocks[1].opening_tag, "<style module>");
+        assert_eq!(
+            &VUE_FILE_WITH_STYLES[blocks[1].range.clone()],
+            ".d { margin: 0 }\n"
+        );
+    }
+
+    #[test]
+    fn keeps_the_indentation_of_style_blocks() {
+        assert_eq!(
+            indent_style_block("    a{color:red}\n  ", "a {\n\tcolor: red;\n}\n"),
+            "    a {\n    \tcolor: red;\n    }\n  "
+        );
+        assert_eq!(indent_style_block("\n", ""), "\n");
+    }
+}
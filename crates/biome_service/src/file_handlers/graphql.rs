@@ -1,6 +1,7 @@
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, SearchCapabilities,
+    code_actions_with_analyzer, fix_all_with_analyzer, lint_with_analyzer, structure,
+    CodeActionsParams, DocumentFileSource, ExtensionHandler, FixAllParams, LintParams, LintResults,
+    ParseResult, SearchCapabilities, StructureCapabilities,
 };
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
@@ -11,14 +12,10 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+    DocumentSymbol, DocumentSymbolKind, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
 };
 use crate::WorkspaceError;
-use biome_analyze::{
-    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never,
-    RuleCategoriesBuilder, RuleCategory, RuleError,
-};
-use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
+use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
 use biome_formatter::{
     BracketSpacing, FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
     QuoteStyle,
@@ -28,10 +25,11 @@ use biome_graphql_analyze::analyze;
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_formatter::format_node;
 use biome_graphql_parser::parse_graphql_with_cache;
-use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode, TextRange, TextSize};
+use biome_graphql_syntax::{
+    GraphqlLanguage, GraphqlRoot, GraphqlSyntaxKind, GraphqlSyntaxNode, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, NodeCache, TokenAtOffset};
-use std::borrow::Cow;
+use biome_rowan::{AstNode, AstNodeList, NodeCache, NodeOrToken, TokenAtOffset};
 use tracing::{debug_span, error, info, trace_span};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -145,6 +143,8 @@ impl ServiceLanguage for GraphqlLanguage {
         AnalyzerOptions {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
+            suppression_reason: None,
+            profiler: None,
         }
     }
 }
@@ -165,15 +165,23 @@ impl ExtensionHandler for GraphqlFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                symbol_references: None,
                 fix_all: Some(fix_all),
+                transform: None,
                 organize_imports: None,
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                minify: None,
             },
             search: SearchCapabilities { search: None },
+            structure: StructureCapabilities {
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(structure::folding_ranges::<GraphqlLanguage>),
+                selection_ranges: Some(structure::selection_ranges::<GraphqlLanguage>),
+            },
         }
     }
 }
@@ -288,297 +296,146 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     debug_span!("Linting GraphQL file", path =? params.path, language =? params.language).in_scope(
         move || {
-            let workspace_settings = &params.workspace;
-            let analyzer_options = workspace_settings
-                .analyzer_options::<GraphqlLanguage>(params.path, &params.language);
-            let tree = params.parse.tree();
-
-            let has_only_filter = !params.only.is_empty();
-            let rules = params
-                .workspace
-                .settings()
-                .as_ref()
-                .and_then(|settings| settings.as_linter_rules(params.path.as_path()));
-
-            let (enabled_rules, disabled_rules) =
-                AnalyzerVisitorBuilder::new(params.workspace.settings())
-                    .with_syntax_rules()
-                    .with_linter_rules(&params.only, &params.skip, params.path.as_path())
-                    .with_assists_rules(&params.only, &params.skip, params.path.as_path())
-                    .finish();
-            let mut diagnostics = params.parse.into_diagnostics();
-
-            let filter = AnalysisFilter {
-                categories: params.categories,
-                enabled_rules: Some(enabled_rules.as_slice()),
-                disabled_rules: &disabled_rules,
-                range: None,
-            };
-
-            // Do not report unused suppression comment diagnostics if:
-            // - it is a syntax-only analyzer pass, or
-            // - if a single rule is run.
-            let ignores_suppression_comment =
-                !filter.categories.contains(RuleCategory::Lint) || has_only_filter;
-
-            let mut diagnostic_count = diagnostics.len() as u32;
-            let mut errors = diagnostics
-                .iter()
-                .filter(|diag| diag.severity() <= Severity::Error)
-                .count();
-
-            info!("Analyze file {}", params.path.display());
-            let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if ignores_suppression_comment
-                        && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
-
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity >= Severity::Error {
-                        errors += 1;
-                    }
-
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
-                            }
-                        }
-
-                        let error = diagnostic.with_severity(severity);
-
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
-                    }
-                }
-
-                ControlFlow::<Never>::Continue(())
-            });
-
-            diagnostics.extend(
-                analyze_diagnostics
-                    .into_iter()
-                    .map(biome_diagnostics::serde::Diagnostic::new)
-                    .collect::<Vec<_>>(),
-            );
-            let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
-
-            LintResults {
-                diagnostics,
-                errors,
-                skipped_diagnostics,
-            }
+            let tree: GraphqlRoot = params.parse.tree();
+            lint_with_analyzer(params, |filter, options, emit_signal| {
+                analyze(&tree, filter, options, emit_signal).1
+            })
         },
     )
 }
 
 #[tracing::instrument(level = "debug", skip(params))]
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    let CodeActionsParams {
-        parse,
-        range,
-        workspace,
-        path,
-        manifest: _,
-        language,
-        only,
-        skip,
-    } = params;
-    debug_span!("Code actions GraphQL", range =? range, path =? path).in_scope(move || {
-        let tree = parse.tree();
-        trace_span!("Parsed file", tree =? tree).in_scope(move || {
-            let Some(_) = language.to_graphql_file_source() else {
-                error!("Could not determine the file source of the file");
-                return PullActionsResult {
-                    actions: Vec::new(),
+    debug_span!("Code actions GraphQL", range =? params.range, path =? params.path).in_scope(
+        move || {
+            let tree: GraphqlRoot = params.parse.tree();
+            trace_span!("Parsed file", tree =? tree).in_scope(move || {
+                let Some(_) = params.language.to_graphql_file_source() else {
+                    error!("Could not determine the file source of the file");
+                    return PullActionsResult {
+                        actions: Vec::new(),
+                    };
                 };
-            };
-
-            let analyzer_options = workspace.analyzer_options::<GraphqlLanguage>(path, &language);
-            let mut actions = Vec::new();
-            let (enabled_rules, disabled_rules) =
-                AnalyzerVisitorBuilder::new(params.workspace.settings())
-                    .with_syntax_rules()
-                    .with_linter_rules(&only, &skip, params.path.as_path())
-                    .with_assists_rules(&only, &skip, params.path.as_path())
-                    .finish();
-
-            let filter = AnalysisFilter {
-                categories: RuleCategoriesBuilder::default()
-                    .with_syntax()
-                    .with_lint()
-                    .with_action()
-                    .build(),
-                enabled_rules: Some(enabled_rules.as_slice()),
-                disabled_rules: &disabled_rules,
-                range,
-            };
 
-            info!("GraphQL runs the analyzer");
+                info!("GraphQL runs the analyzer");
 
-            analyze(&tree, filter, &analyzer_options, |signal| {
-                actions.extend(signal.actions().into_code_action_iter().map(|item| {
-                    CodeAction {
-                        category: item.category.clone(),
-                        rule_name: item
-                            .rule_name
-                            .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                        suggestion: item.suggestion,
-                    }
-                }));
-
-                ControlFlow::<Never>::Continue(())
-            });
-
-            PullActionsResult { actions }
-        })
-    })
+                code_actions_with_analyzer(params, |filter, options, emit_signal| {
+                    analyze(&tree, filter, options, emit_signal);
+                })
+            })
+        },
+    )
 }
 
 /// If applies all the safe fixes to the given syntax tree.
 pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    let mut tree: GraphqlRoot = params.parse.tree();
-    let Some(settings) = params.workspace.settings() else {
-        return Ok(FixFileResult {
-            actions: Vec::new(),
-            errors: 0,
-            skipped_suggested_fixes: 0,
-            code: tree.syntax().to_string(),
-        });
-    };
-
-    // Compute final rules (taking `overrides` into account)
-    let rules = settings.as_linter_rules(params.biome_path.as_path());
-
-    let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
-        .with_syntax_rules()
-        .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
-        .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
-        .finish();
-
-    let filter = AnalysisFilter {
-        categories: RuleCategoriesBuilder::default()
-            .with_syntax()
-            .with_lint()
-            .build(),
-        enabled_rules: Some(enabled_rules.as_slice()),
-        disabled_rules: &disabled_rules,
-        range: None,
-    };
+    let tree: GraphqlRoot = params.parse.tree();
+    fix_all_with_analyzer(
+        &params,
+        tree,
+        |tree, filter, options, emit_signal| analyze(tree, filter, options, emit_signal).0,
+        // we don't have a formatter yet
+        |tree| Ok(tree.syntax().to_string()),
+    )
+}
 
-    let mut actions = Vec::new();
-    let mut skipped_suggested_fixes = 0;
-    let mut errors: u16 = 0;
-    let analyzer_options = params
-        .workspace
-        .analyzer_options::<GraphqlLanguage>(params.biome_path, &params.document_file_source);
-    loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
-                }
-            }
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: GraphqlRoot = parse.tree();
+    root.definitions()
+        .iter()
+        .filter_map(|definition| definition_symbol(definition.syntax()))
+        .collect()
+}
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+fn definition_symbol(node: &GraphqlSyntaxNode) -> Option<DocumentSymbol> {
+    let kind = match node.kind() {
+        GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_SELECTION_SET => DocumentSymbolKind::Function,
+        GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => DocumentSymbolKind::Object,
+        GraphqlSyntaxKind::GRAPHQL_SCHEMA_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_SCHEMA_EXTENSION => DocumentSymbolKind::Module,
+        GraphqlSyntaxKind::GRAPHQL_OBJECT_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_OBJECT_TYPE_EXTENSION => DocumentSymbolKind::Class,
+        GraphqlSyntaxKind::GRAPHQL_INTERFACE_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_INTERFACE_TYPE_EXTENSION => DocumentSymbolKind::Interface,
+        GraphqlSyntaxKind::GRAPHQL_INPUT_OBJECT_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_INPUT_OBJECT_TYPE_EXTENSION => DocumentSymbolKind::Struct,
+        GraphqlSyntaxKind::GRAPHQL_ENUM_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_ENUM_TYPE_EXTENSION => DocumentSymbolKind::Enum,
+        GraphqlSyntaxKind::GRAPHQL_UNION_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_UNION_TYPE_EXTENSION
+        | GraphqlSyntaxKind::GRAPHQL_SCALAR_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_SCALAR_TYPE_EXTENSION => DocumentSymbolKind::TypeParameter,
+        GraphqlSyntaxKind::GRAPHQL_DIRECTIVE_DEFINITION => DocumentSymbolKind::Operator,
+        _ => return None,
+    };
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
-                        }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
-                        }
+    let range = node.text_trimmed_range();
+    let name = node.children().find(|child| {
+        matches!(
+            child.kind(),
+            GraphqlSyntaxKind::GRAPHQL_NAME_BINDING | GraphqlSyntaxKind::GRAPHQL_NAME_REFERENCE
+        )
+    });
+    let (name, selection_range) = match name {
+        Some(name) if kind == DocumentSymbolKind::Operator => (
+            format!("@{}", name.text_trimmed()),
+            name.text_trimmed_range(),
+        ),
+        Some(name) => (name.text_trimmed().to_string(), name.text_trimmed_range()),
+        // `schema { ... }`, and the anonymous operations `query { ... }` or `{ ... }`
+        None => {
+            let keyword = node
+                .children_with_tokens()
+                .find_map(|element| match element {
+                    NodeOrToken::Node(node)
+                        if node.kind() == GraphqlSyntaxKind::GRAPHQL_OPERATION_TYPE =>
+                    {
+                        Some((node.text_trimmed().to_string(), node.text_trimmed_range()))
                     }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
-                        }
+                    NodeOrToken::Token(token) if token.kind() == GraphqlSyntaxKind::SCHEMA_KW => {
+                        Some((token.text_trimmed().to_string(), token.text_trimmed_range()))
                     }
-                }
-            }
-
-            ControlFlow::Continue(())
-        });
+                    _ => None,
+                });
+            keyword.unwrap_or_else(|| (String::from("query"), TextRange::empty(range.start())))
+        }
+    };
 
-        match action {
-            Some(action) => {
-                if let (root, Some((range, _))) =
-                    action.mutation.commit_with_text_range_and_edit(true)
+    let children = node
+        .descendants()
+        .filter_map(|node| {
+            let kind = match node.kind() {
+                GraphqlSyntaxKind::GRAPHQL_FIELD_DEFINITION => DocumentSymbolKind::Field,
+                GraphqlSyntaxKind::GRAPHQL_INPUT_VALUE_DEFINITION
+                    if node.parent()?.kind() == GraphqlSyntaxKind::GRAPHQL_INPUT_FIELD_LIST =>
                 {
-                    tree = match GraphqlRoot::cast(root) {
-                        Some(tree) => tree,
-                        None => {
-                            return Err(WorkspaceError::RuleError(
-                                RuleError::ReplacedRootWithNonRootError {
-                                    rule_name: action.rule_name.map(|(group, rule)| {
-                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
-                                    }),
-                                },
-                            ));
-                        }
-                    };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
+                    DocumentSymbolKind::Field
                 }
-            }
-            None => {
-                // we don't have a formatter yet
-                // let code = if should_format {
-                //     format_node(
-                //         workspace.format_options::<GraphqlLanguage>(biome_path, &document_file_source),
-                //         tree.syntax(),
-                //     )?
-                //         .print()?
-                //         .into_code()
-                // } else {
-                let code = tree.syntax().to_string();
-                // };
-                return Ok(FixFileResult {
-                    code,
-                    skipped_suggested_fixes,
-                    actions,
-                    errors: errors.into(),
-                });
-            }
-        }
-    }
+                GraphqlSyntaxKind::GRAPHQL_ENUM_VALUE_DEFINITION => DocumentSymbolKind::EnumMember,
+                _ => return None,
+            };
+            let name = node
+                .children()
+                .find(|child| child.kind() == GraphqlSyntaxKind::GRAPHQL_LITERAL_NAME)?;
+            Some(DocumentSymbol {
+                name: name.text_trimmed().to_string(),
+                kind,
+                range: node.text_trimmed_range(),
+                selection_range: name.text_trimmed_range(),
+                children: Vec::new(),
+            })
+        })
+        .collect();
+
+    Some(DocumentSymbol {
+        name,
+        kind,
+        range,
+        selection_range,
+        children,
+    })
 }
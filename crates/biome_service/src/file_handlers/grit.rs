@@ -1,5 +1,6 @@
 use crate::{
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+    workspace::{DocumentSymbol, DocumentSymbolKind},
     WorkspaceError,
 };
 use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
@@ -7,13 +8,14 @@ use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_grit_formatter::{context::GritFormatOptions, format_node};
 use biome_grit_parser::parse_grit_with_cache;
-use biome_grit_syntax::GritLanguage;
+use biome_grit_syntax::{GritLanguage, GritRoot, GritSyntaxKind};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{AstNode, NodeCache};
 
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler,
-    FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+    structure, AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource,
+    ExtensionHandler, FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+    StructureCapabilities,
 };
 
 impl ServiceLanguage for GritLanguage {
@@ -50,6 +52,8 @@ impl ServiceLanguage for GritLanguage {
         AnalyzerOptions {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
+            suppression_reason: None,
+            profiler: None,
         }
     }
 }
@@ -70,15 +74,23 @@ impl ExtensionHandler for GritFileHandler {
                 lint: None,
                 code_actions: None,
                 rename: None,
+                symbol_references: None,
                 fix_all: None,
+                transform: None,
                 organize_imports: None,
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: None,
                 format_on_type: None,
+                minify: None,
             },
             search: SearchCapabilities { search: None },
+            structure: StructureCapabilities {
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(structure::folding_ranges::<GritLanguage>),
+                selection_ranges: Some(structure::selection_ranges::<GritLanguage>),
+            },
         }
     }
 }
@@ -117,3 +129,30 @@ fn format(
         Err(error) => Err(WorkspaceError::FormatError(error.into())),
     }
 }
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: GritRoot = parse.tree();
+    root.syntax()
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                GritSyntaxKind::GRIT_PATTERN_DEFINITION
+                    | GritSyntaxKind::GRIT_PREDICATE_DEFINITION
+                    | GritSyntaxKind::GRIT_FUNCTION_DEFINITION
+            )
+        })
+        .filter_map(|node| {
+            let name = node
+                .children()
+                .find(|child| child.kind() == GritSyntaxKind::GRIT_NAME)?;
+            Some(DocumentSymbol {
+                name: name.text_trimmed().to_string(),
+                kind: DocumentSymbolKind::Function,
+                range: node.text_trimmed_range(),
+                selection_range: name.text_trimmed_range(),
+                children: Vec::new(),
+            })
+        })
+        .collect()
+}
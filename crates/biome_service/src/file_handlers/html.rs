@@ -1,21 +1,30 @@
 use biome_analyze::{AnalyzerConfiguration, AnalyzerOptions};
 use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::BiomePath;
+use biome_html_analyze::analyze;
 use biome_html_formatter::{format_node, HtmlFormatOptions};
 use biome_html_parser::parse_html_with_cache;
-use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_html_syntax::{
+    HtmlElement, HtmlLanguage, HtmlRoot, HtmlSelfClosingElement, HtmlSyntaxKind, HtmlSyntaxNode,
+};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
+use biome_rowan::{AstNode, NodeCache};
+use tracing::{debug_span, error, info, trace_span};
 
 use crate::{
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
-    workspace::GetSyntaxTreeResult,
+    workspace::{
+        DocumentSymbol, DocumentSymbolKind, FixFileResult, FoldingRange, FoldingRangeKind,
+        GetSyntaxTreeResult, PullActionsResult,
+    },
     WorkspaceError,
 };
 
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, ExtensionHandler,
-    FormatterCapabilities, ParseResult, ParserCapabilities, SearchCapabilities,
+    code_actions_with_analyzer, fix_all_with_analyzer, lint_with_analyzer, structure,
+    AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities, DocumentFileSource,
+    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
+    ParserCapabilities, SearchCapabilities, StructureCapabilities,
 };
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -102,6 +111,8 @@ impl ServiceLanguage for HtmlLanguage {
         AnalyzerOptions {
             configuration: AnalyzerConfiguration::default(),
             file_path: path.to_path_buf(),
+            suppression_reason: None,
+            profiler: None,
         }
     }
 }
@@ -119,18 +130,26 @@ impl ExtensionHandler for HtmlFileHandler {
                 debug_formatter_ir: Some(debug_formatter_ir),
             },
             analyzer: AnalyzerCapabilities {
-                lint: None,
-                code_actions: None,
+                lint: Some(lint),
+                code_actions: Some(code_actions),
                 rename: None,
-                fix_all: None,
+                symbol_references: None,
+                fix_all: Some(fix_all),
+                transform: None,
                 organize_imports: None,
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: None,
                 format_on_type: None,
+                minify: None,
             },
             search: SearchCapabilities { search: None },
+            structure: StructureCapabilities {
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(structure::selection_ranges::<HtmlLanguage>),
+            },
         }
     }
 }
@@ -193,3 +212,117 @@ fn format(
         Err(error) => Err(WorkspaceError::FormatError(error.into())),
     }
 }
+
+/// The `<style>` blocks are linted by the workspace.
+fn lint(params: LintParams) -> LintResults {
+    debug_span!("Linting HTML file", path =? params.path, language =? params.language).in_scope(
+        move || {
+            let tree: HtmlRoot = params.parse.tree();
+            lint_with_analyzer(params, |filter, options, emit_signal| {
+                analyze(&tree, filter, options, emit_signal).1
+            })
+        },
+    )
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    debug_span!("Code actions HTML", range =? params.range, path =? params.path).in_scope(
+        move || {
+            let tree: HtmlRoot = params.parse.tree();
+            trace_span!("Parsed file", tree =? tree).in_scope(move || {
+                let Some(_) = params.language.to_html_file_source() else {
+                    error!("Could not determine the file source of the file");
+                    return PullActionsResult {
+                        actions: Vec::new(),
+                    };
+                };
+
+                info!("HTML runs the analyzer");
+
+                code_actions_with_analyzer(params, |filter, options, emit_signal| {
+                    analyze(&tree, filter, options, emit_signal);
+                })
+            })
+        },
+    )
+}
+
+/// If applies all the safe fixes to the given syntax tree.
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let tree: HtmlRoot = params.parse.tree();
+    fix_all_with_analyzer(
+        &params,
+        tree,
+        |tree, filter, options, emit_signal| analyze(tree, filter, options, emit_signal).0,
+        |tree| {
+            Ok(format_node(
+                params.workspace.format_options::<HtmlLanguage>(
+                    params.biome_path,
+                    &params.document_file_source,
+                ),
+                tree.syntax(),
+            )?
+            .print()?
+            .into_code())
+        },
+    )
+}
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: HtmlRoot = parse.tree();
+    element_symbols(root.syntax())
+}
+
+/// Returns the elements that are descendants of `node`. The elements nested
+/// in an element become its children.
+fn element_symbols(node: &HtmlSyntaxNode) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for child in node.children() {
+        let name = match HtmlElement::cast_ref(&child) {
+            Some(element) => element
+                .opening_element()
+                .and_then(|opening_element| opening_element.name()),
+            None => match HtmlSelfClosingElement::cast_ref(&child) {
+                Some(element) => element.name(),
+                None => {
+                    symbols.extend(element_symbols(&child));
+                    continue;
+                }
+            },
+        };
+        let Ok(name) = name else {
+            continue;
+        };
+
+        symbols.push(DocumentSymbol {
+            name: name.syntax().text_trimmed().to_string(),
+            kind: DocumentSymbolKind::Field,
+            range: child.text_trimmed_range(),
+            selection_range: name.range(),
+            children: element_symbols(&child),
+        });
+    }
+    symbols
+}
+
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root: HtmlRoot = parse.tree();
+    let mut ranges: Vec<_> = root
+        .syntax()
+        .descendants()
+        .filter_map(|node| {
+            let kind = match node.kind() {
+                HtmlSyntaxKind::HTML_ELEMENT | HtmlSyntaxKind::HTML_SELF_CLOSING_ELEMENT => {
+                    FoldingRangeKind::Block
+                }
+                HtmlSyntaxKind::HTML_COMMENT => FoldingRangeKind::Comment,
+                _ => return None,
+            };
+            structure::multiline_folding_range(&node.first_token()?, &node.last_token()?, kind)
+        })
+        .collect();
+    ranges.extend(structure::comment_folding_ranges(root.syntax()));
+    ranges.sort_by_key(|range| range.range.start());
+    ranges
+}
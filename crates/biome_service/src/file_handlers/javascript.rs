@@ -1,11 +1,14 @@
 use super::{
-    search, AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    css, graphql, search, structure, AnalyzerCapabilities, AnalyzerVisitorBuilder,
+    CodeActionsParams, DebugCapabilities, ExtensionHandler, FormatterCapabilities, LintParams,
+    LintResults, ParseResult, ParserCapabilities, SearchCapabilities, StructureCapabilities,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
-use crate::file_handlers::{is_diagnostic_error, FixAllParams};
+use crate::file_handlers::{
+    apply_fix_action, is_diagnostic_error, FixAllParams, SuppressionGuard, SymbolReferencesParams,
+    TransformParams,
+};
 use crate::settings::{LinterSettings, OverrideSettings, Settings};
 use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
 use crate::{
@@ -14,8 +17,10 @@ use crate::{
         WorkspaceSettingsHandle,
     },
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, DocumentSymbol, DocumentSymbolKind, FixAction, FixFileMode, FixFileResult,
+        FoldingRange, FoldingRangeKind, GetSymbolReferencesResult, GetSyntaxTreeResult,
+        MinifyFileResult, PullActionsResult, RenameResult, SymbolLocation, SymbolReference,
+        TransformFileResult,
     },
     WorkspaceError,
 };
@@ -38,13 +43,20 @@ use biome_js_formatter::context::{
     ArrowParentheses, BracketSameLine, JsFormatOptions, QuoteProperties, Semicolons,
 };
 use biome_js_formatter::format_node;
+use biome_js_minifier::MinifyError;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{semantic_model, SemanticModelOptions};
+use biome_js_semantic::{semantic_model, Binding, SemanticModel, SemanticModelOptions};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
 use biome_js_syntax::{
-    AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
+    inner_string_text, AnyJsBinding, AnyJsBindingPattern, AnyJsClassMemberName, AnyJsExpression,
+    AnyJsIdentifierUsage, AnyJsImportClause, AnyJsModuleSource, AnyJsObjectMemberName, AnyJsRoot,
+    AnyJsTemplateElement, AnyTsEnumMemberName, AnyTsIdentifierBinding, AnyTsModuleName, JsExport,
+    JsFileSource, JsLanguage, JsSyntaxKind, JsSyntaxNode, JsTemplateExpression,
+    JsVariableDeclarator, TextRange, TextSize, TokenAtOffset,
 };
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
+use indexmap::IndexSet;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -67,6 +79,7 @@ pub struct JsFormatterSettings {
     pub indent_style: Option<IndentStyle>,
     pub enabled: Option<bool>,
     pub attribute_position: Option<AttributePosition>,
+    pub format_embedded_css: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -85,15 +98,30 @@ pub struct JsLinterSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsOrganizeImportsSettings {}
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsEnvironmentSettings {
     pub jsx_runtime: JsxRuntime,
+
+    /// The tags of the template literals that contain GraphQL
+    pub graphql_tags: IndexSet<String>,
+}
+
+impl Default for JsEnvironmentSettings {
+    fn default() -> Self {
+        Self {
+            jsx_runtime: JsxRuntime::default(),
+            graphql_tags: ["gql", "graphql"].map(str::to_string).into(),
+        }
+    }
 }
 
 impl From<JsxRuntime> for JsEnvironmentSettings {
     fn from(jsx_runtime: JsxRuntime) -> Self {
-        Self { jsx_runtime }
+        Self {
+            jsx_runtime,
+            ..Self::default()
+        }
     }
 }
 
@@ -176,7 +204,8 @@ impl ServiceLanguage for JsLanguage {
                 .and_then(|l| l.attribute_position)
                 .or(global.and_then(|g| g.attribute_position))
                 .unwrap_or_default(),
-        );
+        )
+        .with_format_embedded_css(language.and_then(|l| l.format_embedded_css).unwrap_or(true));
 
         if let Some(overrides) = overrides {
             overrides.override_js_format_options(path, options)
@@ -279,6 +308,8 @@ impl ServiceLanguage for JsLanguage {
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
+            suppression_reason: None,
+            profiler: None,
         }
     }
 }
@@ -299,17 +330,25 @@ impl ExtensionHandler for JsFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
+                transform: Some(transform),
                 rename: Some(rename),
+                symbol_references: Some(symbol_references),
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                minify: Some(minify),
             },
             search: SearchCapabilities {
                 search: Some(search),
             },
+            structure: StructureCapabilities {
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(folding_ranges),
+                selection_ranges: Some(structure::selection_ranges::<JsLanguage>),
+            },
         }
     }
 }
@@ -392,19 +431,44 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        Default::default(),
         |_| ControlFlow::<Never>::Continue(()),
     );
 
     control_flow_graph.map(|(cfg, _)| cfg).unwrap_or_default()
 }
 
+/// Resolves the format options of the file at `path`, including the tags of the
+/// templates that contain GraphQL.
+pub(crate) fn format_options(
+    settings: &WorkspaceSettingsHandle,
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+) -> JsFormatOptions {
+    let graphql_tags = graphql_tags(path, settings.settings());
+    settings
+        .format_options::<JsLanguage>(path, document_file_source)
+        .with_graphql_tags(graphql_tags.into_iter().collect())
+}
+
+/// Returns the tags of the templates that contain GraphQL in the file at `path`.
+fn graphql_tags(path: &BiomePath, settings: Option<&Settings>) -> IndexSet<String> {
+    match settings {
+        Some(settings) => settings.override_settings.override_graphql_tags(
+            path,
+            &settings.languages.javascript.environment.graphql_tags,
+        ),
+        None => JsEnvironmentSettings::default().graphql_tags,
+    }
+}
+
 fn debug_formatter_ir(
     path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<String, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(path, document_file_source);
+    let options = format_options(&settings, path, document_file_source);
 
     let tree = parse.syntax();
     let formatted = format_node(options, &tree)?;
@@ -428,9 +492,10 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 };
             };
             let tree = params.parse.tree();
-            let analyzer_options = &params
+            let mut analyzer_options = params
                 .workspace
                 .analyzer_options::<JsLanguage>(params.path, &params.language);
+            analyzer_options.profiler.clone_from(&params.profiler);
 
             let rules = params
                 .workspace
@@ -455,6 +520,9 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
             let ignores_suppression_comment =
                 !filter.categories.contains(RuleCategory::Lint) || !params.only.is_empty();
 
+            let css_template_results = lint_css_templates(&tree, &params);
+            let graphql_template_results = lint_graphql_templates(&tree, &params);
+
             let mut diagnostics = params.parse.into_diagnostics();
             let mut diagnostic_count = diagnostics.len() as u32;
             let mut errors = diagnostics
@@ -466,9 +534,10 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
             let (_, analyze_diagnostics) = analyze(
                 &tree,
                 filter,
-                analyzer_options,
+                &analyzer_options,
                 file_source,
                 params.manifest,
+                params.module_graph,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
@@ -523,14 +592,205 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
             );
             let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
 
+            let max_diagnostics =
+                (params.max_diagnostics as usize).saturating_sub(diagnostics.len());
+            diagnostics.extend(
+                css_template_results
+                    .diagnostics
+                    .into_iter()
+                    .chain(graphql_template_results.diagnostics)
+                    .take(max_diagnostics),
+            );
+
             LintResults {
                 diagnostics,
-                errors,
-                skipped_diagnostics,
+                errors: errors + css_template_results.errors + graphql_template_results.errors,
+                skipped_diagnostics: skipped_diagnostics
+                    + css_template_results.skipped_diagnostics
+                    + graphql_template_results.skipped_diagnostics,
             }
         })
 }
 
+/// Lints the CSS of the CSS-in-JS templates of `tree`, e.g. `` styled.div`color: red;` ``,
+/// with the CSS linter.
+///
+/// The templates with syntax errors are ignored, as well as the diagnostics
+/// that overlap an interpolation.
+fn lint_css_templates(tree: &AnyJsRoot, params: &LintParams) -> LintResults {
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+    let settings = params.workspace.settings();
+    if settings.is_some_and(Settings::css_linter_disabled) {
+        return results;
+    }
+
+    let parser_options = css::parser_options(params.path, settings).allow_wrong_line_comments();
+    for template in tree
+        .syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+    {
+        let Some(kind) = template.css_template_kind() else {
+            continue;
+        };
+        // The placeholders have the length of the interpolations, so that the ranges
+        // of the CSS only need to be shifted by the offset of the template
+        let Some(source) = template.css_template_source(kind, |_, len| "x".repeat(len)) else {
+            continue;
+        };
+        if source.content(kind).trim().is_empty() {
+            continue;
+        }
+        let (Ok(l_tick), Ok(r_tick)) = (template.l_tick_token(), template.r_tick_token()) else {
+            continue;
+        };
+        let content_range = TextRange::new(
+            l_tick.text_trimmed_range().end(),
+            r_tick.text_trimmed_range().start(),
+        );
+        let Some(offset) = content_range
+            .start()
+            .checked_sub(TextSize::from(kind.wrapper().0.len() as u32))
+        else {
+            continue;
+        };
+        let parse = biome_css_parser::parse_css(&source.source, parser_options);
+        if parse.has_errors() {
+            continue;
+        }
+
+        let template_results = css::lint(LintParams {
+            parse: parse.into(),
+            language: DocumentFileSource::Css(biome_css_syntax::CssFileSource::css()),
+            ..params.clone()
+        });
+        for diagnostic in template_results.diagnostics {
+            let diagnostic = diagnostic.with_offset(offset);
+            let is_in_content = diagnostic.location().span.is_some_and(|span| {
+                content_range.contains_range(span)
+                    && !source.placeholders.iter().any(|placeholder| {
+                        placeholder
+                            .element
+                            .syntax()
+                            .text_trimmed_range()
+                            .intersect(span)
+                            .is_some()
+                    })
+            });
+            if is_in_content {
+                if diagnostic.severity() >= Severity::Error {
+                    results.errors += 1;
+                }
+                results.diagnostics.push(diagnostic);
+            }
+        }
+        results.skipped_diagnostics += template_results.skipped_diagnostics;
+    }
+    results
+}
+
+/// Lints the GraphQL of the templates of `tree` tagged with one of the GraphQL tags,
+/// e.g. `` gql`query { user }` ``, with the GraphQL linter.
+///
+/// The templates with syntax errors are ignored, as well as the diagnostics
+/// that overlap an interpolation.
+fn lint_graphql_templates(tree: &AnyJsRoot, params: &LintParams) -> LintResults {
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+    let settings = params.workspace.settings();
+    if settings.is_some_and(Settings::graphql_linter_disabled) {
+        return results;
+    }
+
+    let graphql_tags = graphql_tags(params.path, settings);
+    for template in tree
+        .syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+    {
+        if !template
+            .tag_name()
+            .is_some_and(|name| graphql_tags.contains(name.text()))
+        {
+            continue;
+        }
+        let Some((source, offset, interpolations)) = graphql_template_source(&template) else {
+            continue;
+        };
+        let parse = biome_graphql_parser::parse_graphql(&source);
+        if parse.has_errors() {
+            continue;
+        }
+
+        let template_results = graphql::lint(LintParams {
+            parse: parse.into(),
+            language: DocumentFileSource::Graphql(
+                biome_graphql_syntax::GraphqlFileSource::default(),
+            ),
+            ..params.clone()
+        });
+        for diagnostic in template_results.diagnostics {
+            let diagnostic = diagnostic.with_offset(offset);
+            let is_outside_interpolations = diagnostic.location().span.is_some_and(|span| {
+                !interpolations
+                    .iter()
+                    .any(|interpolation| interpolation.intersect(span).is_some())
+            });
+            if is_outside_interpolations {
+                if diagnostic.severity() >= Severity::Error {
+                    results.errors += 1;
+                }
+                results.diagnostics.push(diagnostic);
+            }
+        }
+        results.skipped_diagnostics += template_results.skipped_diagnostics;
+    }
+    results
+}
+
+/// Returns the GraphQL of `template`, the offset of the GraphQL in the file, and the
+/// ranges of the interpolations in the file.
+///
+/// The interpolations are opaque: the ones that follow a spread, e.g. `...${fragment}`,
+/// are replaced by a fragment name of the same length, and the other ones by spaces.
+fn graphql_template_source(
+    template: &JsTemplateExpression,
+) -> Option<(String, TextSize, Vec<TextRange>)> {
+    let offset = template.l_tick_token().ok()?.text_trimmed_range().end();
+
+    let mut source = String::new();
+    let mut interpolations = Vec::new();
+    for element in template.elements() {
+        match element {
+            AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                source.push_str(chunk.template_chunk_token().ok()?.text_trimmed());
+            }
+            AnyJsTemplateElement::JsTemplateElement(element) => {
+                let range = element.syntax().text_trimmed_range();
+                let len = usize::from(range.len());
+                if source.trim_end().ends_with("...") {
+                    source.push_str(&"_".repeat(len));
+                } else {
+                    source.push_str(&" ".repeat(len));
+                }
+                interpolations.push(range);
+            }
+        }
+    }
+    if source.trim().is_empty() {
+        return None;
+    }
+
+    Some((source, offset, interpolations))
+}
+
 #[tracing::instrument(level = "debug", skip(params))]
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     let CodeActionsParams {
@@ -539,6 +799,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest,
+        module_graph,
         language,
         only,
         skip,
@@ -580,6 +841,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                module_graph,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -609,6 +871,8 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             errors: 0,
             skipped_suggested_fixes: 0,
             code: tree.syntax().to_string(),
+            diagnostics: Vec::new(),
+            profile: None,
         });
     };
 
@@ -639,9 +903,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params
+    let mut analyzer_options = params
         .workspace
         .analyzer_options::<JsLanguage>(params.biome_path, &params.document_file_source);
+    analyzer_options
+        .suppression_reason
+        .clone_from(&params.suppression_reason);
+    analyzer_options.profiler.clone_from(&params.profiler);
+    let mut suppressions = SuppressionGuard::default();
     loop {
         let (action, _) = analyze(
             &tree,
@@ -649,6 +918,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             &analyzer_options,
             file_source,
             params.manifest.clone(),
+            params.module_graph.clone(),
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
@@ -659,8 +929,11 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                 }
 
                 for action in signal.actions() {
-                    // suppression actions should not be part of the fixes (safe or suggested)
-                    if action.is_suppression() {
+                    // suppression actions should not be part of the fixes (safe or suggested),
+                    // and they are the only actions applied when suppressing the diagnostics
+                    if action.is_suppression()
+                        != matches!(params.fix_file_mode, FixFileMode::ApplySuppressions)
+                    {
                         continue;
                     }
 
@@ -683,6 +956,11 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                                 return ControlFlow::Break(action);
                             }
                         }
+                        FixFileMode::ApplySuppressions => {
+                            errors = errors.saturating_sub(1);
+                            suppressions.suppress(current_diagnostic.as_ref());
+                            return ControlFlow::Break(action);
+                        }
                     }
                 }
 
@@ -692,33 +970,17 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
 
         match action {
             Some(action) => {
-                if let (root, Some((range, _))) =
-                    action.mutation.commit_with_text_range_and_edit(true)
+                if let Some(action) =
+                    apply_fix_action(&mut tree, action, params.fix_file_mode, &mut suppressions)?
                 {
-                    tree = match AnyJsRoot::cast(root) {
-                        Some(tree) => tree,
-                        None => {
-                            return Err(WorkspaceError::RuleError(
-                                RuleError::ReplacedRootWithNonRootError {
-                                    rule_name: action.rule_name.map(|(group, rule)| {
-                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
-                                    }),
-                                },
-                            ));
-                        }
-                    };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
+                    actions.push(action);
                 }
             }
             None => {
                 let code = if params.should_format {
                     format_node(
-                        params.workspace.format_options::<JsLanguage>(
+                        format_options(
+                            params.workspace,
                             params.biome_path,
                             &params.document_file_source,
                         ),
@@ -734,12 +996,115 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                     skipped_suggested_fixes,
                     actions,
                     errors: errors.into(),
+                    diagnostics: Vec::new(),
+                    profile: None,
                 });
             }
         }
     }
 }
 
+/// Applies the transformations to the given syntax tree, one at a time, until
+/// none of them emits a mutation anymore
+pub(crate) fn transform(params: TransformParams) -> Result<TransformFileResult, WorkspaceError> {
+    let mut tree: AnyJsRoot = params.parse.tree();
+    let Some(file_source) = params
+        .document_file_source
+        .to_js_file_source()
+        .or(JsFileSource::try_from(params.biome_path.as_path()).ok())
+    else {
+        return Err(extension_error(params.biome_path));
+    };
+
+    let enabled_rules: Vec<_> = params
+        .transformations
+        .iter()
+        .map(|name| RuleFilter::Rule("transformations", name))
+        .collect();
+    let filter = AnalysisFilter {
+        categories: RuleCategoriesBuilder::default()
+            .with_transformation()
+            .build(),
+        enabled_rules: (!enabled_rules.is_empty()).then_some(enabled_rules.as_slice()),
+        ..AnalysisFilter::default()
+    };
+    let analyzer_options = params
+        .workspace
+        .analyzer_options::<JsLanguage>(params.biome_path, &params.document_file_source);
+
+    let mut actions = Vec::new();
+    let mut diagnostics = Vec::new();
+    loop {
+        // Only the diagnostics of the last run are kept, because they refer to the final code
+        diagnostics.clear();
+        let (transformation, _) = biome_js_transform::transform(
+            &tree,
+            filter,
+            &analyzer_options,
+            file_source,
+            |signal| {
+                if let Some(diagnostic) = signal.diagnostic() {
+                    diagnostics.push(biome_diagnostics::serde::Diagnostic::new(diagnostic));
+                }
+                match signal.transformations().next() {
+                    Some(transformation) => ControlFlow::Break(transformation),
+                    None => ControlFlow::Continue(()),
+                }
+            },
+        );
+
+        let Some(transformation) = transformation else {
+            break;
+        };
+        let rule_name = transformation
+            .rule_name
+            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule)));
+        // A mutation that doesn't change the code would be emitted again and again
+        let (root, Some((range, _))) = transformation
+            .mutation
+            .commit_with_text_range_and_edit(true)
+        else {
+            break;
+        };
+        tree = match AnyJsRoot::cast(root) {
+            Some(tree) => tree,
+            None => {
+                return Err(WorkspaceError::RuleError(
+                    RuleError::ReplacedRootWithNonRootError { rule_name },
+                ));
+            }
+        };
+        actions.push(FixAction { rule_name, range });
+    }
+
+    Ok(TransformFileResult {
+        code: tree.syntax().to_string(),
+        actions,
+        diagnostics,
+    })
+}
+
+#[tracing::instrument(level = "trace", skip(parse))]
+pub(crate) fn minify(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+) -> Result<MinifyFileResult, WorkspaceError> {
+    if parse.has_errors() {
+        return Err(MinifyError::SyntaxErrors.into());
+    }
+    let Some(file_source) = document_file_source
+        .to_js_file_source()
+        .or(JsFileSource::try_from(biome_path.as_path()).ok())
+    else {
+        return Err(extension_error(biome_path));
+    };
+
+    let tree: AnyJsRoot = parse.tree();
+    let code = biome_js_minifier::minify(&tree, file_source)?;
+    Ok(MinifyFileResult { code })
+}
+
 #[tracing::instrument(level = "trace", skip(parse, settings))]
 pub(crate) fn format(
     biome_path: &BiomePath,
@@ -747,7 +1112,7 @@ pub(crate) fn format(
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let options = format_options(&settings, biome_path, document_file_source);
 
     debug!("Options used for format: \n{}", options);
 
@@ -771,7 +1136,7 @@ pub(crate) fn format_range(
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let options = format_options(&settings, biome_path, document_file_source);
 
     let tree = parse.syntax();
     let printed = biome_js_formatter::format_range(options, &tree, range)?;
@@ -786,7 +1151,7 @@ pub(crate) fn format_on_type(
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(path, document_file_source);
+    let options = format_options(&settings, path, document_file_source);
 
     let tree = parse.syntax();
 
@@ -857,6 +1222,382 @@ fn rename(
     }
 }
 
+fn symbol_references(
+    params: SymbolReferencesParams,
+) -> Result<GetSymbolReferencesResult, WorkspaceError> {
+    let root: AnyJsRoot = params.parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let Some(binding) = binding_at(&root, &model, params.symbol_at) else {
+        return Ok(GetSymbolReferencesResult::default());
+    };
+
+    let declaration = binding.tree();
+    let references = binding
+        .all_references()
+        .map(|reference| SymbolReference {
+            range: reference.syntax().text_trimmed_range(),
+            is_write: reference.is_write(),
+        })
+        .collect();
+
+    let (imported_declaration, import_candidates) =
+        match imported_declaration(&params, &declaration) {
+            Some(ImportedDeclaration::Resolved(location)) => (Some(location), Vec::new()),
+            Some(ImportedDeclaration::Unresolved(candidates)) => (None, candidates),
+            None => (None, Vec::new()),
+        };
+    Ok(GetSymbolReferencesResult {
+        declaration: Some(declaration.syntax().text_trimmed_range()),
+        references,
+        imported_declaration,
+        import_candidates,
+    })
+}
+
+/// Returns the binding declared or referenced by the identifier at `offset`
+fn binding_at(root: &AnyJsRoot, model: &SemanticModel, offset: TextSize) -> Option<Binding> {
+    root.syntax().token_at_offset(offset).find_map(|token| {
+        let node = token.parent()?;
+        if AnyJsIdentifierBinding::can_cast(node.kind()) {
+            return model
+                .all_bindings()
+                .find(|binding| binding.syntax() == &node);
+        }
+        match AnyJsIdentifierUsage::cast(node)? {
+            AnyJsIdentifierUsage::JsReferenceIdentifier(reference) => model.binding(&reference),
+            AnyJsIdentifierUsage::JsIdentifierAssignment(assignment) => model.binding(&assignment),
+            AnyJsIdentifierUsage::JsxReferenceIdentifier(reference) => model.binding(&reference),
+        }
+    })
+}
+
+enum ImportedDeclaration {
+    /// The location of the declaration in the imported module
+    Resolved(SymbolLocation),
+    /// The imported module isn't open in the workspace, these are the paths
+    /// where it can be, in order of precedence
+    Unresolved(Vec<BiomePath>),
+}
+
+/// Returns the declaration of the symbol imported by `binding`, in the module it's imported from.
+///
+/// Only the relative imports are followed, and only to the modules open in the workspace. The
+/// location points to the start of the imported module when the module doesn't export the symbol,
+/// or when the whole module is imported.
+fn imported_declaration(
+    params: &SymbolReferencesParams,
+    binding: &AnyJsIdentifierBinding,
+) -> Option<ImportedDeclaration> {
+    let declaration = binding.declaration()?;
+    let imported_name = match &declaration {
+        // `import { a }`
+        AnyJsBindingDeclaration::JsShorthandNamedImportSpecifier(_) => {
+            Some(binding.syntax().text_trimmed().to_string())
+        }
+        // `import { a as b }`
+        AnyJsBindingDeclaration::JsNamedImportSpecifier(specifier) => {
+            let name = specifier.name().ok()?.value().ok()?;
+            Some(inner_string_text(&name).to_string())
+        }
+        AnyJsBindingDeclaration::JsDefaultImportSpecifier(_) => Some(String::from("default")),
+        AnyJsBindingDeclaration::JsNamespaceImportSpecifier(_) => None,
+        _ => return None,
+    };
+
+    let clause = declaration
+        .syntax()
+        .ancestors()
+        .find_map(AnyJsImportClause::cast)?;
+    let specifier = clause.source().ok()?.inner_string_text().ok()?;
+    if !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return None;
+    }
+
+    let resolved = params
+        .module_graph
+        .resolve_with(params.path, &specifier, &|path| {
+            (params.get_parse)(&BiomePath::new(path)).is_some()
+        });
+    let Some(path) = resolved else {
+        let candidates = params
+            .module_graph
+            .resolution_candidates(params.path, &specifier)
+            .into_iter()
+            .map(BiomePath::new)
+            .collect();
+        return Some(ImportedDeclaration::Unresolved(candidates));
+    };
+
+    let path = BiomePath::new(path);
+    let range = imported_name
+        .and_then(|name| {
+            let parse = (params.get_parse)(&path)?;
+            find_export(&parse.tree(), &name)
+        })
+        .unwrap_or_default();
+    Some(ImportedDeclaration::Resolved(SymbolLocation {
+        path,
+        range,
+    }))
+}
+
+/// Returns the range of the declaration exported as `name` by the module `root`
+fn find_export(root: &AnyJsRoot, name: &str) -> Option<TextRange> {
+    let AnyJsRoot::JsModule(module) = root else {
+        return None;
+    };
+
+    // `export const a`, `export class a`, `export { a }`
+    if name != "default" {
+        let model = semantic_model(root, SemanticModelOptions::default());
+        if let Some(binding) = model.global_scope().get_binding(name) {
+            if model.is_exported(&binding.tree()) {
+                return Some(binding.syntax().text_trimmed_range());
+            }
+        }
+    }
+
+    // `export { b as a }`, `export default a`
+    module
+        .items()
+        .into_iter()
+        .filter_map(|item| JsExport::cast(item.into_syntax()))
+        .flat_map(|export| export.get_exported_items())
+        .find_map(|item| {
+            let matches = if name == "default" {
+                item.is_default
+            } else {
+                item.identifier
+                    .as_ref()
+                    .is_some_and(|identifier| identifier.syntax().text_trimmed() == name)
+            };
+            if !matches {
+                return None;
+            }
+            match (item.identifier, item.exported) {
+                (Some(identifier), _) => Some(identifier.syntax().text_trimmed_range()),
+                (None, Some(exported)) => Some(exported.syntax().text_trimmed_range()),
+                (None, None) => None,
+            }
+        })
+}
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: AnyJsRoot = parse.tree();
+    symbols(root.syntax())
+}
+
+/// Returns the symbols declared by the descendants of `node`. The symbols
+/// declared inside a symbol become its children.
+fn symbols(node: &JsSyntaxNode) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for child in node.children() {
+        match symbol(&child) {
+            Some(symbol) => symbols.push(symbol),
+            None => symbols.extend(self::symbols(&child)),
+        }
+    }
+    symbols
+}
+
+fn symbol(node: &JsSyntaxNode) -> Option<DocumentSymbol> {
+    let (name, kind) = match node.kind() {
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+        | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+        | JsSyntaxKind::TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION => {
+            (declaration_name(node), DocumentSymbolKind::Function)
+        }
+        JsSyntaxKind::JS_CLASS_DECLARATION | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => {
+            (declaration_name(node), DocumentSymbolKind::Class)
+        }
+        JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+        | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+        | JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_METHOD_SIGNATURE_TYPE_MEMBER => {
+            (Some(member_name(node)?), DocumentSymbolKind::Method)
+        }
+        JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+        | JsSyntaxKind::TS_CONSTRUCTOR_SIGNATURE_CLASS_MEMBER => {
+            (Some(member_name(node)?), DocumentSymbolKind::Constructor)
+        }
+        JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+        | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+        | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+        | JsSyntaxKind::TS_PROPERTY_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_GETTER_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_SETTER_SIGNATURE_CLASS_MEMBER
+        | JsSyntaxKind::TS_PROPERTY_SIGNATURE_TYPE_MEMBER
+        | JsSyntaxKind::TS_GETTER_SIGNATURE_TYPE_MEMBER
+        | JsSyntaxKind::TS_SETTER_SIGNATURE_TYPE_MEMBER => {
+            (Some(member_name(node)?), DocumentSymbolKind::Property)
+        }
+        JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER
+        | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+        | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+        | JsSyntaxKind::JS_SHORTHAND_PROPERTY_OBJECT_MEMBER
+            if has_member_symbols(&node.grand_parent()?) =>
+        {
+            (Some(member_name(node)?), DocumentSymbolKind::Property)
+        }
+        JsSyntaxKind::JS_VARIABLE_DECLARATOR => variable_symbol(node)?,
+        JsSyntaxKind::TS_INTERFACE_DECLARATION | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION => {
+            (declaration_name(node), DocumentSymbolKind::Interface)
+        }
+        JsSyntaxKind::TS_ENUM_DECLARATION => (declaration_name(node), DocumentSymbolKind::Enum),
+        JsSyntaxKind::TS_ENUM_MEMBER => (Some(member_name(node)?), DocumentSymbolKind::EnumMember),
+        JsSyntaxKind::TS_MODULE_DECLARATION => {
+            (declaration_name(node), DocumentSymbolKind::Namespace)
+        }
+        JsSyntaxKind::TS_EXTERNAL_MODULE_DECLARATION => {
+            (declaration_name(node), DocumentSymbolKind::Module)
+        }
+        _ => return None,
+    };
+
+    let range = node.text_trimmed_range();
+    let (name, selection_range) = match name {
+        Some(name) => (name.text_trimmed().to_string(), name.text_trimmed_range()),
+        // `export default function() {}`
+        None => (String::from("default"), TextRange::empty(range.start())),
+    };
+
+    Some(DocumentSymbol {
+        name,
+        kind,
+        range,
+        selection_range,
+        children: symbols(node),
+    })
+}
+
+/// Returns the name and the kind of the symbol declared by a variable
+/// declarator, when it declares a single binding
+fn variable_symbol(
+    declarator: &JsSyntaxNode,
+) -> Option<(Option<JsSyntaxNode>, DocumentSymbolKind)> {
+    let declarator = JsVariableDeclarator::cast_ref(declarator)?;
+    let AnyJsBindingPattern::AnyJsBinding(binding) = declarator.id().ok()? else {
+        return None;
+    };
+
+    let value = declarator
+        .initializer()
+        .and_then(|initializer| initializer.expression().ok());
+    let kind = match value {
+        Some(
+            AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_),
+        ) => DocumentSymbolKind::Function,
+        Some(AnyJsExpression::JsClassExpression(_)) => DocumentSymbolKind::Class,
+        _ if declarator
+            .declaration()
+            .is_some_and(|declaration| declaration.is_const()) =>
+        {
+            DocumentSymbolKind::Constant
+        }
+        _ => DocumentSymbolKind::Variable,
+    };
+
+    Some((Some(binding.into_syntax()), kind))
+}
+
+/// Returns the name of a declaration, the first of its children that's a binding
+fn declaration_name(node: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    node.children().find(|child| {
+        AnyJsBinding::can_cast(child.kind())
+            || AnyTsIdentifierBinding::can_cast(child.kind())
+            || AnyTsModuleName::can_cast(child.kind())
+            || AnyJsModuleSource::can_cast(child.kind())
+    })
+}
+
+/// Returns the name of a class, object, type or enum member
+fn member_name(node: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    node.children().find(|child| {
+        AnyJsClassMemberName::can_cast(child.kind())
+            || AnyJsObjectMemberName::can_cast(child.kind())
+            || AnyTsEnumMemberName::can_cast(child.kind())
+            || child.kind() == JsSyntaxKind::JS_REFERENCE_IDENTIFIER
+    })
+}
+
+/// Whether the members of an object expression are listed as symbols. They
+/// are when the object is the value of a variable, of a class property, of a
+/// default export, or of an object member that is listed.
+fn has_member_symbols(object: &JsSyntaxNode) -> bool {
+    let Some(parent) = object.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        JsSyntaxKind::JS_INITIALIZER_CLAUSE => parent.parent().is_some_and(|node| {
+            matches!(
+                node.kind(),
+                JsSyntaxKind::JS_VARIABLE_DECLARATOR | JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+            )
+        }),
+        JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE => true,
+        JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER => parent
+            .grand_parent()
+            .is_some_and(|object| has_member_symbols(&object)),
+        _ => false,
+    }
+}
+
+fn folding_ranges(parse: AnyParse) -> Vec<FoldingRange> {
+    let root: AnyJsRoot = parse.tree();
+    let mut ranges = Vec::new();
+    for node in root.syntax().descendants() {
+        match node.kind() {
+            JsSyntaxKind::JSX_ELEMENT
+            | JsSyntaxKind::JSX_FRAGMENT
+            | JsSyntaxKind::JSX_SELF_CLOSING_ELEMENT => {
+                let range = node
+                    .first_token()
+                    .zip(node.last_token())
+                    .and_then(|(first, last)| {
+                        structure::multiline_folding_range(&first, &last, FoldingRangeKind::Block)
+                    });
+                ranges.extend(range);
+            }
+            JsSyntaxKind::JS_MODULE_ITEM_LIST => ranges.extend(import_folding_ranges(&node)),
+            _ => ranges.extend(structure::delimited_folding_range(&node)),
+        }
+    }
+    ranges.extend(structure::comment_folding_ranges(root.syntax()));
+    ranges.sort_by_key(|range| range.range.start());
+    ranges
+}
+
+/// Returns the folding ranges of the groups of consecutive imports of a module
+fn import_folding_ranges(items: &JsSyntaxNode) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+    let mut group: Vec<JsSyntaxNode> = Vec::new();
+    let mut push_group = |group: &mut Vec<JsSyntaxNode>| {
+        if let ([first, _, ..], Some(last)) = (group.as_slice(), group.last()) {
+            let range = first
+                .first_token()
+                .zip(last.last_token())
+                .and_then(|(first, last)| {
+                    structure::multiline_folding_range(&first, &last, FoldingRangeKind::Imports)
+                });
+            ranges.extend(range);
+        }
+        group.clear();
+    };
+
+    for item in items.children() {
+        if item.kind() == JsSyntaxKind::JS_IMPORT {
+            group.push(item);
+        } else {
+            push_group(&mut group);
+        }
+    }
+    push_group(&mut group);
+
+    ranges
+}
+
 pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
@@ -872,6 +1613,7 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         &AnalyzerOptions::default(),
         JsFileSource::default(),
         None,
+        Default::default(),
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -2,8 +2,9 @@ use std::borrow::Cow;
 use std::ffi::OsStr;
 
 use super::{
-    is_diagnostic_error, AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource,
-    ExtensionHandler, ParseResult, SearchCapabilities,
+    apply_fix_action, is_diagnostic_error, structure, AnalyzerVisitorBuilder, CodeActionsParams,
+    DocumentFileSource, ExtensionHandler, ParseResult, SearchCapabilities, StructureCapabilities,
+    SuppressionGuard,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -16,8 +17,8 @@ use crate::settings::{
     ServiceLanguage, Settings, WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult,
-    PullActionsResult,
+    CodeAction, DocumentSymbol, DocumentSymbolKind, FixFileMode, FixFileResult,
+    GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::{extension_error, WorkspaceError};
 use biome_analyze::options::PreferredQuote;
@@ -34,9 +35,9 @@ use biome_json_analyze::analyze;
 use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_syntax::{AnyJsonValue, JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, AstSeparatedList, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 use tracing::{debug_span, error, trace, trace_span};
 
@@ -144,6 +145,8 @@ impl ServiceLanguage for JsonLanguage {
         AnalyzerOptions {
             configuration,
             file_path: path.to_path_buf(),
+            suppression_reason: None,
+            profiler: None,
         }
     }
 }
@@ -164,15 +167,23 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                symbol_references: None,
                 fix_all: Some(fix_all),
+                transform: None,
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                minify: None,
             },
             search: SearchCapabilities { search: None },
+            structure: StructureCapabilities {
+                document_symbols: Some(document_symbols),
+                folding_ranges: Some(structure::folding_ranges::<JsonLanguage>),
+                selection_ranges: Some(structure::selection_ranges::<JsonLanguage>),
+            },
         }
     }
 }
@@ -184,21 +195,36 @@ fn parse(
     settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let options = if biome_path.ends_with(ConfigName::biome_jsonc()) {
+    let options = parser_options(biome_path, file_source.to_json_file_source(), settings);
+
+    let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+/// Returns the options used to parse the JSON file at `biome_path`
+fn parser_options(
+    biome_path: &BiomePath,
+    file_source: Option<JsonFileSource>,
+    settings: Option<&Settings>,
+) -> JsonParserOptions {
+    if biome_path.ends_with(ConfigName::biome_jsonc()) {
         JsonParserOptions::default()
             .with_allow_comments()
             .with_allow_trailing_commas()
     } else {
         let parser = settings.map(|s| &s.languages.json.parser);
         let overrides = settings.map(|s| &s.override_settings);
-        let optional_json_file_source = file_source.to_json_file_source();
         let options = JsonParserOptions {
             allow_comments: parser.and_then(|p| p.allow_comments).map_or_else(
-                || optional_json_file_source.map_or(false, |x| x.allow_comments()),
+                || file_source.map_or(false, |x| x.allow_comments()),
                 |value| value,
             ),
             allow_trailing_commas: parser.and_then(|p| p.allow_trailing_commas).map_or_else(
-                || optional_json_file_source.map_or(false, |x| x.allow_trailing_commas()),
+                || file_source.map_or(false, |x| x.allow_trailing_commas()),
                 |value| value,
             ),
         };
@@ -207,13 +233,20 @@ fn parse(
         } else {
             options
         }
-    };
-
-    let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
+    }
+}
 
-    ParseResult {
-        any_parse: parse.into(),
-        language: Some(file_source),
+/// Returns the file source passed to the analyzer, which allows comments when the parser
+/// options of the file do, so that its diagnostics can be suppressed
+fn analyzer_file_source(
+    biome_path: &BiomePath,
+    file_source: JsonFileSource,
+    settings: Option<&Settings>,
+) -> JsonFileSource {
+    if parser_options(biome_path, Some(file_source), settings).allow_comments {
+        file_source.with_allow_comments()
+    } else {
+        file_source
     }
 }
 
@@ -328,9 +361,10 @@ fn lint(params: LintParams) -> LintResults {
             };
             let root: JsonRoot = params.parse.tree();
 
-            let analyzer_options = &params
+            let mut analyzer_options = params
                 .workspace
                 .analyzer_options::<JsonLanguage>(params.path, &params.language);
+            analyzer_options.profiler.clone_from(&params.profiler);
 
             let has_only_filter = !params.only.is_empty();
             let rules = params
@@ -383,7 +417,7 @@ fn lint(params: LintParams) -> LintResults {
             let skipped_diagnostics = diagnostic_count - diagnostics.len() as u32;
 
             let (_, analyze_diagnostics) =
-                analyze(&root, filter, analyzer_options, file_source, |signal| {
+                analyze(&root, filter, &analyzer_options, file_source, |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         if ignores_suppression_comment
                             && diagnostic.category() == Some(category!("suppressions/unused"))
@@ -450,6 +484,7 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest: _,
+        module_graph: _,
         language,
         skip,
         only,
@@ -483,6 +518,7 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 error!("Could not determine the file source of the file");
                 return PullActionsResult { actions: vec![] };
             };
+            let file_source = analyzer_file_source(path, file_source, workspace.settings());
 
             trace!("JSON runs the analyzer");
             analyze(&tree, filter, &analyzer_options, file_source, |signal| {
@@ -512,6 +548,8 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
             errors: 0,
             skipped_suggested_fixes: 0,
             code: tree.syntax().to_string(),
+            diagnostics: Vec::new(),
+            profile: None,
         });
     };
 
@@ -542,13 +580,22 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     else {
         return Err(extension_error(params.biome_path));
     };
+    let file_source = analyzer_file_source(params.biome_path, file_source, Some(settings));
 
     let mut actions = Vec::new();
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = params
+    let mut analyzer_options = params
         .workspace
         .analyzer_options::<JsonLanguage>(params.biome_path, &params.document_file_source);
+    analyzer_options
+        .suppression_reason
+        .clone_from(&params.suppression_reason);
+    analyzer_options.profiler.clone_from(&params.profiler);
+    let mut suppressions = SuppressionGuard::default();
+    // Comments aren't allowed in plain JSON files, so their diagnostics can't be suppressed
+    let can_suppress = file_source.allow_comments();
+    let mut unsupported_suppression = None;
     loop {
         let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
             let current_diagnostic = signal.diagnostic();
@@ -557,11 +604,23 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                 if is_diagnostic_error(diagnostic, rules.as_deref()) {
                     errors += 1;
                 }
+                if matches!(params.fix_file_mode, FixFileMode::ApplySuppressions)
+                    && !can_suppress
+                    && unsupported_suppression.is_none()
+                {
+                    unsupported_suppression = diagnostic
+                        .category()
+                        .and_then(|category| category.name().strip_prefix("lint/"))
+                        .map(|rule_name| rule_name.split_once('/'));
+                }
             }
 
             for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
+                // suppression actions should not be part of the fixes (safe or suggested),
+                // and they are the only actions applied when suppressing the diagnostics
+                if action.is_suppression()
+                    != matches!(params.fix_file_mode, FixFileMode::ApplySuppressions)
+                {
                     continue;
                 }
 
@@ -584,6 +643,11 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                             return ControlFlow::Break(action);
                         }
                     }
+                    FixFileMode::ApplySuppressions => {
+                        errors = errors.saturating_sub(1);
+                        suppressions.suppress(current_diagnostic.as_ref());
+                        return ControlFlow::Break(action);
+                    }
                 }
             }
 
@@ -592,27 +656,10 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
 
         match action {
             Some(action) => {
-                if let (root, Some((range, _))) =
-                    action.mutation.commit_with_text_range_and_edit(true)
+                if let Some(action) =
+                    apply_fix_action(&mut tree, action, params.fix_file_mode, &mut suppressions)?
                 {
-                    tree = match JsonRoot::cast(root) {
-                        Some(tree) => tree,
-                        None => {
-                            return Err(WorkspaceError::RuleError(
-                                RuleError::ReplacedRootWithNonRootError {
-                                    rule_name: action.rule_name.map(|(group, rule)| {
-                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
-                                    }),
-                                },
-                            ));
-                        }
-                    };
-                    actions.push(FixAction {
-                        rule_name: action
-                            .rule_name
-                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
-                        range,
-                    });
+                    actions.push(action);
                 }
             }
             None => {
@@ -629,11 +676,27 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
                 } else {
                     tree.syntax().to_string()
                 };
+                let diagnostics = unsupported_suppression
+                    .map(|rule_name| {
+                        let error = RuleError::UnsupportedSuppressionError {
+                            rule_name: rule_name
+                                .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+                        };
+                        biome_diagnostics::serde::Diagnostic::new(
+                            error
+                                .with_category(category!("lint"))
+                                .with_file_path(params.biome_path.as_path().display().to_string()),
+                        )
+                    })
+                    .into_iter()
+                    .collect();
                 return Ok(FixFileResult {
                     code,
                     skipped_suggested_fixes,
                     actions,
                     errors: errors.into(),
+                    diagnostics,
+                    profile: None,
                 });
             }
         }
@@ -645,3 +708,65 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
         code: parse.syntax::<JsonLanguage>().to_string(),
     })
 }
+
+fn document_symbols(parse: AnyParse) -> Vec<DocumentSymbol> {
+    let root: JsonRoot = parse.tree();
+    root.value()
+        .map(|value| value_symbols(&value))
+        .unwrap_or_default()
+}
+
+/// Returns the symbols of the members of an object, or of the objects and
+/// arrays contained in an array
+fn value_symbols(value: &AnyJsonValue) -> Vec<DocumentSymbol> {
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => object
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|member| {
+                let name = member.name().ok()?;
+                let value = member.value().ok()?;
+                Some(DocumentSymbol {
+                    name: name.inner_string_text().ok()?.to_string(),
+                    kind: value_symbol_kind(&value),
+                    range: member.range(),
+                    selection_range: name.range(),
+                    children: value_symbols(&value),
+                })
+            })
+            .collect(),
+        AnyJsonValue::JsonArrayValue(array) => array
+            .elements()
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, element)| {
+                matches!(
+                    element,
+                    AnyJsonValue::JsonObjectValue(_) | AnyJsonValue::JsonArrayValue(_)
+                )
+            })
+            .map(|(index, element)| DocumentSymbol {
+                name: index.to_string(),
+                kind: value_symbol_kind(&element),
+                range: element.range(),
+                selection_range: element.range(),
+                children: value_symbols(&element),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn value_symbol_kind(value: &AnyJsonValue) -> DocumentSymbolKind {
+    match value {
+        AnyJsonValue::JsonObjectValue(_) => DocumentSymbolKind::Object,
+        AnyJsonValue::JsonArrayValue(_) => DocumentSymbolKind::Array,
+        AnyJsonValue::JsonStringValue(_) => DocumentSymbolKind::String,
+        AnyJsonValue::JsonNumberValue(_) => DocumentSymbolKind::Number,
+        AnyJsonValue::JsonBooleanValue(_) => DocumentSymbolKind::Boolean,
+        AnyJsonValue::JsonNullValue(_) => DocumentSymbolKind::Null,
+        AnyJsonValue::JsonBogusValue(_) => DocumentSymbolKind::Variable,
+    }
+}
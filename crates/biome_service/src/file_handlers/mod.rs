@@ -7,37 +7,45 @@ pub use crate::file_handlers::astro::{AstroFileHandler, ASTRO_FENCE};
 use crate::file_handlers::graphql::GraphqlFileHandler;
 pub use crate::file_handlers::svelte::{SvelteFileHandler, SVELTE_FENCE};
 pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
-use crate::settings::Settings;
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+use crate::settings::{ServiceLanguage, Settings};
+use crate::workspace::{
+    CodeAction, DocumentSymbol, FixAction, FixFileMode, FoldingRange, OrganizeImportsResult,
+    SearchResults,
+};
 use crate::{
     settings::WorkspaceSettingsHandle,
-    workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
+    workspace::{
+        FixFileResult, GetSymbolReferencesResult, GetSyntaxTreeResult, MinifyFileResult,
+        PullActionsResult, RenameResult, TransformFileResult,
+    },
     WorkspaceError,
 };
 use biome_analyze::{
-    AnalyzerDiagnostic, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategories,
-    RuleCategory, RuleFilter, RuleGroup,
+    AnalysisFilter, AnalyzerAction, AnalyzerDiagnostic, AnalyzerOptions, AnalyzerSignal,
+    ControlFlow, GroupCategory, Never, Profiler, Queryable, RegistryVisitor, Rule, RuleCategories,
+    RuleCategoriesBuilder, RuleCategory, RuleError, RuleFilter, RuleGroup,
 };
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::Rules;
 use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_css_syntax::{CssFileSource, CssLanguage};
-use biome_diagnostics::{Diagnostic, Severity};
+use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_grit_patterns::{GritQuery, GritQueryResult, GritTargetFile};
 use biome_grit_syntax::file_source::GritFileSource;
-use biome_html_syntax::HtmlFileSource;
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{
     EmbeddingKind, JsFileSource, JsLanguage, Language, LanguageVariant, TextRange, TextSize,
 };
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_module_graph::ModuleGraph;
 use biome_parser::AnyParse;
 use biome_project::PackageJson;
-use biome_rowan::{FileSourceError, NodeCache};
+use biome_rowan::{AstNode, FileSourceError, NodeCache};
 use biome_string_case::StrLikeExtension;
 
 use grit::GritFileHandler;
@@ -46,19 +54,27 @@ pub use javascript::JsFormatterSettings;
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::path::Path;
-use tracing::instrument;
+use std::sync::Arc;
+use tracing::{info, instrument};
 
 mod astro;
 mod css;
+mod embedded;
 mod graphql;
 mod grit;
 mod html;
 mod javascript;
 mod json;
+mod structure;
 mod svelte;
 mod unknown;
 mod vue;
 
+pub(crate) use embedded::{
+    fix_script_blocks, fix_style_blocks, format_script_blocks, format_style_blocks,
+    lint_style_blocks, map_script_blocks_diagnostics, replace_script_blocks,
+};
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -331,14 +347,34 @@ impl DocumentFileSource {
         }
     }
 
+    /// Returns the kind of the file, if it embeds other languages in `<script>` or `<style>` blocks
+    pub(crate) fn to_embedding_kind(self) -> Option<EmbeddingKind> {
+        match self {
+            DocumentFileSource::Js(js) => match js.as_embedding_kind() {
+                EmbeddingKind::None => None,
+                embedding_kind => Some(*embedding_kind),
+            },
+            DocumentFileSource::Html(_) => Some(EmbeddingKind::Html),
+            _ => None,
+        }
+    }
+
     pub fn can_parse(path: &Path, content: &str) -> bool {
         let file_source = DocumentFileSource::from(path);
         match file_source {
             DocumentFileSource::Js(js) => match js.as_embedding_kind() {
-                EmbeddingKind::Astro => ASTRO_FENCE.is_match(content),
-                EmbeddingKind::Vue => VUE_FENCE.is_match(content),
-                EmbeddingKind::Svelte => SVELTE_FENCE.is_match(content),
-                EmbeddingKind::None => true,
+                EmbeddingKind::Astro => {
+                    ASTRO_FENCE.is_match(content)
+                        || embedded::SCRIPT_FENCE.is_match(content)
+                        || embedded::STYLE_FENCE.is_match(content)
+                }
+                EmbeddingKind::Vue => {
+                    VUE_FENCE.is_match(content) || embedded::STYLE_FENCE.is_match(content)
+                }
+                EmbeddingKind::Svelte => {
+                    SVELTE_FENCE.is_match(content) || embedded::STYLE_FENCE.is_match(content)
+                }
+                EmbeddingKind::Html | EmbeddingKind::None => true,
             },
             DocumentFileSource::Css(_)
             | DocumentFileSource::Graphql(_)
@@ -383,18 +419,419 @@ impl biome_console::fmt::Display for DocumentFileSource {
     }
 }
 
+#[derive(Clone)]
 pub struct FixAllParams<'a> {
     pub(crate) parse: AnyParse,
     pub(crate) fix_file_mode: FixFileMode,
-    pub(crate) workspace: WorkspaceSettingsHandle<'a>,
+    pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
     pub(crate) document_file_source: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) rule_categories: RuleCategories,
+    pub(crate) suppression_reason: Option<String>,
+    /// Measures the time spent by the analyzer, when it's set
+    pub(crate) profiler: Option<Profiler>,
+}
+
+/// Guards the loop that applies the suppression actions of a file against a suppression comment
+/// that doesn't suppress its diagnostic, which would be added again and again.
+#[derive(Debug, Default)]
+pub(crate) struct SuppressionGuard {
+    /// The rule and the range of the suppressed diagnostics, in the current revision of the file
+    suppressed: Vec<(Option<(&'static str, &'static str)>, TextRange)>,
+    /// The range of the diagnostic suppressed by the action that's being applied
+    pending: Option<TextRange>,
+}
+
+impl SuppressionGuard {
+    /// Marks `diagnostic` as the diagnostic suppressed by the next applied action
+    pub(crate) fn suppress(&mut self, diagnostic: Option<&AnalyzerDiagnostic>) {
+        self.pending = diagnostic.and_then(|diagnostic| diagnostic.location().span);
+    }
+
+    /// Records the suppression of the diagnostic of `rule_name` at `range`, and the edit that
+    /// inserted the suppression comment in `edit_range`, changing the length of the file from
+    /// `old_len` to `new_len`.
+    ///
+    /// Returns an error if the diagnostic was already suppressed.
+    pub(crate) fn record(
+        &mut self,
+        rule_name: Option<(&'static str, &'static str)>,
+        range: TextRange,
+        edit_range: TextRange,
+        old_len: TextSize,
+        new_len: TextSize,
+    ) -> Result<(), WorkspaceError> {
+        if self.suppressed.contains(&(rule_name, range)) {
+            return Err(WorkspaceError::RuleError(
+                RuleError::IneffectiveSuppressionError {
+                    rule_name: rule_name
+                        .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+                },
+            ));
+        }
+        self.suppressed.push((rule_name, range));
+
+        // The suppression comments are only inserted, the diagnostics after them are moved
+        let inserted = new_len.checked_sub(old_len).unwrap_or_default();
+        for (_, range) in &mut self.suppressed {
+            if range.start() >= edit_range.start() {
+                *range += inserted;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Applies the `action` picked by the `fix_all` of a language to `tree`, and returns the
+/// applied action if it changed the file.
+///
+/// When the diagnostics are suppressed, the suppression comment is recorded in `suppressions`,
+/// which returns an error if it doesn't suppress its diagnostic.
+pub(crate) fn apply_fix_action<N>(
+    tree: &mut N,
+    action: AnalyzerAction<N::Language>,
+    fix_file_mode: FixFileMode,
+    suppressions: &mut SuppressionGuard,
+) -> Result<Option<FixAction>, WorkspaceError>
+where
+    N: AstNode,
+{
+    let old_len = tree.syntax().text_range().len();
+    let (root, Some((range, _))) = action.mutation.commit_with_text_range_and_edit(true) else {
+        return Ok(None);
+    };
+    *tree = N::cast(root).ok_or_else(|| {
+        WorkspaceError::RuleError(RuleError::ReplacedRootWithNonRootError {
+            rule_name: action
+                .rule_name
+                .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+        })
+    })?;
+    if matches!(fix_file_mode, FixFileMode::ApplySuppressions) {
+        let suppressed_range = suppressions.pending.take().unwrap_or_default();
+        suppressions.record(
+            action.rule_name,
+            suppressed_range,
+            range,
+            old_len,
+            tree.syntax().text_range().len(),
+        )?;
+    }
+    Ok(Some(FixAction {
+        rule_name: action
+            .rule_name
+            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+        range,
+    }))
+}
+
+/// Lints a file with the analyzer of its language, run by `analyze` on the syntax tree
+/// of the file with the given filter and options, e.g.
+/// `|filter, options, emit_signal| analyze(&tree, filter, options, emit_signal).1`.
+///
+/// The severity of the diagnostics is the one of the rules in the configuration.
+pub(crate) fn lint_with_analyzer<L>(
+    params: LintParams,
+    analyze: impl FnOnce(
+        AnalysisFilter,
+        &AnalyzerOptions,
+        &mut dyn FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<Never>,
+    ) -> Vec<biome_diagnostics::Error>,
+) -> LintResults
+where
+    L: ServiceLanguage,
+{
+    let mut analyzer_options = params
+        .workspace
+        .analyzer_options::<L>(params.path, &params.language);
+    analyzer_options.profiler.clone_from(&params.profiler);
+
+    let has_only_filter = !params.only.is_empty();
+    let rules = params
+        .workspace
+        .settings()
+        .as_ref()
+        .and_then(|settings| settings.as_linter_rules(params.path.as_path()));
+
+    let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_syntax_rules()
+        .with_linter_rules(&params.only, &params.skip, params.path.as_path())
+        .with_assists_rules(&params.only, &params.skip, params.path.as_path())
+        .finish();
+    let mut diagnostics = params.parse.into_diagnostics();
+
+    let filter = AnalysisFilter {
+        categories: params.categories,
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: None,
+    };
+
+    // Do not report unused suppression comment diagnostics if:
+    // - it is a syntax-only analyzer pass, or
+    // - if a single rule is run.
+    let ignores_suppression_comment =
+        !filter.categories.contains(RuleCategory::Lint) || has_only_filter;
+
+    let mut diagnostic_count = diagnostics.len() as u32;
+    let mut errors = diagnostics
+        .iter()
+        .filter(|diag| diag.severity() <= Severity::Error)
+        .count();
+
+    info!("Analyze file {}", params.path.display());
+    let analyze_diagnostics = analyze(filter, &analyzer_options, &mut |signal| {
+        if let Some(mut diagnostic) = signal.diagnostic() {
+            // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+            if ignores_suppression_comment
+                && diagnostic.category() == Some(category!("suppressions/unused"))
+            {
+                return ControlFlow::<Never>::Continue(());
+            }
+
+            diagnostic_count += 1;
+
+            // We do now check if the severity of the diagnostics should be changed.
+            // The configuration allows to change the severity of the diagnostics emitted by rules.
+            let severity = diagnostic
+                .category()
+                .filter(|category| category.name().starts_with("lint/"))
+                .map_or_else(
+                    || diagnostic.severity(),
+                    |category| {
+                        rules
+                            .as_ref()
+                            .and_then(|rules| rules.get_severity_from_code(category))
+                            .unwrap_or(Severity::Warning)
+                    },
+                );
+
+            if severity >= Severity::Error {
+                errors += 1;
+            }
+
+            if diagnostic_count <= params.max_diagnostics {
+                for action in signal.actions() {
+                    if !action.is_suppression() {
+                        diagnostic = diagnostic.add_code_suggestion(action.into());
+                    }
+                }
+
+                let error = diagnostic.with_severity(severity);
+
+                diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+            }
+        }
+
+        ControlFlow::<Never>::Continue(())
+    });
+
+    diagnostics.extend(
+        analyze_diagnostics
+            .into_iter()
+            .map(biome_diagnostics::serde::Diagnostic::new)
+            .collect::<Vec<_>>(),
+    );
+    let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+
+    LintResults {
+        diagnostics,
+        errors,
+        skipped_diagnostics,
+    }
+}
+
+/// Pulls the code actions of a file from the analyzer of its language, run by `analyze`
+/// like in [lint_with_analyzer].
+pub(crate) fn code_actions_with_analyzer<L>(
+    params: CodeActionsParams,
+    analyze: impl FnOnce(
+        AnalysisFilter,
+        &AnalyzerOptions,
+        &mut dyn FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<Never>,
+    ),
+) -> PullActionsResult
+where
+    L: ServiceLanguage,
+{
+    let analyzer_options = params
+        .workspace
+        .analyzer_options::<L>(params.path, &params.language);
+    let mut actions = Vec::new();
+    let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_syntax_rules()
+        .with_linter_rules(&params.only, &params.skip, params.path.as_path())
+        .with_assists_rules(&params.only, &params.skip, params.path.as_path())
+        .finish();
+
+    let filter = AnalysisFilter {
+        categories: RuleCategoriesBuilder::default()
+            .with_syntax()
+            .with_lint()
+            .with_action()
+            .build(),
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: params.range,
+    };
+
+    analyze(filter, &analyzer_options, &mut |signal| {
+        actions.extend(signal.actions().into_code_action_iter().map(|item| {
+            CodeAction {
+                category: item.category.clone(),
+                rule_name: item
+                    .rule_name
+                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                suggestion: item.suggestion,
+            }
+        }));
+
+        ControlFlow::<Never>::Continue(())
+    });
+
+    PullActionsResult { actions }
+}
+
+/// Applies the fixes or the suppressions of the diagnostics of the analyzer to `tree`,
+/// until the analyzer run by `analyze` on the fixed tree has no more actions to apply.
+///
+/// The fixed tree is printed with `format` when the code should be formatted.
+pub(crate) fn fix_all_with_analyzer<N>(
+    params: &FixAllParams,
+    mut tree: N,
+    analyze: impl Fn(
+        &N,
+        AnalysisFilter,
+        &AnalyzerOptions,
+        &mut dyn FnMut(&dyn AnalyzerSignal<N::Language>) -> ControlFlow<AnalyzerAction<N::Language>>,
+    ) -> Option<AnalyzerAction<N::Language>>,
+    format: impl FnOnce(&N) -> Result<String, WorkspaceError>,
+) -> Result<FixFileResult, WorkspaceError>
+where
+    N: AstNode,
+    N::Language: ServiceLanguage,
+{
+    let Some(settings) = params.workspace.settings() else {
+        return Ok(FixFileResult {
+            actions: Vec::new(),
+            errors: 0,
+            skipped_suggested_fixes: 0,
+            code: tree.syntax().to_string(),
+            diagnostics: Vec::new(),
+            profile: None,
+        });
+    };
+
+    // Compute final rules (taking `overrides` into account)
+    let rules = settings.as_linter_rules(params.biome_path.as_path());
+
+    let (enabled_rules, disabled_rules) = AnalyzerVisitorBuilder::new(params.workspace.settings())
+        .with_syntax_rules()
+        .with_linter_rules(&params.only, &params.skip, params.biome_path.as_path())
+        .with_assists_rules(&params.only, &params.skip, params.biome_path.as_path())
+        .finish();
+
+    let filter = AnalysisFilter {
+        categories: RuleCategoriesBuilder::default()
+            .with_syntax()
+            .with_lint()
+            .build(),
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: None,
+    };
+
+    let mut actions = Vec::new();
+    let mut skipped_suggested_fixes = 0;
+    let mut errors: u16 = 0;
+    let mut analyzer_options = params
+        .workspace
+        .analyzer_options::<N::Language>(params.biome_path, &params.document_file_source);
+    analyzer_options
+        .suppression_reason
+        .clone_from(&params.suppression_reason);
+    analyzer_options.profiler.clone_from(&params.profiler);
+    let mut suppressions = SuppressionGuard::default();
+    loop {
+        let action = analyze(&tree, filter, &analyzer_options, &mut |signal| {
+            let current_diagnostic = signal.diagnostic();
+
+            if let Some(diagnostic) = current_diagnostic.as_ref() {
+                if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                    errors += 1;
+                }
+            }
+
+            for action in signal.actions() {
+                // suppression actions should not be part of the fixes (safe or suggested),
+                // and they are the only actions applied when suppressing the diagnostics
+                if action.is_suppression()
+                    != matches!(params.fix_file_mode, FixFileMode::ApplySuppressions)
+                {
+                    continue;
+                }
+
+                match params.fix_file_mode {
+                    FixFileMode::SafeFixes => {
+                        if action.applicability == Applicability::MaybeIncorrect {
+                            skipped_suggested_fixes += 1;
+                        }
+                        if action.applicability == Applicability::Always {
+                            errors = errors.saturating_sub(1);
+                            return ControlFlow::Break(action);
+                        }
+                    }
+                    FixFileMode::SafeAndUnsafeFixes => {
+                        if matches!(
+                            action.applicability,
+                            Applicability::Always | Applicability::MaybeIncorrect
+                        ) {
+                            errors = errors.saturating_sub(1);
+                            return ControlFlow::Break(action);
+                        }
+                    }
+                    FixFileMode::ApplySuppressions => {
+                        errors = errors.saturating_sub(1);
+                        suppressions.suppress(current_diagnostic.as_ref());
+                        return ControlFlow::Break(action);
+                    }
+                }
+            }
+
+            ControlFlow::Continue(())
+        });
+
+        match action {
+            Some(action) => {
+                if let Some(action) =
+                    apply_fix_action(&mut tree, action, params.fix_file_mode, &mut suppressions)?
+                {
+                    actions.push(action);
+                }
+            }
+            None => {
+                let code = if params.should_format {
+                    format(&tree)?
+                } else {
+                    tree.syntax().to_string()
+                };
+                return Ok(FixFileResult {
+                    code,
+                    skipped_suggested_fixes,
+                    actions,
+                    errors: errors.into(),
+                    diagnostics: Vec::new(),
+                    profile: None,
+                });
+            }
+        }
+    }
 }
 
 #[derive(Default)]
@@ -405,6 +842,7 @@ pub struct Capabilities {
     pub(crate) analyzer: AnalyzerCapabilities,
     pub(crate) formatter: FormatterCapabilities,
     pub(crate) search: SearchCapabilities,
+    pub(crate) structure: StructureCapabilities,
 }
 
 #[derive(Clone)]
@@ -441,7 +879,7 @@ pub struct DebugCapabilities {
     pub(crate) debug_formatter_ir: Option<DebugFormatterIR>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct LintParams<'a> {
     pub(crate) parse: AnyParse,
     pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
@@ -452,6 +890,18 @@ pub(crate) struct LintParams<'a> {
     pub(crate) skip: Vec<RuleSelector>,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
+    /// Measures the time spent by the analyzer, when it's set
+    pub(crate) profiler: Option<Profiler>,
+}
+
+pub(crate) struct SymbolReferencesParams<'a> {
+    pub(crate) parse: AnyParse,
+    pub(crate) path: &'a BiomePath,
+    pub(crate) symbol_at: TextSize,
+    pub(crate) module_graph: Arc<ModuleGraph>,
+    /// Returns the syntax tree of a file open in the workspace
+    pub(crate) get_parse: &'a dyn Fn(&BiomePath) -> Option<AnyParse>,
 }
 
 pub(crate) struct LintResults {
@@ -466,15 +916,27 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) module_graph: Arc<ModuleGraph>,
     pub(crate) language: DocumentFileSource,
     pub(crate) only: Vec<RuleSelector>,
     pub(crate) skip: Vec<RuleSelector>,
 }
 
+pub(crate) struct TransformParams<'a> {
+    pub(crate) parse: AnyParse,
+    pub(crate) workspace: &'a WorkspaceSettingsHandle<'a>,
+    pub(crate) biome_path: &'a BiomePath,
+    pub(crate) document_file_source: DocumentFileSource,
+    pub(crate) transformations: Vec<String>,
+}
+
 type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
+type Transform = fn(TransformParams) -> Result<TransformFileResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
+type SymbolReferences =
+    fn(SymbolReferencesParams) -> Result<GetSymbolReferencesResult, WorkspaceError>;
 type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
 
 #[derive(Default)]
@@ -485,8 +947,12 @@ pub struct AnalyzerCapabilities {
     pub(crate) code_actions: Option<CodeActions>,
     /// Applies fixes to a file
     pub(crate) fix_all: Option<FixAll>,
+    /// Applies transformations to a file
+    pub(crate) transform: Option<Transform>,
     /// It renames a binding inside a file
     pub(crate) rename: Option<Rename>,
+    /// It finds the declaration and the references of a binding
+    pub(crate) symbol_references: Option<SymbolReferences>,
     /// It organizes imports
     pub(crate) organize_imports: Option<OrganizeImports>,
 }
@@ -512,6 +978,9 @@ type FormatOnType = fn(
     TextSize,
 ) -> Result<Printed, WorkspaceError>;
 
+type Minify =
+    fn(&BiomePath, &DocumentFileSource, AnyParse) -> Result<MinifyFileResult, WorkspaceError>;
+
 #[derive(Default)]
 pub(crate) struct FormatterCapabilities {
     /// It formats a file
@@ -520,6 +989,8 @@ pub(crate) struct FormatterCapabilities {
     pub(crate) format_range: Option<FormatRange>,
     /// It formats a file while typing
     pub(crate) format_on_type: Option<FormatOnType>,
+    /// It minifies a file
+    pub(crate) minify: Option<Minify>,
 }
 
 type Search = fn(
@@ -528,7 +999,7 @@ type Search = fn(
     AnyParse,
     &GritQuery,
     WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<SearchResults, WorkspaceError>;
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
@@ -536,6 +1007,20 @@ pub(crate) struct SearchCapabilities {
     pub(crate) search: Option<Search>,
 }
 
+type DocumentSymbols = fn(AnyParse) -> Vec<DocumentSymbol>;
+type FoldingRanges = fn(AnyParse) -> Vec<FoldingRange>;
+type SelectionRanges = fn(AnyParse, &[TextSize]) -> Vec<Vec<TextRange>>;
+
+#[derive(Default)]
+pub(crate) struct StructureCapabilities {
+    /// It lists the symbols declared in a file
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// It computes the regions of a file that can be folded
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It computes the ranges that can be selected around an offset
+    pub(crate) selection_ranges: Option<SelectionRanges>,
+}
+
 /// Main trait to use to add a new language to Biome
 pub(crate) trait ExtensionHandler {
     /// Capabilities that can applied to a file
@@ -586,7 +1071,7 @@ impl Features {
                 EmbeddingKind::Astro => self.astro.capabilities(),
                 EmbeddingKind::Vue => self.vue.capabilities(),
                 EmbeddingKind::Svelte => self.svelte.capabilities(),
-                EmbeddingKind::None => self.js.capabilities(),
+                EmbeddingKind::Html | EmbeddingKind::None => self.js.capabilities(),
             },
             DocumentFileSource::Json(_) => self.json.capabilities(),
             DocumentFileSource::Css(_) => self.css.capabilities(),
@@ -678,7 +1163,7 @@ pub(crate) fn search(
     parse: AnyParse,
     query: &GritQuery,
     _settings: WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<SearchResults, WorkspaceError> {
     let (query_result, _logs) = query
         .execute(GritTargetFile {
             path: path.to_path_buf(),
@@ -688,16 +1173,27 @@ pub(crate) fn search(
             WorkspaceError::SearchError(SearchError::QueryError(QueryDiagnostic(err.to_string())))
         })?;
 
-    let matches = query_result
-        .into_iter()
-        .flat_map(|result| match result {
-            GritQueryResult::Match(m) => m.ranges,
-            _ => Vec::new(),
-        })
-        .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-        .collect();
+    let mut matches = Vec::new();
+    let mut rewritten = None;
+    for result in query_result {
+        match result {
+            GritQueryResult::Match(m) => matches.extend(m.ranges),
+            GritQueryResult::Rewrite(rewrite) => {
+                matches.extend(rewrite.original.ranges);
+                rewritten = Some(rewrite.rewritten.content);
+            }
+            GritQueryResult::CreateFile(_) => {}
+        }
+    }
 
-    Ok(matches)
+    Ok(SearchResults {
+        file: path.clone(),
+        matches: matches
+            .into_iter()
+            .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
+            .collect(),
+        rewritten,
+    })
 }
 
 #[test]
@@ -814,6 +1310,25 @@ impl<'a> RegistryVisitor<GraphqlLanguage> for SyntaxVisitor<'a> {
     }
 }
 
+impl<'a> RegistryVisitor<HtmlLanguage> for SyntaxVisitor<'a> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
 /// Type meant to register all the lint rules for each language supported by Biome
 ///
 #[derive(Debug)]
@@ -1001,6 +1516,36 @@ impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for LintVisitor<'a, 'b> {
     }
 }
 
+impl<'a, 'b> RegistryVisitor<HtmlLanguage> for LintVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = HtmlLanguage>>(&mut self) {
+        for selector in self.only {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+
+        for selector in self.skip {
+            if RuleFilter::from(selector).match_group::<G>() {
+                G::record_rules(self)
+            }
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>()
+    }
+}
+
 struct AssistsVisitor<'a, 'b> {
     settings: Option<&'b Settings>,
     enabled_rules: Vec<RuleFilter<'a>>,
@@ -1139,6 +1684,22 @@ impl<'a, 'b> RegistryVisitor<GraphqlLanguage> for AssistsVisitor<'a, 'b> {
     }
 }
 
+impl<'a, 'b> RegistryVisitor<HtmlLanguage> for AssistsVisitor<'a, 'b> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
 pub(crate) struct AnalyzerVisitorBuilder<'a, 'b> {
     syntax: Option<SyntaxVisitor<'a>>,
     lint: Option<LintVisitor<'a, 'b>>,
@@ -1192,6 +1753,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut syntax);
             biome_json_analyze::visit_registry(&mut syntax);
             biome_graphql_analyze::visit_registry(&mut syntax);
+            biome_html_analyze::visit_registry(&mut syntax);
             enabled_rules.extend(syntax.enabled_rules);
         }
 
@@ -1200,6 +1762,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut lint);
             biome_json_analyze::visit_registry(&mut lint);
             biome_graphql_analyze::visit_registry(&mut lint);
+            biome_html_analyze::visit_registry(&mut lint);
             let (linter_enabled_rules, linter_disabled_rules) = lint.finish();
             enabled_rules.extend(linter_enabled_rules);
             disabled_rules.extend(linter_disabled_rules);
@@ -1210,6 +1773,7 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
             biome_css_analyze::visit_registry(&mut assists);
             biome_json_analyze::visit_registry(&mut assists);
             biome_graphql_analyze::visit_registry(&mut assists);
+            biome_html_analyze::visit_registry(&mut assists);
             let (assists_enabled_rules, assists_disabled_rules) = assists.finish();
             enabled_rules.extend(assists_enabled_rules);
             disabled_rules.extend(assists_disabled_rules);
@@ -1219,6 +1783,47 @@ impl<'a, 'b> AnalyzerVisitorBuilder<'a, 'b> {
     }
 }
 
+/// Returns `true` if one of the lint rules enabled by `settings`, or by `only`,
+/// queries the module graph. The rules enabled by the overrides are taken into
+/// account, whatever the files they apply to.
+pub(crate) fn is_module_graph_required(
+    settings: Option<&Settings>,
+    only: &[RuleSelector],
+    skip: &[RuleSelector],
+) -> bool {
+    let Some(settings) = settings else {
+        return false;
+    };
+    let patterns = &settings.override_settings.patterns;
+    if !settings.linter.enabled
+        && !patterns
+            .iter()
+            .any(|pattern| pattern.linter.enabled == Some(true))
+    {
+        return false;
+    }
+
+    let mut enabled_rules: Vec<RuleFilter> = only.iter().map(RuleFilter::from).collect();
+    if enabled_rules.is_empty() {
+        let rules = settings.linter.rules.iter().chain(
+            patterns
+                .iter()
+                .filter_map(|pattern| pattern.linter.rules.as_ref()),
+        );
+        for rules in rules {
+            enabled_rules.extend(rules.as_enabled_rules());
+        }
+    }
+    let disabled_rules: Vec<RuleFilter> = skip.iter().map(RuleFilter::from).collect();
+
+    biome_js_analyze::uses_module_graph(&AnalysisFilter {
+        categories: RuleCategoriesBuilder::default().with_lint().build(),
+        enabled_rules: Some(&enabled_rules),
+        disabled_rules: &disabled_rules,
+        range: None,
+    })
+}
+
 #[test]
 fn test_vue_script_lang() {
     const VUE_JS_SCRIPT_OPENING_TAG: &str = r#"<script>"#;
@@ -1274,3 +1879,43 @@ fn test_vue_script_lang() {
             .is_typescript()
     );
 }
+
+#[test]
+fn suppression_guard_detects_repeated_suppressions() {
+    let rule = Some(("suspicious", "noDebugger"));
+    let mut guard = SuppressionGuard::default();
+
+    // The comment is inserted before the diagnostic, at the start of its line
+    assert!(guard
+        .record(
+            rule,
+            TextRange::new(10.into(), 19.into()),
+            TextRange::new(10.into(), 20.into()),
+            30.into(),
+            60.into(),
+        )
+        .is_ok());
+    // Another diagnostic of the same rule
+    assert!(guard
+        .record(
+            rule,
+            TextRange::new(50.into(), 59.into()),
+            TextRange::new(50.into(), 60.into()),
+            60.into(),
+            90.into(),
+        )
+        .is_ok());
+    // The first diagnostic, moved by its comment, is reported again
+    assert!(matches!(
+        guard.record(
+            rule,
+            TextRange::new(40.into(), 49.into()),
+            TextRange::new(40.into(), 50.into()),
+            90.into(),
+            120.into(),
+        ),
+        Err(WorkspaceError::RuleError(
+            RuleError::IneffectiveSuppressionError { .. }
+        ))
+    ));
+}
@@ -0,0 +1,178 @@
+//! Language agnostic helpers to compute the structure of a document from its
+//! syntax tree: folding ranges and selection ranges.
+
+use crate::workspace::{FoldingRange, FoldingRangeKind};
+use biome_parser::AnyParse;
+use biome_rowan::{
+    Direction, Language, SyntaxNode, SyntaxToken, SyntaxTriviaPiece, TextRange, TextSize,
+};
+
+/// Returns the folding ranges of the regions delimited by brackets and of the
+/// comments of a document
+pub(crate) fn folding_ranges<L: Language + 'static>(parse: AnyParse) -> Vec<FoldingRange> {
+    let root = parse.syntax::<L>();
+    let mut ranges: Vec<_> = root
+        .descendants()
+        .filter_map(|node| delimited_folding_range(&node))
+        .collect();
+    ranges.extend(comment_folding_ranges(&root));
+    ranges.sort_by_key(|range| range.range.start());
+    ranges
+}
+
+/// Returns the folding range of `node` when its content is delimited by a pair
+/// of braces, brackets or parentheses that are its children, and spans several lines
+pub(crate) fn delimited_folding_range<L: Language>(node: &SyntaxNode<L>) -> Option<FoldingRange> {
+    let mut tokens = node
+        .children_with_tokens()
+        .filter_map(|element| element.into_token());
+    let open = tokens.find(|token| matches!(token.text_trimmed(), "{" | "[" | "("))?;
+    let close_text = match open.text_trimmed() {
+        "{" => "}",
+        "[" => "]",
+        _ => ")",
+    };
+    let close = tokens
+        .filter(|token| token.text_trimmed() == close_text)
+        .last()?;
+
+    multiline_folding_range(&open, &close, FoldingRangeKind::Block)
+}
+
+/// Returns the folding range going from the start of `first` to the end of
+/// `last`, if there's a line break between them
+pub(crate) fn multiline_folding_range<L: Language>(
+    first: &SyntaxToken<L>,
+    last: &SyntaxToken<L>,
+    kind: FoldingRangeKind,
+) -> Option<FoldingRange> {
+    let range = TextRange::new(
+        first.text_trimmed_range().start(),
+        last.text_trimmed_range().end(),
+    );
+    if range.is_empty() {
+        return None;
+    }
+
+    let mut next = first.next_token();
+    while let Some(token) = next {
+        if token.text_trimmed_range().start() > range.end() {
+            break;
+        }
+        if token.has_leading_newline() {
+            return Some(FoldingRange { range, kind });
+        }
+        next = token.next_token();
+    }
+    None
+}
+
+/// Returns the folding ranges of the comments of `root`: the block comments
+/// that span several lines, and the groups of line comments placed on
+/// consecutive lines
+pub(crate) fn comment_folding_ranges<L: Language>(root: &SyntaxNode<L>) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+    // The range of the current group of line comments, and its number of comments
+    let mut group: Option<(TextRange, usize)> = None;
+
+    for token in root.descendants_tokens(Direction::Next) {
+        let mut newlines = 0;
+        for piece in token.leading_trivia().pieces() {
+            let kind = piece.kind();
+            if kind.is_single_line_comment() {
+                group = Some(match group {
+                    Some((range, count)) => (range.cover(piece.text_range()), count + 1),
+                    None => (piece.text_range(), 1),
+                });
+                newlines = 0;
+            } else if kind.is_newline() {
+                newlines += 1;
+                // Line comments separated by an empty line belong to different groups
+                if newlines > 1 {
+                    push_comment_group(&mut ranges, group.take());
+                }
+            } else if !kind.is_whitespace() {
+                push_comment_group(&mut ranges, group.take());
+                push_multiline_comment(&mut ranges, &piece);
+            }
+        }
+        push_comment_group(&mut ranges, group.take());
+
+        // Line comments that follow a token aren't grouped
+        for piece in token.trailing_trivia().pieces() {
+            push_multiline_comment(&mut ranges, &piece);
+        }
+    }
+
+    ranges
+}
+
+fn push_multiline_comment<L: Language>(
+    ranges: &mut Vec<FoldingRange>,
+    piece: &SyntaxTriviaPiece<L>,
+) {
+    if piece.kind().is_multiline_comment() && piece.text().contains('\n') {
+        ranges.push(FoldingRange {
+            range: piece.text_range(),
+            kind: FoldingRangeKind::Comment,
+        });
+    }
+}
+
+fn push_comment_group(ranges: &mut Vec<FoldingRange>, group: Option<(TextRange, usize)>) {
+    if let Some((range, count)) = group {
+        if count > 1 {
+            ranges.push(FoldingRange {
+                range,
+                kind: FoldingRangeKind::Comment,
+            });
+        }
+    }
+}
+
+/// Returns, for each position, the ranges that can be selected around it from
+/// the innermost to the outermost: the comment or the token at the position,
+/// then its ancestors
+pub(crate) fn selection_ranges<L: Language + 'static>(
+    parse: AnyParse,
+    positions: &[TextSize],
+) -> Vec<Vec<TextRange>> {
+    let root = parse.syntax::<L>();
+    positions
+        .iter()
+        .map(|offset| selection_ranges_at(&root, *offset))
+        .collect()
+}
+
+fn selection_ranges_at<L: Language>(root: &SyntaxNode<L>, offset: TextSize) -> Vec<TextRange> {
+    let token_at_offset = root.token_at_offset(offset);
+    let token = token_at_offset
+        .clone()
+        .find(|token| token.text_trimmed_range().contains_inclusive(offset))
+        .or_else(|| token_at_offset.right_biased());
+    let Some(token) = token else {
+        return Vec::new();
+    };
+
+    let mut ranges: Vec<TextRange> = Vec::new();
+    let mut push = |range: TextRange| {
+        if range.contains_inclusive(offset) && ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    };
+
+    let comment = token
+        .leading_trivia()
+        .pieces()
+        .chain(token.trailing_trivia().pieces())
+        .find(|piece| piece.is_comments() && piece.text_range().contains(offset));
+    if let Some(comment) = comment {
+        push(comment.text_range());
+    }
+    push(token.text_trimmed_range());
+    for node in token.ancestors() {
+        push(node.text_trimmed_range());
+    }
+
+    ranges
+}
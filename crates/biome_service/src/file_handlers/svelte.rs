@@ -10,15 +10,19 @@ use crate::workspace::{
 use crate::WorkspaceError;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
-use biome_js_parser::{parse_js_with_cache, JsParserOptions};
 use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::NodeCache;
-use regex::{Match, Regex};
+use regex::Regex;
+use std::borrow::Cow;
 use std::sync::LazyLock;
 use tracing::debug;
 
-use super::{parse_lang_from_script_opening_tag, SearchCapabilities};
+use super::embedded::{
+    join_script_blocks, parse_script_blocks, script_blocks, script_blocks_file_source,
+    script_blocks_start,
+};
+use super::{SearchCapabilities, StructureCapabilities};
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct SvelteFileHandler;
@@ -29,55 +33,21 @@ pub static SVELTE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
 });
 
 impl SvelteFileHandler {
-    /// It extracts the JavaScript/TypeScript code contained in the script block of a Svelte file
+    /// It extracts the JavaScript/TypeScript code contained in the script blocks of a Svelte file.
+    /// When there are several blocks, they are joined in a single document that keeps their offsets.
     ///
     /// If there's no script block, an empty string is returned.
-    pub fn input(text: &str) -> &str {
-        match Self::matches_script(text) {
-            Some(script) => &text[script.start()..script.end()],
-            _ => "",
-        }
-    }
-
-    /// It takes the original content of a Svelte file, and new output of an Svelte file. The output is only the content contained inside the
-    /// Svelte `<script>` tag. The function replaces `output` inside that `<script>`.
-    pub fn output(input: &str, output: &str) -> String {
-        if let Some(script) = Self::matches_script(input) {
-            format!(
-                "{}{}{}",
-                &input[..script.start()],
-                output,
-                &input[script.end()..]
-            )
-        } else {
-            input.to_string()
-        }
+    pub fn input(text: &str) -> Cow<str> {
+        join_script_blocks(text, &script_blocks(&SVELTE_FENCE, text))
     }
 
-    /// Returns the start byte offset of the Svelte `<script>` tag
+    /// Returns the start byte offset of the first Svelte `<script>` block
     pub fn start(input: &str) -> Option<u32> {
-        Self::matches_script(input).map(|m| m.start() as u32)
-    }
-
-    fn matches_script(input: &str) -> Option<Match> {
-        SVELTE_FENCE
-            .captures(input)
-            .and_then(|captures| captures.name("script"))
+        script_blocks_start(input, EmbeddingKind::Svelte)
     }
 
     pub fn file_source(text: &str) -> JsFileSource {
-        SVELTE_FENCE
-            .captures(text)
-            .and_then(|captures| {
-                let (language, variant) =
-                    parse_lang_from_script_opening_tag(captures.name("opening")?.as_str());
-                Some(
-                    JsFileSource::from(language)
-                        .with_variant(variant)
-                        .with_embedding_kind(EmbeddingKind::Svelte),
-                )
-            })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+        script_blocks_file_source(&script_blocks(&SVELTE_FENCE, text), EmbeddingKind::Svelte)
     }
 }
 
@@ -94,16 +64,24 @@ impl ExtensionHandler for SvelteFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                symbol_references: None,
                 fix_all: Some(fix_all),
+                transform: None,
                 organize_imports: Some(organize_imports),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
+                minify: None,
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
+            structure: StructureCapabilities {
+                document_symbols: None,
+                folding_ranges: None,
+                selection_ranges: None,
+            },
         }
     }
 }
@@ -115,15 +93,12 @@ fn parse(
     _settings: Option<&Settings>,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = SvelteFileHandler::input(text);
-    let file_source = SvelteFileHandler::file_source(text);
+    let (any_parse, file_source) = parse_script_blocks(text, EmbeddingKind::Svelte, cache);
 
     debug!("Parsing file with language {:?}", file_source);
 
-    let parse = parse_js_with_cache(script, file_source, JsParserOptions::default(), cache);
-
     ParseResult {
-        any_parse: parse.into(),
+        any_parse,
         language: Some(file_source.into()),
     }
 }
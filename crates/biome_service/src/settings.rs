@@ -319,6 +319,13 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the linter is disabled for GraphQL files
+    pub fn graphql_linter_disabled(&self) -> bool {
+        let enabled = self.languages.graphql.linter.enabled.as_ref();
+        trace!("GRAPHQL LINTER DISABLED {:?}", enabled);
+        enabled == Some(&false)
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -580,6 +587,7 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
         language_setting.formatter.semicolons = Some(formatter.semicolons);
         language_setting.formatter.arrow_parentheses = Some(formatter.arrow_parentheses);
         language_setting.formatter.bracket_same_line = Some(formatter.bracket_same_line.into());
+        language_setting.formatter.format_embedded_css = Some(formatter.format_embedded_css);
         language_setting.formatter.enabled = Some(formatter.enabled);
         language_setting.formatter.line_width = formatter.line_width;
         language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
@@ -590,7 +598,8 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
             javascript.parser.unsafe_parameter_decorators_enabled;
 
         language_setting.globals = Some(javascript.globals.into_index_set());
-        language_setting.environment = javascript.jsx_runtime.into();
+        language_setting.environment.jsx_runtime = javascript.jsx_runtime;
+        language_setting.environment.graphql_tags = javascript.graphql_tags.into_index_set();
         language_setting.linter.enabled = Some(javascript.linter.enabled);
 
         language_setting
@@ -948,6 +957,32 @@ impl OverrideSettings {
             .unwrap_or_default()
     }
 
+    pub fn override_graphql_tags(
+        &self,
+        path: &BiomePath,
+        base_set: &IndexSet<String>,
+    ) -> IndexSet<String> {
+        self.patterns
+            .iter()
+            // Reverse the traversal as only the last override takes effect
+            .rev()
+            .find_map(|pattern| {
+                if pattern.include.matches_path(path) && !pattern.exclude.matches_path(path) {
+                    Some(
+                        pattern
+                            .languages
+                            .javascript
+                            .environment
+                            .graphql_tags
+                            .clone(),
+                    )
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| base_set.clone())
+    }
+
     pub fn override_jsx_runtime(&self, path: &BiomePath, base_setting: JsxRuntime) -> JsxRuntime {
         self.patterns
             .iter()
@@ -1087,6 +1122,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
             }
         }
@@ -1221,6 +1261,9 @@ impl OverrideSettingPattern {
         if let Some(bracket_same_line) = js_formatter.bracket_same_line {
             options.set_bracket_same_line(bracket_same_line);
         }
+        if let Some(format_embedded_css) = js_formatter.format_embedded_css {
+            options.set_format_embedded_css(format_embedded_css);
+        }
         if let Some(attribute_position) = js_formatter
             .attribute_position
             .or(formatter.attribute_position)
@@ -1580,6 +1623,7 @@ fn to_javascript_language_settings(
     language_setting.formatter.arrow_parentheses = formatter.arrow_parentheses;
     language_setting.formatter.bracket_spacing = formatter.bracket_spacing;
     language_setting.formatter.bracket_same_line = formatter.bracket_same_line.map(Into::into);
+    language_setting.formatter.format_embedded_css = formatter.format_embedded_css;
     language_setting.formatter.enabled = formatter.enabled;
     language_setting.formatter.line_width = formatter.line_width;
     language_setting.formatter.line_ending = formatter.line_ending;
@@ -1603,6 +1647,10 @@ fn to_javascript_language_settings(
     language_setting.environment.jsx_runtime = conf
         .jsx_runtime
         .unwrap_or(parent_settings.environment.jsx_runtime);
+    language_setting.environment.graphql_tags = conf.graphql_tags.map_or_else(
+        || parent_settings.environment.graphql_tags.clone(),
+        StringSet::into_index_set,
+    );
 
     language_setting
 }
@@ -56,8 +56,8 @@ use crate::file_handlers::Capabilities;
 pub use crate::file_handlers::DocumentFileSource;
 use crate::settings::Settings;
 use crate::{Deserialize, Serialize, WorkspaceError};
-use biome_analyze::ActionCategory;
 pub use biome_analyze::RuleCategories;
+use biome_analyze::{ActionCategory, Profile};
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::PartialConfiguration;
 use biome_console::{markup, Markup, MarkupBuf};
@@ -120,13 +120,14 @@ impl FileFeaturesResult {
     }
 
     /// By default, all features are not supported by a file.
-    const WORKSPACE_FEATURES: [(FeatureKind, SupportKind); 6] = [
+    const WORKSPACE_FEATURES: [(FeatureKind, SupportKind); 7] = [
         (FeatureKind::Lint, SupportKind::FileNotSupported),
         (FeatureKind::Format, SupportKind::FileNotSupported),
         (FeatureKind::OrganizeImports, SupportKind::FileNotSupported),
         (FeatureKind::Search, SupportKind::FileNotSupported),
         (FeatureKind::Assists, SupportKind::FileNotSupported),
         (FeatureKind::Debug, SupportKind::FileNotSupported),
+        (FeatureKind::Transform, SupportKind::FileNotSupported),
     ];
 
     pub fn new() -> Self {
@@ -159,6 +160,11 @@ impl FileFeaturesResult {
                 .insert(FeatureKind::Search, SupportKind::Supported);
         }
 
+        if capabilities.analyzer.transform.is_some() {
+            self.features_supported
+                .insert(FeatureKind::Transform, SupportKind::Supported);
+        }
+
         if capabilities.debug.debug_syntax_tree.is_some()
             || capabilities.debug.debug_formatter_ir.is_some()
             || capabilities.debug.debug_control_flow.is_some()
@@ -290,6 +296,10 @@ impl FileFeaturesResult {
         self.supports_for(&FeatureKind::Search)
     }
 
+    pub fn supports_transform(&self) -> bool {
+        self.supports_for(&FeatureKind::Transform)
+    }
+
     /// Loops through all the features of the current file, and if a feature is [SupportKind::FileNotSupported],
     /// it gets changed to [SupportKind::Ignored]
     pub fn ignore_not_supported(&mut self) {
@@ -415,12 +425,13 @@ pub enum FeatureKind {
     Search,
     Assists,
     Debug,
+    Transform,
 }
 
 #[derive(Debug, Copy, Clone, Hash, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
 #[serde(
-    from = "smallvec::SmallVec<[FeatureKind; 6]>",
-    into = "smallvec::SmallVec<[FeatureKind; 6]>"
+    from = "smallvec::SmallVec<[FeatureKind; 7]>",
+    into = "smallvec::SmallVec<[FeatureKind; 7]>"
 )]
 pub struct FeatureName(BitFlags<FeatureKind>);
 
@@ -437,8 +448,8 @@ impl FeatureName {
     }
 }
 
-impl From<SmallVec<[FeatureKind; 6]>> for FeatureName {
-    fn from(value: SmallVec<[FeatureKind; 6]>) -> Self {
+impl From<SmallVec<[FeatureKind; 7]>> for FeatureName {
+    fn from(value: SmallVec<[FeatureKind; 7]>) -> Self {
         value
             .into_iter()
             .fold(FeatureName::empty(), |mut acc, kind| {
@@ -448,7 +459,7 @@ impl From<SmallVec<[FeatureKind; 6]>> for FeatureName {
     }
 }
 
-impl From<FeatureName> for SmallVec<[FeatureKind; 6]> {
+impl From<FeatureName> for SmallVec<[FeatureKind; 7]> {
     fn from(value: FeatureName) -> Self {
         value.iter().collect()
     }
@@ -498,6 +509,11 @@ impl FeaturesBuilder {
         self
     }
 
+    pub fn with_transform(mut self) -> Self {
+        self.0.insert(FeatureKind::Transform);
+        self
+    }
+
     pub fn build(self) -> FeatureName {
         FeatureName(self.0)
     }
@@ -550,6 +566,36 @@ impl From<(BiomePath, String)> for SetManifestForProjectParams {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UpdateModuleGraphParams {
+    /// The path of a JavaScript module, a `package.json` or a `tsconfig.json`
+    pub path: BiomePath,
+    pub update_kind: UpdateKind,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UpdateKind {
+    /// The file has been added or changed
+    AddOrUpdate { content: String },
+    /// The file has been deleted
+    Remove,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SetModuleGraphCompleteParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IsModuleGraphRequiredParams {
+    /// The rules to run instead of the rules enabled by the configuration
+    pub only: Vec<RuleSelector>,
+    /// The rules to skip
+    pub skip: Vec<RuleSelector>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetSyntaxTreeParams {
@@ -604,6 +650,9 @@ pub struct PullDiagnosticsParams {
     pub max_diagnostics: u64,
     pub only: Vec<RuleSelector>,
     pub skip: Vec<RuleSelector>,
+    /// Measures the time spent by the analyzer, and returns it in [PullDiagnosticsResult::profile]
+    #[serde(default)]
+    pub profile: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -612,6 +661,8 @@ pub struct PullDiagnosticsResult {
     pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
     pub errors: usize,
     pub skipped_diagnostics: u64,
+    /// The time spent by the analyzer, when [PullDiagnosticsParams::profile] is enabled
+    pub profile: Option<Profile>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -665,6 +716,8 @@ pub enum FixFileMode {
     SafeFixes,
     /// Applies [safe](biome_diagnostics::Applicability::Always) and [unsafe](biome_diagnostics::Applicability::MaybeIncorrect) fixes
     SafeAndUnsafeFixes,
+    /// Applies the suppression comments of the lint rules, instead of their fixes
+    ApplySuppressions,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -676,6 +729,25 @@ pub struct FixFileParams {
     pub only: Vec<RuleSelector>,
     pub skip: Vec<RuleSelector>,
     pub rule_categories: RuleCategories,
+    /// The reason written in the suppression comments, when using [FixFileMode::ApplySuppressions]
+    #[serde(default)]
+    pub suppression_reason: Option<String>,
+    /// Measures the time spent by the analyzer, and returns it in [FixFileResult::profile]
+    #[serde(default)]
+    pub profile: bool,
+}
+
+/// The parameters of [FileGuard::fix_file], which are the ones of [FixFileParams]
+/// without the path of the file
+#[derive(Debug)]
+pub struct FixFileGuardParams {
+    pub fix_file_mode: FixFileMode,
+    pub should_format: bool,
+    pub only: Vec<RuleSelector>,
+    pub skip: Vec<RuleSelector>,
+    pub rule_categories: RuleCategories,
+    pub suppression_reason: Option<String>,
+    pub profile: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -691,6 +763,12 @@ pub struct FixFileResult {
 
     /// number of skipped suggested fixes
     pub skipped_suggested_fixes: u32,
+
+    /// Diagnostics emitted while applying the fixes, like the diagnostics that can't be suppressed
+    pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+
+    /// The time spent by the analyzer, when [FixFileParams::profile] is enabled
+    pub profile: Option<Profile>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -702,6 +780,40 @@ pub struct FixAction {
     pub range: TextRange,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransformFileParams {
+    pub path: BiomePath,
+    /// The names of the transformations to apply, e.g. `transformEnum`.
+    /// All the transformations are applied when it's empty.
+    pub transformations: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransformFileResult {
+    /// New source code for the file with all the transformations applied
+    pub code: String,
+    /// List of all the transformations applied to the file
+    pub actions: Vec<FixAction>,
+    /// The diagnostics emitted by the transformations, e.g. for the code that
+    /// couldn't be transformed
+    pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MinifyFileParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MinifyFileResult {
+    /// The minified source code of the file
+    pub code: String,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RenameParams {
@@ -719,6 +831,147 @@ pub struct RenameResult {
     pub indels: TextEdit,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSymbolReferencesParams {
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSymbolReferencesResult {
+    /// Range of the binding that declares the symbol, `None` if the symbol isn't declared in the file
+    pub declaration: Option<TextRange>,
+    /// List of the references to the symbol in the file
+    pub references: Vec<SymbolReference>,
+    /// Location of the declaration in the imported module, when the symbol is
+    /// imported with a relative import that can be resolved
+    pub imported_declaration: Option<SymbolLocation>,
+    /// When the symbol is imported with a relative import of a module that isn't
+    /// open in the workspace, the paths where the module can be, in order of precedence.
+    /// Once the module is open, the import can be resolved.
+    pub import_candidates: Vec<BiomePath>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SymbolReference {
+    pub range: TextRange,
+    /// Whether the reference writes the symbol, like an assignment
+    pub is_write: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SymbolLocation {
+    pub path: BiomePath,
+    /// Range of the declaration, empty at the start of the file when the
+    /// module doesn't export the symbol
+    pub range: TextRange,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetDocumentSymbolsParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetDocumentSymbolsResult {
+    /// The symbols declared at the top level of the document
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: DocumentSymbolKind,
+    /// Range of the whole declaration of the symbol
+    pub range: TextRange,
+    /// Range of the name of the symbol
+    pub selection_range: TextRange,
+    /// The symbols declared inside this symbol, like the methods of a class
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+/// The kind of a symbol, following the symbol kinds of the Language Server Protocol
+pub enum DocumentSymbolKind {
+    Module,
+    Namespace,
+    Class,
+    Method,
+    Property,
+    Field,
+    Constructor,
+    Enum,
+    Interface,
+    Function,
+    Variable,
+    Constant,
+    String,
+    Number,
+    Boolean,
+    Array,
+    Object,
+    Null,
+    EnumMember,
+    Struct,
+    Operator,
+    TypeParameter,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesParams {
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetFoldingRangesResult {
+    pub ranges: Vec<FoldingRange>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FoldingRange {
+    /// Range of the region, from its opening delimiter to its closing
+    /// delimiter when it has some
+    pub range: TextRange,
+    pub kind: FoldingRangeKind,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FoldingRangeKind {
+    /// A region delimited by brackets or tags, like a block or an element
+    Block,
+    /// A block comment, or consecutive line comments
+    Comment,
+    /// Consecutive import statements
+    Imports,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesParams {
+    pub path: BiomePath,
+    pub positions: Vec<TextSize>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSelectionRangesResult {
+    /// For each position, the ranges of the syntax elements that contain it,
+    /// from the innermost to the outermost
+    pub ranges: Vec<Vec<TextRange>>,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ServerInfo {
@@ -805,6 +1058,9 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub file: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The content of the file with the rewrites of the pattern applied, if
+    /// the pattern rewrote the file.
+    pub rewritten: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -893,6 +1149,37 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: SetManifestForProjectParams,
     ) -> Result<(), WorkspaceError>;
 
+    /// Adds a file to the module graph used to resolve the imports between files,
+    /// without opening it, or removes a deleted file from the graph. The file can
+    /// be a JavaScript module, a `package.json` or a `tsconfig.json`; other files
+    /// are ignored.
+    ///
+    /// The files opened in the workspace are added to the graph too. Once a file
+    /// has been added with this method, the modules stay in the graph when their
+    /// file is closed; otherwise closing a file removes its module. The LSP
+    /// doesn't use this method, so its graph only contains the files that are open.
+    ///
+    /// Adding files doesn't make the graph complete, see
+    /// [Workspace::set_module_graph_complete].
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError>;
+
+    /// Marks the module graph as complete, once all the files of the project
+    /// have been added with [Workspace::update_module_graph]. The rules that
+    /// need every importer of a module, like `noUnusedExports`, don't report
+    /// anything until the graph is complete.
+    fn set_module_graph_complete(
+        &self,
+        params: SetModuleGraphCompleteParams,
+    ) -> Result<(), WorkspaceError>;
+
+    /// Returns `true` if one of the lint rules enabled by the settings, or by
+    /// `only`, queries the module graph. The files of the project only need to
+    /// be added to the graph with [Workspace::update_module_graph] in that case.
+    fn is_module_graph_required(
+        &self,
+        params: IsModuleGraphRequiredParams,
+    ) -> Result<bool, WorkspaceError>;
+
     /// Register a possible workspace project folder. Returns the key of said project. Use this key when you want to switch to different projects.
     fn register_project_folder(
         &self,
@@ -953,9 +1240,42 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Return the content of the file with all safe code actions applied
     fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
 
+    /// Return the content of the file with the given transformations applied
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError>;
+
+    /// Return the minified content of the file
+    fn minify_file(&self, params: MinifyFileParams) -> Result<MinifyFileResult, WorkspaceError>;
+
     /// Return the content of the file after renaming a symbol
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Return the declaration and the references of the symbol at the given offset
+    fn get_symbol_references(
+        &self,
+        params: GetSymbolReferencesParams,
+    ) -> Result<GetSymbolReferencesResult, WorkspaceError>;
+
+    /// Return the symbols declared in a file
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError>;
+
+    /// Return the regions of a file that can be folded
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError>;
+
+    /// Return the ranges that can be selected around the given offsets
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError>;
+
     /// Returns debug information about this workspace.
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError>;
 
@@ -1050,6 +1370,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         max_diagnostics: u32,
         only: Vec<RuleSelector>,
         skip: Vec<RuleSelector>,
+        profile: bool,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
@@ -1057,6 +1378,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             max_diagnostics: max_diagnostics.into(),
             only,
             skip,
+            profile,
         })
     }
 
@@ -1094,14 +1416,16 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
-    pub fn fix_file(
-        &self,
-        fix_file_mode: FixFileMode,
-        should_format: bool,
-        rule_categories: RuleCategories,
-        only: Vec<RuleSelector>,
-        skip: Vec<RuleSelector>,
-    ) -> Result<FixFileResult, WorkspaceError> {
+    pub fn fix_file(&self, params: FixFileGuardParams) -> Result<FixFileResult, WorkspaceError> {
+        let FixFileGuardParams {
+            fix_file_mode,
+            should_format,
+            only,
+            skip,
+            rule_categories,
+            suppression_reason,
+            profile,
+        } = params;
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
@@ -1109,6 +1433,24 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             only,
             skip,
             rule_categories,
+            suppression_reason,
+            profile,
+        })
+    }
+
+    pub fn transform_file(
+        &self,
+        transformations: Vec<String>,
+    ) -> Result<TransformFileResult, WorkspaceError> {
+        self.workspace.transform_file(TransformFileParams {
+            path: self.path.clone(),
+            transformations,
+        })
+    }
+
+    pub fn minify_file(&self) -> Result<MinifyFileResult, WorkspaceError> {
+        self.workspace.minify_file(MinifyFileParams {
+            path: self.path.clone(),
         })
     }
 
@@ -1118,6 +1460,30 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         })
     }
 
+    pub fn get_document_symbols(&self) -> Result<GetDocumentSymbolsResult, WorkspaceError> {
+        self.workspace
+            .get_document_symbols(GetDocumentSymbolsParams {
+                path: self.path.clone(),
+            })
+    }
+
+    pub fn get_folding_ranges(&self) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        self.workspace.get_folding_ranges(GetFoldingRangesParams {
+            path: self.path.clone(),
+        })
+    }
+
+    pub fn get_selection_ranges(
+        &self,
+        positions: Vec<TextSize>,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        self.workspace
+            .get_selection_ranges(GetSelectionRangesParams {
+                path: self.path.clone(),
+                positions,
+            })
+    }
+
     pub fn search_pattern(&self, pattern: &PatternId) -> Result<SearchResults, WorkspaceError> {
         self.workspace.search_pattern(SearchPatternParams {
             path: self.path.clone(),
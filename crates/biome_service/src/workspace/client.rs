@@ -1,7 +1,8 @@
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, ProjectKey, RageParams, RageResult, RegisterProjectFolderParams,
-    ServerInfo, SetManifestForProjectParams, UnregisterProjectFolderParams,
+    FileFeaturesResult, GetFileContentParams, IsModuleGraphRequiredParams, IsPathIgnoredParams,
+    OrganizeImportsParams, OrganizeImportsResult, ProjectKey, RageParams, RageResult,
+    RegisterProjectFolderParams, ServerInfo, SetManifestForProjectParams,
+    SetModuleGraphCompleteParams, UnregisterProjectFolderParams, UpdateModuleGraphParams,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -14,10 +15,13 @@ use std::{
 
 use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult, SearchPatternParams,
-    SearchResults, SupportsFeatureParams, UpdateSettingsParams,
+    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetDocumentSymbolsParams,
+    GetDocumentSymbolsResult, GetFoldingRangesParams, GetFoldingRangesResult, GetFormatterIRParams,
+    GetSelectionRangesParams, GetSelectionRangesResult, GetSymbolReferencesParams,
+    GetSymbolReferencesResult, GetSyntaxTreeParams, GetSyntaxTreeResult, MinifyFileParams,
+    MinifyFileResult, OpenFileParams, PullActionsParams, PullActionsResult, PullDiagnosticsParams,
+    PullDiagnosticsResult, RenameParams, RenameResult, SearchPatternParams, SearchResults,
+    SupportsFeatureParams, TransformFileParams, TransformFileResult, UpdateSettingsParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -125,6 +129,24 @@ where
         self.request("biome/set_manifest_for_project", params)
     }
 
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError> {
+        self.request("biome/update_module_graph", params)
+    }
+
+    fn set_module_graph_complete(
+        &self,
+        params: SetModuleGraphCompleteParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("biome/set_module_graph_complete", params)
+    }
+
+    fn is_module_graph_required(
+        &self,
+        params: IsModuleGraphRequiredParams,
+    ) -> Result<bool, WorkspaceError> {
+        self.request("biome/is_module_graph_required", params)
+    }
+
     fn register_project_folder(
         &self,
         params: RegisterProjectFolderParams,
@@ -196,10 +218,49 @@ where
         self.request("biome/fix_file", params)
     }
 
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError> {
+        self.request("biome/transform_file", params)
+    }
+
+    fn minify_file(&self, params: MinifyFileParams) -> Result<MinifyFileResult, WorkspaceError> {
+        self.request("biome/minify_file", params)
+    }
+
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError> {
         self.request("biome/rename", params)
     }
 
+    fn get_symbol_references(
+        &self,
+        params: GetSymbolReferencesParams,
+    ) -> Result<GetSymbolReferencesResult, WorkspaceError> {
+        self.request("biome/get_symbol_references", params)
+    }
+
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/get_document_symbols", params)
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        self.request("biome/get_folding_ranges", params)
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        self.request("biome/get_selection_ranges", params)
+    }
+
     fn rage(&self, params: RageParams) -> Result<RageResult, WorkspaceError> {
         self.request("biome/rage", params)
     }
@@ -1,15 +1,22 @@
 use super::{
     ChangeFileParams, CloseFileParams, FeatureKind, FeatureName, FixFileResult, FormatFileParams,
-    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, ParsePatternParams,
-    ParsePatternResult, PatternId, ProjectKey, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams, RenameResult,
-    SearchPatternParams, SearchResults, SetManifestForProjectParams, SupportsFeatureParams,
-    UnregisterProjectFolderParams, UpdateSettingsParams,
+    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetDocumentSymbolsParams,
+    GetDocumentSymbolsResult, GetFoldingRangesParams, GetFoldingRangesResult, GetFormatterIRParams,
+    GetSelectionRangesParams, GetSelectionRangesResult, GetSymbolReferencesParams,
+    GetSymbolReferencesResult, GetSyntaxTreeParams, GetSyntaxTreeResult,
+    IsModuleGraphRequiredParams, MinifyFileParams, MinifyFileResult, OpenFileParams,
+    ParsePatternParams, ParsePatternResult, PatternId, ProjectKey, PullActionsParams,
+    PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult, RegisterProjectFolderParams,
+    RenameResult, SearchPatternParams, SearchResults, SetManifestForProjectParams,
+    SetModuleGraphCompleteParams, SupportsFeatureParams, TransformFileParams, TransformFileResult,
+    UnregisterProjectFolderParams, UpdateKind, UpdateModuleGraphParams, UpdateSettingsParams,
 };
 use crate::diagnostics::{InvalidPattern, SearchError};
 use crate::file_handlers::{
-    Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
+    fix_script_blocks, fix_style_blocks, format_script_blocks, format_style_blocks,
+    is_module_graph_required, lint_style_blocks, map_script_blocks_diagnostics,
+    replace_script_blocks, Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams,
+    LintParams, ParseResult, SymbolReferencesParams, TransformParams,
 };
 use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
 use crate::workspace::{
@@ -19,6 +26,7 @@ use crate::workspace::{
 use crate::{
     file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
+use biome_analyze::Profiler;
 use biome_configuration::DEFAULT_FILE_SIZE_LIMIT;
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
@@ -26,18 +34,21 @@ use biome_diagnostics::{
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName};
 use biome_grit_patterns::GritQuery;
-use biome_js_syntax::ModuleKind;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{AnyJsRoot, ModuleKind};
 use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::JsonFileSource;
+use biome_module_graph::{ModuleGraph, ModuleInfo};
 use biome_parser::AnyParse;
-use biome_project::{NodeJsProject, PackageJson, PackageType, Project};
+use biome_project::{Manifest, NodeJsProject, PackageJson, PackageType, Project, TsConfigJson};
 use biome_rowan::NodeCache;
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
@@ -56,6 +67,12 @@ pub(super) struct WorkspaceServer {
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
     /// Stores patterns to search for.
     patterns: DashMap<PatternId, GritQuery>,
+    /// The modules of the project and the imports between them, shared with the analyzer
+    module_graph: Arc<ModuleGraph>,
+    /// Whether files have been added to the module graph with
+    /// [Workspace::update_module_graph], in which case the modules stay in the
+    /// graph when their file is closed
+    module_graph_indexed: AtomicBool,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -91,6 +108,8 @@ impl WorkspaceServer {
             current_project_path: RwLock::default(),
             file_sources: RwLock::default(),
             patterns: Default::default(),
+            module_graph: Arc::default(),
+            module_graph_indexed: AtomicBool::default(),
         }
     }
 
@@ -111,6 +130,14 @@ impl WorkspaceServer {
         self.features.get_capabilities(path, language)
     }
 
+    /// Retrieves the content of a file
+    fn get_content(&self, path: &BiomePath) -> Result<String, WorkspaceError> {
+        self.documents
+            .get(path)
+            .map(|document| document.content.clone())
+            .ok_or_else(WorkspaceError::not_found)
+    }
+
     /// Retrieves the supported language of a file
     fn get_file_source(&self, path: &BiomePath) -> DocumentFileSource {
         self.documents
@@ -273,6 +300,12 @@ impl WorkspaceServer {
                 if let Some(language) = language {
                     document.file_source_index = self.set_source(language);
                 }
+                if language.unwrap_or(file_source).is_javascript_like() {
+                    self.module_graph.update_module(
+                        biome_path,
+                        ModuleInfo::from_root(&any_parse.tree::<AnyJsRoot>()),
+                    );
+                }
                 Ok(entry.insert(any_parse).clone())
             }
         }
@@ -356,6 +389,7 @@ impl WorkspaceServer {
             // TODO: enable once the configuration is available
             FeatureKind::Search => return false, // There is no search-specific config.
             FeatureKind::Debug => return false,
+            FeatureKind::Transform => return false, // There is no transform-specific config.
         };
         let is_feature_included = feature_included_files.is_empty()
             || is_dir(path)
@@ -485,6 +519,68 @@ impl Workspace for WorkspaceServer {
         Ok(())
     }
 
+    fn update_module_graph(&self, params: UpdateModuleGraphParams) -> Result<(), WorkspaceError> {
+        let path = params.path.as_path();
+        let content = match params.update_kind {
+            UpdateKind::AddOrUpdate { content } => content,
+            UpdateKind::Remove => {
+                self.module_graph.remove_module(path);
+                return Ok(());
+            }
+        };
+        self.module_graph_indexed.store(true, Ordering::Relaxed);
+        match path.file_name().and_then(OsStr::to_str) {
+            Some("package.json") => {
+                let parsed = biome_json_parser::parse_json(&content, JsonParserOptions::default());
+                let manifest = PackageJson::deserialize_manifest(&parsed.tree());
+                if let Some(manifest) = manifest.into_deserialized() {
+                    self.module_graph.update_manifest(path, manifest);
+                }
+            }
+            Some("tsconfig.json") => {
+                let parsed = biome_json_parser::parse_json(
+                    &content,
+                    JsonParserOptions::default()
+                        .with_allow_comments()
+                        .with_allow_trailing_commas(),
+                );
+                let tsconfig = TsConfigJson::deserialize_manifest(&parsed.tree());
+                if let Some(tsconfig) = tsconfig.into_deserialized() {
+                    self.module_graph.update_tsconfig(path, tsconfig);
+                }
+            }
+            _ => {
+                if let Some(file_source) = DocumentFileSource::from_path(path).to_js_file_source() {
+                    let parsed =
+                        biome_js_parser::parse(&content, file_source, JsParserOptions::default());
+                    self.module_graph
+                        .update_module(path, ModuleInfo::from_root(&parsed.tree()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn set_module_graph_complete(
+        &self,
+        _params: SetModuleGraphCompleteParams,
+    ) -> Result<(), WorkspaceError> {
+        self.module_graph.set_complete();
+        Ok(())
+    }
+
+    fn is_module_graph_required(
+        &self,
+        params: IsModuleGraphRequiredParams,
+    ) -> Result<bool, WorkspaceError> {
+        let workspace = self.workspace();
+        Ok(is_module_graph_required(
+            workspace.settings(),
+            &params.only,
+            &params.skip,
+        ))
+    }
+
     fn register_project_folder(
         &self,
         params: RegisterProjectFolderParams,
@@ -609,6 +705,11 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(WorkspaceError::not_found)?;
 
         self.syntax.remove(&params.path);
+        // Only the open files are part of the graph, unless the files of the
+        // project have been added to it
+        if !self.module_graph_indexed.load(Ordering::Relaxed) {
+            self.module_graph.remove_module(&params.path);
+        }
         Ok(())
     }
 
@@ -620,20 +721,26 @@ impl Workspace for WorkspaceServer {
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         let parse = self.get_parse(params.path.clone())?;
         let manifest = self.get_current_manifest()?;
-        let (diagnostics, errors, skipped_diagnostics) =
+        let workspace = self.workspace();
+        let language = self.get_file_source(&params.path);
+        let profiler = params.profile.then(Profiler::default);
+        let lint_params = LintParams {
+            parse: parse.clone(),
+            workspace: &workspace,
+            max_diagnostics: params.max_diagnostics as u32,
+            path: &params.path,
+            only: params.only,
+            skip: params.skip,
+            language,
+            categories: params.categories,
+            manifest,
+            module_graph: self.module_graph.clone(),
+            profiler: profiler.clone(),
+        };
+        let (mut diagnostics, mut errors, mut skipped_diagnostics) =
             if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
                 info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
-                    let results = lint(LintParams {
-                        parse,
-                        workspace: &self.workspace(),
-                        max_diagnostics: params.max_diagnostics as u32,
-                        path: &params.path,
-                        only: params.only,
-                        skip: params.skip,
-                        language: self.get_file_source(&params.path),
-                        categories: params.categories,
-                        manifest,
-                    });
+                    let results = lint(lint_params.clone());
 
                     (
                         results.diagnostics,
@@ -651,6 +758,27 @@ impl Workspace for WorkspaceServer {
                 (parse_diagnostics, errors, 0)
             };
 
+        // The diagnostics of the files that embed other languages use the offsets of the file
+        if let Some(embedding_kind) = language.to_embedding_kind() {
+            let content = self.get_content(&params.path)?;
+            diagnostics =
+                map_script_blocks_diagnostics(&content, embedding_kind, &params.path, diagnostics);
+
+            let results = lint_style_blocks(
+                &content,
+                embedding_kind,
+                &LintParams {
+                    max_diagnostics: lint_params
+                        .max_diagnostics
+                        .saturating_sub(diagnostics.len() as u32),
+                    ..lint_params
+                },
+            );
+            diagnostics.extend(results.diagnostics);
+            errors += results.errors;
+            skipped_diagnostics += results.skipped_diagnostics;
+        }
+
         info!("Pulled {:?} diagnostic(s)", diagnostics.len());
         Ok(PullDiagnosticsResult {
             diagnostics: diagnostics
@@ -662,6 +790,7 @@ impl Workspace for WorkspaceServer {
                 .collect(),
             errors,
             skipped_diagnostics: skipped_diagnostics.into(),
+            profile: profiler.map(|profiler| profiler.take()),
         })
     }
 
@@ -685,6 +814,7 @@ impl Workspace for WorkspaceServer {
             workspace: &workspace,
             path: &params.path,
             manifest,
+            module_graph: self.module_graph.clone(),
             language,
             only: params.only,
             skip: params.skip,
@@ -709,7 +839,23 @@ impl Workspace for WorkspaceServer {
             }
         }
         let document_file_source = self.get_file_source(&params.path);
-        format(&params.path, &document_file_source, parse, workspace)
+        let Some(embedding_kind) = document_file_source.to_embedding_kind() else {
+            return format(&params.path, &document_file_source, parse, workspace);
+        };
+
+        // The files that embed other languages are printed as a whole
+        let code = if embedding_kind.is_html() {
+            format(&params.path, &document_file_source, parse, workspace)?.into_code()
+        } else {
+            format_script_blocks(
+                &self.get_content(&params.path)?,
+                embedding_kind,
+                &params.path,
+                &workspace,
+            )?
+        };
+        let code = format_style_blocks(code, embedding_kind, &params.path, &self.workspace())?;
+        Ok(Printed::new(code, None, Vec::new(), Vec::new()))
     }
 
     fn format_range(&self, params: FormatRangeParams) -> Result<Printed, WorkspaceError> {
@@ -774,22 +920,80 @@ impl Workspace for WorkspaceServer {
 
         let manifest = self.get_current_manifest()?;
         let language = self.get_file_source(&params.path);
-        fix_all(FixAllParams {
+        let workspace = self.workspace();
+        let profiler = params.profile.then(Profiler::default);
+        let fix_all_params = FixAllParams {
             parse,
-            // rules: rules.as_ref().map(|x| x.borrow()),
             fix_file_mode: params.fix_file_mode,
-            // filter,
-            workspace: self.workspace(),
+            workspace: &workspace,
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            module_graph: self.module_graph.clone(),
             document_file_source: language,
             only: params.only,
             skip: params.skip,
             rule_categories: params.rule_categories,
+            suppression_reason: params.suppression_reason,
+            profiler: profiler.clone(),
+        };
+        let embedding_kind = language.to_embedding_kind();
+        let result = match embedding_kind {
+            // The script blocks are formatted separately, once their fixes are written back
+            Some(embedding_kind) if !embedding_kind.is_html() => {
+                let result = fix_all(FixAllParams {
+                    should_format: false,
+                    ..fix_all_params.clone()
+                })?;
+                fix_script_blocks(
+                    &self.get_content(&params.path)?,
+                    embedding_kind,
+                    &fix_all_params,
+                    result,
+                )?
+            }
+            _ => fix_all(fix_all_params.clone())?,
+        };
+        let mut result = match embedding_kind {
+            Some(embedding_kind) => fix_style_blocks(embedding_kind, &fix_all_params, result)?,
+            None => result,
+        };
+        result.profile = profiler.map(|profiler| profiler.take());
+        Ok(result)
+    }
+
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let transform = capabilities
+            .analyzer
+            .transform
+            .ok_or_else(self.build_capability_error(&params.path))?;
+        let parse = self.get_parse(params.path.clone())?;
+        let workspace = self.workspace();
+
+        transform(TransformParams {
+            parse,
+            workspace: &workspace,
+            biome_path: &params.path,
+            document_file_source: self.get_file_source(&params.path),
+            transformations: params.transformations,
         })
     }
 
+    fn minify_file(&self, params: MinifyFileParams) -> Result<MinifyFileResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let minify = capabilities
+            .formatter
+            .minify
+            .ok_or_else(self.build_capability_error(&params.path))?;
+        let parse = self.get_parse(params.path.clone())?;
+
+        minify(&params.path, &self.get_file_source(&params.path), parse)
+    }
+
     fn rename(&self, params: super::RenameParams) -> Result<RenameResult, WorkspaceError> {
         let capabilities = self.get_file_capabilities(&params.path);
         let rename = capabilities
@@ -803,6 +1007,74 @@ impl Workspace for WorkspaceServer {
         Ok(result)
     }
 
+    fn get_symbol_references(
+        &self,
+        params: GetSymbolReferencesParams,
+    ) -> Result<GetSymbolReferencesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let symbol_references = capabilities
+            .analyzer
+            .symbol_references
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone())?;
+        symbol_references(SymbolReferencesParams {
+            parse,
+            path: &params.path,
+            symbol_at: params.symbol_at,
+            module_graph: self.module_graph.clone(),
+            get_parse: &|path| self.get_parse(path.clone()).ok(),
+        })
+    }
+
+    fn get_document_symbols(
+        &self,
+        params: GetDocumentSymbolsParams,
+    ) -> Result<GetDocumentSymbolsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let document_symbols = capabilities
+            .structure
+            .document_symbols
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetDocumentSymbolsResult {
+            symbols: document_symbols(parse),
+        })
+    }
+
+    fn get_folding_ranges(
+        &self,
+        params: GetFoldingRangesParams,
+    ) -> Result<GetFoldingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let folding_ranges = capabilities
+            .structure
+            .folding_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetFoldingRangesResult {
+            ranges: folding_ranges(parse),
+        })
+    }
+
+    fn get_selection_ranges(
+        &self,
+        params: GetSelectionRangesParams,
+    ) -> Result<GetSelectionRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let selection_ranges = capabilities
+            .structure
+            .selection_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path)?;
+        Ok(GetSelectionRangesResult {
+            ranges: selection_ranges(parse, &params.positions),
+        })
+    }
+
     fn rage(&self, _: RageParams) -> Result<RageResult, WorkspaceError> {
         let entries = vec![
             RageEntry::section("Workspace"),
@@ -843,18 +1115,13 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(params.path.clone())?;
 
         let document_file_source = self.get_file_source(&params.path);
-        let matches = search(
+        search(
             &params.path,
             &document_file_source,
             parse,
             &query,
             workspace,
-        )?;
-
-        Ok(SearchResults {
-            file: params.path,
-            matches,
-        })
+        )
     }
 
     fn drop_pattern(&self, params: super::DropPatternParams) -> Result<(), WorkspaceError> {
@@ -876,10 +1143,20 @@ impl Workspace for WorkspaceServer {
             .organize_imports
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let parse = self.get_parse(params.path)?;
+        let parse = self.get_parse(params.path.clone())?;
         let result = organize_imports(parse)?;
 
-        Ok(result)
+        match self.get_file_source(&params.path).to_embedding_kind() {
+            Some(embedding_kind) if !embedding_kind.is_html() => Ok(OrganizeImportsResult {
+                code: replace_script_blocks(
+                    &self.get_content(&params.path)?,
+                    embedding_kind,
+                    &params.path,
+                    &result.code,
+                )?,
+            }),
+            _ => Ok(result),
+        }
     }
 }
 
@@ -451,12 +451,14 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 19] {
+pub fn methods() -> [WorkspaceMethod; 27] {
     [
         workspace_method!(file_features),
         workspace_method!(update_settings),
         workspace_method!(register_project_folder),
         workspace_method!(set_manifest_for_project),
+        workspace_method!(update_module_graph),
+        workspace_method!(set_module_graph_complete),
         workspace_method!(open_file),
         workspace_method!(change_file),
         workspace_method!(close_file),
@@ -471,6 +473,12 @@ pub fn methods() -> [WorkspaceMethod; 19] {
         workspace_method!(format_range),
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
+        workspace_method!(transform_file),
+        workspace_method!(minify_file),
         workspace_method!(rename),
+        workspace_method!(get_symbol_references),
+        workspace_method!(get_document_symbols),
+        workspace_method!(get_folding_ranges),
+        workspace_method!(get_selection_ranges),
     ]
 }
@@ -2,13 +2,16 @@
 mod test {
     use biome_analyze::RuleCategories;
     use biome_configuration::analyzer::{RuleGroup, RuleSelector};
+    use biome_configuration::PartialConfiguration;
     use biome_fs::BiomePath;
     use biome_js_syntax::{JsFileSource, TextSize};
     use biome_service::file_handlers::DocumentFileSource;
     use biome_service::workspace::{
-        server, FileGuard, OpenFileParams, RegisterProjectFolderParams,
+        server, DocumentSymbolKind, FileGuard, FoldingRangeKind, IsModuleGraphRequiredParams,
+        OpenFileParams, RegisterProjectFolderParams, UpdateSettingsParams,
     };
     use biome_service::Workspace;
+    use std::path::Path;
     fn create_server() -> Box<dyn Workspace> {
         let workspace = server();
         workspace
@@ -243,9 +246,259 @@ type User {
                 "useDeprecatedReason",
             )],
             vec![],
+            false,
         );
         assert!(result.is_ok());
         let diagnostics = result.unwrap().diagnostics;
         assert_eq!(diagnostics.len(), 1)
     }
+
+    #[test]
+    fn pulls_the_profile_of_the_analyzer() {
+        let workspace = create_server();
+
+        let js_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: "debugger;\n".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let only = vec![RuleSelector::Rule(RuleGroup::Suspicious, "noDebugger")];
+
+        let result = js_file
+            .pull_diagnostics(RuleCategories::all(), 10, only.clone(), vec![], false)
+            .unwrap();
+        assert!(result.profile.is_none());
+
+        let profile = js_file
+            .pull_diagnostics(RuleCategories::all(), 10, only, vec![], true)
+            .unwrap()
+            .profile
+            .expect("the analyzer should be profiled");
+        assert_eq!(profile.rules["suspicious/noDebugger"].matches, 1);
+        assert!(profile.files.contains_key(Path::new("file.js")));
+    }
+
+    #[test]
+    fn pulls_document_symbols() {
+        let workspace = create_server();
+
+        let js_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: r#"const a = 1;
+class A {
+  method() {}
+}
+function f() {}"#
+                    .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let symbols = js_file.get_document_symbols().unwrap().symbols;
+        let names = symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("a", DocumentSymbolKind::Constant),
+                ("A", DocumentSymbolKind::Class),
+                ("f", DocumentSymbolKind::Function),
+            ]
+        );
+        assert_eq!(symbols[1].children.len(), 1);
+        assert_eq!(symbols[1].children[0].name, "method");
+        assert_eq!(symbols[1].children[0].kind, DocumentSymbolKind::Method);
+
+        let json_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.json"),
+                content: r#"{ "a": { "b": 1 } }"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let symbols = json_file.get_document_symbols().unwrap().symbols;
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "a");
+        assert_eq!(symbols[0].kind, DocumentSymbolKind::Object);
+        assert_eq!(symbols[0].children[0].name, "b");
+        assert_eq!(symbols[0].children[0].kind, DocumentSymbolKind::Number);
+
+        let css_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.css"),
+                content: "@media screen {\n  .a,  .b {}\n}".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let symbols = css_file.get_document_symbols().unwrap().symbols;
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "@media screen");
+        assert_eq!(symbols[0].children[0].name, ".a, .b");
+
+        let graphql_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.graphql"),
+                content: "type Query {\n  me: User\n}".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let symbols = graphql_file.get_document_symbols().unwrap().symbols;
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].name, "Query");
+        assert_eq!(symbols[0].children[0].name, "me");
+    }
+
+    #[test]
+    fn pulls_folding_ranges() {
+        let workspace = create_server();
+
+        let js_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: r#"import a from "a";
+import b from "b";
+// first
+// second
+function f() {
+  return 1;
+}"#
+                .into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let kinds = js_file
+            .get_folding_ranges()
+            .unwrap()
+            .ranges
+            .into_iter()
+            .map(|range| range.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                FoldingRangeKind::Imports,
+                FoldingRangeKind::Comment,
+                FoldingRangeKind::Block,
+            ]
+        );
+    }
+
+    #[test]
+    fn pulls_selection_ranges() {
+        let workspace = create_server();
+
+        let content = "const a = [1, 2];";
+        let js_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("file.js"),
+                content: content.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let ranges = js_file
+            .get_selection_ranges(vec![TextSize::from(14)])
+            .unwrap()
+            .ranges;
+        let texts = ranges[0]
+            .iter()
+            .map(|range| &content[*range])
+            .collect::<Vec<_>>();
+        assert_eq!(texts.first(), Some(&"2"));
+        assert!(texts.contains(&"[1, 2]"));
+        assert_eq!(texts.last(), Some(&content));
+    }
+
+    #[test]
+    fn requires_the_module_graph_for_the_rules_that_query_it() {
+        let workspace = create_server();
+        workspace
+            .update_settings(UpdateSettingsParams {
+                configuration: PartialConfiguration::init(),
+                vcs_base_path: None,
+                gitignore_matches: vec![],
+                workspace_directory: None,
+            })
+            .unwrap();
+
+        let is_module_graph_required = |only: Vec<RuleSelector>, skip: Vec<RuleSelector>| {
+            workspace
+                .is_module_graph_required(IsModuleGraphRequiredParams { only, skip })
+                .unwrap()
+        };
+        assert!(!is_module_graph_required(vec![], vec![]));
+        assert!(is_module_graph_required(
+            vec![RuleSelector::Rule(RuleGroup::Nursery, "noImportCycles")],
+            vec![]
+        ));
+        assert!(!is_module_graph_required(
+            vec![RuleSelector::Rule(RuleGroup::Nursery, "noImportCycles")],
+            vec![RuleSelector::Group(RuleGroup::Nursery)]
+        ));
+        assert!(!is_module_graph_required(
+            vec![RuleSelector::Rule(RuleGroup::Suspicious, "noDebugger")],
+            vec![]
+        ));
+    }
+
+    #[test]
+    fn does_not_report_unused_exports_of_open_files() {
+        let workspace = create_server();
+
+        let utils_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("utils.js"),
+                content: "export function used() {}\nexport function unused() {}".into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+        let _main_file = FileGuard::open(
+            workspace.as_ref(),
+            OpenFileParams {
+                path: BiomePath::new("main.js"),
+                content: r#"import { used } from "./utils.js";"#.into(),
+                version: 0,
+                document_file_source: None,
+            },
+        )
+        .unwrap();
+
+        // Only the open files are part of the module graph, the exports may be
+        // imported by the other files of the project
+        let result = utils_file.pull_diagnostics(
+            RuleCategories::all(),
+            10,
+            vec![RuleSelector::Rule(RuleGroup::Nursery, "noUnusedExports")],
+            vec![],
+            false,
+        );
+        assert_eq!(result.unwrap().diagnostics.len(), 0);
+    }
 }
@@ -25,6 +25,26 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
     /// If the comment is `// biome-ignore`
     pub is_legacy: bool,
+    /// Portion of the file this suppression applies to
+    pub scope: SuppressionScope,
+}
+
+/// Portion of the file a suppression comment applies to, selected by the
+/// suffix of the `biome-ignore` keyword
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SuppressionScope {
+    /// `// biome-ignore`: suppresses the next line
+    #[default]
+    Line,
+    /// `// biome-ignore-all`: suppresses the whole file, must be placed at
+    /// the top of the file
+    File,
+    /// `// biome-ignore-start`: suppresses everything until the matching
+    /// `// biome-ignore-end` comment
+    RangeStart,
+    /// `// biome-ignore-end`: closes the range opened by a
+    /// `// biome-ignore-start` comment with the same categories
+    RangeEnd,
 }
 
 pub fn parse_suppression_comment(
@@ -96,6 +116,7 @@ pub fn parse_suppression_comment(
         ];
 
         let mut is_legacy = false;
+        let mut scope = SuppressionScope::Line;
         // it's a biome-ignore comment
         if line.starts_with("biome-ignore") {
             // Checks for `/biome[-_]ignore/i` without a regex, or skip the line
@@ -103,6 +124,7 @@ pub fn parse_suppression_comment(
             for pattern in PATTERN {
                 line = line.strip_prefix(pattern)?;
             }
+            (scope, line) = parse_suppression_scope(line);
         } else {
             is_legacy = true;
             for pattern in DEPRECATED_PATTERNS {
@@ -115,7 +137,7 @@ pub fn parse_suppression_comment(
 
         let line = line.trim_start();
         Some(
-            parse_suppression_line(line, is_legacy).map_err(|err| SuppressionDiagnostic {
+            parse_suppression_line(line, is_legacy, scope).map_err(|err| SuppressionDiagnostic {
                 message: err.message,
                 // Adjust the position of the diagnostic in the whole comment
                 span: err.span + offset_from(base, line),
@@ -180,10 +202,39 @@ impl biome_console::fmt::Display for SuppressionDiagnosticKind {
     }
 }
 
+/// Parse the optional `-all`, `-start` or `-end` suffix following the
+/// `biome-ignore` keyword
+fn parse_suppression_scope(line: &str) -> (SuppressionScope, &str) {
+    const SUFFIXES: [(&str, SuppressionScope); 3] = [
+        ("all", SuppressionScope::File),
+        ("start", SuppressionScope::RangeStart),
+        ("end", SuppressionScope::RangeEnd),
+    ];
+
+    let Some(suffix) = line.strip_prefix(['-', '_']) else {
+        return (SuppressionScope::Line, line);
+    };
+
+    for (name, scope) in SUFFIXES {
+        let Some((head, rest)) = suffix.split_at_checked(name.len()) else {
+            continue;
+        };
+
+        if head.eq_ignore_ascii_case(name)
+            && (rest.is_empty() || rest.starts_with(|c: char| c == ':' || c.is_whitespace()))
+        {
+            return (scope, rest);
+        }
+    }
+
+    (SuppressionScope::Line, line)
+}
+
 /// Parse the `{ <category> { (<value>) }? }+: <reason>` section of a suppression line
 fn parse_suppression_line(
     base: &str,
     is_legacy: bool,
+    scope: SuppressionScope,
 ) -> Result<Suppression, SuppressionDiagnostic> {
     let mut line = base;
     let mut categories = Vec::new();
@@ -259,6 +310,7 @@ fn parse_suppression_line(
         categories,
         reason,
         is_legacy,
+        scope,
     })
 }
 
@@ -294,7 +346,7 @@ mod tests {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -303,7 +355,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -312,7 +365,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -326,7 +380,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -341,7 +396,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -352,7 +408,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -361,7 +418,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -370,7 +428,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -386,7 +445,8 @@ mod tests {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -399,7 +459,8 @@ mod tests {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -416,7 +477,8 @@ mod tests {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -434,7 +496,8 @@ mod tests {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -447,7 +510,8 @@ mod tests {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: true
+                is_legacy: true,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -517,7 +581,7 @@ mod tests_biome_ignore {
 
     use crate::{offset_from, SuppressionDiagnostic, SuppressionDiagnosticKind};
 
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionScope};
 
     #[test]
     fn parse_simple_suppression() {
@@ -526,7 +590,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation1",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -536,7 +601,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation2",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -550,7 +616,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation3",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -565,7 +632,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("parse"), None)],
                 reason: "explanation4",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -576,7 +644,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -585,7 +654,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -594,7 +664,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -610,7 +681,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("dog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -623,7 +695,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("cat"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -640,7 +713,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("frog"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
 
@@ -658,7 +732,8 @@ mod tests_biome_ignore {
                     (category!("parse"), Some("fish"))
                 ],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -671,7 +746,8 @@ mod tests_biome_ignore {
             vec![Ok(Suppression {
                 categories: vec![(category!("format"), None), (category!("lint"), None)],
                 reason: "explanation",
-                is_legacy: false
+                is_legacy: false,
+                scope: SuppressionScope::Line
             })],
         );
     }
@@ -732,4 +808,51 @@ mod tests_biome_ignore {
             })],
         );
     }
+
+    #[test]
+    fn parse_scoped_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-all lint: explanation").collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::File
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* biome-ignore-start lint/style/useWhile: explanation */")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useWhile"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::RangeStart
+            })],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("# biome-ignore-end lint/style/useWhile: explanation")
+                .collect::<Vec<_>>(),
+            vec![Ok(Suppression {
+                categories: vec![(category!("lint/style/useWhile"), None)],
+                reason: "explanation",
+                is_legacy: false,
+                scope: SuppressionScope::RangeEnd
+            })],
+        );
+    }
+
+    #[test]
+    fn diagnostic_unknown_scope() {
+        assert_eq!(
+            parse_suppression_comment("// biome-ignore-everything lint: explanation")
+                .collect::<Vec<_>>(),
+            vec![Err(SuppressionDiagnostic {
+                message: SuppressionDiagnosticKind::ParseCategory(String::from("-everything")),
+                span: TextRange::new(TextSize::from(15), TextSize::from(26))
+            })],
+        );
+    }
 }
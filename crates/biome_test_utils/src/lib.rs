@@ -312,6 +312,7 @@ pub fn write_analyzer_snapshot(
 pub fn write_transformation_snapshot(
     snapshot: &mut String,
     input_code: &str,
+    diagnostics: &[String],
     transformations: &[String],
     extension: &str,
 ) {
@@ -321,6 +322,16 @@ pub fn write_transformation_snapshot(
     writeln!(snapshot, "```").unwrap();
     writeln!(snapshot).unwrap();
 
+    if !diagnostics.is_empty() {
+        writeln!(snapshot, "# Diagnostics").unwrap();
+        for diagnostic in diagnostics {
+            writeln!(snapshot, "```").unwrap();
+            writeln!(snapshot, "{diagnostic}").unwrap();
+            writeln!(snapshot, "```").unwrap();
+            writeln!(snapshot).unwrap();
+        }
+    }
+
     if !transformations.is_empty() {
         writeln!(snapshot, "# Transformations").unwrap();
         for transformation in transformations {
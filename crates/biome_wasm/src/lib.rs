@@ -3,9 +3,11 @@ use wasm_bindgen::prelude::*;
 
 use biome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
-    FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RegisterProjectFolderParams, RenameParams, UpdateSettingsParams,
+    FormatRangeParams, GetControlFlowGraphParams, GetDocumentSymbolsParams, GetFileContentParams,
+    GetFoldingRangesParams, GetFormatterIRParams, GetSelectionRangesParams,
+    GetSymbolReferencesParams, GetSyntaxTreeParams, MinifyFileParams, OrganizeImportsParams,
+    PullActionsParams, PullDiagnosticsParams, RegisterProjectFolderParams, RenameParams,
+    TransformFileParams, UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -188,6 +190,29 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = transformFile)]
+    pub fn transform_file(
+        &self,
+        params: ITransformFileParams,
+    ) -> Result<ITransformFileResult, Error> {
+        let params: TransformFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.transform_file(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ITransformFileResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = minifyFile)]
+    pub fn minify_file(&self, params: IMinifyFileParams) -> Result<IMinifyFileResult, Error> {
+        let params: MinifyFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.minify_file(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IMinifyFileResult::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = organizeImports)]
     pub fn organize_imports(
         &self,
@@ -209,6 +234,67 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    #[wasm_bindgen(js_name = getSymbolReferences)]
+    pub fn get_symbol_references(
+        &self,
+        params: IGetSymbolReferencesParams,
+    ) -> Result<IGetSymbolReferencesResult, Error> {
+        let params: GetSymbolReferencesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_symbol_references(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetSymbolReferencesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getDocumentSymbols)]
+    pub fn get_document_symbols(
+        &self,
+        params: IGetDocumentSymbolsParams,
+    ) -> Result<IGetDocumentSymbolsResult, Error> {
+        let params: GetDocumentSymbolsParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_document_symbols(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetDocumentSymbolsResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getFoldingRanges)]
+    pub fn get_folding_ranges(
+        &self,
+        params: IGetFoldingRangesParams,
+    ) -> Result<IGetFoldingRangesResult, Error> {
+        let params: GetFoldingRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.get_folding_ranges(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGetFoldingRangesResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = getSelectionRanges)]
+    pub fn get_selection_ranges(
+        &self,
+        params: IGetSelectionRangesParams,
+    ) -> Result<IGetSelectionRangesResult, Error> {
+        let params: GetSelectionRangesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_selection_ranges(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetSelectionRangesResult::from)
+            .map_err(into_error)
+    }
 }
 
 fn to_value<T: serde::ser::Serialize + ?Sized>(
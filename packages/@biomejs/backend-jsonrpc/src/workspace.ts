@@ -25,7 +25,8 @@ export type FeatureKind =
 	| "OrganizeImports"
 	| "Search"
 	| "Assists"
-	| "Debug";
+	| "Debug"
+	| "Transform";
 export type FileKind = FileKind2[];
 /**
  * The priority of the file
@@ -146,6 +147,10 @@ export type StringSet = string[];
  * The configuration of the filesystem
  */
 export interface PartialFilesConfiguration {
+	/**
+	 * Stores the results of the processed files, and reuses them for the files that didn't change in the next runs of `check`, `ci`, `format` and `lint`. The same as passing `--cache`. Defaults to `false`
+	 */
+	cache?: boolean;
 	/**
 	 * A list of Unix shell style patterns. Biome will ignore files/folders that will match these patterns.
 	 */
@@ -241,6 +246,12 @@ export interface PartialJavascriptConfiguration {
 If defined here, they should not emit diagnostics. 
 	 */
 	globals?: StringSet;
+	/**
+	* A list of tags of the template literals that contain GraphQL, e.g. `` gql`query { user }` ``.
+
+These templates are formatted and linted as GraphQL. Defaults to `["gql", "graphql"]`. 
+	 */
+	graphqlTags?: StringSet;
 	/**
 	 * Indicates the type of runtime or transformation used for interpreting JSX.
 	 */
@@ -485,6 +496,10 @@ export interface PartialJavascriptFormatter {
 	 * Control the formatter for JavaScript (and its super languages) files.
 	 */
 	enabled?: boolean;
+	/**
+	 * Whether to format the CSS of the CSS-in-JS templates, e.g. `` styled.div`color: red;` ``. Defaults to true.
+	 */
+	formatEmbeddedCss?: boolean;
 	/**
 	 * The size of the indentation applied to JavaScript (and its super languages) files. Default to 2.
 	 */
@@ -1254,6 +1269,10 @@ export interface Nursery {
 	 * Prevent usage of \<head> element in a Next.js project.
 	 */
 	noHeadElement?: RuleConfiguration_for_Null;
+	/**
+	 * Prevent import cycles.
+	 */
+	noImportCycles?: RuleConfiguration_for_Null;
 	/**
 	 * Disallows the use of irregular whitespace characters.
 	 */
@@ -1306,6 +1325,10 @@ export interface Nursery {
 	 * Disallow unknown pseudo-element selectors.
 	 */
 	noUnknownPseudoElement?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow exports that aren't imported by any module of the project.
+	 */
+	noUnusedExports?: RuleFixConfiguration_for_NoUnusedExportsOptions;
 	/**
 	 * Disallow unnecessary escape sequence in regular expression literals.
 	 */
@@ -2004,6 +2027,9 @@ export type RuleConfiguration_for_RestrictedImportsOptions =
 export type RuleFixConfiguration_for_NoRestrictedTypesOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_NoRestrictedTypesOptions;
+export type RuleFixConfiguration_for_NoUnusedExportsOptions =
+	| RulePlainConfiguration
+	| RuleWithFixOptions_for_NoUnusedExportsOptions;
 export type RuleConfiguration_for_UseComponentExportOnlyModulesOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UseComponentExportOnlyModulesOptions;
@@ -2165,6 +2191,20 @@ export interface RuleWithFixOptions_for_NoRestrictedTypesOptions {
 	 */
 	options: NoRestrictedTypesOptions;
 }
+export interface RuleWithFixOptions_for_NoUnusedExportsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnusedExportsOptions;
+}
 export interface RuleWithOptions_for_UseComponentExportOnlyModulesOptions {
 	/**
 	 * The severity of the emitted diagnostics by the rule
@@ -2309,6 +2349,9 @@ export interface NoLabelWithoutControlOptions {
 	 */
 	labelComponents?: string[];
 }
+/**
+ * Options of the `useValidAriaRole` rule, shared by the JSX and HTML versions of the rule
+ */
 export interface ValidAriaRoleOptions {
 	allowInvalidRoles?: string[];
 	ignoreNonDom?: boolean;
@@ -2361,6 +2404,12 @@ export interface RestrictedImportsOptions {
 export interface NoRestrictedTypesOptions {
 	types?: {};
 }
+export interface NoUnusedExportsOptions {
+	/**
+	 * Paths of the modules whose exports are used outside of the project
+	 */
+	entryPoints: string[];
+}
 export interface UseComponentExportOnlyModulesOptions {
 	/**
 	 * Allows the export of constants. This option is for environments that support it, such as [Vite](https://vitejs.dev/)
@@ -2591,6 +2640,15 @@ export interface SetManifestForProjectParams {
 	manifest_path: BiomePath;
 	version: number;
 }
+export interface UpdateModuleGraphParams {
+	/**
+	 * The path of a JavaScript module, a `package.json` or a `tsconfig.json`
+	 */
+	path: BiomePath;
+	update_kind: UpdateKind;
+}
+export type UpdateKind = { AddOrUpdate: { content: string } } | "Remove";
+export interface SetModuleGraphCompleteParams {}
 export interface OpenFileParams {
 	content: string;
 	document_file_source?: DocumentFileSource;
@@ -2620,6 +2678,10 @@ export interface JsonFileSource {
 	allow_trailing_commas: boolean;
 }
 export interface CssFileSource {
+	/**
+	 * Used to mark if the source is the `<style>` block of an Astro, HTML, Svelte or Vue file
+	 */
+	embedding_kind: EmbeddingKind;
 	variant: CssVariant;
 }
 export interface GraphqlFileSource {
@@ -2631,7 +2693,10 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
-export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
+/**
+ * The kind of document a source is embedded in, e.g. the `<script>` or `<style>` block of an Astro, HTML, Svelte or Vue file
+ */
+export type EmbeddingKind = "Astro" | "Html" | "Svelte" | "Vue" | "None";
 export type Language =
 	| "JavaScript"
 	| { TypeScript: { definition_file: boolean } };
@@ -2695,6 +2760,10 @@ export interface PullDiagnosticsParams {
 	max_diagnostics: number;
 	only: RuleCode[];
 	path: BiomePath;
+	/**
+	 * Measures the time spent by the analyzer, and returns it in [PullDiagnosticsResult::profile]
+	 */
+	profile?: boolean;
 	skip: RuleCode[];
 }
 export type RuleCategories = RuleCategory[];
@@ -2703,6 +2772,10 @@ export type RuleCategory = "Syntax" | "Lint" | "Action" | "Transformation";
 export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
 	errors: number;
+	/**
+	 * The time spent by the analyzer, when [PullDiagnosticsParams::profile] is enabled
+	 */
+	profile?: Profile;
 	skipped_diagnostics: number;
 }
 /**
@@ -2719,6 +2792,19 @@ export interface Diagnostic {
 	tags: DiagnosticTags;
 	verboseAdvices: Advices;
 }
+/**
+ * The measurements collected by [Profiler::take]
+ */
+export interface Profile {
+	/**
+	 * The time spent by the analyzer on each file
+	 */
+	files: {};
+	/**
+	 * The rules that were executed, by name, e.g. `suspicious/noDebugger`
+	 */
+	rules: {};
+}
 /**
  * Implementation of [Visitor] collecting serializable [Advice] into a vector.
  */
@@ -2858,6 +2944,7 @@ export type Category =
 	| "lint/nursery/noDynamicNamespaceImportAccess"
 	| "lint/nursery/noEnum"
 	| "lint/nursery/noExportedImports"
+	| "lint/nursery/noImportCycles"
 	| "lint/nursery/noImportantInKeyframe"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
 	| "lint/nursery/noInvalidGridAreas"
@@ -2887,6 +2974,7 @@ export type Category =
 	| "lint/nursery/noUnknownSelectorPseudoElement"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnusedExports"
 	| "lint/nursery/noUnusedFunctionParameters"
 	| "lint/nursery/noUselessEscapeInRegex"
 	| "lint/nursery/noValueAtRule"
@@ -3043,9 +3131,12 @@ export type Category =
 	| "organizeImports"
 	| "assists"
 	| "migrate"
+	| "minify"
 	| "deserialize"
 	| "project"
 	| "search"
+	| "transform"
+	| "baseline"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
@@ -3064,6 +3155,7 @@ export type Category =
 	| "suppressions/unknownRule"
 	| "suppressions/unused"
 	| "suppressions/deprecatedSuppressionComment"
+	| "suppressions/incorrect"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -3248,14 +3340,25 @@ export interface FixFileParams {
 	fix_file_mode: FixFileMode;
 	only: RuleCode[];
 	path: BiomePath;
+	/**
+	 * Measures the time spent by the analyzer, and returns it in [FixFileResult::profile]
+	 */
+	profile?: boolean;
 	rule_categories: RuleCategories;
 	should_format: boolean;
 	skip: RuleCode[];
+	/**
+	 * The reason written in the suppression comments, when using [FixFileMode::ApplySuppressions]
+	 */
+	suppression_reason?: string;
 }
 /**
  * Which fixes should be applied during the analyzing phase
  */
-export type FixFileMode = "SafeFixes" | "SafeAndUnsafeFixes";
+export type FixFileMode =
+	| "SafeFixes"
+	| "SafeAndUnsafeFixes"
+	| "ApplySuppressions";
 export interface FixFileResult {
 	/**
 	 * List of all the code actions applied to the file
@@ -3265,10 +3368,18 @@ export interface FixFileResult {
 	 * New source code for the file with all fixes applied
 	 */
 	code: string;
+	/**
+	 * Diagnostics emitted while applying the fixes, like the diagnostics that can't be suppressed
+	 */
+	diagnostics: Diagnostic[];
 	/**
 	 * Number of errors
 	 */
 	errors: number;
+	/**
+	 * The time spent by the analyzer, when [FixFileParams::profile] is enabled
+	 */
+	profile?: Profile;
 	/**
 	 * number of skipped suggested fixes
 	 */
@@ -3284,6 +3395,36 @@ export interface FixAction {
 	 */
 	rule_name?: [string, string];
 }
+export interface TransformFileParams {
+	path: BiomePath;
+	/**
+	 * The names of the transformations to apply, e.g. `transformEnum`. All the transformations are applied when it's empty.
+	 */
+	transformations: string[];
+}
+export interface TransformFileResult {
+	/**
+	 * List of all the transformations applied to the file
+	 */
+	actions: FixAction[];
+	/**
+	 * New source code for the file with all the transformations applied
+	 */
+	code: string;
+	/**
+	 * The diagnostics emitted by the transformations, e.g. for the code that couldn't be transformed
+	 */
+	diagnostics: Diagnostic[];
+}
+export interface MinifyFileParams {
+	path: BiomePath;
+}
+export interface MinifyFileResult {
+	/**
+	 * The minified source code of the file
+	 */
+	code: string;
+}
 export interface RenameParams {
 	new_name: string;
 	path: BiomePath;
@@ -3299,6 +3440,117 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface GetSymbolReferencesParams {
+	path: BiomePath;
+	symbol_at: TextSize;
+}
+export interface GetSymbolReferencesResult {
+	/**
+	 * Range of the binding that declares the symbol, `None` if the symbol isn't declared in the file
+	 */
+	declaration?: TextRange;
+	/**
+	 * When the symbol is imported with a relative import of a module that isn't open in the workspace, the paths where the module can be, in order of precedence. Once the module is open, the import can be resolved.
+	 */
+	import_candidates: BiomePath[];
+	/**
+	 * Location of the declaration in the imported module, when the symbol is imported with a relative import that can be resolved
+	 */
+	imported_declaration?: SymbolLocation;
+	/**
+	 * List of the references to the symbol in the file
+	 */
+	references: SymbolReference[];
+}
+export interface SymbolLocation {
+	path: BiomePath;
+	/**
+	 * Range of the declaration, empty at the start of the file when the module doesn't export the symbol
+	 */
+	range: TextRange;
+}
+export interface SymbolReference {
+	/**
+	 * Whether the reference writes the symbol, like an assignment
+	 */
+	is_write: boolean;
+	range: TextRange;
+}
+export interface GetDocumentSymbolsParams {
+	path: BiomePath;
+}
+export interface GetDocumentSymbolsResult {
+	/**
+	 * The symbols declared at the top level of the document
+	 */
+	symbols: DocumentSymbol[];
+}
+export interface DocumentSymbol {
+	/**
+	 * The symbols declared inside this symbol, like the methods of a class
+	 */
+	children: DocumentSymbol[];
+	kind: DocumentSymbolKind;
+	name: string;
+	/**
+	 * Range of the whole declaration of the symbol
+	 */
+	range: TextRange;
+	/**
+	 * Range of the name of the symbol
+	 */
+	selection_range: TextRange;
+}
+/**
+ * The kind of a symbol, following the symbol kinds of the Language Server Protocol
+ */
+export type DocumentSymbolKind =
+	| "Module"
+	| "Namespace"
+	| "Class"
+	| "Method"
+	| "Property"
+	| "Field"
+	| "Constructor"
+	| "Enum"
+	| "Interface"
+	| "Function"
+	| "Variable"
+	| "Constant"
+	| "String"
+	| "Number"
+	| "Boolean"
+	| "Array"
+	| "Object"
+	| "Null"
+	| "EnumMember"
+	| "Struct"
+	| "Operator"
+	| "TypeParameter";
+export interface GetFoldingRangesParams {
+	path: BiomePath;
+}
+export interface GetFoldingRangesResult {
+	ranges: FoldingRange[];
+}
+export interface FoldingRange {
+	kind: FoldingRangeKind;
+	/**
+	 * Range of the region, from its opening delimiter to its closing delimiter when it has some
+	 */
+	range: TextRange;
+}
+export type FoldingRangeKind = "Block" | "Comment" | "Imports";
+export interface GetSelectionRangesParams {
+	path: BiomePath;
+	positions: TextSize[];
+}
+export interface GetSelectionRangesResult {
+	/**
+	 * For each position, the ranges of the syntax elements that contain it, from the innermost to the outermost
+	 */
+	ranges: TextRange[][];
+}
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<FileFeaturesResult>;
@@ -3307,6 +3559,8 @@ export interface Workspace {
 		params: RegisterProjectFolderParams,
 	): Promise<ProjectKey>;
 	setManifestForProject(params: SetManifestForProjectParams): Promise<void>;
+	updateModuleGraph(params: UpdateModuleGraphParams): Promise<void>;
+	setModuleGraphComplete(params: SetModuleGraphCompleteParams): Promise<void>;
 	openFile(params: OpenFileParams): Promise<void>;
 	changeFile(params: ChangeFileParams): Promise<void>;
 	closeFile(params: CloseFileParams): Promise<void>;
@@ -3325,7 +3579,21 @@ export interface Workspace {
 	formatRange(params: FormatRangeParams): Promise<Printed>;
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
+	transformFile(params: TransformFileParams): Promise<TransformFileResult>;
+	minifyFile(params: MinifyFileParams): Promise<MinifyFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	getSymbolReferences(
+		params: GetSymbolReferencesParams,
+	): Promise<GetSymbolReferencesResult>;
+	getDocumentSymbols(
+		params: GetDocumentSymbolsParams,
+	): Promise<GetDocumentSymbolsResult>;
+	getFoldingRanges(
+		params: GetFoldingRangesParams,
+	): Promise<GetFoldingRangesResult>;
+	getSelectionRanges(
+		params: GetSelectionRangesParams,
+	): Promise<GetSelectionRangesResult>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -3342,6 +3610,12 @@ export function createWorkspace(transport: Transport): Workspace {
 		setManifestForProject(params) {
 			return transport.request("biome/set_manifest_for_project", params);
 		},
+		updateModuleGraph(params) {
+			return transport.request("biome/update_module_graph", params);
+		},
+		setModuleGraphComplete(params) {
+			return transport.request("biome/set_module_graph_complete", params);
+		},
 		openFile(params) {
 			return transport.request("biome/open_file", params);
 		},
@@ -3384,9 +3658,27 @@ export function createWorkspace(transport: Transport): Workspace {
 		fixFile(params) {
 			return transport.request("biome/fix_file", params);
 		},
+		transformFile(params) {
+			return transport.request("biome/transform_file", params);
+		},
+		minifyFile(params) {
+			return transport.request("biome/minify_file", params);
+		},
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		getSymbolReferences(params) {
+			return transport.request("biome/get_symbol_references", params);
+		},
+		getDocumentSymbols(params) {
+			return transport.request("biome/get_document_symbols", params);
+		},
+		getFoldingRanges(params) {
+			return transport.request("biome/get_folding_ranges", params);
+		},
+		getSelectionRanges(params) {
+			return transport.request("biome/get_selection_ranges", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
@@ -3,6 +3,7 @@ use biome_analyze::{
 };
 use biome_css_syntax::CssLanguage;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use biome_string_case::Case;
@@ -95,6 +96,27 @@ impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        // The HTML rules ported from JSX share their configuration with the original rules
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .entry(R::METADATA.name)
+            .or_insert(R::METADATA);
+    }
+}
+
 // ======= ASSISTS ======
 #[derive(Default)]
 struct AssistsRulesVisitor {
@@ -176,6 +198,27 @@ impl RegistryVisitor<GraphqlLanguage> for AssistsRulesVisitor {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for AssistsRulesVisitor {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Action) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        // The HTML rules ported from JSX share their configuration with the original rules
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .entry(R::METADATA.name)
+            .or_insert(R::METADATA);
+    }
+}
+
 pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     let linter_config_root = project_root().join("crates/biome_configuration/src/analyzer/linter");
     let assists_config_root =
@@ -192,6 +235,8 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     biome_css_analyze::visit_registry(&mut assists_visitor);
     biome_graphql_analyze::visit_registry(&mut lint_visitor);
     biome_graphql_analyze::visit_registry(&mut assists_visitor);
+    biome_html_analyze::visit_registry(&mut lint_visitor);
+    biome_html_analyze::visit_registry(&mut assists_visitor);
 
     // let LintRulesVisitor { groups } = lint_visitor;
 
@@ -734,6 +779,9 @@ fn generate_group_struct(
             "graphql" => quote! {
                 biome_graphql_analyze::options::#rule_name
             },
+            "html" => quote! {
+                biome_html_analyze::options::#rule_name
+            },
             "json" => quote! {
                 biome_json_analyze::options::#rule_name
             },
@@ -12,6 +12,7 @@ use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage};
 use biome_json_parser::JsonParserOptions;
@@ -19,6 +20,7 @@ use biome_json_syntax::JsonLanguage;
 use biome_service::settings::WorkspaceSettings;
 use biome_service::workspace::DocumentFileSource;
 use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt::Write;
 use std::path::PathBuf;
@@ -107,11 +109,28 @@ pub fn check_rules() -> anyhow::Result<()> {
         }
     }
 
+    impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+                + 'static,
+        {
+            self.push_rule::<R, <R::Query as Queryable>::Language>()
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
     biome_js_analyze::visit_registry(&mut visitor);
     biome_json_analyze::visit_registry(&mut visitor);
     biome_css_analyze::visit_registry(&mut visitor);
     biome_graphql_analyze::visit_registry(&mut visitor);
+    biome_html_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups } = visitor;
 
@@ -131,6 +150,10 @@ struct CodeBlockTest {
 
 impl CodeBlockTest {
     fn document_file_source(&self) -> DocumentFileSource {
+        // The workspace only recognizes HTML files with the `experimental-html` feature
+        if self.tag == "html" {
+            return HtmlFileSource::html().into();
+        }
         DocumentFileSource::from_extension(&self.tag)
     }
 }
@@ -239,8 +262,9 @@ fn assert_lint(
                     biome_service::file_handlers::VueFileHandler::input(code),
                     biome_service::file_handlers::VueFileHandler::file_source(code),
                 ),
-                _ => (code, file_source),
+                _ => (Cow::Borrowed(code), file_source),
             };
+            let code = &*code;
 
             let parse = biome_js_parser::parse(code, file_source, JsParserOptions::default());
 
@@ -264,8 +288,68 @@ fn assert_lint(
                         ..Default::default()
                     },
                     file_path: PathBuf::from(&file_path),
+                    ..Default::default()
                 };
-                biome_js_analyze::analyze(&root, filter, &options, file_source, None, |signal| {
+                biome_js_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    file_source,
+                    None,
+                    Default::default(),
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            let category = diag.category().expect("linter diagnostic has no code");
+                            let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
+                                "If you see this error, it means you need to run cargo codegen-configuration",
+                            );
+
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diag
+                                .with_severity(severity)
+                                .with_file_path(&file_path)
+                                .with_file_source_code(code);
+                            let res = write_diagnostic(code, error);
+
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
+                        }
+
+                        ControlFlow::Continue(())
+                    },
+                );
+            }
+        }
+        DocumentFileSource::Json(file_source) => {
+            let parse = biome_json_parser::parse_json(code, JsonParserOptions::from(&file_source));
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                    write_diagnostic(code, error)?;
+                }
+            } else {
+                let root = parse.tree();
+
+                let rule_filter = RuleFilter::Rule(group, rule);
+                let filter = AnalysisFilter {
+                    enabled_rules: Some(slice::from_ref(&rule_filter)),
+                    ..AnalysisFilter::default()
+                };
+
+                let options = AnalyzerOptions {
+                    file_path: PathBuf::from(&file_path),
+                    ..Default::default()
+                };
+                biome_json_analyze::analyze(&root, filter, &options, file_source, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -295,8 +379,8 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Json(file_source) => {
-            let parse = biome_json_parser::parse_json(code, JsonParserOptions::from(&file_source));
+        DocumentFileSource::Css(file_source) => {
+            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -316,7 +400,7 @@ fn assert_lint(
                     file_path: PathBuf::from(&file_path),
                     ..Default::default()
                 };
-                biome_json_analyze::analyze(&root, filter, &options, file_source, |signal| {
+                biome_css_analyze::analyze(&root, filter, &options, file_source, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -346,8 +430,8 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Css(..) => {
-            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+        DocumentFileSource::Graphql(..) => {
+            let parse = biome_graphql_parser::parse_graphql(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -367,12 +451,12 @@ fn assert_lint(
                     file_path: PathBuf::from(&file_path),
                     ..Default::default()
                 };
-                biome_css_analyze::analyze(&root, filter, &options, |signal| {
+                biome_graphql_analyze::analyze(&root, filter, &options, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
-                                "If you see this error, it means you need to run cargo codegen-configuration",
-                            );
+                            "If you see this error, it means you need to run cargo codegen-configuration",
+                        );
 
                         for action in signal.actions() {
                             if !action.is_suppression() {
@@ -397,8 +481,8 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Graphql(..) => {
-            let parse = biome_graphql_parser::parse_graphql(code);
+        DocumentFileSource::Html(..) => {
+            let parse = biome_html_parser::parse_html(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -418,7 +502,7 @@ fn assert_lint(
                     file_path: PathBuf::from(&file_path),
                     ..Default::default()
                 };
-                biome_graphql_analyze::analyze(&root, filter, &options, |signal| {
+                biome_html_analyze::analyze(&root, filter, &options, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         let category = diag.category().expect("linter diagnostic has no code");
                         let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
@@ -448,7 +532,6 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Html(..) => todo!("HTML analysis is not yet supported"),
         DocumentFileSource::Grit(..) => todo!("Grit analysis is not yet supported"),
 
         // Unknown code blocks should be ignored by tests